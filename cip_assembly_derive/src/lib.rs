@@ -0,0 +1,291 @@
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::{parse_macro_input, Attribute, Data, DeriveInput, Field, Fields, Ident, LitInt, Type};
+
+/// Derives `eipscanne_rs::cip::assembly::CipAssembly`, along with `BinRead` and `BinWrite`
+///
+/// Every field declares where it lives in the assembly data:
+///
+/// - `#[cip(byte = 4)]` places a value (`u8`, `i16`, `f32`, arrays, ...) at a byte offset
+/// - `#[cip(byte = 2, bit = 5)]` places a `bool` at a single bit of a byte
+///
+/// A derived struct is itself an `AssemblyValue`, so it can be placed at a byte offset of a larger
+/// assembly to nest the layouts.
+///
+/// The assembly size is the end of the last field unless the struct sets it with
+/// `#[cip(size = 48)]`, e.g. to account for trailing padding. Fields that overlap or that do not
+/// fit in the size fail to compile.
+#[proc_macro_derive(CipAssembly, attributes(cip))]
+pub fn derive_cip_assembly(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    expand_cip_assembly(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+struct FieldLayout {
+    ident: Ident,
+    ty: Type,
+    byte_offset: usize,
+    bit_offset: Option<u8>,
+}
+
+fn parse_struct_size(attrs: &[Attribute]) -> syn::Result<Option<usize>> {
+    let mut size = None;
+
+    for attr in attrs.iter().filter(|attr| attr.path().is_ident("cip")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("size") {
+                size = Some(meta.value()?.parse::<LitInt>()?.base10_parse()?);
+                Ok(())
+            } else {
+                Err(meta.error("expected `size = ...`"))
+            }
+        })?;
+    }
+
+    Ok(size)
+}
+
+fn is_bool(ty: &Type) -> bool {
+    matches!(ty, Type::Path(type_path) if type_path.path.is_ident("bool"))
+}
+
+fn parse_field_layout(field: &Field) -> syn::Result<FieldLayout> {
+    let ident = field
+        .ident
+        .clone()
+        .ok_or_else(|| syn::Error::new_spanned(field, "assembly fields must be named"))?;
+
+    let mut byte_offset = None;
+    let mut bit_offset = None;
+
+    for attr in field
+        .attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("cip"))
+    {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("byte") {
+                byte_offset = Some(meta.value()?.parse::<LitInt>()?.base10_parse()?);
+                Ok(())
+            } else if meta.path.is_ident("bit") {
+                let bit = meta.value()?.parse::<LitInt>()?;
+                let bit_value: u8 = bit.base10_parse()?;
+                if bit_value > 7 {
+                    return Err(syn::Error::new_spanned(bit, "bit offsets are 0 to 7"));
+                }
+                bit_offset = Some(bit_value);
+                Ok(())
+            } else {
+                Err(meta.error("expected `byte = ...` or `bit = ...`"))
+            }
+        })?;
+    }
+
+    let byte_offset = byte_offset.ok_or_else(|| {
+        syn::Error::new_spanned(&ident, "missing `#[cip(byte = ...)]` for the field offset")
+    })?;
+
+    if bit_offset.is_some() && !is_bool(&field.ty) {
+        return Err(syn::Error::new_spanned(
+            &field.ty,
+            "only `bool` fields can be placed at a bit offset",
+        ));
+    }
+
+    Ok(FieldLayout {
+        ident,
+        ty: field.ty.clone(),
+        byte_offset,
+        bit_offset,
+    })
+}
+
+fn expand_cip_assembly(input: DeriveInput) -> syn::Result<TokenStream2> {
+    let name = &input.ident;
+
+    if !input.generics.params.is_empty() {
+        return Err(syn::Error::new_spanned(
+            &input.generics,
+            "assembly structs cannot be generic",
+        ));
+    }
+
+    let fields = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => &fields.named,
+            _ => {
+                return Err(syn::Error::new_spanned(
+                    name,
+                    "assembly structs must have named fields",
+                ))
+            }
+        },
+        _ => {
+            return Err(syn::Error::new_spanned(
+                name,
+                "only structs can be assembly layouts",
+            ))
+        }
+    };
+
+    let layouts = fields
+        .iter()
+        .map(parse_field_layout)
+        .collect::<syn::Result<Vec<_>>>()?;
+
+    let assembly = quote!(::eipscanne_rs::cip::assembly);
+
+    let size = match parse_struct_size(&input.attrs)? {
+        Some(size) => quote!(#size),
+        None => quote!(#assembly::layout_size(Self::LAYOUT)),
+    };
+
+    let layout_entries = layouts.iter().map(|layout| {
+        let field_name = layout.ident.to_string();
+        let byte_offset = layout.byte_offset;
+        let ty = &layout.ty;
+
+        match layout.bit_offset {
+            Some(bit_offset) => quote! {
+                #assembly::AssemblyField {
+                    name: #field_name,
+                    cip_type: "BOOL",
+                    byte_offset: #byte_offset,
+                    bit_offset: Some(#bit_offset),
+                    size: 1,
+                }
+            },
+            None => quote! {
+                #assembly::AssemblyField {
+                    name: #field_name,
+                    cip_type: <#ty as #assembly::AssemblyValue>::CIP_TYPE,
+                    byte_offset: #byte_offset,
+                    bit_offset: None,
+                    size: <#ty as #assembly::AssemblyValue>::SIZE,
+                }
+            },
+        }
+    });
+
+    let field_reads = layouts.iter().map(|layout| {
+        let ident = &layout.ident;
+        let byte_offset = layout.byte_offset;
+        let ty = &layout.ty;
+
+        match layout.bit_offset {
+            Some(bit_offset) => quote! {
+                #ident: data[#byte_offset] & (1 << #bit_offset) != 0
+            },
+            None => quote! {
+                #ident: <#ty as #assembly::AssemblyValue>::read_from(
+                    &data[#byte_offset..#byte_offset + <#ty as #assembly::AssemblyValue>::SIZE],
+                )
+            },
+        }
+    });
+
+    let field_writes = layouts.iter().map(|layout| {
+        let ident = &layout.ident;
+        let byte_offset = layout.byte_offset;
+        let ty = &layout.ty;
+
+        match layout.bit_offset {
+            Some(bit_offset) => quote! {
+                if self.#ident {
+                    data[#byte_offset] |= 1 << #bit_offset;
+                }
+            },
+            None => quote! {
+                #assembly::AssemblyValue::write_to(
+                    &self.#ident,
+                    &mut data[#byte_offset..#byte_offset + <#ty as #assembly::AssemblyValue>::SIZE],
+                );
+            },
+        }
+    });
+
+    let layout_checks = layouts.iter().enumerate().map(|(index, layout)| {
+        let fits_message = format!("`{}` does not fit in the assembly size", layout.ident);
+        let overlap_message = format!("`{}` overlaps an earlier field", layout.ident);
+
+        quote! {
+            assert!(
+                layout[#index].byte_offset + layout[#index].size <= size,
+                #fits_message
+            );
+            assert!(
+                !#assembly::overlaps_any(&layout[#index], layout.split_at(#index).0),
+                #overlap_message
+            );
+        }
+    });
+
+    Ok(quote! {
+        impl #assembly::CipAssembly for #name {
+            const SIZE: usize = #size;
+            const LAYOUT: &'static [#assembly::AssemblyField] = &[#(#layout_entries),*];
+
+            fn read_fields(data: &[u8]) -> Self {
+                #name {
+                    #(#field_reads),*
+                }
+            }
+
+            fn write_fields(&self, data: &mut [u8]) {
+                #(#field_writes)*
+            }
+        }
+
+        impl #assembly::AssemblyValue for #name {
+            const CIP_TYPE: &'static str = "STRUCT";
+            const SIZE: usize = <Self as #assembly::CipAssembly>::SIZE;
+
+            fn read_from(bytes: &[u8]) -> Self {
+                <Self as #assembly::CipAssembly>::read_fields(bytes)
+            }
+
+            fn write_to(&self, bytes: &mut [u8]) {
+                #assembly::CipAssembly::write_fields(self, bytes)
+            }
+        }
+
+        const _: () = {
+            let layout = <#name as #assembly::CipAssembly>::LAYOUT;
+            let size = <#name as #assembly::CipAssembly>::SIZE;
+            #(#layout_checks)*
+        };
+
+        impl ::binrw::BinRead for #name {
+            type Args<'a> = ();
+
+            fn read_options<R: ::binrw::io::Read + ::binrw::io::Seek>(
+                reader: &mut R,
+                _endian: ::binrw::Endian,
+                _args: Self::Args<'_>,
+            ) -> ::binrw::BinResult<Self> {
+                let mut data = vec![0; <Self as #assembly::CipAssembly>::SIZE];
+                reader.read_exact(&mut data)?;
+
+                <Self as #assembly::CipAssembly>::decode(&data)
+            }
+        }
+
+        impl ::binrw::BinWrite for #name {
+            type Args<'a> = ();
+
+            fn write_options<W: ::binrw::io::Write + ::binrw::io::Seek>(
+                &self,
+                writer: &mut W,
+                _endian: ::binrw::Endian,
+                _args: Self::Args<'_>,
+            ) -> ::binrw::BinResult<()> {
+                writer.write_all(&#assembly::CipAssembly::encode(self))?;
+                Ok(())
+            }
+        }
+    })
+}
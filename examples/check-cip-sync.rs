@@ -0,0 +1,104 @@
+use std::net::{Ipv4Addr, SocketAddr, SocketAddrV4};
+
+use clap::Parser;
+use tokio::net::TcpStream;
+
+use eipscanne_rs::cip::time_sync::{common_grandmaster, TimeSyncRead, TimeSyncStatus};
+use eipscanne_rs::object_assembly::RequestObjectAssembly;
+
+mod stream_utils;
+
+const ETHERNET_IP_PORT: u16 = 0xAF12;
+
+/// Checks that every device is synchronized to the same CIP Sync grandmaster
+#[derive(Parser)]
+#[command(version, about)]
+struct Cli {
+    /// The IP addresses of the devices
+    #[arg(required = true)]
+    addresses: Vec<Ipv4Addr>,
+}
+
+async fn read_time_sync(address: Ipv4Addr) -> Result<TimeSyncStatus, Box<dyn std::error::Error>> {
+    let mut stream =
+        TcpStream::connect(SocketAddr::V4(SocketAddrV4::new(address, ETHERNET_IP_PORT))).await?;
+
+    // ========= Register the session ============
+    stream_utils::write_object_assembly(&mut stream, RequestObjectAssembly::new_registration())
+        .await;
+    let registration_response = stream_utils::read_object_assembly(&mut stream).await?;
+
+    let session_handle = registration_response
+        .packet_description
+        .header
+        .session_handle;
+    // ^^^^^^^^^ Register the session ^^^^^^^^^^^^
+
+    // ========= Read the Time Sync object ============
+    let mut time_sync_read = TimeSyncRead::new();
+    while let Some(time_sync_request) = time_sync_read.next_request() {
+        stream_utils::write_object_assembly(
+            &mut stream,
+            RequestObjectAssembly::new_router_request(session_handle, time_sync_request),
+        )
+        .await;
+
+        let time_sync_response = stream_utils::read_object_assembly(&mut stream).await?;
+        let Some(router_response) = time_sync_response.cip_message else {
+            return Err("No CIP response to the Time Sync request".into());
+        };
+
+        time_sync_read.handle_response(&router_response)?;
+    }
+    // ^^^^^^^^^ Read the Time Sync object ^^^^^^^^^^^^
+
+    // ========= UnRegister the sesion ============
+    stream_utils::write_object_assembly(
+        &mut stream,
+        RequestObjectAssembly::new_unregistration(session_handle),
+    )
+    .await;
+    // ^^^^^^^^^ UnRegister the session ^^^^^^^^^^^^
+
+    time_sync_read
+        .into_status()
+        .ok_or_else(|| "The Time Sync read did not complete".into())
+}
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let cli = Cli::parse();
+
+    let mut statuses = Vec::new();
+    for address in cli.addresses.iter() {
+        println!("REQUESTING Time Sync of {}", address);
+        match read_time_sync(*address).await {
+            Ok(status) => statuses.push((*address, status)),
+            Err(error) => println!("  --> {} could not be read: {}", address, error),
+        }
+    }
+
+    let Some(grandmaster) = common_grandmaster(statuses.iter().map(|(_, status)| status)) else {
+        return Err("No device is synchronized to a grandmaster".into());
+    };
+    println!("\nGrandmaster: {}", grandmaster);
+
+    let mut locked_count = 0;
+    for (address, status) in statuses.iter() {
+        match status.sync_problem(&grandmaster) {
+            Some(problem) => println!("  --> {} {}", address, problem),
+            None => {
+                locked_count += 1;
+                println!(
+                    "  --> {} is locked, {} ns from its master",
+                    address,
+                    status.offset_from_master.unwrap_or_default()
+                );
+            }
+        }
+    }
+
+    println!("{} of {} devices are locked", locked_count, cli.addresses.len());
+
+    Ok(())
+}
@@ -0,0 +1,143 @@
+use std::net::{Ipv4Addr, SocketAddr, SocketAddrV4};
+
+use clap::Parser;
+use tokio::net::TcpStream;
+
+use eipscanne_rs::cip::connection_manager::{
+    ConnectionManagerRead, ConnectionManagerStatus, UnconnectedSend,
+};
+use eipscanne_rs::cip::path::RoutePath;
+use eipscanne_rs::object_assembly::RequestObjectAssembly;
+
+mod stream_utils;
+
+const ETHERNET_IP_PORT: u16 = 0xAF12;
+
+/// Reads the Connection Manager counters and connection table of a device
+#[derive(Parser)]
+#[command(version, about)]
+struct Cli {
+    /// The IP address of the device we talk to
+    address: Ipv4Addr,
+
+    /// The route to a device behind the one we talk to, e.g. "backplane, slot 0" for a controller
+    #[arg(long)]
+    route: Option<String>,
+}
+
+fn format_counter<T: ToString>(counter: Option<T>) -> String {
+    counter.map_or("unsupported".to_string(), |counter| counter.to_string())
+}
+
+fn print_status(status: &ConnectionManagerStatus) {
+    println!(
+        "  --> Open requests: {}",
+        format_counter(status.open_requests)
+    );
+    println!(
+        "  --> Open rejects: {} format, {} resource, {} other",
+        format_counter(status.open_format_rejects),
+        format_counter(status.open_resource_rejects),
+        format_counter(status.open_other_rejects)
+    );
+    println!(
+        "  --> Close requests: {}",
+        format_counter(status.close_requests)
+    );
+    println!(
+        "  --> Connection timeouts: {}",
+        format_counter(status.connection_timeouts)
+    );
+
+    if let Some(entries) = &status.connection_entries {
+        println!(
+            "  --> Connection entries: {} of {} free",
+            entries.free_entries(),
+            entries.entry_count
+        );
+    }
+
+    for connection in status.connections.iter() {
+        println!(
+            "      #{} {:?} from vendor {} serial 0x{:08X}, RPI {}/{} us",
+            connection.connection_number,
+            connection.connection_state,
+            connection.originator_vendor_id,
+            connection.originator_serial_number,
+            connection.originator_o_t_rpi,
+            connection.originator_t_o_rpi
+        );
+    }
+
+    if status.is_exhausted() {
+        println!("\nEvery connection entry is in use, new connections will be refused");
+    } else if status.has_resource_rejects() {
+        println!("\nThe device has refused connections for lack of resources since power up");
+    }
+}
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let cli = Cli::parse();
+
+    let unconnected_send = match &cli.route {
+        Some(route) => Some(UnconnectedSend::new(RoutePath::from_route(route)?)),
+        None => None,
+    };
+
+    let mut stream = TcpStream::connect(SocketAddr::V4(SocketAddrV4::new(
+        cli.address,
+        ETHERNET_IP_PORT,
+    )))
+    .await?;
+
+    // ========= Register the session ============
+    stream_utils::write_object_assembly(&mut stream, RequestObjectAssembly::new_registration())
+        .await;
+    let registration_response = stream_utils::read_object_assembly(&mut stream).await?;
+
+    let session_handle = registration_response
+        .packet_description
+        .header
+        .session_handle;
+    // ^^^^^^^^^ Register the session ^^^^^^^^^^^^
+
+    // ========= Read the Connection Manager ============
+    println!("REQUESTING the Connection Manager of {}", cli.address);
+    let mut connection_manager_read = ConnectionManagerRead::new();
+    while let Some(request) = connection_manager_read.next_request() {
+        let service = request.service_container.service();
+        let request_object = match &unconnected_send {
+            Some(unconnected_send) => {
+                RequestObjectAssembly::new_routed_request(session_handle, unconnected_send, request)
+            }
+            None => RequestObjectAssembly::new_router_request(session_handle, request),
+        };
+        stream_utils::write_object_assembly(&mut stream, request_object).await;
+
+        let response = stream_utils::read_object_assembly(&mut stream).await?;
+        let Some(mut router_response) = response.cip_message else {
+            return Err("No CIP response to the Connection Manager request".into());
+        };
+        if unconnected_send.is_some() {
+            router_response = UnconnectedSend::unwrap_response(router_response, service)?;
+        }
+
+        connection_manager_read.handle_response(&router_response)?;
+    }
+    // ^^^^^^^^^ Read the Connection Manager ^^^^^^^^^^^^
+
+    if let Some(status) = connection_manager_read.into_status() {
+        print_status(&status);
+    }
+
+    // ========= UnRegister the sesion ============
+    stream_utils::write_object_assembly(
+        &mut stream,
+        RequestObjectAssembly::new_unregistration(session_handle),
+    )
+    .await;
+    // ^^^^^^^^^ UnRegister the session ^^^^^^^^^^^^
+
+    Ok(())
+}
@@ -0,0 +1,149 @@
+use std::net::{Ipv4Addr, SocketAddr, SocketAddrV4};
+
+use clap::Parser;
+use tokio::net::TcpStream;
+
+use eipscanne_rs::cip::dlr::{DlrRead, DlrStatus};
+use eipscanne_rs::cip::message::request::MessageRouterRequest;
+use eipscanne_rs::cip::message::response::MessageRouterResponse;
+use eipscanne_rs::cip::qos::{QosRead, QosSettings};
+use eipscanne_rs::object_assembly::RequestObjectAssembly;
+
+mod stream_utils;
+
+const ETHERNET_IP_PORT: u16 = 0xAF12;
+
+/// Reads the Device Level Ring and QoS objects of every node of a ring
+#[derive(Parser)]
+#[command(version, about)]
+struct Cli {
+    /// The IP addresses of the ring nodes
+    #[arg(required = true)]
+    addresses: Vec<Ipv4Addr>,
+}
+
+async fn exchange(
+    stream: &mut TcpStream,
+    session_handle: u32,
+    request: MessageRouterRequest,
+) -> Result<MessageRouterResponse, Box<dyn std::error::Error>> {
+    stream_utils::write_object_assembly(
+        stream,
+        RequestObjectAssembly::new_router_request(session_handle, request),
+    )
+    .await;
+
+    let response = stream_utils::read_object_assembly(stream).await?;
+    response
+        .cip_message
+        .ok_or_else(|| "No CIP response to the request".into())
+}
+
+async fn read_node(
+    address: Ipv4Addr,
+) -> Result<(DlrStatus, QosSettings), Box<dyn std::error::Error>> {
+    let mut stream =
+        TcpStream::connect(SocketAddr::V4(SocketAddrV4::new(address, ETHERNET_IP_PORT))).await?;
+
+    // ========= Register the session ============
+    stream_utils::write_object_assembly(&mut stream, RequestObjectAssembly::new_registration())
+        .await;
+    let registration_response = stream_utils::read_object_assembly(&mut stream).await?;
+
+    let session_handle = registration_response
+        .packet_description
+        .header
+        .session_handle;
+    // ^^^^^^^^^ Register the session ^^^^^^^^^^^^
+
+    // ========= Read the DLR and QoS objects ============
+    let mut dlr_read = DlrRead::new();
+    while let Some(dlr_request) = dlr_read.next_request() {
+        let response = exchange(&mut stream, session_handle, dlr_request).await?;
+        dlr_read.handle_response(&response)?;
+    }
+
+    let mut qos_read = QosRead::new();
+    while let Some(qos_request) = qos_read.next_request() {
+        let response = exchange(&mut stream, session_handle, qos_request).await?;
+        qos_read.handle_response(&response)?;
+    }
+    // ^^^^^^^^^ Read the DLR and QoS objects ^^^^^^^^^^^^
+
+    // ========= UnRegister the sesion ============
+    stream_utils::write_object_assembly(
+        &mut stream,
+        RequestObjectAssembly::new_unregistration(session_handle),
+    )
+    .await;
+    // ^^^^^^^^^ UnRegister the session ^^^^^^^^^^^^
+
+    match (dlr_read.into_status(), qos_read.into_settings()) {
+        (Some(dlr_status), Some(qos_settings)) => Ok((dlr_status, qos_settings)),
+        _ => Err("The DLR or QoS read did not complete".into()),
+    }
+}
+
+fn print_node(address: Ipv4Addr, dlr_status: &DlrStatus, qos_settings: &QosSettings) {
+    println!(
+        "  --> {}: {:?} {:?} as {:?}, {} ring faults",
+        address,
+        dlr_status.network_topology,
+        dlr_status.network_status,
+        dlr_status.supervisor_status,
+        dlr_status
+            .ring_faults_count
+            .map_or("unknown".to_string(), |count| count.to_string())
+    );
+
+    if let Some(active_supervisor) = dlr_status.active_supervisor {
+        println!("      Active supervisor: {}", active_supervisor);
+    }
+    if let Some((port_1_node, port_2_node)) = dlr_status.fault_location() {
+        println!("      Ring broken between {} and {}", port_1_node, port_2_node);
+    }
+    if let Some(participants) = &dlr_status.participants {
+        for participant in participants.iter() {
+            println!("      Participant: {}", participant);
+        }
+    }
+
+    println!("      802.1Q tagging: {:?}", qos_settings.tag_enable);
+    for (traffic_class, dscp) in qos_settings.non_default_dscp() {
+        println!(
+            "      DSCP {:?}: {} instead of {}",
+            traffic_class,
+            dscp,
+            traffic_class.default_dscp()
+        );
+    }
+}
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let cli = Cli::parse();
+
+    let mut supervisors = Vec::new();
+    for address in cli.addresses.iter() {
+        println!("REQUESTING DLR and QoS of {}", address);
+        match read_node(*address).await {
+            Ok((dlr_status, qos_settings)) => {
+                print_node(*address, &dlr_status, &qos_settings);
+                supervisors.extend(dlr_status.active_supervisor);
+            }
+            Err(error) => println!("  --> {} could not be read: {}", address, error),
+        }
+    }
+
+    // Every node of a healthy ring follows the same supervisor
+    supervisors.sort_by_key(|supervisor| supervisor.ip_address);
+    supervisors.dedup();
+    if supervisors.len() > 1 {
+        println!("\nThe nodes disagree on the active supervisor:");
+        for supervisor in supervisors.iter() {
+            println!("  --> {}", supervisor);
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,33 @@
+use std::path::PathBuf;
+
+use clap::Parser;
+
+use eipscanne_rs::eds::codegen::generate_rust;
+use eipscanne_rs::eds::file::EdsFile;
+
+/// Generates Rust types for the assemblies and parameters described by an EDS file
+#[derive(Parser)]
+#[command(version, about)]
+struct Cli {
+    /// The EDS file of the device
+    eds_path: PathBuf,
+
+    /// Writes the generated code to a file instead of stdout
+    #[arg(long, short)]
+    output: Option<PathBuf>,
+}
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let cli = Cli::parse();
+
+    let eds_text = std::fs::read_to_string(&cli.eds_path)?;
+    let eds = EdsFile::parse(&eds_text)?;
+
+    let code = generate_rust(&eds);
+    match cli.output {
+        Some(output_path) => std::fs::write(output_path, code)?,
+        None => print!("{}", code),
+    }
+
+    Ok(())
+}
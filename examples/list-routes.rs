@@ -0,0 +1,122 @@
+use std::net::{Ipv4Addr, SocketAddr, SocketAddrV4};
+use std::time::Duration;
+
+use clap::Parser;
+use tokio::net::TcpStream;
+
+use eipscanne_rs::cip::path::RoutePath;
+use eipscanne_rs::cip::port::{DiscoveredRoutes, RouteDiscovery};
+use eipscanne_rs::object_assembly::RequestObjectAssembly;
+
+mod stream_utils;
+
+const ETHERNET_IP_PORT: u16 = 0xAF12;
+
+/// Lists the backplane modules and bridged networks reachable through a device
+#[derive(Parser)]
+#[command(version, about)]
+struct Cli {
+    /// The IP address of the device we talk to, e.g. an Ethernet bridge module
+    address: Ipv4Addr,
+
+    /// How long to wait for a module in a slot, in milliseconds
+    #[arg(long, default_value_t = 1000)]
+    slot_timeout: u64,
+}
+
+/// Formats the route the way `RoutePath::from_route()` parses it
+fn format_route(route: &RoutePath) -> String {
+    if route.segments.is_empty() {
+        return "(local)".to_string();
+    }
+
+    route
+        .segments
+        .iter()
+        .map(|segment| match segment.link_address.as_slice() {
+            [slot] => format!("{},{}", segment.port, slot),
+            address => format!("{},{}", segment.port, String::from_utf8_lossy(address)),
+        })
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+fn print_routes(routes: &DiscoveredRoutes) {
+    println!("Devices:");
+    for device in routes.devices.iter() {
+        println!(
+            "  --> {} {}",
+            format_route(&device.route),
+            device
+                .product_name
+                .as_deref()
+                .unwrap_or("(no product name)")
+        );
+    }
+
+    println!("Bridged networks:");
+    for network in routes.networks.iter() {
+        println!(
+            "  --> {} port {} -- {:?} {}",
+            format_route(&network.route),
+            network.port.number(),
+            network.port.port_type,
+            network.port.name.as_deref().unwrap_or("")
+        );
+    }
+}
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let cli = Cli::parse();
+
+    let mut stream = TcpStream::connect(SocketAddr::V4(SocketAddrV4::new(
+        cli.address,
+        ETHERNET_IP_PORT,
+    )))
+    .await?;
+
+    // ========= Register the session ============
+    stream_utils::write_object_assembly(&mut stream, RequestObjectAssembly::new_registration())
+        .await;
+    let registration_response = stream_utils::read_object_assembly(&mut stream).await?;
+
+    let session_handle = registration_response
+        .packet_description
+        .header
+        .session_handle;
+    // ^^^^^^^^^ Register the session ^^^^^^^^^^^^
+
+    // ========= Walk the ports ============
+    println!("DISCOVERING routes through {}", cli.address);
+    let mut discovery = RouteDiscovery::new().with_timeout(Duration::from_millis(cli.slot_timeout));
+    while let Some(request) = discovery.next_request() {
+        stream_utils::write_object_assembly(
+            &mut stream,
+            RequestObjectAssembly::new_router_request(session_handle, request),
+        )
+        .await;
+
+        let response = stream_utils::read_object_assembly(&mut stream).await?;
+        let Some(router_response) = response.cip_message else {
+            return Err("No CIP response to the discovery request".into());
+        };
+
+        discovery.handle_response(&router_response)?;
+    }
+    // ^^^^^^^^^ Walk the ports ^^^^^^^^^^^^
+
+    if let Some(routes) = discovery.into_routes() {
+        print_routes(&routes);
+    }
+
+    // ========= UnRegister the sesion ============
+    stream_utils::write_object_assembly(
+        &mut stream,
+        RequestObjectAssembly::new_unregistration(session_handle),
+    )
+    .await;
+    // ^^^^^^^^^ UnRegister the session ^^^^^^^^^^^^
+
+    Ok(())
+}
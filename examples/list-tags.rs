@@ -0,0 +1,106 @@
+use std::net::{Ipv4Addr, SocketAddr, SocketAddrV4};
+
+use tokio::net::TcpStream;
+
+use eipscanne_rs::logix::symbol::{SymbolBrowse, SymbolEntry};
+use eipscanne_rs::object_assembly::RequestObjectAssembly;
+
+mod stream_utils;
+
+const ETHERNET_IP_PORT: u16 = 0xAF12;
+
+async fn browse_symbols(
+    stream: &mut TcpStream,
+    session_handle: u32,
+    mut symbol_browse: SymbolBrowse,
+) -> Result<Vec<SymbolEntry>, Box<dyn std::error::Error>> {
+    while let Some(browse_request) = symbol_browse.next_request() {
+        stream_utils::write_object_assembly(
+            stream,
+            RequestObjectAssembly::new_router_request(session_handle, browse_request),
+        )
+        .await;
+
+        let browse_response = stream_utils::read_object_assembly(stream).await?;
+        let Some(router_response) = browse_response.cip_message else {
+            return Err("No CIP response to the symbol list request".into());
+        };
+
+        symbol_browse.handle_response(&router_response)?;
+    }
+
+    Ok(symbol_browse.into_symbols())
+}
+
+fn print_symbol(scope: &str, symbol: &SymbolEntry) {
+    println!(
+        "  {}{} -- {:?} {:?} (instance {})",
+        scope,
+        symbol.name(),
+        symbol.symbol_type.data_type(),
+        symbol.dimensions(),
+        symbol.instance_id
+    );
+}
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    // Change the SocketAddr to match the Logix controller
+    let address = SocketAddr::V4(SocketAddrV4::new(Ipv4Addr::LOCALHOST, ETHERNET_IP_PORT));
+
+    let mut stream = TcpStream::connect(address).await?;
+
+    // ========= Register the session ============
+    println!("REQUESTING registration");
+    stream_utils::write_object_assembly(&mut stream, RequestObjectAssembly::new_registration())
+        .await;
+    let registration_response = stream_utils::read_object_assembly(&mut stream).await?;
+
+    let provided_session_handle = registration_response
+        .packet_description
+        .header
+        .session_handle;
+    // ^^^^^^^^^ Register the session ^^^^^^^^^^^^
+
+    // ========= Browse the controller and program tags ============
+    println!("REQUESTING controller tags");
+    let controller_symbols = browse_symbols(
+        &mut stream,
+        provided_session_handle,
+        SymbolBrowse::new_controller_scope(),
+    )
+    .await?;
+
+    for symbol in controller_symbols.iter() {
+        print_symbol("", symbol);
+    }
+
+    for program_name in controller_symbols.iter().filter_map(|symbol| symbol.program_name()) {
+        println!("REQUESTING {} tags", program_name);
+        let program_symbols = browse_symbols(
+            &mut stream,
+            provided_session_handle,
+            SymbolBrowse::new_program_scope(&program_name),
+        )
+        .await?;
+
+        let scope = format!("Program:{}.", program_name);
+        for symbol in program_symbols.iter() {
+            print_symbol(&scope, symbol);
+        }
+    }
+    // ^^^^^^^^^ Browse the controller and program tags ^^^^^^^^^^^^
+
+    // ========= UnRegister the sesion ============
+    println!("REQUESTING un-registration");
+    stream_utils::write_object_assembly(
+        &mut stream,
+        RequestObjectAssembly::new_unregistration(provided_session_handle),
+    )
+    .await;
+
+    println!("UN Registered the CIP session");
+    // ^^^^^^^^^ UnRegister the session ^^^^^^^^^^^^
+
+    Ok(())
+}
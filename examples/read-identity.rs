@@ -50,6 +50,10 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     // println!("{:#?}\n", identity_response_object);      // NOTE: the :#? triggers a pretty-print
     println!("{:?}\n", identity_response_object);
 
+    println!(
+        "  --> Device: {}, {}",
+        identity_response.vendor_id, identity_response.device_type
+    );
     println!(
         "  --> Product Name: {:?}\n",
         String::from(identity_response.product_name)
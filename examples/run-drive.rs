@@ -0,0 +1,157 @@
+use std::net::{Ipv4Addr, SocketAddr, SocketAddrV4};
+
+use clap::{Parser, Subcommand};
+use tokio::net::TcpStream;
+
+use eipscanne_rs::cip::drive::{Drive, DriveStatus};
+use eipscanne_rs::cip::message::request::MessageRouterRequest;
+use eipscanne_rs::cip::message::response::MessageRouterResponse;
+use eipscanne_rs::object_assembly::RequestObjectAssembly;
+
+mod stream_utils;
+
+const ETHERNET_IP_PORT: u16 = 0xAF12;
+
+/// Starts, stops and reads any drive that implements the CIP drive profile
+#[derive(Parser)]
+#[command(version, about)]
+struct Cli {
+    /// The IP address of the drive
+    address: Ipv4Addr,
+
+    #[command(subcommand)]
+    command: Option<Command>,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Takes network control and runs the drive forward at the speed
+    Start {
+        /// The speed in RPM
+        rpm: f64,
+    },
+    Stop,
+    /// Resets a drive fault
+    Reset,
+}
+
+async fn exchange(
+    stream: &mut TcpStream,
+    session_handle: u32,
+    request: MessageRouterRequest,
+) -> Result<MessageRouterResponse, Box<dyn std::error::Error>> {
+    stream_utils::write_object_assembly(
+        stream,
+        RequestObjectAssembly::new_router_request(session_handle, request),
+    )
+    .await;
+
+    let response = stream_utils::read_object_assembly(stream).await?;
+    response
+        .cip_message
+        .ok_or_else(|| "No CIP response to the request".into())
+}
+
+/// Sends the requests of a drive command in order, stopping at the first one the drive refuses
+async fn send_command(
+    stream: &mut TcpStream,
+    session_handle: u32,
+    requests: Vec<MessageRouterRequest>,
+) -> Result<(), Box<dyn std::error::Error>> {
+    for request in requests {
+        exchange(stream, session_handle, request)
+            .await?
+            .check_status()?;
+    }
+
+    Ok(())
+}
+
+async fn read_status(
+    stream: &mut TcpStream,
+    session_handle: u32,
+) -> Result<DriveStatus, Box<dyn std::error::Error>> {
+    let mut status_read = Drive::new().status_read();
+    while let Some(request) = status_read.next_request() {
+        let response = exchange(stream, session_handle, request).await?;
+        status_read.handle_response(&response)?;
+    }
+
+    status_read
+        .into_status()
+        .ok_or_else(|| "The drive status read did not complete".into())
+}
+
+fn print_status(status: &DriveStatus) {
+    println!(
+        "  --> {:?}, running: {}, faulted: {} (fault code {:?})",
+        status.state,
+        status.is_running(),
+        status.is_faulted(),
+        status.fault_code
+    );
+    println!(
+        "  --> Speed: {:?} RPM of {:?} RPM, current: {:?} A",
+        status.speed_actual_rpm(),
+        status.speed_reference_rpm(),
+        status.current_actual_amps()
+    );
+}
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let cli = Cli::parse();
+
+    let mut stream = TcpStream::connect(SocketAddr::V4(SocketAddrV4::new(
+        cli.address,
+        ETHERNET_IP_PORT,
+    )))
+    .await?;
+
+    // ========= Register the session ============
+    stream_utils::write_object_assembly(&mut stream, RequestObjectAssembly::new_registration())
+        .await;
+    let registration_response = stream_utils::read_object_assembly(&mut stream).await?;
+
+    let session_handle = registration_response
+        .packet_description
+        .header
+        .session_handle;
+    // ^^^^^^^^^ Register the session ^^^^^^^^^^^^
+
+    // ========= Command the drive ============
+    let status = read_status(&mut stream, session_handle).await?;
+    let drive = Drive::from_status(&status);
+
+    match cli.command {
+        Some(Command::Start { rpm }) => {
+            println!("STARTING the drive at {} RPM", rpm);
+            let mut requests = drive.take_network_control();
+            requests.push(drive.set_speed_request(rpm)?);
+            requests.extend(drive.start_forward());
+            send_command(&mut stream, session_handle, requests).await?;
+        }
+        Some(Command::Stop) => {
+            println!("STOPPING the drive");
+            send_command(&mut stream, session_handle, drive.stop()).await?;
+        }
+        Some(Command::Reset) => {
+            println!("RESETTING the drive fault");
+            send_command(&mut stream, session_handle, drive.reset_fault()).await?;
+        }
+        None => (),
+    }
+
+    print_status(&read_status(&mut stream, session_handle).await?);
+    // ^^^^^^^^^ Command the drive ^^^^^^^^^^^^
+
+    // ========= UnRegister the sesion ============
+    stream_utils::write_object_assembly(
+        &mut stream,
+        RequestObjectAssembly::new_unregistration(session_handle),
+    )
+    .await;
+    // ^^^^^^^^^ UnRegister the session ^^^^^^^^^^^^
+
+    Ok(())
+}
@@ -35,6 +35,7 @@ pub async fn read_object_assembly(
     ResponseObjectAssembly::read(&mut response_reader)
 }
 
+#[allow(dead_code)]
 pub async fn read_typed_object_assembly<T>(
     stream: &mut TcpStream,
 ) -> Result<(ResponseObjectAssembly, T), binrw::Error>
@@ -0,0 +1,101 @@
+use std::net::{Ipv4Addr, SocketAddr, SocketAddrV4};
+use std::path::PathBuf;
+
+use clap::Parser;
+use tokio::net::TcpStream;
+
+use eipscanne_rs::cip::file::{FileUpload, EDS_FILE_INSTANCE};
+use eipscanne_rs::eds::file::EdsFile;
+use eipscanne_rs::object_assembly::RequestObjectAssembly;
+
+mod stream_utils;
+
+const ETHERNET_IP_PORT: u16 = 0xAF12;
+
+/// Uploads the EDS stored in the File object of a device
+#[derive(Parser)]
+#[command(version, about)]
+struct Cli {
+    /// Where to save the EDS
+    #[arg(long, short, default_value = "device.eds")]
+    output: PathBuf,
+}
+
+async fn upload(
+    stream: &mut TcpStream,
+    session_handle: u32,
+    mut file_upload: FileUpload,
+) -> Result<Vec<u8>, Box<dyn std::error::Error>> {
+    while let Some(upload_request) = file_upload.next_request() {
+        stream_utils::write_object_assembly(
+            stream,
+            RequestObjectAssembly::new_router_request(session_handle, upload_request),
+        )
+        .await;
+
+        let upload_response = stream_utils::read_object_assembly(stream).await?;
+        let Some(router_response) = upload_response.cip_message else {
+            return Err("No CIP response to the file upload request".into());
+        };
+
+        file_upload.handle_response(&router_response)?;
+    }
+
+    file_upload
+        .into_data()
+        .ok_or_else(|| "The file upload did not complete".into())
+}
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let cli = Cli::parse();
+
+    // Change the SocketAddr to match the Ethernet/IP Adapter
+    let address = SocketAddr::V4(SocketAddrV4::new(Ipv4Addr::LOCALHOST, ETHERNET_IP_PORT));
+
+    let mut stream = TcpStream::connect(address).await?;
+
+    // ========= Register the session ============
+    println!("REQUESTING registration");
+    stream_utils::write_object_assembly(&mut stream, RequestObjectAssembly::new_registration())
+        .await;
+    let registration_response = stream_utils::read_object_assembly(&mut stream).await?;
+
+    let provided_session_handle = registration_response
+        .packet_description
+        .header
+        .session_handle;
+    // ^^^^^^^^^ Register the session ^^^^^^^^^^^^
+
+    // ========= Upload the EDS ============
+    println!("REQUESTING the EDS file");
+    let eds_data = upload(
+        &mut stream,
+        provided_session_handle,
+        FileUpload::new(EDS_FILE_INSTANCE),
+    )
+    .await?;
+
+    std::fs::write(&cli.output, &eds_data)?;
+    println!("  --> Saved {} bytes to {:?}", eds_data.len(), cli.output);
+
+    // Devices may store the EDS compressed, which is left to an external tool
+    match EdsFile::parse(&String::from_utf8_lossy(&eds_data)) {
+        Ok(eds) => println!("  --> Product Name: {:?}", eds.device.product_name),
+        Err(error) => println!("  --> Not a plain text EDS: {}", error),
+    }
+    // ^^^^^^^^^ Upload the EDS ^^^^^^^^^^^^
+
+    // ========= UnRegister the sesion ============
+    println!("REQUESTING un-registration");
+    stream_utils::write_object_assembly(
+        &mut stream,
+        RequestObjectAssembly::new_unregistration(provided_session_handle),
+    )
+    .await;
+
+    println!("UN Registered the CIP session");
+    // ^^^^^^^^^ UnRegister the session ^^^^^^^^^^^^
+
+    Ok(())
+}
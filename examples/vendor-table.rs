@@ -0,0 +1,111 @@
+use std::collections::BTreeMap;
+use std::fmt::Write;
+use std::path::PathBuf;
+
+use clap::Parser;
+
+/// Generates the vendor name table of `eipscanne_rs::cip::registry` from the ODVA vendor list
+///
+/// Reads Wireshark's `epan/dissectors/packet-cip.c`, which keeps the ODVA vendor list in
+/// `cip_vendor_vals`, or any file with one `{ id, "name" }` or `(id, "name")` entry per line.
+#[derive(Parser)]
+#[command(version, about)]
+struct Cli {
+    /// The file holding the vendor list
+    source_path: PathBuf,
+
+    /// Writes the generated table to a file instead of stdout
+    #[arg(long, short)]
+    output: Option<PathBuf>,
+}
+
+/// The part of the source holding the vendor list, all of it unless it is `packet-cip.c`
+fn vendor_list(source: &str) -> &str {
+    let Some(start) = source.find("cip_vendor_vals[]") else {
+        return source;
+    };
+
+    let list = &source[start..];
+    match list.find("};") {
+        Some(end) => &list[..end],
+        None => list,
+    }
+}
+
+fn parse_id(id: &str) -> Option<u16> {
+    match id.strip_prefix("0x").or_else(|| id.strip_prefix("0X")) {
+        Some(hex_id) => u16::from_str_radix(hex_id, 16).ok(),
+        None => id.parse().ok(),
+    }
+}
+
+/// The text of a C or Rust string literal up to its closing quote
+fn parse_name(quoted_name: &str) -> Option<String> {
+    let mut name = String::new();
+    let mut characters = quoted_name.strip_prefix('"')?.chars();
+
+    loop {
+        match characters.next()? {
+            '"' => return Some(name),
+            '\\' => name.push(characters.next()?),
+            character => name.push(character),
+        }
+    }
+}
+
+/// An entry like `{ 424, "Teknic, Inc." },`
+fn parse_entry(line: &str) -> Option<(u16, String)> {
+    let entry = line.trim_start().strip_prefix(['{', '('])?;
+    let (id, quoted_name) = entry.split_once(',')?;
+
+    Some((parse_id(id.trim())?, parse_name(quoted_name.trim_start())?))
+}
+
+fn generate_table(source: &str) -> String {
+    // Sorted for the binary search of the lookup, the first name wins for a repeated ID
+    let mut vendors = BTreeMap::new();
+    for (id, name) in vendor_list(source).lines().filter_map(parse_entry) {
+        if id != 0 && name != "Reserved" {
+            vendors.entry(id).or_insert(name);
+        }
+    }
+
+    let mut code = String::new();
+    writeln!(
+        code,
+        "// Generated by `cargo run --example vendor-table`, see examples/README.md to regenerate it\n"
+    )
+    .unwrap();
+    writeln!(
+        code,
+        "/// Names of the ODVA registered vendor IDs, sorted by ID"
+    )
+    .unwrap();
+    writeln!(code, "///").unwrap();
+    writeln!(
+        code,
+        "/// IDs missing from the table are reported as unknown rather than guessed at."
+    )
+    .unwrap();
+    writeln!(code, "pub static VENDOR_NAMES: &[(u16, &str)] = &[").unwrap();
+    for (id, name) in &vendors {
+        writeln!(code, "    ({}, {:?}),", id, name).unwrap();
+    }
+    writeln!(code, "];").unwrap();
+
+    code
+}
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let cli = Cli::parse();
+
+    let source = std::fs::read_to_string(&cli.source_path)?;
+
+    let code = generate_table(&source);
+    match cli.output {
+        Some(output_path) => std::fs::write(output_path, code)?,
+        None => print!("{}", code),
+    }
+
+    Ok(())
+}
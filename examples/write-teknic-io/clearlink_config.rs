@@ -1,63 +1,90 @@
-use binrw::{binrw, BinRead, BinWrite};
-
-use bilge::prelude::{bitsize, DebugBits, FromBits, Number};
-use bilge::Bitsized;
+use eipscanne_rs::cip::assembly::{AssemblyValue, CipAssembly};
 use eipscanne_rs::cip::types::{CipBool, CipDint, CipDword, CipSint, CipUdint, CipUint, CipUsint};
 
-#[binrw]
-#[brw(little)]
-#[derive(Debug, PartialEq)]
-#[repr(u8)] // CipUsint
+// https://www.teknic.com/files/downloads/clearlink_ethernet-ip_object_reference.pdf#page=18
+
+/// Places a one byte setting enum in an assembly, keeping unknown values in `Other`
+macro_rules! impl_setting_value {
+    ($setting:ident { $($variant:ident = $value:literal),* $(,)? }) => {
+        impl AssemblyValue for $setting {
+            const CIP_TYPE: &'static str = "USINT";
+            const SIZE: usize = 1;
+
+            fn read_from(bytes: &[u8]) -> Self {
+                match bytes[0] {
+                    $($value => $setting::$variant,)*
+                    other => $setting::Other(other),
+                }
+            }
+
+            fn write_to(&self, bytes: &mut [u8]) {
+                bytes[0] = match self {
+                    $($setting::$variant => $value,)*
+                    $setting::Other(other) => *other,
+                };
+            }
+        }
+    };
+}
+
+#[derive(Debug, PartialEq, Clone, Copy)]
 pub enum AnalogInputRange {
-    #[brw(magic = 2u8)]
     ZeroToTenVolts,
-
-    #[brw(magic = 100u8)]
     AsDigitalInput,
+    Other(CipUsint),
 }
 
-#[binrw]
-#[brw(little)]
-#[derive(Debug, PartialEq)]
-#[repr(u8)] // CipUsint
+impl_setting_value!(AnalogInputRange {
+    ZeroToTenVolts = 2,
+    AsDigitalInput = 100,
+});
+
+#[derive(Debug, PartialEq, Clone, Copy)]
 pub enum AnalogOutputRange {
-    #[brw(magic = 0u8)]
     FourToTwentyMilliamps,
-
-    #[brw(magic = 2u8)]
     ZeroToTwentyMilliamps,
-
-    #[brw(magic = 100u8)]
     AsDigitalOutput,
+    Other(CipUsint),
 }
 
-#[binrw]
-#[brw(little)]
-#[derive(Debug, PartialEq)]
-#[repr(u8)] // CipBool
+impl_setting_value!(AnalogOutputRange {
+    FourToTwentyMilliamps = 0,
+    ZeroToTwentyMilliamps = 2,
+    AsDigitalOutput = 100,
+});
+
+#[derive(Debug, PartialEq, Clone, Copy)]
 pub enum PWMFrequency {
-    #[brw(magic = 0u8)]
     FiveHundredHz,
-
-    #[brw(magic = 1u8)]
     EightKiloHz,
+    Other(CipUsint),
 }
 
-#[binrw]
-#[brw(little)]
-#[derive(Debug, PartialEq)]
+impl_setting_value!(PWMFrequency {
+    FiveHundredHz = 0,
+    EightKiloHz = 1,
+});
+
+#[derive(CipAssembly, Debug, PartialEq)]
+#[cip(size = 8)]
 pub struct IOModeConfigData {
+    #[cip(byte = 0)]
     ai0_range: AnalogInputRange,
+    #[cip(byte = 1)]
     ai1_range: AnalogInputRange,
+    #[cip(byte = 2)]
     ai2_range: AnalogInputRange,
+    #[cip(byte = 3)]
     ai3_range: AnalogInputRange,
+    #[cip(byte = 4)]
     ao0_range: AnalogOutputRange,
+    #[cip(byte = 5)]
     dop_pwm_frequency: PWMFrequency,
-    #[brw(pad_after = 1)]
+    #[cip(byte = 6)]
     ccio_enable: CipBool,
 }
 
-// ======= Start of FiltersConfig impl ========
+// ======= Start of IOModeConfigData impl ========
 
 impl IOModeConfigData {
     fn default() -> Self {
@@ -73,14 +100,15 @@ impl IOModeConfigData {
     }
 }
 
-// ^^^^^^^ End of IOFiltersConfigData impl ^^^^^^^^
+// ^^^^^^^ End of IOModeConfigData impl ^^^^^^^^
 
-#[binrw]
-#[brw(little)]
-#[derive(Debug, PartialEq)]
+#[derive(CipAssembly, Debug, PartialEq)]
 pub struct IOFiltersConfigData {
+    #[cip(byte = 0)]
     aip_filters: [CipUsint; 4],
+    #[cip(byte = 4)]
     dip_filters: [CipUint; 26],
+    #[cip(byte = 56)]
     ccio_filters: [CipUsint; 8],
 }
 
@@ -98,12 +126,12 @@ impl IOFiltersConfigData {
 
 // ^^^^^^^ End of IOFiltersConfigData impl ^^^^^^^^
 
-#[binrw]
-#[brw(little)]
-#[derive(Debug, PartialEq)]
+#[derive(CipAssembly, Debug, PartialEq)]
+#[cip(size = 8)]
 pub struct EncoderConfigData {
+    #[cip(byte = 0)]
     encoder_velocity_resolution: CipUdint,
-    #[brw(pad_after = 3)]
+    #[cip(byte = 4)]
     reserved_set_byte: CipUsint,
 }
 
@@ -120,45 +148,59 @@ impl EncoderConfigData {
 
 // ^^^^^^^ End of EncoderConfigData impl ^^^^^^^^
 
-#[bitsize(32)]
-#[derive(FromBits, PartialEq, DebugBits, BinRead, BinWrite, Copy, Clone)]
-#[br(map = u32::into)]
-#[bw(map = |&x| u32::from(x))]
-pub struct ConfigRegisterData {
-    homing_enable: bool,                 // = bit 0
-    home_sensor_active_level: bool,      // bit = 1,
-    enable_inversion: bool,              // bit = 2,
-    hlfb_inversion: bool,                // bit = 3, // NOTE: The default if HIGH
-    position_capture_active_level: bool, // bit = 4,
-    software_limit_enable: bool,         // bit = 5,
-    _padding: [bool; 26],                // bits 6-31
-}
-#[binrw]
-#[brw(little)]
-#[derive(Debug, PartialEq)]
+#[derive(CipAssembly, Debug, PartialEq)]
+#[cip(size = 32)]
 pub struct MotorConfigData {
-    config_register: ConfigRegisterData,
+    // The config register bits, 6-31 are reserved
+    #[cip(byte = 0, bit = 0)]
+    homing_enable: bool,
+    #[cip(byte = 0, bit = 1)]
+    home_sensor_active_level: bool,
+    #[cip(byte = 0, bit = 2)]
+    enable_inversion: bool,
+    #[cip(byte = 0, bit = 3)]
+    hlfb_inversion: bool, // NOTE: The default if HIGH
+    #[cip(byte = 0, bit = 4)]
+    position_capture_active_level: bool,
+    #[cip(byte = 0, bit = 5)]
+    software_limit_enable: bool,
+    #[cip(byte = 4)]
     follow_divisor: CipDint,
+    #[cip(byte = 8)]
     follow_multiplier: CipDint,
+    #[cip(byte = 12)]
     max_deceleration: CipDint,
+    #[cip(byte = 16)]
     soft_limit_position1: CipDint,
+    #[cip(byte = 20)]
     soft_limit_position2: CipDint,
+    #[cip(byte = 24)]
     positive_limit_connector: CipSint,
+    #[cip(byte = 25)]
     negative_limit_connector: CipSint,
+    #[cip(byte = 26)]
     home_sensor_connector: CipSint,
+    #[cip(byte = 27)]
     brake_output_connector: CipSint,
+    #[cip(byte = 28)]
     stop_sensor_connector: CipSint,
+    #[cip(byte = 29)]
     trigger_position_capture_connector: CipSint,
-    #[brw(pad_after = 1)]
+    #[cip(byte = 30)]
     follow_axis: CipSint,
 }
 
-// ======= Start of EncoderConfigData impl ========
+// ======= Start of MotorConfigData impl ========
 
 impl MotorConfigData {
     fn default() -> Self {
         Self {
-            config_register: ConfigRegisterData::new(false, false, false, true, false, false),
+            homing_enable: false,
+            home_sensor_active_level: false,
+            enable_inversion: false,
+            hlfb_inversion: true,
+            position_capture_active_level: false,
+            software_limit_enable: false,
             follow_divisor: 1,
             follow_multiplier: 1,
             max_deceleration: 10000000,
@@ -175,17 +217,21 @@ impl MotorConfigData {
     }
 }
 
-// ^^^^^^^ End of EncoderConfigData impl ^^^^^^^^
+// ^^^^^^^ End of MotorConfigData impl ^^^^^^^^
 
-#[binrw]
-#[brw(little)]
-#[derive(Debug, PartialEq)]
+#[derive(CipAssembly, Debug, PartialEq)]
 pub struct SerialAsciiConfigData {
+    #[cip(byte = 0)]
     serial_baud_rate: CipUdint,
+    #[cip(byte = 4)]
     input_start_delimiter: CipDword,
+    #[cip(byte = 8)]
     input_end_delimiter: CipDword,
+    #[cip(byte = 12)]
     output_start_delimiter: CipDword,
+    #[cip(byte = 16)]
     output_end_delimiter: CipDword,
+    #[cip(byte = 20)]
     input_timeout: CipUdint,
 }
 
@@ -206,17 +252,23 @@ impl SerialAsciiConfigData {
 
 // ^^^^^^^ End of SerialAsciiConfigData impl ^^^^^^^^
 
-#[binrw]
-#[brw(little)]
-#[derive(Debug, PartialEq)]
+#[derive(CipAssembly, Debug, PartialEq)]
 pub struct ConfigAssemblyObject {
+    #[cip(byte = 0)]
     io_mode_config_data: IOModeConfigData,
+    #[cip(byte = 8)]
     io_filters_config_data: IOFiltersConfigData,
+    #[cip(byte = 72)]
     encoder_config_data: EncoderConfigData,
+    #[cip(byte = 80)]
     motor0_config_data: MotorConfigData,
+    #[cip(byte = 112)]
     motor1_config_data: MotorConfigData,
+    #[cip(byte = 144)]
     motor2_config_data: MotorConfigData,
+    #[cip(byte = 176)]
     motor3_config_data: MotorConfigData,
+    #[cip(byte = 208)]
     serial_ascii_config_data: SerialAsciiConfigData,
 }
 
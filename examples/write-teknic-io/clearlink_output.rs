@@ -1,56 +1,66 @@
-use binrw::{binrw, BinRead, BinWrite};
-
-use bilge::prelude::{bitsize, u10, Bitsized, DebugBits, FromBits, Number};
-
+use eipscanne_rs::cip::assembly::CipAssembly;
 use eipscanne_rs::cip::types::{CipDint, CipDword, CipInt, CipUdint, CipUlint, CipUsint};
 
 // https://www.teknic.com/files/downloads/clearlink_ethernet-ip_object_reference.pdf#page=20
 
-#[bitsize(16)]
-#[derive(FromBits, PartialEq, DebugBits, BinRead, BinWrite, Copy, Clone)]
-#[br(repr = u16)]
-#[bw(map = |&x| u16::from(x))]
+#[derive(CipAssembly, Debug, PartialEq, Clone, Copy)]
+#[cip(size = 2)]
 pub struct DigitalOutputs {
+    // Bits 6-15 are reserved
+    #[cip(byte = 0, bit = 0)]
     pub output0: bool,
+    #[cip(byte = 0, bit = 1)]
     pub output1: bool,
+    #[cip(byte = 0, bit = 2)]
     pub output2: bool,
+    #[cip(byte = 0, bit = 3)]
     pub output3: bool,
+    #[cip(byte = 0, bit = 4)]
     pub output4: bool,
+    #[cip(byte = 0, bit = 5)]
     pub output5: bool,
-    extra_padding: u10,
 }
 
 // ======= Start of private IOOutputData impl ========
 
 impl DigitalOutputs {
     pub fn default() -> Self {
-        DigitalOutputs::new(false, false, false, false, false, false, u10::new(0x0))
+        DigitalOutputs {
+            output0: false,
+            output1: false,
+            output2: false,
+            output3: false,
+            output4: false,
+            output5: false,
+        }
     }
 
     fn set_digital_output(&mut self, index: usize, value: bool) {
         match index {
-            0 => &self.set_output0(value),
-            1 => &self.set_output1(value),
-            2 => &self.set_output2(value),
-            3 => &self.set_output3(value),
-            4 => &self.set_output4(value),
-            5 => &self.set_output5(value),
-            _ => &(),
+            0 => self.output0 = value,
+            1 => self.output1 = value,
+            2 => self.output2 = value,
+            3 => self.output3 = value,
+            4 => self.output4 = value,
+            5 => self.output5 = value,
+            _ => (),
         };
     }
 }
 
 // ^^^^^^^^ End of private IOOutputData impl ^^^^^^^^
 
-#[binrw]
-#[brw(little)]
-#[derive(Debug, PartialEq)]
+#[derive(CipAssembly, Debug, PartialEq)]
 pub struct IOOutputData {
+    #[cip(byte = 0)]
     aop_value: CipInt,
+    #[cip(byte = 2)]
     pub dop_value: DigitalOutputs,
+    #[cip(byte = 4)]
     dop_pwm: [CipUsint; 6],
-    #[brw(pad_before = 2)]
+    #[cip(byte = 12)]
     ccio_output_data: CipUlint,
+    #[cip(byte = 20)]
     encoder_add_to_position: CipDint,
 }
 
@@ -91,16 +101,21 @@ impl IOOutputData {
 
 // ^^^^^^^^ End of private IOOutputData impl ^^^^^^^^
 
-#[binrw]
-#[brw(little)]
-#[derive(Debug, PartialEq)]
+#[derive(CipAssembly, Debug, PartialEq)]
 pub struct MotorOutputData {
+    #[cip(byte = 0)]
     move_distance: CipDint,
+    #[cip(byte = 4)]
     velocity_limit: CipUdint,
+    #[cip(byte = 8)]
     acceleration_limit: CipUdint,
+    #[cip(byte = 12)]
     deceleration_limit: CipUdint,
+    #[cip(byte = 16)]
     jog_velocity: CipDint,
+    #[cip(byte = 20)]
     add_to_position: CipDint,
+    #[cip(byte = 24)]
     output_register: CipDword,
 }
 
@@ -123,14 +138,17 @@ impl MotorOutputData {
 
 // ^^^^^^^^ End of private MotorOutputData impl ^^^^^^^^
 
-#[binrw]
-#[brw(little)]
-#[derive(Debug, PartialEq)]
+#[derive(CipAssembly, Debug, PartialEq)]
 pub struct SerialAsciiOutputData {
+    #[cip(byte = 0)]
     serial_config: CipDword,
+    #[cip(byte = 4)]
     input_sequence_ack: CipUdint,
+    #[cip(byte = 8)]
     output_size: CipUdint,
+    #[cip(byte = 12)]
     output_sequence: CipUdint,
+    #[cip(byte = 16)]
     output_data: [CipUsint; 128],
 }
 
@@ -151,15 +169,19 @@ impl SerialAsciiOutputData {
 
 // ^^^^^^^^ End of private SerialAsciiOutputData impl ^^^^^^^^
 
-#[binrw]
-#[brw(little)]
-#[derive(Debug, PartialEq)]
+#[derive(CipAssembly, Debug, PartialEq)]
 pub struct OutputAssemblyObject {
+    #[cip(byte = 0)]
     pub io_output_data: IOOutputData,
+    #[cip(byte = 24)]
     motor0_output_data: MotorOutputData,
+    #[cip(byte = 52)]
     motor1_output_data: MotorOutputData,
+    #[cip(byte = 80)]
     motor2_output_data: MotorOutputData,
+    #[cip(byte = 108)]
     motor3_output_data: MotorOutputData,
+    #[cip(byte = 136)]
     serial_ascii_output_data: SerialAsciiOutputData,
 }
 
@@ -167,8 +189,6 @@ pub struct OutputAssemblyObject {
 mod tests {
     use binrw::{BinRead, BinWrite};
 
-    use bilge::prelude::u10;
-
     use eipscanne_rs::cip::message::request::RequestData;
     use pretty_assertions::assert_eq;
 
@@ -224,15 +244,10 @@ mod tests {
             ServiceCode::SetAttributeSingle,
             CipPath::new_full(0x4, 0x70, 0x3),
             Some(Box::new(OutputAssemblyObject {
-                io_output_data: IOOutputData::new_digital_outputs(DigitalOutputs::new(
-                    false,
-                    true,
-                    false,
-                    false,
-                    false,
-                    false,
-                    u10::new(0x0),
-                )),
+                io_output_data: IOOutputData::new_digital_outputs(DigitalOutputs {
+                    output1: true,
+                    ..DigitalOutputs::default()
+                }),
                 motor0_output_data: MotorOutputData::new(),
                 motor1_output_data: MotorOutputData::new(),
                 motor2_output_data: MotorOutputData::new(),
@@ -359,15 +374,10 @@ mod tests {
             ServiceCode::SetAttributeSingle,
             CipPath::new_full(0x4, 0x70, 0x3),
             Some(Box::new(OutputAssemblyObject {
-                io_output_data: IOOutputData::new_digital_outputs(DigitalOutputs::new(
-                    false,
-                    true,
-                    false,
-                    false,
-                    false,
-                    false,
-                    u10::new(0x0),
-                )),
+                io_output_data: IOOutputData::new_digital_outputs(DigitalOutputs {
+                    output1: true,
+                    ..DigitalOutputs::default()
+                }),
                 motor0_output_data: MotorOutputData::new(),
                 motor1_output_data: MotorOutputData::new(),
                 motor2_output_data: MotorOutputData::new(),
@@ -521,15 +531,7 @@ mod tests {
                     status: ResponseStatusCode::Success,
                     additional_status_size: 0,
                     data: CipDataOpt::Typed(Box::new(OutputAssemblyObject {
-                        io_output_data: IOOutputData::new_digital_outputs(DigitalOutputs::new(
-                            false,
-                            false,
-                            false,
-                            false,
-                            false,
-                            false,
-                            u10::new(0x0),
-                        )),
+                        io_output_data: IOOutputData::new_digital_outputs(DigitalOutputs::default()),
                         motor0_output_data: MotorOutputData::new(),
                         motor1_output_data: MotorOutputData::new(),
                         motor2_output_data: MotorOutputData::new(),
@@ -620,15 +622,7 @@ mod tests {
                     Some(Box::new(OutputAssemblyObject {
                         io_output_data: IOOutputData {
                             aop_value: 0x00, // 0x02
-                            dop_value: DigitalOutputs::new(
-                                false,
-                                false,
-                                false,
-                                false,
-                                false,
-                                false,
-                                u10::new(0x0),
-                            ),
+                            dop_value: DigitalOutputs::default(),
                             dop_pwm: [0x0; 6],
                             ccio_output_data: 0x0,
                             encoder_add_to_position: 0x0,
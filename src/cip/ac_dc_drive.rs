@@ -0,0 +1,160 @@
+use binrw::binrw; // #[binrw] attribute
+
+use crate::cip::message::data::CipData;
+use crate::cip::message::request::MessageRouterRequest;
+use crate::cip::message::shared::ServiceCode;
+use crate::cip::path::CipPath;
+use crate::cip::types::{CipBool, CipInt, CipSint, CipUint};
+
+/// The AC/DC Drive object of the drive profile, which holds the speed, current and torque of the drive
+pub const AC_DC_DRIVE_CLASS_ID: u16 = 0x2A;
+
+/*
+AC/DC Drive instance attributes
+    Attribute: 3 (AtReference)
+    Attribute: 4 (NetRef, settable)
+    Attribute: 5 (NetProc, settable)
+    Attribute: 6 (DriveMode, settable)
+    Attribute: 7 (SpeedActual)
+    Attribute: 8 (SpeedRef, settable)
+    Attribute: 9 (CurrentActual)
+    Attribute: 10 (CurrentLimit, settable)
+    Attribute: 11 (TorqueActual)
+    Attribute: 12 (TorqueRef, settable)
+    Attribute: 13 (ProcessActual)
+    Attribute: 14 (ProcessRef, settable)
+    Attribute: 15 (PowerActual)
+    Attribute: 16 (InputVoltage)
+    Attribute: 17 (OutputVoltage)
+    Attribute: 18 (AccelTime, settable)
+    Attribute: 19 (DecelTime, settable)
+    Attribute: 20 (LowSpdLimit, settable)
+    Attribute: 21 (HighSpdLimit, settable)
+    Attribute: 22 (SpeedScale, settable)
+    Attribute: 23 (CurrentScale, settable)
+    Attribute: 29 (RefFromNet)
+*/
+#[derive(Debug, PartialEq, Clone, Copy)]
+#[repr(u16)]
+pub enum AcDcDriveAttributeId {
+    AtReference = 0x03,
+    NetReference = 0x04,
+    NetProcess = 0x05,
+    DriveMode = 0x06,
+    SpeedActual = 0x07,
+    SpeedReference = 0x08,
+    CurrentActual = 0x09,
+    CurrentLimit = 0x0A,
+    TorqueActual = 0x0B,
+    TorqueReference = 0x0C,
+    ProcessActual = 0x0D,
+    ProcessReference = 0x0E,
+    PowerActual = 0x0F,
+    InputVoltage = 0x10,
+    OutputVoltage = 0x11,
+    AccelTime = 0x12,
+    DecelTime = 0x13,
+    LowSpeedLimit = 0x14,
+    HighSpeedLimit = 0x15,
+    SpeedScale = 0x16,
+    CurrentScale = 0x17,
+    ReferenceFromNet = 0x1D,
+}
+
+#[binrw]
+#[brw(little)]
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum DriveMode {
+    #[brw(magic = 0x00u8)]
+    VendorSpecific,
+    #[brw(magic = 0x01u8)]
+    OpenLoopSpeed,
+    #[brw(magic = 0x02u8)]
+    ClosedLoopSpeed,
+    #[brw(magic = 0x03u8)]
+    Torque,
+    #[brw(magic = 0x04u8)]
+    Process,
+    #[brw(magic = 0x05u8)]
+    Position,
+    Reserved(u8),
+}
+
+fn ac_dc_drive_path(attribute_id: AcDcDriveAttributeId) -> CipPath {
+    CipPath::new(AC_DC_DRIVE_CLASS_ID, 0x01).with_attribute(attribute_id as u16)
+}
+
+/// A request reading a single attribute of the AC/DC Drive instance
+pub fn ac_dc_drive_attribute_request(attribute_id: AcDcDriveAttributeId) -> MessageRouterRequest {
+    MessageRouterRequest::new(
+        ServiceCode::GetAttributeSingle,
+        ac_dc_drive_path(attribute_id),
+    )
+}
+
+/// A writable attribute of the AC/DC Drive object, along with its new value
+///
+/// Speeds are in units of 2^SpeedScale RPM and currents in units of 2^CurrentScale * 100 mA.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum AcDcDriveSetting {
+    /// Take the speed or torque reference from the network instead of the local inputs
+    NetReference(bool),
+    DriveMode(DriveMode),
+    SpeedReference(CipInt),
+    CurrentLimit(CipInt),
+    TorqueReference(CipInt),
+    /// In milliseconds from zero to the high speed limit
+    AccelTime(CipUint),
+    /// In milliseconds from the high speed limit to zero
+    DecelTime(CipUint),
+    LowSpeedLimit(CipUint),
+    HighSpeedLimit(CipUint),
+    SpeedScale(CipSint),
+    CurrentScale(CipSint),
+}
+
+// ======= Start of AcDcDriveSetting impl ========
+
+impl AcDcDriveSetting {
+    pub fn attribute_id(&self) -> AcDcDriveAttributeId {
+        match self {
+            AcDcDriveSetting::NetReference(_) => AcDcDriveAttributeId::NetReference,
+            AcDcDriveSetting::DriveMode(_) => AcDcDriveAttributeId::DriveMode,
+            AcDcDriveSetting::SpeedReference(_) => AcDcDriveAttributeId::SpeedReference,
+            AcDcDriveSetting::CurrentLimit(_) => AcDcDriveAttributeId::CurrentLimit,
+            AcDcDriveSetting::TorqueReference(_) => AcDcDriveAttributeId::TorqueReference,
+            AcDcDriveSetting::AccelTime(_) => AcDcDriveAttributeId::AccelTime,
+            AcDcDriveSetting::DecelTime(_) => AcDcDriveAttributeId::DecelTime,
+            AcDcDriveSetting::LowSpeedLimit(_) => AcDcDriveAttributeId::LowSpeedLimit,
+            AcDcDriveSetting::HighSpeedLimit(_) => AcDcDriveAttributeId::HighSpeedLimit,
+            AcDcDriveSetting::SpeedScale(_) => AcDcDriveAttributeId::SpeedScale,
+            AcDcDriveSetting::CurrentScale(_) => AcDcDriveAttributeId::CurrentScale,
+        }
+    }
+
+    /// A Set Attribute Single request writing the setting to the AC/DC Drive instance
+    pub fn into_router_request(self) -> MessageRouterRequest {
+        let value: Box<dyn CipData> = match self {
+            AcDcDriveSetting::NetReference(enable) => Box::new(enable as CipBool),
+            AcDcDriveSetting::DriveMode(mode) => Box::new(mode),
+            AcDcDriveSetting::SpeedReference(value)
+            | AcDcDriveSetting::CurrentLimit(value)
+            | AcDcDriveSetting::TorqueReference(value) => Box::new(value),
+            AcDcDriveSetting::AccelTime(value)
+            | AcDcDriveSetting::DecelTime(value)
+            | AcDcDriveSetting::LowSpeedLimit(value)
+            | AcDcDriveSetting::HighSpeedLimit(value) => Box::new(value),
+            AcDcDriveSetting::SpeedScale(scale) | AcDcDriveSetting::CurrentScale(scale) => {
+                Box::new(scale)
+            }
+        };
+
+        MessageRouterRequest::new_data(
+            ServiceCode::SetAttributeSingle,
+            ac_dc_drive_path(self.attribute_id()),
+            Some(value),
+        )
+    }
+}
+
+// ^^^^^^^^ End of AcDcDriveSetting impl ^^^^^^^^
@@ -0,0 +1,416 @@
+use std::marker::PhantomData;
+
+use binrw::{
+    binrw, // #[binrw] attribute
+    helpers::until_eof,
+    BinRead,
+    BinResult,
+    BinWrite,
+};
+
+pub use cip_assembly_derive::CipAssembly;
+
+use crate::cip::message::data::encode;
+use crate::cip::message::request::MessageRouterRequest;
+use crate::cip::message::response::MessageRouterResponse;
+use crate::cip::message::shared::ServiceCode;
+use crate::cip::path::{CipPath, LogicalPathSegment, LogicalSegmentType};
+use crate::cip::types::{CipByte, CipUint};
+
+/// The Assembly object, which groups the I/O data of a device into a single attribute
+pub const ASSEMBLY_CLASS_ID: u16 = 0x04;
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+#[repr(u8)]
+pub enum AssemblyAttributeId {
+    Data = 0x03,
+    Size = 0x04,
+}
+
+/// The path to an attribute of an assembly instance, in the 8-bit format when the instance fits
+pub fn assembly_path(instance_id: u16, attribute_id: AssemblyAttributeId) -> CipPath {
+    let instance_id_segment = match u8::try_from(instance_id) {
+        Ok(instance_id) => LogicalPathSegment::new_u8(LogicalSegmentType::InstanceId, instance_id),
+        Err(_) => LogicalPathSegment::new_u16(LogicalSegmentType::InstanceId, instance_id),
+    };
+
+    CipPath {
+        class_id_segment: match u8::try_from(ASSEMBLY_CLASS_ID) {
+            Ok(class_id) => LogicalPathSegment::new_u8(LogicalSegmentType::ClassId, class_id),
+            Err(_) => LogicalPathSegment::new_u16(LogicalSegmentType::ClassId, ASSEMBLY_CLASS_ID),
+        },
+        instance_id_segment,
+        attribute_id_segment: Some(LogicalPathSegment::new_u8(
+            LogicalSegmentType::AttributeId,
+            attribute_id as u8,
+        )),
+    }
+}
+
+/// A typed read of an assembly attribute
+///
+/// Send the `request()` and decode its response with `read_reply()`.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct AssemblyRead<T> {
+    pub instance_id: u16,
+    pub attribute_id: AssemblyAttributeId,
+    reply_type: PhantomData<T>,
+}
+
+// ======= Start of AssemblyRead impl ========
+
+impl<T> AssemblyRead<T>
+where
+    T: for<'a> BinRead<Args<'a> = ()>,
+{
+    pub fn request(&self) -> MessageRouterRequest {
+        MessageRouterRequest::new(
+            ServiceCode::GetAttributeSingle,
+            assembly_path(self.instance_id, self.attribute_id),
+        )
+    }
+
+    pub fn read_reply(&self, response: &MessageRouterResponse) -> BinResult<T> {
+        response.check_status()?;
+        response.response_data.read_reply()
+    }
+}
+
+// ^^^^^^^^ End of AssemblyRead impl ^^^^^^^^
+
+/// Reads the data of an assembly instance into `T`, e.g. a binrw struct or a [`ProcessImage`]
+pub fn read_assembly<T>(instance_id: u16) -> AssemblyRead<T> {
+    AssemblyRead {
+        instance_id,
+        attribute_id: AssemblyAttributeId::Data,
+        reply_type: PhantomData,
+    }
+}
+
+/// Reads the number of data bytes of an assembly instance
+pub fn read_assembly_size(instance_id: u16) -> AssemblyRead<CipUint> {
+    AssemblyRead {
+        instance_id,
+        attribute_id: AssemblyAttributeId::Size,
+        reply_type: PhantomData,
+    }
+}
+
+/// A request writing `data` to an assembly instance
+pub fn write_assembly<T>(instance_id: u16, data: &T) -> BinResult<MessageRouterRequest>
+where
+    T: for<'a> BinWrite<Args<'a> = ()>,
+{
+    let process_image = ProcessImage::from(encode(data)?);
+
+    Ok(MessageRouterRequest::new_data(
+        ServiceCode::SetAttributeSingle,
+        assembly_path(instance_id, AssemblyAttributeId::Data),
+        Some(Box::new(process_image)),
+    ))
+}
+
+/// The raw data of an assembly, with typed access to the values at byte and bit offsets
+///
+/// Multi-byte values are little endian, as everywhere in CIP.
+#[binrw]
+#[brw(little)]
+#[derive(Debug, PartialEq, Clone, Default)]
+pub struct ProcessImage {
+    #[br(parse_with = until_eof)]
+    pub data: Vec<CipByte>,
+}
+
+// ======= Start of ProcessImage impl ========
+
+impl ProcessImage {
+    /// A zeroed image of the given size, e.g. from `read_assembly_size()`
+    pub fn new(size: usize) -> Self {
+        ProcessImage {
+            data: vec![0; size],
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    pub fn as_bytes(&self) -> &[CipByte] {
+        &self.data
+    }
+
+    fn out_of_range(&self, byte_offset: usize, size: usize) -> binrw::Error {
+        binrw::Error::Custom {
+            pos: byte_offset as u64,
+            err: Box::new(format!(
+                "{} bytes at offset {} are outside of the {} byte process image",
+                size,
+                byte_offset,
+                self.len()
+            )),
+        }
+    }
+
+    /// Decodes the value at a byte offset
+    pub fn read_at<T>(&self, byte_offset: usize) -> BinResult<T>
+    where
+        T: for<'a> BinRead<Args<'a> = ()>,
+    {
+        let value_bytes = self
+            .data
+            .get(byte_offset..)
+            .ok_or_else(|| self.out_of_range(byte_offset, 0))?;
+
+        let mut value_reader = std::io::Cursor::new(value_bytes);
+        T::read_le(&mut value_reader)
+    }
+
+    /// Encodes a value over the bytes at a byte offset
+    pub fn write_at<T>(&mut self, byte_offset: usize, value: &T) -> BinResult<()>
+    where
+        T: for<'a> BinWrite<Args<'a> = ()>,
+    {
+        let value_bytes = encode(value)?;
+        let byte_range = byte_offset..byte_offset + value_bytes.len();
+
+        if byte_range.end > self.len() {
+            return Err(self.out_of_range(byte_offset, value_bytes.len()));
+        }
+
+        self.data[byte_range].copy_from_slice(&value_bytes);
+        Ok(())
+    }
+
+    /// The bit at a bit offset (0 to 7) of the byte at a byte offset
+    pub fn bit(&self, byte_offset: usize, bit_offset: u8) -> BinResult<bool> {
+        let byte = self
+            .data
+            .get(byte_offset)
+            .ok_or_else(|| self.out_of_range(byte_offset, 1))?;
+
+        Ok(byte & Self::bit_mask(bit_offset)? != 0)
+    }
+
+    pub fn set_bit(&mut self, byte_offset: usize, bit_offset: u8, value: bool) -> BinResult<()> {
+        let bit_mask = Self::bit_mask(bit_offset)?;
+        let out_of_range = self.out_of_range(byte_offset, 1);
+        let byte = self.data.get_mut(byte_offset).ok_or(out_of_range)?;
+
+        match value {
+            true => *byte |= bit_mask,
+            false => *byte &= !bit_mask,
+        }
+        Ok(())
+    }
+
+    fn bit_mask(bit_offset: u8) -> BinResult<u8> {
+        1u8.checked_shl(bit_offset as u32)
+            .ok_or_else(|| binrw::Error::Custom {
+                pos: 0,
+                err: Box::new(format!("Bit offset {} is outside of a byte", bit_offset)),
+            })
+    }
+}
+
+impl From<Vec<CipByte>> for ProcessImage {
+    fn from(data: Vec<CipByte>) -> Self {
+        ProcessImage { data }
+    }
+}
+
+// ^^^^^^^^ End of ProcessImage impl ^^^^^^^^
+
+/// A value that can be placed at a byte offset of a [`CipAssembly`], little endian
+pub trait AssemblyValue: Sized {
+    const CIP_TYPE: &'static str;
+    const SIZE: usize;
+
+    /// Decodes the value from exactly `SIZE` bytes
+    fn read_from(bytes: &[u8]) -> Self;
+
+    /// Encodes the value into exactly `SIZE` bytes
+    fn write_to(&self, bytes: &mut [u8]);
+}
+
+macro_rules! impl_assembly_value {
+    ($($value_type:ty => $cip_type:literal),* $(,)?) => {
+        $(
+            impl AssemblyValue for $value_type {
+                const CIP_TYPE: &'static str = $cip_type;
+                const SIZE: usize = std::mem::size_of::<$value_type>();
+
+                fn read_from(bytes: &[u8]) -> Self {
+                    <$value_type>::from_le_bytes(bytes.try_into().unwrap())
+                }
+
+                fn write_to(&self, bytes: &mut [u8]) {
+                    bytes.copy_from_slice(&self.to_le_bytes());
+                }
+            }
+        )*
+    };
+}
+
+impl_assembly_value! {
+    u8 => "USINT",
+    i8 => "SINT",
+    u16 => "UINT",
+    i16 => "INT",
+    u32 => "UDINT",
+    i32 => "DINT",
+    u64 => "ULINT",
+    i64 => "LINT",
+    f32 => "REAL",
+    f64 => "LREAL",
+}
+
+/// A whole byte BOOL, use a bit offset to pack booleans into a byte instead
+impl AssemblyValue for bool {
+    const CIP_TYPE: &'static str = "BOOL";
+    const SIZE: usize = 1;
+
+    fn read_from(bytes: &[u8]) -> Self {
+        bytes[0] != 0
+    }
+
+    fn write_to(&self, bytes: &mut [u8]) {
+        bytes[0] = *self as u8;
+    }
+}
+
+impl<T: AssemblyValue, const N: usize> AssemblyValue for [T; N] {
+    const CIP_TYPE: &'static str = T::CIP_TYPE;
+    const SIZE: usize = T::SIZE * N;
+
+    fn read_from(bytes: &[u8]) -> Self {
+        std::array::from_fn(|index| T::read_from(&bytes[index * T::SIZE..(index + 1) * T::SIZE]))
+    }
+
+    fn write_to(&self, bytes: &mut [u8]) {
+        for (value, value_bytes) in self.iter().zip(bytes.chunks_exact_mut(T::SIZE)) {
+            value.write_to(value_bytes);
+        }
+    }
+}
+
+/// Where a field of a [`CipAssembly`] lives in the assembly data
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct AssemblyField {
+    pub name: &'static str,
+    pub cip_type: &'static str,
+    pub byte_offset: usize,
+    /// Set for booleans packed into a single bit of the byte
+    pub bit_offset: Option<u8>,
+    /// Size in bytes, one for bits
+    pub size: usize,
+}
+
+// ======= Start of AssemblyField impl ========
+
+impl AssemblyField {
+    pub const fn overlaps(&self, other: &AssemblyField) -> bool {
+        match (self.bit_offset, other.bit_offset) {
+            (Some(bit_offset), Some(other_bit_offset)) => {
+                self.byte_offset == other.byte_offset && bit_offset == other_bit_offset
+            }
+            _ => {
+                self.byte_offset < other.byte_offset + other.size
+                    && other.byte_offset < self.byte_offset + self.size
+            }
+        }
+    }
+}
+
+// ^^^^^^^^ End of AssemblyField impl ^^^^^^^^
+
+/// The number of bytes needed to hold every field of a layout
+pub const fn layout_size(layout: &[AssemblyField]) -> usize {
+    let mut size = 0;
+    let mut index = 0;
+
+    while index < layout.len() {
+        let field_end = layout[index].byte_offset + layout[index].size;
+        if field_end > size {
+            size = field_end;
+        }
+        index += 1;
+    }
+
+    size
+}
+
+pub const fn overlaps_any(field: &AssemblyField, layout: &[AssemblyField]) -> bool {
+    let mut index = 0;
+
+    while index < layout.len() {
+        if field.overlaps(&layout[index]) {
+            return true;
+        }
+        index += 1;
+    }
+
+    false
+}
+
+/// An assembly with a fixed layout of fields at byte and bit offsets
+///
+/// Implement it with `#[derive(CipAssembly)]`, which also implements `BinRead` and `BinWrite` so
+/// the struct works with [`read_assembly`] and [`write_assembly`].
+pub trait CipAssembly: Sized {
+    /// The size of the assembly data in bytes
+    const SIZE: usize;
+    const LAYOUT: &'static [AssemblyField];
+
+    /// Decodes the fields from exactly `SIZE` bytes
+    fn read_fields(data: &[u8]) -> Self;
+
+    /// Encodes the fields into `SIZE` zeroed bytes
+    fn write_fields(&self, data: &mut [u8]);
+
+    fn decode(data: &[u8]) -> BinResult<Self> {
+        if data.len() != Self::SIZE {
+            return Err(binrw::Error::Custom {
+                pos: 0,
+                err: Box::new(format!(
+                    "Expected {} bytes of assembly data, got {}",
+                    Self::SIZE,
+                    data.len()
+                )),
+            });
+        }
+
+        Ok(Self::read_fields(data))
+    }
+
+    fn encode(&self) -> Vec<CipByte> {
+        let mut data = vec![0; Self::SIZE];
+        self.write_fields(&mut data);
+        data
+    }
+
+    /// A table of the field offsets, for checking a layout against the device documentation
+    fn layout_table() -> String {
+        let mut table = format!(
+            "{:>5} {:>3}  {:<6} {:>4}  Field\n",
+            "Byte", "Bit", "Type", "Size"
+        );
+
+        for field in Self::LAYOUT {
+            let bit_offset = match field.bit_offset {
+                Some(bit_offset) => bit_offset.to_string(),
+                None => "-".to_string(),
+            };
+
+            table += &format!(
+                "{:>5} {:>3}  {:<6} {:>4}  {}\n",
+                field.byte_offset, bit_offset, field.cip_type, field.size, field.name
+            );
+        }
+
+        table
+    }
+}
@@ -0,0 +1,331 @@
+use binrw::{
+    binrw, // #[binrw] attribute
+    BinResult,
+};
+
+use crate::cip::message::request::MessageRouterRequest;
+use crate::cip::message::response::{MessageRouterResponse, ResponseStatusCode};
+use crate::cip::message::shared::ServiceCode;
+use crate::cip::path::CipPath;
+use crate::cip::types::{CipUint, CipUsint};
+
+/// Number of instance IDs requested with each Find Next Object Instance request
+pub const DEFAULT_FIND_NEXT_COUNT: CipUsint = 100;
+
+/*
+Class attributes, common to every object class (all optional)
+    Attribute: 1 (Revision)
+    Attribute: 2 (Max Instance)
+    Attribute: 3 (Number of Instances)
+    Attribute: 4 (Optional Attribute List)
+    Attribute: 6 (Maximum ID Number Class Attributes)
+    Attribute: 7 (Maximum ID Number Instance Attributes)
+*/
+#[derive(Debug, PartialEq, Clone, Copy)]
+#[repr(u16)]
+pub enum ClassAttributeId {
+    Revision = 0x01,
+    MaxInstance = 0x02,
+    NumberOfInstances = 0x03,
+    OptionalAttributeList = 0x04,
+    MaxClassAttributeId = 0x06,
+    MaxInstanceAttributeId = 0x07,
+}
+
+// ======= Start of ClassAttributeId impl ========
+
+impl ClassAttributeId {
+    pub const ALL: [ClassAttributeId; 6] = [
+        ClassAttributeId::Revision,
+        ClassAttributeId::MaxInstance,
+        ClassAttributeId::NumberOfInstances,
+        ClassAttributeId::OptionalAttributeList,
+        ClassAttributeId::MaxClassAttributeId,
+        ClassAttributeId::MaxInstanceAttributeId,
+    ];
+}
+
+// ^^^^^^^^ End of ClassAttributeId impl ^^^^^^^^
+
+#[binrw]
+#[brw(little)]
+#[derive(Debug, PartialEq, Clone)]
+pub struct AttributeIdList {
+    #[br(temp)]
+    #[bw(calc = attribute_ids.len() as CipUint)]
+    attribute_count: CipUint,
+
+    #[br(count = attribute_count)]
+    pub attribute_ids: Vec<CipUint>,
+}
+
+/// The class attributes of an object class, each left as `None` if the device does not support it
+#[derive(Debug, PartialEq, Clone, Default)]
+pub struct ClassAttributes {
+    pub revision: Option<CipUint>,
+    pub max_instance: Option<CipUint>,
+    pub number_of_instances: Option<CipUint>,
+    pub optional_attributes: Option<Vec<CipUint>>,
+    pub max_class_attribute_id: Option<CipUint>,
+    pub max_instance_attribute_id: Option<CipUint>,
+}
+
+/// Reads the class attributes of an object class one Get Attribute Single at a time
+#[derive(Debug)]
+pub struct ClassAttributesRead {
+    class_id: CipUint,
+    next_attribute: usize,
+    attributes: ClassAttributes,
+}
+
+// ======= Start of ClassAttributesRead impl ========
+
+impl ClassAttributesRead {
+    pub fn new(class_id: CipUint) -> Self {
+        ClassAttributesRead {
+            class_id,
+            next_attribute: 0,
+            attributes: ClassAttributes::default(),
+        }
+    }
+
+    pub fn class_id(&self) -> CipUint {
+        self.class_id
+    }
+
+    pub fn is_complete(&self) -> bool {
+        self.next_attribute >= ClassAttributeId::ALL.len()
+    }
+
+    pub fn next_request(&self) -> Option<MessageRouterRequest> {
+        let attribute_id = ClassAttributeId::ALL.get(self.next_attribute)?;
+
+        Some(MessageRouterRequest::new(
+            ServiceCode::GetAttributeSingle,
+            CipPath::new_class(self.class_id).with_attribute(*attribute_id as u16),
+        ))
+    }
+
+    pub fn handle_response(&mut self, response: &MessageRouterResponse) -> BinResult<()> {
+        let Some(attribute_id) = ClassAttributeId::ALL.get(self.next_attribute) else {
+            return Ok(());
+        };
+        self.next_attribute += 1;
+
+        // Without a class instance there are no class attributes to read at all
+        if response.response_data.is_object_missing() {
+            self.next_attribute = ClassAttributeId::ALL.len();
+            return Ok(());
+        }
+        if response.response_data.is_attribute_unsupported() {
+            return Ok(());
+        }
+        response.check_status()?;
+
+        let attributes = &mut self.attributes;
+        let reply_data = &response.response_data;
+        match attribute_id {
+            ClassAttributeId::Revision => attributes.revision = Some(reply_data.read_reply()?),
+            ClassAttributeId::MaxInstance => {
+                attributes.max_instance = Some(reply_data.read_reply()?)
+            }
+            ClassAttributeId::NumberOfInstances => {
+                attributes.number_of_instances = Some(reply_data.read_reply()?)
+            }
+            ClassAttributeId::OptionalAttributeList => {
+                let attribute_list: AttributeIdList = reply_data.read_reply()?;
+                attributes.optional_attributes = Some(attribute_list.attribute_ids);
+            }
+            ClassAttributeId::MaxClassAttributeId => {
+                attributes.max_class_attribute_id = Some(reply_data.read_reply()?)
+            }
+            ClassAttributeId::MaxInstanceAttributeId => {
+                attributes.max_instance_attribute_id = Some(reply_data.read_reply()?)
+            }
+        }
+
+        Ok(())
+    }
+
+    /// The class attributes read so far
+    pub fn attributes(&self) -> &ClassAttributes {
+        &self.attributes
+    }
+
+    /// The class attributes, once the read is complete
+    pub fn into_attributes(self) -> Option<ClassAttributes> {
+        self.is_complete().then_some(self.attributes)
+    }
+}
+
+// ^^^^^^^^ End of ClassAttributesRead impl ^^^^^^^^
+
+#[binrw]
+#[brw(little)]
+#[derive(Debug, PartialEq)]
+pub struct FindNextObjectInstanceRequest {
+    pub max_instance_count: CipUsint,
+}
+
+#[binrw]
+#[brw(little)]
+#[derive(Debug, PartialEq)]
+pub struct FindNextObjectInstanceReply {
+    #[br(temp)]
+    #[bw(calc = instance_ids.len() as CipUsint)]
+    instance_count: CipUsint,
+
+    #[br(count = instance_count)]
+    pub instance_ids: Vec<CipUint>,
+}
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+enum EnumerationStep {
+    FindNext {
+        last_instance_id: CipUint,
+    },
+    ReadMaxInstance,
+    Scan {
+        instance_id: CipUint,
+        max_instance: CipUint,
+    },
+    Complete,
+}
+
+/// Lists the instances of an object class
+///
+/// The instances are found with Find Next Object Instance. Devices that do not support the service
+/// are scanned instead, probing every instance up to the class's max instance attribute.
+#[derive(Debug)]
+pub struct InstanceEnumeration {
+    class_id: CipUint,
+    find_next_count: CipUsint,
+    step: EnumerationStep,
+    instance_ids: Vec<CipUint>,
+}
+
+// ======= Start of InstanceEnumeration impl ========
+
+impl InstanceEnumeration {
+    pub fn new(class_id: CipUint) -> Self {
+        InstanceEnumeration {
+            class_id,
+            find_next_count: DEFAULT_FIND_NEXT_COUNT,
+            step: EnumerationStep::FindNext {
+                last_instance_id: 0,
+            },
+            instance_ids: Vec::new(),
+        }
+    }
+
+    /// Skips Find Next Object Instance, for devices known to reject it
+    pub fn new_scan(class_id: CipUint) -> Self {
+        InstanceEnumeration {
+            step: EnumerationStep::ReadMaxInstance,
+            ..Self::new(class_id)
+        }
+    }
+
+    /// Overrides the number of instances requested with each Find Next Object Instance
+    pub fn with_find_next_count(mut self, find_next_count: CipUsint) -> Self {
+        self.find_next_count = find_next_count.max(1);
+        self
+    }
+
+    pub fn is_complete(&self) -> bool {
+        self.step == EnumerationStep::Complete
+    }
+
+    pub fn next_request(&self) -> Option<MessageRouterRequest> {
+        match self.step {
+            EnumerationStep::FindNext { last_instance_id } => Some(MessageRouterRequest::new_data(
+                ServiceCode::FindNextObjectInstance,
+                CipPath::new(self.class_id, last_instance_id),
+                Some(Box::new(FindNextObjectInstanceRequest {
+                    max_instance_count: self.find_next_count,
+                })),
+            )),
+            EnumerationStep::ReadMaxInstance => Some(MessageRouterRequest::new(
+                ServiceCode::GetAttributeSingle,
+                CipPath::new_class(self.class_id)
+                    .with_attribute(ClassAttributeId::MaxInstance as u16),
+            )),
+            EnumerationStep::Scan { instance_id, .. } => Some(MessageRouterRequest::new(
+                ServiceCode::GetAttributeSingle,
+                CipPath::new(self.class_id, instance_id).with_attribute(0x01),
+            )),
+            EnumerationStep::Complete => None,
+        }
+    }
+
+    fn scan_from(&self, instance_id: CipUint, max_instance: CipUint) -> EnumerationStep {
+        match instance_id {
+            0 => EnumerationStep::Complete,
+            instance_id if instance_id > max_instance => EnumerationStep::Complete,
+            instance_id => EnumerationStep::Scan {
+                instance_id,
+                max_instance,
+            },
+        }
+    }
+
+    pub fn handle_response(&mut self, response: &MessageRouterResponse) -> BinResult<()> {
+        let status = response.response_data.status;
+
+        self.step = match self.step {
+            EnumerationStep::FindNext { .. }
+                if status == ResponseStatusCode::ServiceNotSupported =>
+            {
+                EnumerationStep::ReadMaxInstance
+            }
+            EnumerationStep::FindNext { .. } => {
+                response.check_status()?;
+                let page: FindNextObjectInstanceReply = response.response_data.read_reply()?;
+
+                let next_step = match page.instance_ids.last() {
+                    Some(last_instance_id)
+                        if page.instance_ids.len() >= self.find_next_count as usize =>
+                    {
+                        EnumerationStep::FindNext {
+                            last_instance_id: *last_instance_id,
+                        }
+                    }
+                    _ => EnumerationStep::Complete,
+                };
+
+                self.instance_ids.extend(page.instance_ids);
+                next_step
+            }
+            EnumerationStep::ReadMaxInstance => {
+                response.check_status()?;
+                let max_instance: CipUint = response.response_data.read_reply()?;
+
+                self.scan_from(1, max_instance)
+            }
+            EnumerationStep::Scan {
+                instance_id,
+                max_instance,
+            } => {
+                // Any other error (e.g. attribute 1 not being supported) still means the instance exists
+                if !response.response_data.is_object_missing() {
+                    self.instance_ids.push(instance_id);
+                }
+
+                self.scan_from(instance_id.wrapping_add(1), max_instance)
+            }
+            EnumerationStep::Complete => EnumerationStep::Complete,
+        };
+
+        Ok(())
+    }
+
+    pub fn instance_ids(&self) -> &[CipUint] {
+        &self.instance_ids
+    }
+
+    pub fn into_instance_ids(self) -> Vec<CipUint> {
+        self.instance_ids
+    }
+}
+
+// ^^^^^^^^ End of InstanceEnumeration impl ^^^^^^^^
@@ -0,0 +1,609 @@
+use std::io::{Read, Seek, Write};
+use std::time::Duration;
+
+use bilge::prelude::{Bitsized, DebugBits, FromBits, Number, bitsize, u3, u4, u7};
+
+use binrw::{BinRead, BinResult, BinWrite, Endian, binrw};
+
+use crate::cip::message::data::encode;
+use crate::cip::message::request::MessageRouterRequest;
+use crate::cip::message::response::{MessageRouterResponse, ResponseStatusCode};
+use crate::cip::message::shared::ServiceCode;
+use crate::cip::path::{CipPath, LogicalPathSegment, LogicalSegmentType, RoutePath};
+use crate::cip::types::{CipByte, CipUdint, CipUint, CipUsint};
+
+pub const CONNECTION_MANAGER_CLASS_ID: u16 = 0x06;
+
+/// Object specific services of the Connection Manager object
+#[derive(Debug, PartialEq, Clone, Copy)]
+#[repr(u8)]
+pub enum ConnectionManagerServiceCode {
+    ForwardClose = 0x4E,
+    UnconnectedSend = 0x52,
+    ForwardOpen = 0x54,
+    GetConnectionData = 0x56,
+    SearchConnectionData = 0x57,
+    GetConnectionOwner = 0x5A,
+    LargeForwardOpen = 0x5B,
+}
+
+impl From<ConnectionManagerServiceCode> for ServiceCode {
+    fn from(service_code: ConnectionManagerServiceCode) -> Self {
+        ServiceCode::from(u7::new(service_code as u8))
+    }
+}
+
+/// The path to the Connection Manager object of the device the message is sent to
+pub fn connection_manager_path() -> CipPath {
+    CipPath {
+        class_id_segment: match u8::try_from(CONNECTION_MANAGER_CLASS_ID) {
+            Ok(class_id) => LogicalPathSegment::new_u8(LogicalSegmentType::ClassId, class_id),
+            Err(_) => LogicalPathSegment::new_u16(
+                LogicalSegmentType::ClassId,
+                CONNECTION_MANAGER_CLASS_ID,
+            ),
+        },
+        instance_id_segment: LogicalPathSegment::new_u8(LogicalSegmentType::InstanceId, 0x01),
+        attribute_id_segment: None,
+    }
+}
+
+/*
+Connection Manager instance attributes
+    Attribute: 1 (Open Requests)
+    Attribute: 2 (Open Format Rejects)
+    Attribute: 3 (Open Resource Rejects)
+    Attribute: 4 (Open Other Rejects)
+    Attribute: 5 (Close Requests)
+    Attribute: 6 (Close Format Rejects)
+    Attribute: 7 (Close Other Rejects)
+    Attribute: 8 (Connection Timeouts)
+    Attribute: 9 (Connection Entry List)
+    Attribute: 11 (CPU Utilization)
+    Attribute: 12 (Max Buffer Size)
+    Attribute: 13 (Buffer Size Remaining)
+*/
+#[derive(Debug, PartialEq, Clone, Copy)]
+#[repr(u16)]
+pub enum ConnectionManagerAttributeId {
+    OpenRequests = 0x01,
+    OpenFormatRejects = 0x02,
+    OpenResourceRejects = 0x03,
+    OpenOtherRejects = 0x04,
+    CloseRequests = 0x05,
+    CloseFormatRejects = 0x06,
+    CloseOtherRejects = 0x07,
+    ConnectionTimeouts = 0x08,
+    ConnectionEntryList = 0x09,
+    CpuUtilization = 0x0B,
+    MaxBufferSize = 0x0C,
+    BufferSizeRemaining = 0x0D,
+}
+
+const MAX_TICK_TIME: u8 = 0x0F;
+
+/// The duration of a tick is 2^tick_time milliseconds
+#[bitsize(8)]
+#[derive(FromBits, PartialEq, DebugBits, BinRead, BinWrite, Copy, Clone)]
+#[br(map = u8::into)]
+#[bw(map = |&x| u8::from(x))]
+pub struct PriorityTimeTick {
+    pub tick_time: u4,
+    pub priority: bool,
+    pub reserved: u3,
+}
+
+/// The request data of an Unconnected Send, which carries a message along its route
+#[derive(Debug, PartialEq)]
+pub struct UnconnectedSendRequest {
+    pub priority_time_tick: PriorityTimeTick,
+    pub timeout_ticks: CipUsint,
+    pub message_request: MessageRouterRequest,
+    pub route_path: RoutePath,
+}
+
+// ======= Start of UnconnectedSendRequest impl ========
+
+impl BinWrite for UnconnectedSendRequest {
+    type Args<'a> = ();
+
+    fn write_options<W: Write + Seek>(
+        &self,
+        writer: &mut W,
+        endian: Endian,
+        _args: Self::Args<'_>,
+    ) -> BinResult<()> {
+        self.priority_time_tick.write_options(writer, endian, ())?;
+        self.timeout_ticks.write_options(writer, endian, ())?;
+
+        let message_request = encode(&self.message_request)?;
+        (message_request.len() as CipUint).write_options(writer, endian, ())?;
+        writer.write_all(&message_request)?;
+
+        // The message request is padded to keep the route path word aligned
+        if message_request.len() % 2 == 1 {
+            writer.write_all(&[0])?;
+        }
+
+        (self.route_path.word_size() as CipUsint).write_options(writer, endian, ())?;
+        writer.write_all(&[0])?;
+        self.route_path.write_options(writer, endian, ())
+    }
+}
+
+impl BinRead for UnconnectedSendRequest {
+    type Args<'a> = ();
+
+    fn read_options<R: Read + Seek>(
+        reader: &mut R,
+        endian: Endian,
+        _args: Self::Args<'_>,
+    ) -> BinResult<Self> {
+        let priority_time_tick = PriorityTimeTick::read_options(reader, endian, ())?;
+        let timeout_ticks = CipUsint::read_options(reader, endian, ())?;
+
+        let message_request_size = CipUint::read_options(reader, endian, ())?;
+        let message_request =
+            MessageRouterRequest::read_options(reader, endian, (message_request_size,))?;
+        if message_request_size % 2 == 1 {
+            CipUsint::read_options(reader, endian, ())?;
+        }
+
+        let route_path_size = CipUsint::read_options(reader, endian, ())?;
+        let _reserved = CipUsint::read_options(reader, endian, ())?;
+        let route_path = RoutePath::read_options(reader, endian, (route_path_size,))?;
+
+        Ok(UnconnectedSendRequest {
+            priority_time_tick,
+            timeout_ticks,
+            message_request,
+            route_path,
+        })
+    }
+}
+
+// ^^^^^^^^ End of UnconnectedSendRequest impl ^^^^^^^^
+
+/// An Unconnected Send failed before the message reached its target
+#[derive(Debug, PartialEq)]
+pub struct RoutingError {
+    pub status: ResponseStatusCode,
+
+    /// The extended status, e.g. 0x0204 when the target did not respond in time
+    pub additional_status: Vec<CipUint>,
+
+    /// The number of words of the route path that were not processed, if the router reported it
+    pub remaining_path_size: Option<CipUsint>,
+}
+
+// ======= Start of RoutingError impl ========
+
+impl std::fmt::Display for RoutingError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Routing failed with {:?} (additional status: {:04X?})",
+            self.status, self.additional_status
+        )?;
+
+        if let Some(remaining_path_size) = self.remaining_path_size {
+            write!(
+                f,
+                " with {} words of the route remaining",
+                remaining_path_size
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+// ^^^^^^^^ End of RoutingError impl ^^^^^^^^
+
+/// Wraps requests in an Unconnected Send so they are routed to a device behind the one we talk to,
+/// e.g. a controller in a ControlLogix chassis
+#[derive(Debug, PartialEq, Clone)]
+pub struct UnconnectedSend {
+    pub priority_time_tick: PriorityTimeTick,
+    pub timeout_ticks: CipUsint,
+    pub route_path: RoutePath,
+}
+
+// ======= Start of UnconnectedSend impl ========
+
+impl UnconnectedSend {
+    /// Routes along the path with a timeout of about 5 seconds
+    pub fn new(route_path: RoutePath) -> Self {
+        UnconnectedSend {
+            priority_time_tick: PriorityTimeTick::new(u4::new(0x0A), false),
+            timeout_ticks: 0x05,
+            route_path,
+        }
+    }
+
+    /// Uses the shortest tick that can express the timeout, rounding the timeout up
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        let timeout_ms = timeout.as_millis();
+
+        let tick_time = (0..=MAX_TICK_TIME)
+            .find(|tick_time| timeout_ms.div_ceil(1 << tick_time) <= CipUsint::MAX as u128)
+            .unwrap_or(MAX_TICK_TIME);
+        let timeout_ticks = timeout_ms
+            .div_ceil(1 << tick_time)
+            .clamp(1, CipUsint::MAX as u128);
+
+        self.priority_time_tick.set_tick_time(u4::new(tick_time));
+        self.timeout_ticks = timeout_ticks as CipUsint;
+        self
+    }
+
+    /// Wraps the request so it is forwarded along the route by the Connection Manager
+    pub fn wrap(&self, message_request: MessageRouterRequest) -> MessageRouterRequest {
+        MessageRouterRequest::new_data(
+            ConnectionManagerServiceCode::UnconnectedSend.into(),
+            connection_manager_path(),
+            Some(Box::new(UnconnectedSendRequest {
+                priority_time_tick: self.priority_time_tick,
+                timeout_ticks: self.timeout_ticks,
+                message_request,
+                route_path: self.route_path.clone(),
+            })),
+        )
+    }
+
+    /// Returns the reply of the target, or a `RoutingError` if the message could not be delivered
+    ///
+    /// A successful Unconnected Send is answered with the target's own reply, so an error from the
+    /// target (e.g. an unsupported attribute) is left in the returned response. The service of the
+    /// wrapped request is needed because a routing failure is reported with the Unconnected Send
+    /// service, which Logix controllers also use for fragmented tag reads.
+    ///
+    /// The `RoutingError` can be retrieved from the error with `custom_err::<RoutingError>()`.
+    pub fn unwrap_response(
+        response: MessageRouterResponse,
+        message_service: ServiceCode,
+    ) -> BinResult<MessageRouterResponse> {
+        let unconnected_send = ServiceCode::from(ConnectionManagerServiceCode::UnconnectedSend);
+
+        let status = response.response_data.status;
+        let is_routing_error = response.service_container.service() == unconnected_send
+            && status != ResponseStatusCode::Success
+            && (message_service != unconnected_send
+                || status == ResponseStatusCode::ConnectionFailure);
+
+        if !is_routing_error {
+            return Ok(response);
+        }
+
+        Err(binrw::Error::Custom {
+            pos: 0,
+            err: Box::new(RoutingError {
+                status,
+                additional_status: response.response_data.additional_status(),
+                remaining_path_size: response.response_data.reply_data().first().copied(),
+            }),
+        })
+    }
+}
+
+// ^^^^^^^^ End of UnconnectedSend impl ^^^^^^^^
+
+/// A request reading a single attribute of the Connection Manager instance
+pub fn connection_manager_attribute_request(
+    attribute_id: ConnectionManagerAttributeId,
+) -> MessageRouterRequest {
+    MessageRouterRequest::new(
+        ServiceCode::GetAttributeSingle,
+        connection_manager_path().with_attribute(attribute_id as u16),
+    )
+}
+
+/// A Get Connection Data request for the connection entry with the given number
+pub fn get_connection_data_request(connection_number: CipUint) -> MessageRouterRequest {
+    MessageRouterRequest::new_data(
+        ConnectionManagerServiceCode::GetConnectionData.into(),
+        connection_manager_path(),
+        Some(Box::new(connection_number)),
+    )
+}
+
+/// Which of the connection entries of the device are in use, one bit per entry
+#[binrw]
+#[brw(little)]
+#[derive(Debug, PartialEq, Clone)]
+pub struct ConnectionEntryList {
+    pub entry_count: CipUint,
+
+    #[br(count = (entry_count as usize).div_ceil(8))]
+    pub open_bits: Vec<CipByte>,
+}
+
+// ======= Start of ConnectionEntryList impl ========
+
+impl ConnectionEntryList {
+    pub fn is_open(&self, connection_number: CipUint) -> bool {
+        connection_number < self.entry_count
+            && self
+                .open_bits
+                .get(connection_number as usize / 8)
+                .is_some_and(|bits| bits & (1 << (connection_number % 8)) != 0)
+    }
+
+    /// The numbers of the entries in use, which Get Connection Data takes
+    pub fn open_connections(&self) -> Vec<CipUint> {
+        (0..self.entry_count)
+            .filter(|connection_number| self.is_open(*connection_number))
+            .collect()
+    }
+
+    /// The number of connections the device can still open
+    pub fn free_entries(&self) -> usize {
+        self.entry_count as usize - self.open_connections().len()
+    }
+}
+
+// ^^^^^^^^ End of ConnectionEntryList impl ^^^^^^^^
+
+/// The state of a connection entry
+#[binrw]
+#[brw(little)]
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum ConnectionState {
+    #[brw(magic = 0x0000u16)]
+    NonExistent,
+    #[brw(magic = 0x0001u16)]
+    Configuring,
+    #[brw(magic = 0x0002u16)]
+    WaitingForConnectionId,
+    #[brw(magic = 0x0003u16)]
+    Established,
+    #[brw(magic = 0x0004u16)]
+    TimedOut,
+    #[brw(magic = 0x0005u16)]
+    DeferredDelete,
+    #[brw(magic = 0x0006u16)]
+    Closing,
+    Reserved(u16),
+}
+
+/// The reply of Get Connection Data, describing one connection and who opened it
+#[binrw]
+#[brw(little)]
+#[derive(Debug, PartialEq, Clone)]
+pub struct ConnectionData {
+    pub connection_number: CipUint,
+    pub connection_state: ConnectionState,
+    pub originator_port: CipUint,
+    pub target_port: CipUint,
+    pub connection_serial_number: CipUint,
+    pub originator_vendor_id: CipUint,
+    pub originator_serial_number: CipUdint,
+    pub originator_o_t_connection_id: CipUdint,
+    pub target_o_t_connection_id: CipUdint,
+    #[brw(pad_after = 3)]
+    pub o_t_timeout_multiplier: CipUsint,
+    /// Requested packet interval, in microseconds
+    pub originator_o_t_rpi: CipUdint,
+    /// Actual packet interval, in microseconds
+    pub originator_o_t_api: CipUdint,
+    pub originator_t_o_connection_id: CipUdint,
+    pub target_t_o_connection_id: CipUdint,
+    #[brw(pad_after = 3)]
+    pub t_o_timeout_multiplier: CipUsint,
+    pub originator_t_o_rpi: CipUdint,
+    pub originator_t_o_api: CipUdint,
+}
+
+/// The Connection Manager counters and connections of a device, each attribute left as `None` if
+/// the device does not support it
+///
+/// The counters count up from power up and wrap around.
+#[derive(Debug, PartialEq, Clone, Default)]
+pub struct ConnectionManagerStatus {
+    pub open_requests: Option<CipUint>,
+    pub open_format_rejects: Option<CipUint>,
+    /// Forward Opens turned down because the device ran out of connections or buffers
+    pub open_resource_rejects: Option<CipUint>,
+    pub open_other_rejects: Option<CipUint>,
+    pub close_requests: Option<CipUint>,
+    pub close_format_rejects: Option<CipUint>,
+    pub close_other_rejects: Option<CipUint>,
+    pub connection_timeouts: Option<CipUint>,
+    pub connection_entries: Option<ConnectionEntryList>,
+    /// In tenths of a percent
+    pub cpu_utilization: Option<CipUint>,
+    pub max_buffer_size: Option<CipUdint>,
+    pub buffer_size_remaining: Option<CipUdint>,
+    /// The data of every open connection the device would describe, in connection number order
+    pub connections: Vec<ConnectionData>,
+}
+
+// ======= Start of ConnectionManagerStatus impl ========
+
+impl ConnectionManagerStatus {
+    /// Whether the device has refused a Forward Open for lack of resources since power up
+    pub fn has_resource_rejects(&self) -> bool {
+        self.open_resource_rejects
+            .is_some_and(|rejects| rejects > 0)
+    }
+
+    /// Whether every connection entry is in use, so the next Forward Open will be refused
+    pub fn is_exhausted(&self) -> bool {
+        self.connection_entries
+            .as_ref()
+            .is_some_and(|entries| entries.free_entries() == 0)
+    }
+}
+
+// ^^^^^^^^ End of ConnectionManagerStatus impl ^^^^^^^^
+
+const CONNECTION_MANAGER_ATTRIBUTES: [ConnectionManagerAttributeId; 12] = [
+    ConnectionManagerAttributeId::OpenRequests,
+    ConnectionManagerAttributeId::OpenFormatRejects,
+    ConnectionManagerAttributeId::OpenResourceRejects,
+    ConnectionManagerAttributeId::OpenOtherRejects,
+    ConnectionManagerAttributeId::CloseRequests,
+    ConnectionManagerAttributeId::CloseFormatRejects,
+    ConnectionManagerAttributeId::CloseOtherRejects,
+    ConnectionManagerAttributeId::ConnectionTimeouts,
+    ConnectionManagerAttributeId::CpuUtilization,
+    ConnectionManagerAttributeId::MaxBufferSize,
+    ConnectionManagerAttributeId::BufferSizeRemaining,
+    ConnectionManagerAttributeId::ConnectionEntryList,
+];
+
+/// Reads the Connection Manager counters one Get Attribute Single at a time, then the data of every
+/// open connection in the connection entry list
+///
+/// The connections are left out when the device does not support Get Connection Data.
+#[derive(Debug)]
+pub struct ConnectionManagerRead {
+    next_attribute: usize,
+    remaining_connections: Vec<CipUint>,
+    status: ConnectionManagerStatus,
+}
+
+// ======= Start of ConnectionManagerRead impl ========
+
+impl ConnectionManagerRead {
+    pub fn new() -> Self {
+        ConnectionManagerRead {
+            next_attribute: 0,
+            remaining_connections: Vec::new(),
+            status: ConnectionManagerStatus::default(),
+        }
+    }
+
+    pub fn is_complete(&self) -> bool {
+        self.next_attribute >= CONNECTION_MANAGER_ATTRIBUTES.len()
+            && self.remaining_connections.is_empty()
+    }
+
+    pub fn next_request(&self) -> Option<MessageRouterRequest> {
+        match CONNECTION_MANAGER_ATTRIBUTES.get(self.next_attribute) {
+            Some(attribute_id) => Some(connection_manager_attribute_request(*attribute_id)),
+            None => self
+                .remaining_connections
+                .last()
+                .map(|connection_number| get_connection_data_request(*connection_number)),
+        }
+    }
+
+    pub fn handle_response(&mut self, response: &MessageRouterResponse) -> BinResult<()> {
+        match CONNECTION_MANAGER_ATTRIBUTES
+            .get(self.next_attribute)
+            .copied()
+        {
+            Some(attribute_id) => self.handle_attribute(attribute_id, response),
+            None => self.handle_connection_data(response),
+        }
+    }
+
+    fn handle_attribute(
+        &mut self,
+        attribute_id: ConnectionManagerAttributeId,
+        response: &MessageRouterResponse,
+    ) -> BinResult<()> {
+        self.next_attribute += 1;
+
+        if response.response_data.is_attribute_unsupported() {
+            return Ok(());
+        }
+        response.check_status()?;
+
+        let reply = &response.response_data;
+        let status = &mut self.status;
+        match attribute_id {
+            ConnectionManagerAttributeId::OpenRequests => {
+                status.open_requests = Some(reply.read_reply()?)
+            }
+            ConnectionManagerAttributeId::OpenFormatRejects => {
+                status.open_format_rejects = Some(reply.read_reply()?)
+            }
+            ConnectionManagerAttributeId::OpenResourceRejects => {
+                status.open_resource_rejects = Some(reply.read_reply()?)
+            }
+            ConnectionManagerAttributeId::OpenOtherRejects => {
+                status.open_other_rejects = Some(reply.read_reply()?)
+            }
+            ConnectionManagerAttributeId::CloseRequests => {
+                status.close_requests = Some(reply.read_reply()?)
+            }
+            ConnectionManagerAttributeId::CloseFormatRejects => {
+                status.close_format_rejects = Some(reply.read_reply()?)
+            }
+            ConnectionManagerAttributeId::CloseOtherRejects => {
+                status.close_other_rejects = Some(reply.read_reply()?)
+            }
+            ConnectionManagerAttributeId::ConnectionTimeouts => {
+                status.connection_timeouts = Some(reply.read_reply()?)
+            }
+            ConnectionManagerAttributeId::ConnectionEntryList => {
+                let connection_entries: ConnectionEntryList = reply.read_reply()?;
+
+                // Connections are popped off the end, so keep them in reverse to read them in order
+                self.remaining_connections = connection_entries
+                    .open_connections()
+                    .into_iter()
+                    .rev()
+                    .collect();
+                status.connection_entries = Some(connection_entries);
+            }
+            ConnectionManagerAttributeId::CpuUtilization => {
+                status.cpu_utilization = Some(reply.read_reply()?)
+            }
+            ConnectionManagerAttributeId::MaxBufferSize => {
+                status.max_buffer_size = Some(reply.read_reply()?)
+            }
+            ConnectionManagerAttributeId::BufferSizeRemaining => {
+                status.buffer_size_remaining = Some(reply.read_reply()?)
+            }
+        }
+
+        Ok(())
+    }
+
+    fn handle_connection_data(&mut self, response: &MessageRouterResponse) -> BinResult<()> {
+        if self.remaining_connections.pop().is_none() {
+            return Ok(());
+        }
+
+        match response.response_data.status {
+            // The device does not describe its connections
+            ResponseStatusCode::ServiceNotSupported => {
+                self.remaining_connections.clear();
+                return Ok(());
+            }
+            // The connection closed after the connection entry list was read
+            ResponseStatusCode::ObjectDoesNotExist
+            | ResponseStatusCode::ObjectStateConflict
+            | ResponseStatusCode::InvalidParameter
+            | ResponseStatusCode::InvalidParameterValue => return Ok(()),
+            _ => response.check_status()?,
+        }
+
+        self.status
+            .connections
+            .push(response.response_data.read_reply()?);
+
+        Ok(())
+    }
+
+    /// The counters and connections read so far
+    pub fn status(&self) -> &ConnectionManagerStatus {
+        &self.status
+    }
+
+    /// The counters and connections, once the read is complete
+    pub fn into_status(self) -> Option<ConnectionManagerStatus> {
+        self.is_complete().then_some(self.status)
+    }
+}
+
+impl Default for ConnectionManagerRead {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// ^^^^^^^^ End of ConnectionManagerRead impl ^^^^^^^^
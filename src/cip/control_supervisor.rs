@@ -0,0 +1,150 @@
+use binrw::binrw; // #[binrw] attribute
+
+use crate::cip::message::data::CipData;
+use crate::cip::message::request::MessageRouterRequest;
+use crate::cip::message::shared::ServiceCode;
+use crate::cip::path::CipPath;
+use crate::cip::types::CipBool;
+
+/// The Control Supervisor object of the drive profile, which runs, stops and resets the drive
+pub const CONTROL_SUPERVISOR_CLASS_ID: u16 = 0x29;
+
+/*
+Control Supervisor instance attributes
+    Attribute: 3 (Run1, settable)
+    Attribute: 4 (Run2, settable)
+    Attribute: 5 (NetCtrl, settable)
+    Attribute: 6 (State)
+    Attribute: 7 (Running1)
+    Attribute: 8 (Running2)
+    Attribute: 9 (Ready)
+    Attribute: 10 (Faulted)
+    Attribute: 11 (Warning)
+    Attribute: 12 (FaultRst, settable)
+    Attribute: 13 (FaultCode)
+    Attribute: 14 (WarnCode)
+    Attribute: 15 (CtlFromNet)
+    Attribute: 16 (DNFaultMode, settable)
+    Attribute: 17 (ForceFault, settable)
+    Attribute: 18 (ForceStatus)
+*/
+#[derive(Debug, PartialEq, Clone, Copy)]
+#[repr(u16)]
+pub enum ControlSupervisorAttributeId {
+    RunForward = 0x03,
+    RunReverse = 0x04,
+    NetControl = 0x05,
+    State = 0x06,
+    RunningForward = 0x07,
+    RunningReverse = 0x08,
+    Ready = 0x09,
+    Faulted = 0x0A,
+    Warning = 0x0B,
+    FaultReset = 0x0C,
+    FaultCode = 0x0D,
+    WarningCode = 0x0E,
+    ControlFromNet = 0x0F,
+    NetFaultMode = 0x10,
+    ForceFault = 0x11,
+    ForceStatus = 0x12,
+}
+
+/// The state of the drive state machine
+#[binrw]
+#[brw(little)]
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum ControlSupervisorState {
+    #[brw(magic = 0x00u8)]
+    VendorSpecific,
+    #[brw(magic = 0x01u8)]
+    Startup,
+    #[brw(magic = 0x02u8)]
+    NotReady,
+    #[brw(magic = 0x03u8)]
+    Ready,
+    #[brw(magic = 0x04u8)]
+    Enabled,
+    #[brw(magic = 0x05u8)]
+    Stopping,
+    #[brw(magic = 0x06u8)]
+    FaultStop,
+    #[brw(magic = 0x07u8)]
+    Faulted,
+    Reserved(u8),
+}
+
+/// What the drive does when it loses its network connection
+#[binrw]
+#[brw(little)]
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum NetFaultMode {
+    #[brw(magic = 0x00u8)]
+    FaultAndStop,
+    #[brw(magic = 0x01u8)]
+    IgnoreFault,
+    #[brw(magic = 0x02u8)]
+    VendorSpecific,
+    Reserved(u8),
+}
+
+fn control_supervisor_path(attribute_id: ControlSupervisorAttributeId) -> CipPath {
+    CipPath::new(CONTROL_SUPERVISOR_CLASS_ID, 0x01).with_attribute(attribute_id as u16)
+}
+
+/// A request reading a single attribute of the Control Supervisor instance
+pub fn control_supervisor_attribute_request(
+    attribute_id: ControlSupervisorAttributeId,
+) -> MessageRouterRequest {
+    MessageRouterRequest::new(
+        ServiceCode::GetAttributeSingle,
+        control_supervisor_path(attribute_id),
+    )
+}
+
+/// A writable attribute of the Control Supervisor object, along with its new value
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum ControlSupervisorSetting {
+    RunForward(bool),
+    RunReverse(bool),
+    /// Run and stop from the network instead of the local inputs
+    NetControl(bool),
+    /// The drive resets a fault on the rising edge
+    FaultReset(bool),
+    NetFaultMode(NetFaultMode),
+    ForceFault(bool),
+}
+
+// ======= Start of ControlSupervisorSetting impl ========
+
+impl ControlSupervisorSetting {
+    pub fn attribute_id(&self) -> ControlSupervisorAttributeId {
+        match self {
+            ControlSupervisorSetting::RunForward(_) => ControlSupervisorAttributeId::RunForward,
+            ControlSupervisorSetting::RunReverse(_) => ControlSupervisorAttributeId::RunReverse,
+            ControlSupervisorSetting::NetControl(_) => ControlSupervisorAttributeId::NetControl,
+            ControlSupervisorSetting::FaultReset(_) => ControlSupervisorAttributeId::FaultReset,
+            ControlSupervisorSetting::NetFaultMode(_) => ControlSupervisorAttributeId::NetFaultMode,
+            ControlSupervisorSetting::ForceFault(_) => ControlSupervisorAttributeId::ForceFault,
+        }
+    }
+
+    /// A Set Attribute Single request writing the setting to the Control Supervisor instance
+    pub fn into_router_request(self) -> MessageRouterRequest {
+        let value: Box<dyn CipData> = match self {
+            ControlSupervisorSetting::RunForward(value)
+            | ControlSupervisorSetting::RunReverse(value)
+            | ControlSupervisorSetting::NetControl(value)
+            | ControlSupervisorSetting::FaultReset(value)
+            | ControlSupervisorSetting::ForceFault(value) => Box::new(value as CipBool),
+            ControlSupervisorSetting::NetFaultMode(mode) => Box::new(mode),
+        };
+
+        MessageRouterRequest::new_data(
+            ServiceCode::SetAttributeSingle,
+            control_supervisor_path(self.attribute_id()),
+            Some(value),
+        )
+    }
+}
+
+// ^^^^^^^^ End of ControlSupervisorSetting impl ^^^^^^^^
@@ -0,0 +1,367 @@
+use std::net::Ipv4Addr;
+
+use bilge::prelude::{bitsize, u24, u3, u7, Bitsized, DebugBits, FromBits, Number};
+
+use binrw::{
+    binrw, // #[binrw] attribute
+    helpers::until_eof,
+    BinRead,
+    BinResult,
+    BinWrite,
+};
+
+use crate::cip::ethernet_link::PhysicalAddress;
+use crate::cip::message::request::MessageRouterRequest;
+use crate::cip::message::response::{MessageRouterResponse, ResponseStatusCode};
+use crate::cip::message::shared::ServiceCode;
+use crate::cip::path::CipPath;
+use crate::cip::types::{CipBool, CipDword, CipUdint, CipUint, CipUsint};
+
+/// The Device Level Ring object, present on devices with two Ethernet ports that can join a ring
+pub const DLR_CLASS_ID: u16 = 0x47;
+
+/// Object specific services of the DLR object, only supported by a ring supervisor
+#[derive(Debug, PartialEq, Clone, Copy)]
+#[repr(u8)]
+pub enum DlrServiceCode {
+    /// Updates the last active nodes on both ports to locate a partial fault
+    VerifyFaultLocation = 0x4B,
+    /// Resumes supervision after a rapid fault/restore cycle
+    ClearRapidFaults = 0x4C,
+    /// Rebuilds the list of ring participants
+    RestartSignOn = 0x4D,
+    ClearGatewayPartialNetworkFault = 0x4E,
+}
+
+impl From<DlrServiceCode> for ServiceCode {
+    fn from(service_code: DlrServiceCode) -> Self {
+        ServiceCode::from(u7::new(service_code as u8))
+    }
+}
+
+/*
+DLR instance attributes
+    Attribute: 1 (Network Topology)
+    Attribute: 2 (Network Status)
+    Attribute: 3 (Ring Supervisor Status)
+    Attribute: 4 (Ring Supervisor Config) -- settable
+    Attribute: 5 (Ring Faults Count) -- settable
+    Attribute: 6 (Last Active Node on Port 1)
+    Attribute: 7 (Last Active Node on Port 2)
+    Attribute: 8 (Ring Protocol Participants Count)
+    Attribute: 9 (Ring Protocol Participants List)
+    Attribute: 10 (Active Supervisor Address)
+    Attribute: 11 (Active Supervisor Precedence)
+    Attribute: 12 (Capability Flags)
+*/
+#[derive(Debug, PartialEq, Clone, Copy)]
+#[repr(u16)]
+pub enum DlrAttributeId {
+    NetworkTopology = 0x01,
+    NetworkStatus = 0x02,
+    RingSupervisorStatus = 0x03,
+    RingSupervisorConfig = 0x04,
+    RingFaultsCount = 0x05,
+    LastActiveNodeOnPort1 = 0x06,
+    LastActiveNodeOnPort2 = 0x07,
+    RingParticipantsCount = 0x08,
+    RingParticipantsList = 0x09,
+    ActiveSupervisorAddress = 0x0A,
+    ActiveSupervisorPrecedence = 0x0B,
+    CapabilityFlags = 0x0C,
+}
+
+#[binrw]
+#[brw(little)]
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum NetworkTopology {
+    #[brw(magic = 0x00u8)]
+    Linear,
+    #[brw(magic = 0x01u8)]
+    Ring,
+    Reserved(u8),
+}
+
+#[binrw]
+#[brw(little)]
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum NetworkStatus {
+    #[brw(magic = 0x00u8)]
+    Normal,
+    /// The ring is broken, traffic flows around the break
+    #[brw(magic = 0x01u8)]
+    RingFault,
+    /// A loop was detected while the topology is linear
+    #[brw(magic = 0x02u8)]
+    UnexpectedLoopDetected,
+    /// Beacons are lost in one direction only, e.g. a faulty cable
+    #[brw(magic = 0x03u8)]
+    PartialNetworkFault,
+    /// The supervisor stopped after too many faults in a short time
+    #[brw(magic = 0x04u8)]
+    RapidFaultRestoreCycle,
+    Reserved(u8),
+}
+
+#[binrw]
+#[brw(little)]
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum RingSupervisorStatus {
+    #[brw(magic = 0x00u8)]
+    BackupSupervisor,
+    #[brw(magic = 0x01u8)]
+    ActiveSupervisor,
+    #[brw(magic = 0x02u8)]
+    RingNode,
+    #[brw(magic = 0x03u8)]
+    NonDlrTopology,
+    /// The supervisor cannot support the configured beacon interval or timeout
+    #[brw(magic = 0x04u8)]
+    UnsupportedParameters,
+    Reserved(u8),
+}
+
+#[binrw]
+#[brw(little)]
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct RingSupervisorConfig {
+    #[br(map = |enable: CipBool| enable != 0)]
+    #[bw(map = |enable: &bool| *enable as CipBool)]
+    pub supervisor_enable: bool,
+    /// The supervisor with the highest precedence becomes the active one
+    pub supervisor_precedence: CipUsint,
+    /// Microseconds between beacons
+    pub beacon_interval: CipUdint,
+    /// Microseconds without a beacon before the ring is considered broken
+    pub beacon_timeout: CipUdint,
+    pub dlr_vlan_id: CipUint,
+}
+
+/// A ring node, identified by its IP address (zero if unknown) and MAC address
+#[binrw]
+#[brw(little)]
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct RingNode {
+    #[br(map = |address: CipUdint| Ipv4Addr::from(address))]
+    #[bw(map = |address: &Ipv4Addr| CipUdint::from(*address))]
+    pub ip_address: Ipv4Addr,
+    pub mac_address: PhysicalAddress,
+}
+
+// ======= Start of RingNode impl ========
+
+impl std::fmt::Display for RingNode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} ({})", self.ip_address, self.mac_address)
+    }
+}
+
+// ^^^^^^^^ End of RingNode impl ^^^^^^^^
+
+/// The nodes of the ring, as reported by the active supervisor
+#[binrw]
+#[brw(little)]
+#[derive(Debug, PartialEq, Clone)]
+pub struct RingParticipantsList {
+    #[br(parse_with = until_eof)]
+    pub nodes: Vec<RingNode>,
+}
+
+#[bitsize(32)]
+#[derive(FromBits, PartialEq, DebugBits, BinRead, BinWrite, Copy, Clone)]
+#[br(map = CipDword::into)]
+#[bw(map = |&x| CipDword::from(x))]
+pub struct DlrCapabilityFlags {
+    pub announce_based_ring_node: bool,
+    pub beacon_based_ring_node: bool,
+    pub reserved: u3,
+    pub supervisor_capable: bool,
+    pub redundant_gateway_capable: bool,
+    pub flush_table_frame_capable: bool,
+    pub reserved_2: u24,
+}
+
+fn dlr_path() -> CipPath {
+    CipPath::new(DLR_CLASS_ID, 0x01)
+}
+
+/// A request reading a single attribute of the DLR instance
+pub fn dlr_attribute_request(attribute_id: DlrAttributeId) -> MessageRouterRequest {
+    MessageRouterRequest::new(
+        ServiceCode::GetAttributeSingle,
+        dlr_path().with_attribute(attribute_id as u16),
+    )
+}
+
+/// A request for one of the supervisor services of the DLR instance
+pub fn dlr_service_request(service_code: DlrServiceCode) -> MessageRouterRequest {
+    MessageRouterRequest::new(service_code.into(), dlr_path())
+}
+
+/// The ring state as seen by a device, each left as `None` if the device does not report it
+///
+/// Only the active supervisor reports the ring participants.
+#[derive(Debug, PartialEq, Clone, Default)]
+pub struct DlrStatus {
+    pub network_topology: Option<NetworkTopology>,
+    pub network_status: Option<NetworkStatus>,
+    pub supervisor_status: Option<RingSupervisorStatus>,
+    pub supervisor_config: Option<RingSupervisorConfig>,
+    pub ring_faults_count: Option<CipUint>,
+    /// The last node reachable through port 1 when the ring broke
+    pub last_active_node_port_1: Option<RingNode>,
+    /// The last node reachable through port 2 when the ring broke
+    pub last_active_node_port_2: Option<RingNode>,
+    pub participants_count: Option<CipUint>,
+    pub participants: Option<Vec<RingNode>>,
+    pub active_supervisor: Option<RingNode>,
+    pub active_supervisor_precedence: Option<CipUsint>,
+    pub capability_flags: Option<DlrCapabilityFlags>,
+}
+
+// ======= Start of DlrStatus impl ========
+
+impl DlrStatus {
+    /// Whether the device is in a ring that carries traffic normally
+    pub fn is_ring_normal(&self) -> bool {
+        self.network_topology == Some(NetworkTopology::Ring)
+            && self.network_status == Some(NetworkStatus::Normal)
+    }
+
+    /// The two nodes on either side of a ring break, as reported by the active supervisor
+    pub fn fault_location(&self) -> Option<(RingNode, RingNode)> {
+        match self.network_status? {
+            NetworkStatus::RingFault | NetworkStatus::PartialNetworkFault => {
+                Some((self.last_active_node_port_1?, self.last_active_node_port_2?))
+            }
+            _ => None,
+        }
+    }
+}
+
+// ^^^^^^^^ End of DlrStatus impl ^^^^^^^^
+
+/// Reads the ring state of a device one Get Attribute Single at a time
+///
+/// The attributes only the active ring supervisor knows are skipped on the other ring nodes.
+#[derive(Debug)]
+pub struct DlrRead {
+    next_attribute: usize,
+    status: DlrStatus,
+}
+
+// ======= Start of DlrRead impl ========
+
+impl DlrRead {
+    pub const ATTRIBUTES: [DlrAttributeId; 12] = [
+        DlrAttributeId::NetworkTopology,
+        DlrAttributeId::NetworkStatus,
+        DlrAttributeId::RingSupervisorStatus,
+        DlrAttributeId::RingSupervisorConfig,
+        DlrAttributeId::RingFaultsCount,
+        DlrAttributeId::LastActiveNodeOnPort1,
+        DlrAttributeId::LastActiveNodeOnPort2,
+        DlrAttributeId::RingParticipantsCount,
+        DlrAttributeId::RingParticipantsList,
+        DlrAttributeId::ActiveSupervisorAddress,
+        DlrAttributeId::ActiveSupervisorPrecedence,
+        DlrAttributeId::CapabilityFlags,
+    ];
+
+    pub fn new() -> Self {
+        DlrRead {
+            next_attribute: 0,
+            status: DlrStatus::default(),
+        }
+    }
+
+    pub fn is_complete(&self) -> bool {
+        self.next_attribute >= Self::ATTRIBUTES.len()
+    }
+
+    pub fn next_request(&self) -> Option<MessageRouterRequest> {
+        let attribute_id = Self::ATTRIBUTES.get(self.next_attribute)?;
+
+        Some(dlr_attribute_request(*attribute_id))
+    }
+
+    pub fn handle_response(&mut self, response: &MessageRouterResponse) -> BinResult<()> {
+        let Some(attribute_id) = Self::ATTRIBUTES.get(self.next_attribute) else {
+            return Ok(());
+        };
+        self.next_attribute += 1;
+
+        // Without a DLR object there is nothing else to read
+        if response.response_data.is_object_missing() {
+            self.next_attribute = Self::ATTRIBUTES.len();
+            return Ok(());
+        }
+        // Ring nodes reject the attributes only the active supervisor knows
+        if response.response_data.is_attribute_unsupported()
+            || response.response_data.status == ResponseStatusCode::ObjectStateConflict
+        {
+            return Ok(());
+        }
+        response.check_status()?;
+
+        let status = &mut self.status;
+        let reply_data = &response.response_data;
+        match attribute_id {
+            DlrAttributeId::NetworkTopology => {
+                status.network_topology = Some(reply_data.read_reply()?)
+            }
+            DlrAttributeId::NetworkStatus => status.network_status = Some(reply_data.read_reply()?),
+            DlrAttributeId::RingSupervisorStatus => {
+                status.supervisor_status = Some(reply_data.read_reply()?)
+            }
+            DlrAttributeId::RingSupervisorConfig => {
+                status.supervisor_config = Some(reply_data.read_reply()?)
+            }
+            DlrAttributeId::RingFaultsCount => {
+                status.ring_faults_count = Some(reply_data.read_reply()?)
+            }
+            DlrAttributeId::LastActiveNodeOnPort1 => {
+                status.last_active_node_port_1 = Some(reply_data.read_reply()?)
+            }
+            DlrAttributeId::LastActiveNodeOnPort2 => {
+                status.last_active_node_port_2 = Some(reply_data.read_reply()?)
+            }
+            DlrAttributeId::RingParticipantsCount => {
+                status.participants_count = Some(reply_data.read_reply()?)
+            }
+            DlrAttributeId::RingParticipantsList => {
+                let participants: RingParticipantsList = reply_data.read_reply()?;
+                status.participants = Some(participants.nodes);
+            }
+            DlrAttributeId::ActiveSupervisorAddress => {
+                status.active_supervisor = Some(reply_data.read_reply()?)
+            }
+            DlrAttributeId::ActiveSupervisorPrecedence => {
+                status.active_supervisor_precedence = Some(reply_data.read_reply()?)
+            }
+            DlrAttributeId::CapabilityFlags => {
+                status.capability_flags = Some(reply_data.read_reply()?)
+            }
+        }
+
+        Ok(())
+    }
+
+    /// The ring state read so far
+    pub fn status(&self) -> &DlrStatus {
+        &self.status
+    }
+
+    /// The ring state, once the read is complete
+    pub fn into_status(self) -> Option<DlrStatus> {
+        self.is_complete().then_some(self.status)
+    }
+}
+
+impl Default for DlrRead {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// ^^^^^^^^ End of DlrRead impl ^^^^^^^^
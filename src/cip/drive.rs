@@ -0,0 +1,335 @@
+use binrw::BinResult;
+
+use crate::cip::ac_dc_drive::{
+    ac_dc_drive_attribute_request, AcDcDriveAttributeId, AcDcDriveSetting, DriveMode,
+    AC_DC_DRIVE_CLASS_ID,
+};
+use crate::cip::control_supervisor::{
+    control_supervisor_attribute_request, ControlSupervisorAttributeId, ControlSupervisorSetting,
+    ControlSupervisorState, CONTROL_SUPERVISOR_CLASS_ID,
+};
+use crate::cip::message::request::MessageRouterRequest;
+use crate::cip::message::response::MessageRouterResponse;
+use crate::cip::types::{CipBool, CipInt, CipSint, CipUint};
+
+/// Converts a drive profile value to engineering units, the profile scales `unit` values down by
+/// `2^scale`, e.g. ScaledSpeed = RPM / 2^SpeedScale
+fn unscale(value: CipInt, scale: CipSint, unit: f64) -> f64 {
+    value as f64 * unit * 2f64.powi(scale as i32)
+}
+
+/// The status of a drive, each left as `None` if the drive does not support it
+///
+/// Speeds are in units of 2^SpeedScale RPM and currents in units of 2^CurrentScale * 100 mA, use
+/// the `_rpm` and `_amps` methods for engineering units.
+#[derive(Debug, PartialEq, Clone, Default)]
+pub struct DriveStatus {
+    pub state: Option<ControlSupervisorState>,
+    pub running_forward: Option<bool>,
+    pub running_reverse: Option<bool>,
+    pub ready: Option<bool>,
+    pub faulted: Option<bool>,
+    pub warning: Option<bool>,
+    pub fault_code: Option<CipUint>,
+    pub warning_code: Option<CipUint>,
+    pub control_from_net: Option<bool>,
+    pub at_reference: Option<bool>,
+    pub reference_from_net: Option<bool>,
+    pub drive_mode: Option<DriveMode>,
+    pub speed_actual: Option<CipInt>,
+    pub speed_reference: Option<CipInt>,
+    pub current_actual: Option<CipInt>,
+    pub speed_scale: Option<CipSint>,
+    pub current_scale: Option<CipSint>,
+}
+
+// ======= Start of DriveStatus impl ========
+
+impl DriveStatus {
+    pub fn is_running(&self) -> bool {
+        self.running_forward == Some(true) || self.running_reverse == Some(true)
+    }
+
+    pub fn is_faulted(&self) -> bool {
+        self.faulted == Some(true) || self.state == Some(ControlSupervisorState::Faulted)
+    }
+
+    pub fn speed_actual_rpm(&self) -> Option<f64> {
+        self.speed_actual
+            .map(|speed| unscale(speed, self.speed_scale.unwrap_or(0), 1.0))
+    }
+
+    pub fn speed_reference_rpm(&self) -> Option<f64> {
+        self.speed_reference
+            .map(|speed| unscale(speed, self.speed_scale.unwrap_or(0), 1.0))
+    }
+
+    pub fn current_actual_amps(&self) -> Option<f64> {
+        self.current_actual
+            .map(|current| unscale(current, self.current_scale.unwrap_or(0), 0.1))
+    }
+}
+
+// ^^^^^^^^ End of DriveStatus impl ^^^^^^^^
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+enum DriveAttribute {
+    ControlSupervisor(ControlSupervisorAttributeId),
+    AcDcDrive(AcDcDriveAttributeId),
+}
+
+// ======= Start of DriveAttribute impl ========
+
+impl DriveAttribute {
+    fn class_id(&self) -> u16 {
+        match self {
+            DriveAttribute::ControlSupervisor(_) => CONTROL_SUPERVISOR_CLASS_ID,
+            DriveAttribute::AcDcDrive(_) => AC_DC_DRIVE_CLASS_ID,
+        }
+    }
+
+    fn request(&self) -> MessageRouterRequest {
+        match self {
+            DriveAttribute::ControlSupervisor(attribute_id) => {
+                control_supervisor_attribute_request(*attribute_id)
+            }
+            DriveAttribute::AcDcDrive(attribute_id) => ac_dc_drive_attribute_request(*attribute_id),
+        }
+    }
+}
+
+// ^^^^^^^^ End of DriveAttribute impl ^^^^^^^^
+
+const DRIVE_STATUS_ATTRIBUTES: [DriveAttribute; 17] = [
+    DriveAttribute::ControlSupervisor(ControlSupervisorAttributeId::State),
+    DriveAttribute::ControlSupervisor(ControlSupervisorAttributeId::RunningForward),
+    DriveAttribute::ControlSupervisor(ControlSupervisorAttributeId::RunningReverse),
+    DriveAttribute::ControlSupervisor(ControlSupervisorAttributeId::Ready),
+    DriveAttribute::ControlSupervisor(ControlSupervisorAttributeId::Faulted),
+    DriveAttribute::ControlSupervisor(ControlSupervisorAttributeId::Warning),
+    DriveAttribute::ControlSupervisor(ControlSupervisorAttributeId::FaultCode),
+    DriveAttribute::ControlSupervisor(ControlSupervisorAttributeId::WarningCode),
+    DriveAttribute::ControlSupervisor(ControlSupervisorAttributeId::ControlFromNet),
+    DriveAttribute::AcDcDrive(AcDcDriveAttributeId::AtReference),
+    DriveAttribute::AcDcDrive(AcDcDriveAttributeId::ReferenceFromNet),
+    DriveAttribute::AcDcDrive(AcDcDriveAttributeId::DriveMode),
+    DriveAttribute::AcDcDrive(AcDcDriveAttributeId::SpeedActual),
+    DriveAttribute::AcDcDrive(AcDcDriveAttributeId::SpeedReference),
+    DriveAttribute::AcDcDrive(AcDcDriveAttributeId::CurrentActual),
+    DriveAttribute::AcDcDrive(AcDcDriveAttributeId::SpeedScale),
+    DriveAttribute::AcDcDrive(AcDcDriveAttributeId::CurrentScale),
+];
+
+/// Reads the status of a drive from its Control Supervisor and AC/DC Drive objects, one Get
+/// Attribute Single at a time
+///
+/// A drive without an AC/DC Drive object still reports the Control Supervisor status.
+#[derive(Debug)]
+pub struct DriveStatusRead {
+    next_attribute: usize,
+    status: DriveStatus,
+}
+
+// ======= Start of DriveStatusRead impl ========
+
+impl DriveStatusRead {
+    pub fn new() -> Self {
+        DriveStatusRead {
+            next_attribute: 0,
+            status: DriveStatus::default(),
+        }
+    }
+
+    pub fn is_complete(&self) -> bool {
+        self.next_attribute >= DRIVE_STATUS_ATTRIBUTES.len()
+    }
+
+    pub fn next_request(&self) -> Option<MessageRouterRequest> {
+        DRIVE_STATUS_ATTRIBUTES
+            .get(self.next_attribute)
+            .map(DriveAttribute::request)
+    }
+
+    pub fn handle_response(&mut self, response: &MessageRouterResponse) -> BinResult<()> {
+        let Some(attribute) = DRIVE_STATUS_ATTRIBUTES.get(self.next_attribute).copied() else {
+            return Ok(());
+        };
+        self.next_attribute += 1;
+
+        // Skip the rest of the attributes of a missing object
+        if response.response_data.is_object_missing() {
+            while DRIVE_STATUS_ATTRIBUTES
+                .get(self.next_attribute)
+                .is_some_and(|next| next.class_id() == attribute.class_id())
+            {
+                self.next_attribute += 1;
+            }
+            return Ok(());
+        }
+        if response.response_data.is_attribute_unsupported() {
+            return Ok(());
+        }
+        response.check_status()?;
+
+        let reply = &response.response_data;
+        let read_bool =
+            || -> BinResult<Option<bool>> { Ok(Some(reply.read_reply::<CipBool>()? != 0)) };
+        let status = &mut self.status;
+        match attribute {
+            DriveAttribute::ControlSupervisor(attribute_id) => match attribute_id {
+                ControlSupervisorAttributeId::State => status.state = Some(reply.read_reply()?),
+                ControlSupervisorAttributeId::RunningForward => {
+                    status.running_forward = read_bool()?
+                }
+                ControlSupervisorAttributeId::RunningReverse => {
+                    status.running_reverse = read_bool()?
+                }
+                ControlSupervisorAttributeId::Ready => status.ready = read_bool()?,
+                ControlSupervisorAttributeId::Faulted => status.faulted = read_bool()?,
+                ControlSupervisorAttributeId::Warning => status.warning = read_bool()?,
+                ControlSupervisorAttributeId::FaultCode => {
+                    status.fault_code = Some(reply.read_reply()?)
+                }
+                ControlSupervisorAttributeId::WarningCode => {
+                    status.warning_code = Some(reply.read_reply()?)
+                }
+                ControlSupervisorAttributeId::ControlFromNet => {
+                    status.control_from_net = read_bool()?
+                }
+                _ => (),
+            },
+            DriveAttribute::AcDcDrive(attribute_id) => match attribute_id {
+                AcDcDriveAttributeId::AtReference => status.at_reference = read_bool()?,
+                AcDcDriveAttributeId::ReferenceFromNet => status.reference_from_net = read_bool()?,
+                AcDcDriveAttributeId::DriveMode => status.drive_mode = Some(reply.read_reply()?),
+                AcDcDriveAttributeId::SpeedActual => {
+                    status.speed_actual = Some(reply.read_reply()?)
+                }
+                AcDcDriveAttributeId::SpeedReference => {
+                    status.speed_reference = Some(reply.read_reply()?)
+                }
+                AcDcDriveAttributeId::CurrentActual => {
+                    status.current_actual = Some(reply.read_reply()?)
+                }
+                AcDcDriveAttributeId::SpeedScale => status.speed_scale = Some(reply.read_reply()?),
+                AcDcDriveAttributeId::CurrentScale => {
+                    status.current_scale = Some(reply.read_reply()?)
+                }
+                _ => (),
+            },
+        }
+
+        Ok(())
+    }
+
+    /// The status read so far
+    pub fn status(&self) -> &DriveStatus {
+        &self.status
+    }
+
+    /// The status, once the read is complete
+    pub fn into_status(self) -> Option<DriveStatus> {
+        self.is_complete().then_some(self.status)
+    }
+}
+
+impl Default for DriveStatusRead {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// ^^^^^^^^ End of DriveStatusRead impl ^^^^^^^^
+
+/// Runs any drive that implements the CIP drive profile over explicit messages
+///
+/// Each command is a list of requests to send in order, checking the status of every reply. Wrap
+/// them in an `UnconnectedSend` to reach a drive behind a bridge.
+#[derive(Debug, PartialEq, Clone, Copy, Default)]
+pub struct Drive {
+    /// The SpeedScale of the drive, speeds are in units of 2^speed_scale RPM
+    pub speed_scale: CipSint,
+}
+
+// ======= Start of Drive impl ========
+
+impl Drive {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// A drive using the speed scaling the drive reported, if any
+    pub fn from_status(status: &DriveStatus) -> Self {
+        Drive {
+            speed_scale: status.speed_scale.unwrap_or(0),
+        }
+    }
+
+    /// Runs the drive and takes its speed reference from the network instead of the local inputs
+    pub fn take_network_control(&self) -> Vec<MessageRouterRequest> {
+        vec![
+            ControlSupervisorSetting::NetControl(true).into_router_request(),
+            AcDcDriveSetting::NetReference(true).into_router_request(),
+        ]
+    }
+
+    /// Hands the run command and speed reference back to the local inputs
+    pub fn release_network_control(&self) -> Vec<MessageRouterRequest> {
+        vec![
+            ControlSupervisorSetting::NetControl(false).into_router_request(),
+            AcDcDriveSetting::NetReference(false).into_router_request(),
+        ]
+    }
+
+    pub fn start_forward(&self) -> Vec<MessageRouterRequest> {
+        vec![
+            ControlSupervisorSetting::RunReverse(false).into_router_request(),
+            ControlSupervisorSetting::RunForward(true).into_router_request(),
+        ]
+    }
+
+    pub fn start_reverse(&self) -> Vec<MessageRouterRequest> {
+        vec![
+            ControlSupervisorSetting::RunForward(false).into_router_request(),
+            ControlSupervisorSetting::RunReverse(true).into_router_request(),
+        ]
+    }
+
+    pub fn stop(&self) -> Vec<MessageRouterRequest> {
+        vec![
+            ControlSupervisorSetting::RunForward(false).into_router_request(),
+            ControlSupervisorSetting::RunReverse(false).into_router_request(),
+        ]
+    }
+
+    /// Clears and sets the fault reset, since the drive only resets on the rising edge
+    pub fn reset_fault(&self) -> Vec<MessageRouterRequest> {
+        vec![
+            ControlSupervisorSetting::FaultReset(false).into_router_request(),
+            ControlSupervisorSetting::FaultReset(true).into_router_request(),
+        ]
+    }
+
+    /// Sets the speed reference, rejecting speeds the SpeedRef attribute cannot hold
+    pub fn set_speed_request(&self, rpm: f64) -> BinResult<MessageRouterRequest> {
+        let speed_reference = (rpm / 2f64.powi(self.speed_scale as i32)).round();
+
+        if !(CipInt::MIN as f64..=CipInt::MAX as f64).contains(&speed_reference) {
+            return Err(binrw::Error::Custom {
+                pos: 0,
+                err: Box::new(format!(
+                    "A speed of {} RPM does not fit the speed reference with a speed scale of {}",
+                    rpm, self.speed_scale
+                )),
+            });
+        }
+
+        Ok(AcDcDriveSetting::SpeedReference(speed_reference as CipInt).into_router_request())
+    }
+
+    pub fn status_read(&self) -> DriveStatusRead {
+        DriveStatusRead::new()
+    }
+}
+
+// ^^^^^^^^ End of Drive impl ^^^^^^^^
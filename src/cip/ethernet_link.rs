@@ -0,0 +1,303 @@
+use bilge::prelude::{bitsize, u14, u25, u3, u7, Bitsized, DebugBits, FromBits, Number};
+
+use binrw::{
+    binrw, // #[binrw] attribute
+    BinRead,
+    BinWrite,
+};
+
+use crate::cip::message::data::CipData;
+use crate::cip::message::request::MessageRouterRequest;
+use crate::cip::message::shared::ServiceCode;
+use crate::cip::path::CipPath;
+use crate::cip::types::{CipByte, CipShortString, CipUdint, CipUint, CipUsint};
+
+/// The Ethernet Link object, with one instance per Ethernet port of the device
+pub const ETHERNET_LINK_CLASS_ID: u16 = 0xF6;
+
+/// Object specific services of the Ethernet Link object
+#[derive(Debug, PartialEq, Clone, Copy)]
+#[repr(u8)]
+pub enum EthernetLinkServiceCode {
+    /// Reads the interface or media counters and resets them to zero
+    GetAndClear = 0x4C,
+}
+
+impl From<EthernetLinkServiceCode> for ServiceCode {
+    fn from(service_code: EthernetLinkServiceCode) -> Self {
+        ServiceCode::from(u7::new(service_code as u8))
+    }
+}
+
+/*
+Ethernet Link instance attributes
+    Attribute: 1 (Interface Speed)
+    Attribute: 2 (Interface Flags)
+    Attribute: 3 (Physical Address)
+    Attribute: 4 (Interface Counters)
+    Attribute: 5 (Media Counters)
+    Attribute: 6 (Interface Control) -- settable
+    Attribute: 7 (Interface Type)
+    Attribute: 8 (Interface State)
+    Attribute: 9 (Admin State) -- settable
+    Attribute: 10 (Interface Label)
+*/
+#[derive(Debug, PartialEq, Clone, Copy)]
+#[repr(u16)]
+pub enum EthernetLinkAttributeId {
+    InterfaceSpeed = 0x01,
+    InterfaceFlags = 0x02,
+    PhysicalAddress = 0x03,
+    InterfaceCounters = 0x04,
+    MediaCounters = 0x05,
+    InterfaceControl = 0x06,
+    InterfaceType = 0x07,
+    InterfaceState = 0x08,
+    AdminState = 0x09,
+    InterfaceLabel = 0x0A,
+}
+
+/// Outcome of the speed and duplex negotiation of the link
+#[bitsize(3)]
+#[derive(FromBits, Debug, PartialEq, Clone, Copy)]
+pub enum NegotiationStatus {
+    InProgress = 0,
+    /// Auto-negotiation and speed detection failed, the default speed and half duplex are used
+    SpeedDetectionFailed = 1,
+    /// Auto-negotiation failed but the speed was detected, half duplex is used
+    DuplexNegotiationFailed = 2,
+    Negotiated = 3,
+    /// Auto-negotiation was not attempted, the speed and duplex are forced
+    Forced = 4,
+    #[fallback]
+    Reserved,
+}
+
+#[bitsize(32)]
+#[derive(FromBits, PartialEq, DebugBits, BinRead, BinWrite, Copy, Clone)]
+#[br(map = CipUdint::into)]
+#[bw(map = |&x| CipUdint::from(x))]
+pub struct InterfaceFlags {
+    pub link_active: bool,
+    pub full_duplex: bool,
+    pub negotiation_status: NegotiationStatus,
+    pub manual_setting_requires_reset: bool,
+    pub local_hardware_fault: bool,
+    pub reserved: u25,
+}
+
+#[binrw]
+#[brw(little)]
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct PhysicalAddress(pub [CipByte; 6]);
+
+// ======= Start of PhysicalAddress impl ========
+
+impl std::fmt::Display for PhysicalAddress {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let octets: Vec<String> = self
+            .0
+            .iter()
+            .map(|octet| format!("{:02x}", octet))
+            .collect();
+        write!(f, "{}", octets.join(":"))
+    }
+}
+
+// ^^^^^^^^ End of PhysicalAddress impl ^^^^^^^^
+
+#[binrw]
+#[brw(little)]
+#[derive(Debug, PartialEq, Clone, Default)]
+pub struct InterfaceCounters {
+    pub in_octets: CipUdint,
+    pub in_unicast_packets: CipUdint,
+    pub in_non_unicast_packets: CipUdint,
+    pub in_discards: CipUdint,
+    pub in_errors: CipUdint,
+    pub in_unknown_protocols: CipUdint,
+    pub out_octets: CipUdint,
+    pub out_unicast_packets: CipUdint,
+    pub out_non_unicast_packets: CipUdint,
+    pub out_discards: CipUdint,
+    pub out_errors: CipUdint,
+}
+
+#[binrw]
+#[brw(little)]
+#[derive(Debug, PartialEq, Clone, Default)]
+pub struct MediaCounters {
+    pub alignment_errors: CipUdint,
+    pub fcs_errors: CipUdint,
+    pub single_collisions: CipUdint,
+    pub multiple_collisions: CipUdint,
+    pub sqe_test_errors: CipUdint,
+    pub deferred_transmissions: CipUdint,
+    pub late_collisions: CipUdint,
+    pub excessive_collisions: CipUdint,
+    pub mac_transmit_errors: CipUdint,
+    pub carrier_sense_errors: CipUdint,
+    pub frame_too_long: CipUdint,
+    pub mac_receive_errors: CipUdint,
+}
+
+// ======= Start of MediaCounters impl ========
+
+impl MediaCounters {
+    /// Frames lost to a collision of any kind
+    pub fn total_collisions(&self) -> u64 {
+        [
+            self.single_collisions,
+            self.multiple_collisions,
+            self.late_collisions,
+            self.excessive_collisions,
+        ]
+        .iter()
+        .map(|&count| count as u64)
+        .sum()
+    }
+}
+
+// ^^^^^^^^ End of MediaCounters impl ^^^^^^^^
+
+#[bitsize(16)]
+#[derive(FromBits, PartialEq, DebugBits, BinRead, BinWrite, Copy, Clone)]
+#[br(map = CipUint::into)]
+#[bw(map = |&x| CipUint::from(x))]
+pub struct InterfaceControlBits {
+    pub auto_negotiate: bool,
+    /// Only used when auto-negotiation is disabled
+    pub forced_full_duplex: bool,
+    pub reserved: u14,
+}
+
+#[binrw]
+#[brw(little)]
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct InterfaceControl {
+    pub control_bits: InterfaceControlBits,
+
+    /// The forced speed in Mbps, zero while auto-negotiating
+    pub forced_interface_speed: CipUint,
+}
+
+// ======= Start of InterfaceControl impl ========
+
+impl InterfaceControl {
+    pub fn new_auto_negotiate() -> Self {
+        InterfaceControl {
+            control_bits: InterfaceControlBits::new(true, false),
+            forced_interface_speed: 0,
+        }
+    }
+
+    pub fn new_forced(interface_speed: CipUint, full_duplex: bool) -> Self {
+        InterfaceControl {
+            control_bits: InterfaceControlBits::new(false, full_duplex),
+            forced_interface_speed: interface_speed,
+        }
+    }
+}
+
+// ^^^^^^^^ End of InterfaceControl impl ^^^^^^^^
+
+#[binrw]
+#[brw(little)]
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum InterfaceType {
+    #[brw(magic = 0x00u8)]
+    Unknown,
+    #[brw(magic = 0x01u8)]
+    Internal,
+    #[brw(magic = 0x02u8)]
+    TwistedPair,
+    #[brw(magic = 0x03u8)]
+    OpticalFiber,
+    Reserved(u8),
+}
+
+#[binrw]
+#[brw(little)]
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum InterfaceState {
+    #[brw(magic = 0x00u8)]
+    Unknown,
+    #[brw(magic = 0x01u8)]
+    Enabled,
+    #[brw(magic = 0x02u8)]
+    Disabled,
+    #[brw(magic = 0x03u8)]
+    Testing,
+    Reserved(u8),
+}
+
+#[derive(BinRead, BinWrite)]
+#[brw(little, repr = CipUsint)]
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum AdminState {
+    Enabled = 0x01,
+    Disabled = 0x02,
+}
+
+/// The interface label, e.g. the port name printed on the device
+pub type InterfaceLabel = CipShortString;
+
+fn ethernet_link_path(instance_id: CipUint, attribute_id: EthernetLinkAttributeId) -> CipPath {
+    CipPath::new(ETHERNET_LINK_CLASS_ID, instance_id).with_attribute(attribute_id as u16)
+}
+
+/// A request reading a single attribute of an Ethernet Link instance
+pub fn ethernet_link_attribute_request(
+    instance_id: CipUint,
+    attribute_id: EthernetLinkAttributeId,
+) -> MessageRouterRequest {
+    MessageRouterRequest::new(
+        ServiceCode::GetAttributeSingle,
+        ethernet_link_path(instance_id, attribute_id),
+    )
+}
+
+/// A request reading and then clearing the interface or media counters of an Ethernet Link instance
+///
+/// The reply holds the counters as they were before being cleared, in the same format as a read.
+pub fn get_and_clear_request(
+    instance_id: CipUint,
+    attribute_id: EthernetLinkAttributeId,
+) -> MessageRouterRequest {
+    MessageRouterRequest::new(
+        EthernetLinkServiceCode::GetAndClear.into(),
+        ethernet_link_path(instance_id, attribute_id),
+    )
+}
+
+/// A writable attribute of the Ethernet Link object, along with its new value
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum EthernetLinkSetting {
+    InterfaceControl(InterfaceControl),
+    AdminState(AdminState),
+}
+
+// ======= Start of EthernetLinkSetting impl ========
+
+impl EthernetLinkSetting {
+    pub fn attribute_id(&self) -> EthernetLinkAttributeId {
+        match self {
+            EthernetLinkSetting::InterfaceControl(_) => EthernetLinkAttributeId::InterfaceControl,
+            EthernetLinkSetting::AdminState(_) => EthernetLinkAttributeId::AdminState,
+        }
+    }
+
+    /// A Set Attribute Single request writing the setting to an Ethernet Link instance
+    pub fn into_router_request(self, instance_id: CipUint) -> MessageRouterRequest {
+        let path = ethernet_link_path(instance_id, self.attribute_id());
+
+        let value: Box<dyn CipData> = match self {
+            EthernetLinkSetting::InterfaceControl(control) => Box::new(control),
+            EthernetLinkSetting::AdminState(admin_state) => Box::new(admin_state),
+        };
+
+        MessageRouterRequest::new_data(ServiceCode::SetAttributeSingle, path, Some(value))
+    }
+}
+
+// ^^^^^^^^ End of EthernetLinkSetting impl ^^^^^^^^
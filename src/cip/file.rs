@@ -0,0 +1,677 @@
+use bilge::prelude::u7;
+
+use binrw::{
+    binrw, // #[binrw] attribute
+    helpers::until_eof,
+    BinRead,
+    BinResult,
+    BinWrite,
+};
+
+use crate::cip::message::data::CipData;
+use crate::cip::message::request::MessageRouterRequest;
+use crate::cip::message::response::MessageRouterResponse;
+use crate::cip::message::shared::ServiceCode;
+use crate::cip::path::CipPath;
+use crate::cip::types::{CipByte, CipInt, CipRevision, CipStringI, CipUdint, CipUint, CipUsint};
+
+/// The File object, used to upload and download files such as the EDS or firmware images
+pub const FILE_CLASS_ID: u16 = 0x37;
+
+/// The instance holding the EDS of the device, possibly compressed (see the encoding format)
+pub const EDS_FILE_INSTANCE: u16 = 0xC8;
+
+/// Consecutive failed transfers tolerated before a transfer gives up
+pub const MAX_TRANSFER_RETRIES: usize = 3;
+
+/// Object specific services of the File object
+#[derive(Debug, PartialEq, Clone, Copy)]
+#[repr(u8)]
+pub enum FileServiceCode {
+    InitiateUpload = 0x4B,
+    InitiateDownload = 0x4C,
+    InitiatePartialRead = 0x4D,
+    InitiatePartialWrite = 0x4E,
+    UploadTransfer = 0x4F,
+    DownloadTransfer = 0x50,
+    ClearFile = 0x51,
+}
+
+impl From<FileServiceCode> for ServiceCode {
+    fn from(service_code: FileServiceCode) -> Self {
+        ServiceCode::from(u7::new(service_code as u8))
+    }
+}
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+#[repr(u16)]
+pub enum FileAttributeId {
+    State = 0x01,
+    InstanceName = 0x02,
+    InstanceFormatVersion = 0x03,
+    FileName = 0x04,
+    FileRevision = 0x05,
+    FileSize = 0x06,
+    FileChecksum = 0x07,
+    InvocationMethod = 0x08,
+    FileSaveParameters = 0x09,
+    FileType = 0x0A,
+    FileEncodingFormat = 0x0B,
+}
+
+#[derive(BinRead, BinWrite)]
+#[brw(little, repr = CipUsint)]
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum FileState {
+    NonExistent = 0x00,
+    FileEmpty = 0x01,
+    FileLoaded = 0x02,
+    TransferUploadInitiated = 0x03,
+    TransferDownloadInitiated = 0x04,
+    TransferUploadInProgress = 0x05,
+    TransferDownloadInProgress = 0x06,
+    Storing = 0x07,
+}
+
+#[derive(BinRead, BinWrite)]
+#[brw(little, repr = CipUsint)]
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum FileEncodingFormat {
+    Binary = 0x00,
+    /// Compressed with zlib, as is common for the EDS instance
+    Compressed = 0x01,
+}
+
+#[derive(BinRead, BinWrite)]
+#[brw(little, repr = CipUsint)]
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum TransferPacketType {
+    First = 0x00,
+    Middle = 0x01,
+    Last = 0x02,
+    Abort = 0x03,
+    FirstAndLast = 0x04,
+}
+
+// ======= Start of TransferPacketType impl ========
+
+impl TransferPacketType {
+    fn new(is_first: bool, is_last: bool) -> Self {
+        match (is_first, is_last) {
+            (true, true) => TransferPacketType::FirstAndLast,
+            (true, false) => TransferPacketType::First,
+            (false, true) => TransferPacketType::Last,
+            (false, false) => TransferPacketType::Middle,
+        }
+    }
+
+    /// The last packet of a file is followed by the checksum of the file
+    pub fn is_last(&self) -> bool {
+        matches!(
+            self,
+            TransferPacketType::Last | TransferPacketType::FirstAndLast
+        )
+    }
+}
+
+// ^^^^^^^^ End of TransferPacketType impl ^^^^^^^^
+
+/// The two's complement of the 16-bit sum of the file bytes
+pub fn file_checksum(file_data: &[CipByte]) -> CipInt {
+    let sum = file_data
+        .iter()
+        .fold(0u16, |sum, &byte| sum.wrapping_add(byte as u16));
+
+    0u16.wrapping_sub(sum) as CipInt
+}
+
+/// Splits the file data of a transfer packet from the checksum that ends the last packet
+fn split_checksum(
+    packet_type: TransferPacketType,
+    data: &[CipByte],
+) -> (&[CipByte], Option<CipInt>) {
+    match (packet_type.is_last(), data.len().checked_sub(2)) {
+        (true, Some(checksum_start)) => (
+            &data[..checksum_start],
+            Some(CipInt::from_le_bytes([
+                data[checksum_start],
+                data[checksum_start + 1],
+            ])),
+        ),
+        _ => (data, None),
+    }
+}
+
+fn transfer_error(message: String) -> binrw::Error {
+    binrw::Error::Custom {
+        pos: 0,
+        err: Box::new(message),
+    }
+}
+
+fn file_path(instance_id: CipUint) -> CipPath {
+    CipPath::new(FILE_CLASS_ID, instance_id)
+}
+
+/// A request reading a single attribute of a File instance
+pub fn file_attribute_request(
+    instance_id: CipUint,
+    attribute_id: FileAttributeId,
+) -> MessageRouterRequest {
+    MessageRouterRequest::new(
+        ServiceCode::GetAttributeSingle,
+        file_path(instance_id).with_attribute(attribute_id as u16),
+    )
+}
+
+#[binrw]
+#[brw(little)]
+#[derive(Debug, PartialEq)]
+pub struct InitiateUploadRequest {
+    pub maximum_transfer_size: CipUsint,
+}
+
+#[binrw]
+#[brw(little)]
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct InitiateUploadResponse {
+    pub file_size: CipUdint,
+    pub transfer_size: CipUsint,
+}
+
+#[binrw]
+#[brw(little)]
+#[derive(Debug, PartialEq)]
+pub struct UploadTransferRequest {
+    pub transfer_number: CipUsint,
+}
+
+/*
+Upload Transfer (Response)
+    Transfer Number: 0x00
+    Transfer Packet Type: First (0x00)
+    File Data: ...
+    Checksum: only in a Last or First and Last packet
+*/
+#[binrw]
+#[brw(little)]
+#[derive(Debug, PartialEq, Clone)]
+pub struct UploadTransferResponse {
+    pub transfer_number: CipUsint,
+    pub packet_type: TransferPacketType,
+    /// The file data, followed by the checksum in the last packet
+    #[br(parse_with = until_eof)]
+    pub data: Vec<CipByte>,
+}
+
+// ======= Start of UploadTransferResponse impl ========
+
+impl UploadTransferResponse {
+    pub fn file_data(&self) -> &[CipByte] {
+        split_checksum(self.packet_type, &self.data).0
+    }
+
+    pub fn checksum(&self) -> Option<CipInt> {
+        split_checksum(self.packet_type, &self.data).1
+    }
+}
+
+// ^^^^^^^^ End of UploadTransferResponse impl ^^^^^^^^
+
+#[binrw]
+#[brw(little)]
+#[derive(Debug, PartialEq)]
+pub struct InitiateDownloadRequest {
+    pub file_size: CipUdint,
+    pub file_revision: CipRevision,
+    pub file_name: CipStringI,
+}
+
+#[binrw]
+#[brw(little)]
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct InitiateDownloadResponse {
+    /// The number of bytes the device stores at a time
+    pub incremental_burn: CipUdint,
+    /// The seconds each incremental burn takes
+    pub incremental_burn_time: CipUint,
+    pub transfer_size: CipUsint,
+}
+
+#[binrw]
+#[brw(little)]
+#[derive(Debug, PartialEq, Clone)]
+pub struct DownloadTransferRequest {
+    pub transfer_number: CipUsint,
+    pub packet_type: TransferPacketType,
+    /// The file data, followed by the checksum in the last packet
+    #[br(parse_with = until_eof)]
+    pub data: Vec<CipByte>,
+}
+
+// ======= Start of DownloadTransferRequest impl ========
+
+impl DownloadTransferRequest {
+    pub fn new(
+        transfer_number: CipUsint,
+        packet_type: TransferPacketType,
+        file_data: &[CipByte],
+        checksum: Option<CipInt>,
+    ) -> Self {
+        let mut data = file_data.to_vec();
+        if let Some(checksum) = checksum {
+            data.extend(checksum.to_le_bytes());
+        }
+
+        DownloadTransferRequest {
+            transfer_number,
+            packet_type,
+            data,
+        }
+    }
+
+    pub fn file_data(&self) -> &[CipByte] {
+        split_checksum(self.packet_type, &self.data).0
+    }
+
+    pub fn checksum(&self) -> Option<CipInt> {
+        split_checksum(self.packet_type, &self.data).1
+    }
+}
+
+// ^^^^^^^^ End of DownloadTransferRequest impl ^^^^^^^^
+
+#[binrw]
+#[brw(little)]
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct DownloadTransferResponse {
+    pub transfer_number: CipUsint,
+}
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+enum TransferStep {
+    Initiate,
+    Transfer(CipUsint),
+    Complete,
+}
+
+/// Counts the consecutive failures of a transfer, failing once there were too many
+#[derive(Debug, Default)]
+struct TransferRetries {
+    count: usize,
+}
+
+// ======= Start of TransferRetries impl ========
+
+impl TransferRetries {
+    fn retry(&mut self, reason: String) -> BinResult<()> {
+        self.count += 1;
+
+        match self.count > MAX_TRANSFER_RETRIES {
+            true => Err(transfer_error(format!(
+                "Gave up after {} retries: {}",
+                MAX_TRANSFER_RETRIES, reason
+            ))),
+            false => Ok(()),
+        }
+    }
+
+    fn reset(&mut self) {
+        self.count = 0;
+    }
+}
+
+// ^^^^^^^^ End of TransferRetries impl ^^^^^^^^
+
+/// Uploads a file from the device, verifying its checksum
+///
+/// A packet is requested again when the device answers with the wrong transfer number, or when
+/// `retry()` is called because a response was lost. A checksum mismatch restarts the upload.
+#[derive(Debug)]
+pub struct FileUpload {
+    instance_id: CipUint,
+    maximum_transfer_size: CipUsint,
+    step: TransferStep,
+    file_size: CipUdint,
+    data: Vec<CipByte>,
+    retries: TransferRetries,
+    /// Counted apart from the packet retries, which every good packet resets
+    restarts: TransferRetries,
+}
+
+// ======= Start of FileUpload impl ========
+
+impl FileUpload {
+    pub fn new(instance_id: CipUint) -> Self {
+        FileUpload {
+            instance_id,
+            maximum_transfer_size: CipUsint::MAX,
+            step: TransferStep::Initiate,
+            file_size: 0,
+            data: Vec::new(),
+            retries: TransferRetries::default(),
+            restarts: TransferRetries::default(),
+        }
+    }
+
+    /// Limits the file bytes of each transfer, e.g. to fit through a route with a small buffer
+    pub fn with_maximum_transfer_size(mut self, maximum_transfer_size: CipUsint) -> Self {
+        self.maximum_transfer_size = maximum_transfer_size;
+        self
+    }
+
+    pub fn is_complete(&self) -> bool {
+        self.step == TransferStep::Complete
+    }
+
+    pub fn next_request(&self) -> Option<MessageRouterRequest> {
+        let request_data: Box<dyn CipData> = match self.step {
+            TransferStep::Initiate => Box::new(InitiateUploadRequest {
+                maximum_transfer_size: self.maximum_transfer_size,
+            }),
+            TransferStep::Transfer(transfer_number) => {
+                Box::new(UploadTransferRequest { transfer_number })
+            }
+            TransferStep::Complete => return None,
+        };
+
+        let service = match self.step {
+            TransferStep::Initiate => FileServiceCode::InitiateUpload,
+            _ => FileServiceCode::UploadTransfer,
+        };
+
+        Some(MessageRouterRequest::new_data(
+            service.into(),
+            file_path(self.instance_id),
+            Some(request_data),
+        ))
+    }
+
+    /// Asks for the last packet again, e.g. after its response was lost
+    pub fn retry(&mut self) -> BinResult<()> {
+        self.retries
+            .retry(format!("no response to {:?}", self.step))
+    }
+
+    pub fn handle_response(&mut self, response: &MessageRouterResponse) -> BinResult<()> {
+        match self.step {
+            TransferStep::Initiate => {
+                response.check_status()?;
+
+                let initiate_response: InitiateUploadResponse =
+                    response.response_data.read_reply()?;
+                // The data grows as the packets arrive, the reported size is only checked at the end
+                self.file_size = initiate_response.file_size;
+                self.step = TransferStep::Transfer(0);
+            }
+            TransferStep::Transfer(transfer_number) => {
+                response.check_status()?;
+
+                let transfer_response: UploadTransferResponse =
+                    response.response_data.read_reply()?;
+                if transfer_response.transfer_number != transfer_number {
+                    return self.retries.retry(format!(
+                        "expected transfer {} but received {}",
+                        transfer_number, transfer_response.transfer_number
+                    ));
+                }
+
+                self.retries.reset();
+                self.data.extend_from_slice(transfer_response.file_data());
+
+                self.step = match transfer_response.packet_type {
+                    TransferPacketType::Abort => {
+                        return Err(transfer_error(format!(
+                            "The device aborted the upload of file instance {}",
+                            self.instance_id
+                        )));
+                    }
+                    packet_type if packet_type.is_last() => {
+                        self.finish_upload(transfer_response.checksum())?
+                    }
+                    _ => TransferStep::Transfer(transfer_number.wrapping_add(1)),
+                };
+            }
+            TransferStep::Complete => {}
+        }
+
+        Ok(())
+    }
+
+    fn finish_upload(&mut self, checksum: Option<CipInt>) -> BinResult<TransferStep> {
+        let expected_checksum = file_checksum(&self.data);
+        if checksum == Some(expected_checksum) && self.data.len() == self.file_size as usize {
+            return Ok(TransferStep::Complete);
+        }
+
+        // Start over, the file may have changed or a packet was corrupted on the way
+        self.restarts.retry(format!(
+            "received {} of {} bytes with checksum {:?}, expected {}",
+            self.data.len(),
+            self.file_size,
+            checksum,
+            expected_checksum
+        ))?;
+        self.data.clear();
+
+        Ok(TransferStep::Initiate)
+    }
+
+    /// The file contents, once the upload is complete
+    pub fn into_data(self) -> Option<Vec<CipByte>> {
+        self.is_complete().then_some(self.data)
+    }
+}
+
+// ^^^^^^^^ End of FileUpload impl ^^^^^^^^
+
+/// Downloads a file to the device, ending with the checksum of the file
+///
+/// A packet is sent again when the device answers with the wrong transfer number, or when `retry()`
+/// is called because a response was lost. Send the `abort_request()` to give up on a download.
+#[derive(Debug)]
+pub struct FileDownload {
+    instance_id: CipUint,
+    file_name: String,
+    file_revision: (CipUsint, CipUsint),
+    data: Vec<CipByte>,
+    transfer_size: usize,
+    offset: usize,
+    step: TransferStep,
+    retries: TransferRetries,
+}
+
+// ======= Start of FileDownload impl ========
+
+impl FileDownload {
+    pub fn new(
+        instance_id: CipUint,
+        file_name: &str,
+        file_revision: (CipUsint, CipUsint),
+        data: Vec<CipByte>,
+    ) -> Self {
+        FileDownload {
+            instance_id,
+            file_name: file_name.to_string(),
+            file_revision,
+            data,
+            transfer_size: 0,
+            offset: 0,
+            step: TransferStep::Initiate,
+            retries: TransferRetries::default(),
+        }
+    }
+
+    pub fn is_complete(&self) -> bool {
+        self.step == TransferStep::Complete
+    }
+
+    /// The end of the file data sent by the current transfer
+    fn transfer_end(&self) -> usize {
+        (self.offset + self.transfer_size).min(self.data.len())
+    }
+
+    fn transfer_request(&self, transfer_number: CipUsint) -> DownloadTransferRequest {
+        let transfer_end = self.transfer_end();
+        let is_last = transfer_end == self.data.len();
+
+        DownloadTransferRequest::new(
+            transfer_number,
+            TransferPacketType::new(self.offset == 0, is_last),
+            &self.data[self.offset..transfer_end],
+            is_last.then(|| file_checksum(&self.data)),
+        )
+    }
+
+    pub fn next_request(&self) -> Option<MessageRouterRequest> {
+        let (service, request_data): (_, Box<dyn CipData>) = match self.step {
+            TransferStep::Initiate => (
+                FileServiceCode::InitiateDownload,
+                Box::new(InitiateDownloadRequest {
+                    file_size: self.data.len() as CipUdint,
+                    file_revision: CipRevision {
+                        major_revision: self.file_revision.0,
+                        minor_revision: self.file_revision.1,
+                    },
+                    file_name: CipStringI::from(self.file_name.clone()),
+                }),
+            ),
+            TransferStep::Transfer(transfer_number) => (
+                FileServiceCode::DownloadTransfer,
+                Box::new(self.transfer_request(transfer_number)),
+            ),
+            TransferStep::Complete => return None,
+        };
+
+        Some(MessageRouterRequest::new_data(
+            service.into(),
+            file_path(self.instance_id),
+            Some(request_data),
+        ))
+    }
+
+    /// A request aborting the download, leaving the file of the device as it was
+    pub fn abort_request(&self) -> MessageRouterRequest {
+        let transfer_number = match self.step {
+            TransferStep::Transfer(transfer_number) => transfer_number,
+            _ => 0,
+        };
+
+        MessageRouterRequest::new_data(
+            FileServiceCode::DownloadTransfer.into(),
+            file_path(self.instance_id),
+            Some(Box::new(DownloadTransferRequest::new(
+                transfer_number,
+                TransferPacketType::Abort,
+                &[],
+                None,
+            ))),
+        )
+    }
+
+    /// Sends the last packet again, e.g. after its response was lost
+    pub fn retry(&mut self) -> BinResult<()> {
+        self.retries
+            .retry(format!("no response to {:?}", self.step))
+    }
+
+    pub fn handle_response(&mut self, response: &MessageRouterResponse) -> BinResult<()> {
+        match self.step {
+            TransferStep::Initiate => {
+                response.check_status()?;
+
+                let initiate_response: InitiateDownloadResponse =
+                    response.response_data.read_reply()?;
+                if initiate_response.transfer_size == 0 {
+                    return Err(transfer_error(format!(
+                        "File instance {} accepts no data per transfer",
+                        self.instance_id
+                    )));
+                }
+
+                self.transfer_size = initiate_response.transfer_size as usize;
+                self.step = TransferStep::Transfer(0);
+            }
+            TransferStep::Transfer(transfer_number) => {
+                response.check_status()?;
+
+                let transfer_response: DownloadTransferResponse =
+                    response.response_data.read_reply()?;
+                if transfer_response.transfer_number != transfer_number {
+                    return self.retries.retry(format!(
+                        "expected transfer {} but received {}",
+                        transfer_number, transfer_response.transfer_number
+                    ));
+                }
+
+                self.retries.reset();
+
+                let transfer_end = self.transfer_end();
+                self.step = match transfer_end == self.data.len() {
+                    true => TransferStep::Complete,
+                    false => TransferStep::Transfer(transfer_number.wrapping_add(1)),
+                };
+                self.offset = transfer_end;
+            }
+            TransferStep::Complete => {}
+        }
+
+        Ok(())
+    }
+}
+
+// ^^^^^^^^ End of FileDownload impl ^^^^^^^^
+
+/// Uploads a whole file, e.g. the EDS from `EDS_FILE_INSTANCE`
+///
+/// `exchange` sends a request to the device and returns its response. A failed exchange counts as
+/// a lost response and is retried, async code drives a `FileUpload` instead.
+pub fn upload_file(
+    instance_id: CipUint,
+    mut exchange: impl FnMut(MessageRouterRequest) -> BinResult<MessageRouterResponse>,
+) -> BinResult<Vec<CipByte>> {
+    let mut file_upload = FileUpload::new(instance_id);
+
+    while let Some(request) = file_upload.next_request() {
+        match exchange(request) {
+            Ok(response) => file_upload.handle_response(&response)?,
+            Err(exchange_error) => file_upload.retry().map_err(|_| exchange_error)?,
+        }
+    }
+
+    file_upload.into_data().ok_or_else(|| {
+        transfer_error(format!(
+            "The upload of file instance {} did not complete",
+            instance_id
+        ))
+    })
+}
+
+/// Downloads a whole file, e.g. a firmware image
+///
+/// `exchange` sends a request to the device and returns its response. A failed exchange counts as
+/// a lost response and is retried, async code drives a `FileDownload` instead.
+pub fn download_file(
+    instance_id: CipUint,
+    file_name: &str,
+    file_revision: (CipUsint, CipUsint),
+    data: Vec<CipByte>,
+    mut exchange: impl FnMut(MessageRouterRequest) -> BinResult<MessageRouterResponse>,
+) -> BinResult<()> {
+    let mut file_download = FileDownload::new(instance_id, file_name, file_revision, data);
+
+    while let Some(request) = file_download.next_request() {
+        let result = match exchange(request) {
+            Ok(response) => file_download.handle_response(&response),
+            Err(exchange_error) => file_download.retry().map_err(|_| exchange_error),
+        };
+
+        // Let the device know the download will not finish
+        if let Err(download_error) = result {
+            let _ = exchange(file_download.abort_request());
+            return Err(download_error);
+        }
+    }
+
+    Ok(())
+}
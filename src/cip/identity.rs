@@ -2,9 +2,35 @@ use bilge::prelude::{bitsize, u4, Bitsized, DebugBits, FromBits, Number};
 
 use binrw::{
     binrw, // #[binrw] attribute
+    helpers::until_eof,
+    BinRead,
+    BinWrite,
 };
 
-use crate::cip::types::{CipByte, CipShortString, CipUint};
+use crate::cip::message::request::MessageRouterRequest;
+use crate::cip::message::shared::ServiceCode;
+use crate::cip::path::CipPath;
+use crate::cip::registry;
+use crate::cip::types::{CipByte, CipShortString, CipUint, CipUsint};
+
+pub const IDENTITY_CLASS_ID: u16 = 0x01;
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+#[repr(u16)]
+pub enum IdentityAttributeId {
+    VendorId = 0x01,
+    DeviceType = 0x02,
+    ProductCode = 0x03,
+    Revision = 0x04,
+    Status = 0x05,
+    SerialNumber = 0x06,
+    ProductName = 0x07,
+    State = 0x08,
+    ConfigurationConsistencyValue = 0x09,
+    HeartbeatInterval = 0x0A,
+    ActiveLanguage = 0x0B,
+    SupportedLanguageList = 0x0C,
+}
 
 /*
 Attribute: 1 (Vendor ID)
@@ -34,40 +60,161 @@ Attribute: 7 (Product Name)
 
 #[binrw]
 #[brw(little)]
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Clone, Copy)]
 #[repr(u16)]
 pub enum VendorId {
     #[brw(magic = 0x01a8u16)]
     TeknicInc,
+    /// Any vendor without a variant of its own, `name()` still finds it in the vendor registry
     Unknown(u16),
 }
 
 #[binrw]
 #[brw(little)]
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Clone, Copy)]
 #[repr(u16)]
 pub enum DeviceType {
     #[brw(magic = 0x002bu16)]
     GenericDevice,
+    /// Any device type without a variant of its own, `name()` still finds its profile name
     Unknown(u16),
 }
 
+// ======= Start of VendorId impl ========
+
+impl VendorId {
+    pub fn id(&self) -> u16 {
+        match self {
+            VendorId::TeknicInc => 0x01a8,
+            VendorId::Unknown(vendor_id) => *vendor_id,
+        }
+    }
+
+    /// The registered name of the vendor, if it is in the vendor registry
+    pub fn name(&self) -> Option<&'static str> {
+        registry::vendor_name(self.id())
+    }
+}
+
+impl From<u16> for VendorId {
+    fn from(vendor_id: u16) -> Self {
+        match vendor_id {
+            0x01a8 => VendorId::TeknicInc,
+            vendor_id => VendorId::Unknown(vendor_id),
+        }
+    }
+}
+
+impl std::fmt::Display for VendorId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.name() {
+            Some(name) => write!(f, "{}", name),
+            None => write!(f, "Unknown vendor (0x{:04x})", self.id()),
+        }
+    }
+}
+
+// ^^^^^^^^ End of VendorId impl ^^^^^^^^
+
+// ======= Start of DeviceType impl ========
+
+impl DeviceType {
+    pub fn code(&self) -> u16 {
+        match self {
+            DeviceType::GenericDevice => 0x002b,
+            DeviceType::Unknown(device_type) => *device_type,
+        }
+    }
+
+    /// The name of the device type, if it is a known device profile
+    pub fn name(&self) -> Option<&'static str> {
+        registry::device_type_name(self.code())
+    }
+}
+
+impl From<u16> for DeviceType {
+    fn from(device_type: u16) -> Self {
+        match device_type {
+            0x002b => DeviceType::GenericDevice,
+            device_type => DeviceType::Unknown(device_type),
+        }
+    }
+}
+
+impl std::fmt::Display for DeviceType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.name() {
+            Some(name) => write!(f, "{}", name),
+            None => write!(f, "Unknown device type (0x{:04x})", self.code()),
+        }
+    }
+}
+
+// ^^^^^^^^ End of DeviceType impl ^^^^^^^^
+
 #[binrw]
 #[brw(little)]
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Clone, Copy)]
 pub struct Revision {
     pub major: CipByte,
     pub minor: CipByte,
 }
 
+/// The meaning of the extended device status bits, for devices that follow the common definitions
+///
+/// The variants are in order of their value, from 0b0000 to 0b0111.
+#[bitsize(4)]
+#[derive(FromBits, Debug, PartialEq, Clone, Copy)]
+pub enum ExtendedDeviceStatus {
+    SelfTestingOrUnknown,
+    FirmwareUpdateInProgress,
+    FaultedIoConnection,
+    NoIoConnectionsEstablished,
+    NonVolatileConfigurationBad,
+    MajorFault,
+    IoConnectionInRunMode,
+    IoConnectionsIdle,
+    /// Reserved (0b1000 and 0b1001) or vendor specific (0b1010 and up)
+    #[fallback]
+    Other(u4),
+}
+
+// ======= Start of ExtendedDeviceStatus impl ========
+
+impl std::fmt::Display for ExtendedDeviceStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        use ExtendedDeviceStatus::*;
+        match self {
+            SelfTestingOrUnknown => write!(f, "self-testing or unknown"),
+            FirmwareUpdateInProgress => write!(f, "firmware update in progress"),
+            FaultedIoConnection => write!(f, "at least one faulted I/O connection"),
+            NoIoConnectionsEstablished => write!(f, "no I/O connections established"),
+            NonVolatileConfigurationBad => write!(f, "non-volatile configuration bad"),
+            MajorFault => write!(f, "major fault"),
+            IoConnectionInRunMode => write!(f, "at least one I/O connection in run mode"),
+            IoConnectionsIdle => write!(
+                f,
+                "at least one I/O connection established, all in idle mode"
+            ),
+            Other(status) => write!(
+                f,
+                "reserved or vendor specific status {:#x}",
+                status.value()
+            ),
+        }
+    }
+}
+
+// ^^^^^^^^ End of ExtendedDeviceStatus impl ^^^^^^^^
+
 #[bitsize(16)]
-#[derive(FromBits, PartialEq, DebugBits)]
+#[derive(FromBits, PartialEq, DebugBits, Clone, Copy)]
 pub struct IdentityStatusBits {
     pub owned: bool,
     pub unused1: bool,
     pub configured: bool,
     pub unused2: bool,
-    pub extended_device_status: u4,
+    pub extended_device_status: ExtendedDeviceStatus,
     pub minor_recoverable_fault: bool,
     pub minor_unrecoverable_fault: bool,
     pub major_recoverable_fault: bool,
@@ -77,13 +224,19 @@ pub struct IdentityStatusBits {
 
 #[binrw]
 #[brw(little)]
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Clone, Copy)]
 pub struct IdentityStatus {
     status_representation: CipUint,
 }
 
 // ======= Start of IdentityStatus impl ========
 
+impl IdentityStatus {
+    pub fn bits(&self) -> IdentityStatusBits {
+        IdentityStatusBits::from(self.status_representation)
+    }
+}
+
 impl From<IdentityStatus> for IdentityStatusBits {
     fn from(segment: IdentityStatus) -> Self {
         IdentityStatusBits::from(segment.status_representation)
@@ -112,3 +265,89 @@ pub struct IdentityResponse {
     pub serial_number: u32,
     pub product_name: CipShortString,
 }
+
+/*
+Attribute: 8 (State)
+    State: Operational (3)
+*/
+#[binrw]
+#[brw(little)]
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum DeviceState {
+    #[brw(magic = 0x00u8)]
+    Nonexistent,
+    #[brw(magic = 0x01u8)]
+    SelfTesting,
+    #[brw(magic = 0x02u8)]
+    Standby,
+    #[brw(magic = 0x03u8)]
+    Operational,
+    #[brw(magic = 0x04u8)]
+    MajorRecoverableFault,
+    #[brw(magic = 0x05u8)]
+    MajorUnrecoverableFault,
+    /// Returned by Get Attributes All when the device does not support the attribute
+    #[brw(magic = 0xFFu8)]
+    Default,
+    Reserved(u8),
+}
+
+/// A language as its ISO 639-2/T code, e.g. `eng`
+#[binrw]
+#[brw(little)]
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Language(pub [CipUsint; 3]);
+
+// ======= Start of Language impl ========
+
+impl std::fmt::Display for Language {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", String::from_utf8_lossy(&self.0))
+    }
+}
+
+// ^^^^^^^^ End of Language impl ^^^^^^^^
+
+/// The supported languages take up the rest of the reply, there is no count
+#[binrw]
+#[brw(little)]
+#[derive(Debug, PartialEq, Clone)]
+pub struct SupportedLanguages {
+    #[br(parse_with = until_eof)]
+    pub languages: Vec<Language>,
+}
+
+/// The heartbeat interval, in seconds
+pub type HeartbeatInterval = CipUsint;
+
+/// A request reading a single attribute of the Identity object
+pub fn identity_attribute_request(attribute_id: IdentityAttributeId) -> MessageRouterRequest {
+    MessageRouterRequest::new(
+        ServiceCode::GetAttributeSingle,
+        CipPath::new(IDENTITY_CLASS_ID, 0x01).with_attribute(attribute_id as u16),
+    )
+}
+
+/// The kind of reset requested from the Identity object
+#[derive(BinRead, BinWrite)]
+#[brw(little, repr = CipUsint)]
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum ResetType {
+    /// Emulates cycling the power of the device
+    PowerCycle = 0x00,
+    /// Returns the device to its out of box configuration, then emulates a power cycle
+    FactoryDefaults = 0x01,
+    /// Like `FactoryDefaults`, but keeps the communication link attributes, e.g. the IP address
+    FactoryDefaultsKeepCommunication = 0x02,
+}
+
+/// A Reset request to the Identity object
+///
+/// Devices reply before resetting, so the session is lost right after a successful reply.
+pub fn reset_request(reset_type: ResetType) -> MessageRouterRequest {
+    MessageRouterRequest::new_data(
+        ServiceCode::Reset,
+        CipPath::new(IDENTITY_CLASS_ID, 0x01),
+        Some(Box::new(reset_type)),
+    )
+}
@@ -0,0 +1,411 @@
+use std::collections::HashMap;
+use std::io::{Read, Seek, Write};
+
+use binrw::{
+    binrw, // #[binrw] attribute
+    BinRead,
+    BinResult,
+    BinWrite,
+    Endian,
+};
+
+use super::request::MessageRouterRequest;
+use super::response::{MessageRouterResponse, ResponseStatusCode};
+use super::shared::ServiceCode;
+use crate::cip::path::RequestPath;
+use crate::cip::types::{CipShortString, CipString, CipUint};
+
+/// Maps the attribute IDs of a Get Attribute List reply to the type of their values
+pub type AttributeTypeMap = HashMap<CipUint, AttributeType>;
+
+/// The type of an attribute value, which determines how many bytes of a reply it takes up
+#[derive(Debug, PartialEq, Clone)]
+pub enum AttributeType {
+    Bool,
+    Sint,
+    Int,
+    Dint,
+    Lint,
+    Usint,
+    Uint,
+    Udint,
+    Ulint,
+    Real,
+    Lreal,
+    ShortString,
+    String,
+    /// A fixed number of raw bytes
+    Bytes(usize),
+    /// A fixed number of values of the same type
+    Array(Box<AttributeType>, usize),
+    /// Values of different types that follow each other, e.g. a revision or an IP configuration
+    Structure(Vec<AttributeType>),
+}
+
+/// A decoded attribute value
+#[derive(Debug, PartialEq, Clone)]
+pub enum AttributeValue {
+    Bool(bool),
+    Sint(i8),
+    Int(i16),
+    Dint(i32),
+    Lint(i64),
+    Usint(u8),
+    Uint(u16),
+    Udint(u32),
+    Ulint(u64),
+    Real(f32),
+    Lreal(f64),
+    ShortString(String),
+    String(String),
+    Bytes(Vec<u8>),
+    Array(Vec<AttributeValue>),
+    Structure(Vec<AttributeValue>),
+}
+
+// ======= Start of AttributeType impl ========
+
+impl AttributeType {
+    /// Reads a single value of the type
+    pub fn read_value<R: Read + Seek>(
+        &self,
+        reader: &mut R,
+        endian: Endian,
+    ) -> BinResult<AttributeValue> {
+        use AttributeType::*;
+
+        let value = match self {
+            Bool => AttributeValue::Bool(u8::read_options(reader, endian, ())? != 0),
+            Sint => AttributeValue::Sint(i8::read_options(reader, endian, ())?),
+            Int => AttributeValue::Int(i16::read_options(reader, endian, ())?),
+            Dint => AttributeValue::Dint(i32::read_options(reader, endian, ())?),
+            Lint => AttributeValue::Lint(i64::read_options(reader, endian, ())?),
+            Usint => AttributeValue::Usint(u8::read_options(reader, endian, ())?),
+            Uint => AttributeValue::Uint(u16::read_options(reader, endian, ())?),
+            Udint => AttributeValue::Udint(u32::read_options(reader, endian, ())?),
+            Ulint => AttributeValue::Ulint(u64::read_options(reader, endian, ())?),
+            Real => AttributeValue::Real(f32::read_options(reader, endian, ())?),
+            Lreal => AttributeValue::Lreal(f64::read_options(reader, endian, ())?),
+            ShortString => AttributeValue::ShortString(
+                CipShortString::read_options(reader, endian, ())?.into(),
+            ),
+            String => {
+                let string = CipString::read_options(reader, endian, ())?;
+                AttributeValue::String(std::string::String::from_utf8_lossy(&string.value).into())
+            }
+            Bytes(byte_count) => {
+                let mut bytes = vec![0u8; *byte_count];
+                reader.read_exact(&mut bytes)?;
+                AttributeValue::Bytes(bytes)
+            }
+            Array(element_type, element_count) => AttributeValue::Array(
+                (0..*element_count)
+                    .map(|_| element_type.read_value(reader, endian))
+                    .collect::<BinResult<_>>()?,
+            ),
+            Structure(member_types) => AttributeValue::Structure(
+                member_types
+                    .iter()
+                    .map(|member_type| member_type.read_value(reader, endian))
+                    .collect::<BinResult<_>>()?,
+            ),
+        };
+
+        Ok(value)
+    }
+}
+
+// ^^^^^^^^ End of AttributeType impl ^^^^^^^^
+
+// ======= Start of AttributeValue impl ========
+
+impl BinWrite for AttributeValue {
+    type Args<'a> = ();
+
+    fn write_options<W: Write + Seek>(
+        &self,
+        writer: &mut W,
+        endian: Endian,
+        _args: Self::Args<'_>,
+    ) -> BinResult<()> {
+        use AttributeValue::*;
+
+        match self {
+            Bool(value) => (*value as u8).write_options(writer, endian, ()),
+            Sint(value) => value.write_options(writer, endian, ()),
+            Int(value) => value.write_options(writer, endian, ()),
+            Dint(value) => value.write_options(writer, endian, ()),
+            Lint(value) => value.write_options(writer, endian, ()),
+            Usint(value) => value.write_options(writer, endian, ()),
+            Uint(value) => value.write_options(writer, endian, ()),
+            Udint(value) => value.write_options(writer, endian, ()),
+            Ulint(value) => value.write_options(writer, endian, ()),
+            Real(value) => value.write_options(writer, endian, ()),
+            Lreal(value) => value.write_options(writer, endian, ()),
+            ShortString(value) => {
+                CipShortString::from(value.clone()).write_options(writer, endian, ())
+            }
+            String(value) => CipString::from(value.clone()).write_options(writer, endian, ()),
+            Bytes(bytes) => Ok(writer.write_all(bytes)?),
+            Array(values) | Structure(values) => values
+                .iter()
+                .try_for_each(|value| value.write_options(writer, endian, ())),
+        }
+    }
+}
+
+// ^^^^^^^^ End of AttributeValue impl ^^^^^^^^
+
+/// The request data of the Get Attribute List service
+#[binrw]
+#[brw(little)]
+#[derive(Debug, PartialEq)]
+pub struct GetAttributeListRequest {
+    #[br(temp)]
+    #[bw(calc = attribute_ids.len() as CipUint)]
+    attribute_count: CipUint,
+
+    #[br(count = attribute_count)]
+    pub attribute_ids: Vec<CipUint>,
+}
+
+// ======= Start of GetAttributeListRequest impl ========
+
+impl GetAttributeListRequest {
+    pub fn new(attribute_ids: Vec<CipUint>) -> Self {
+        GetAttributeListRequest { attribute_ids }
+    }
+
+    pub fn into_router_request(self, path: impl Into<RequestPath>) -> MessageRouterRequest {
+        MessageRouterRequest::new_data(ServiceCode::GetAttributeList, path, Some(Box::new(self)))
+    }
+}
+
+// ^^^^^^^^ End of GetAttributeListRequest impl ^^^^^^^^
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct AttributeEntry {
+    pub attribute_id: CipUint,
+    pub value: AttributeValue,
+}
+
+/// The request data of the Set Attribute List service
+///
+/// Reading the request back requires the attribute types, so only writing is supported.
+#[derive(Debug, PartialEq, Clone, Default)]
+pub struct SetAttributeListRequest {
+    pub attributes: Vec<AttributeEntry>,
+}
+
+// ======= Start of SetAttributeListRequest impl ========
+
+impl BinWrite for SetAttributeListRequest {
+    type Args<'a> = ();
+
+    fn write_options<W: Write + Seek>(
+        &self,
+        writer: &mut W,
+        endian: Endian,
+        _args: Self::Args<'_>,
+    ) -> BinResult<()> {
+        (self.attributes.len() as CipUint).write_options(writer, endian, ())?;
+
+        for attribute in &self.attributes {
+            attribute.attribute_id.write_options(writer, endian, ())?;
+            attribute.value.write_options(writer, endian, ())?;
+        }
+
+        Ok(())
+    }
+}
+
+impl BinRead for SetAttributeListRequest {
+    type Args<'a> = ();
+
+    fn read_options<R: Read + Seek>(
+        reader: &mut R,
+        _endian: Endian,
+        _args: Self::Args<'_>,
+    ) -> BinResult<Self> {
+        Err(binrw::Error::Custom {
+            pos: reader.stream_position()?,
+            err: Box::new(
+                "Set Attribute List requests can not be read without the attribute types"
+                    .to_string(),
+            ),
+        })
+    }
+}
+
+impl SetAttributeListRequest {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_attribute(mut self, attribute_id: CipUint, value: AttributeValue) -> Self {
+        self.attributes.push(AttributeEntry {
+            attribute_id,
+            value,
+        });
+        self
+    }
+
+    pub fn into_router_request(self, path: impl Into<RequestPath>) -> MessageRouterRequest {
+        MessageRouterRequest::new_data(ServiceCode::SetAttributeList, path, Some(Box::new(self)))
+    }
+}
+
+// ^^^^^^^^ End of SetAttributeListRequest impl ^^^^^^^^
+
+/// The outcome for one attribute of a Get or Set Attribute List reply
+#[derive(Debug, PartialEq, Clone)]
+pub struct AttributeReply {
+    pub attribute_id: CipUint,
+
+    /// The general status code of the attribute
+    pub status: CipUint,
+
+    /// The value of a successfully read attribute (always `None` for Set Attribute List)
+    pub value: Option<AttributeValue>,
+}
+
+// ======= Start of AttributeReply impl ========
+
+impl AttributeReply {
+    pub fn is_success(&self) -> bool {
+        // The attribute status is a 16 bit general status, zero for a success
+        self.status == 0x0000
+    }
+}
+
+// ^^^^^^^^ End of AttributeReply impl ^^^^^^^^
+
+/// The reply of both the Get and Set Attribute List services
+#[derive(Debug, PartialEq, Clone)]
+pub struct AttributeListReply {
+    pub attributes: Vec<AttributeReply>,
+}
+
+// ======= Start of AttributeListReply impl ========
+
+impl AttributeListReply {
+    /// Checks the general status of the response, which is an attribute list error if any
+    /// of the attributes failed
+    fn check_list_status(response: &MessageRouterResponse) -> BinResult<()> {
+        match response.response_data.status {
+            ResponseStatusCode::AttributeListError => Ok(()),
+            _ => response.check_status(),
+        }
+    }
+
+    /// Decodes a Get Attribute List reply, using the type map to find where each value ends
+    ///
+    /// An attribute missing from the type map can only be the last one of the reply, in which case
+    /// its value is returned as raw bytes.
+    pub fn from_get_response(
+        response: &MessageRouterResponse,
+        attribute_types: &AttributeTypeMap,
+    ) -> BinResult<Self> {
+        Self::check_list_status(response)?;
+
+        let reply_data = response.response_data.reply_data();
+        let reply_length = reply_data.len() as u64;
+        let mut reader = std::io::Cursor::new(reply_data);
+
+        let attribute_count = CipUint::read_le(&mut reader)?;
+        let mut attributes = Vec::with_capacity(attribute_count as usize);
+
+        for _ in 0..attribute_count {
+            let attribute_id = CipUint::read_le(&mut reader)?;
+            let status = CipUint::read_le(&mut reader)?;
+
+            let value = match (status, attribute_types.get(&attribute_id)) {
+                (0, Some(attribute_type)) => {
+                    Some(attribute_type.read_value(&mut reader, Endian::Little)?)
+                }
+                (0, None) if attributes.len() + 1 == attribute_count as usize => {
+                    let mut bytes = Vec::new();
+                    reader.read_to_end(&mut bytes)?;
+                    Some(AttributeValue::Bytes(bytes))
+                }
+                (0, None) => {
+                    return Err(binrw::Error::Custom {
+                        pos: reader.position(),
+                        err: Box::new(format!(
+                            "The type of attribute {} is needed to decode the attributes after it",
+                            attribute_id
+                        )),
+                    });
+                }
+                _ => None,
+            };
+
+            attributes.push(AttributeReply {
+                attribute_id,
+                status,
+                value,
+            });
+        }
+
+        if reader.position() != reply_length {
+            return Err(binrw::Error::Custom {
+                pos: reader.position(),
+                err: Box::new(format!(
+                    "{} bytes left over after the attribute values",
+                    reply_length - reader.position()
+                )),
+            });
+        }
+
+        Ok(AttributeListReply { attributes })
+    }
+
+    /// Decodes a Set Attribute List reply, which only holds the status of each attribute
+    pub fn from_set_response(response: &MessageRouterResponse) -> BinResult<Self> {
+        Self::check_list_status(response)?;
+
+        let statuses: AttributeStatusList = response.response_data.read_reply()?;
+
+        Ok(AttributeListReply {
+            attributes: statuses
+                .statuses
+                .into_iter()
+                .map(|attribute_status| AttributeReply {
+                    attribute_id: attribute_status.attribute_id,
+                    status: attribute_status.status,
+                    value: None,
+                })
+                .collect(),
+        })
+    }
+
+    /// The value of the attribute, if it was read successfully
+    pub fn value(&self, attribute_id: CipUint) -> Option<&AttributeValue> {
+        self.attributes
+            .iter()
+            .find(|attribute| attribute.attribute_id == attribute_id)
+            .and_then(|attribute| attribute.value.as_ref())
+    }
+}
+
+// ^^^^^^^^ End of AttributeListReply impl ^^^^^^^^
+
+#[binrw]
+#[brw(little)]
+#[derive(Debug, PartialEq)]
+struct AttributeStatus {
+    attribute_id: CipUint,
+    status: CipUint,
+}
+
+#[binrw]
+#[brw(little)]
+#[derive(Debug, PartialEq)]
+struct AttributeStatusList {
+    #[br(temp)]
+    #[bw(calc = statuses.len() as CipUint)]
+    attribute_count: CipUint,
+
+    #[br(count = attribute_count)]
+    statuses: Vec<AttributeStatus>,
+}
@@ -43,6 +43,16 @@ where
     }
 }
 
+/// Serializes a message into its own buffer, e.g. to embed it with a length prefix
+pub(crate) fn encode<T>(message: &T) -> BinResult<Vec<u8>>
+where
+    T: for<'a> BinWrite<Args<'a> = ()>,
+{
+    let mut buffer = Vec::new();
+    let mut writer = std::io::Cursor::new(&mut buffer);
+    message.write_options(&mut writer, Endian::Little, ())?;
+    Ok(buffer)
+}
 
 #[derive(Debug)]
 pub enum CipDataOpt {
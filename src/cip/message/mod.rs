@@ -1,4 +1,6 @@
+pub mod attribute_list;
 pub mod data;
+pub mod multiple_service;
 pub mod request;
 pub mod response;
 pub mod shared;
\ No newline at end of file
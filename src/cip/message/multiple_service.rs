@@ -0,0 +1,265 @@
+use std::io::{Read, Seek, Write};
+
+use binrw::{BinRead, BinResult, BinWrite, Endian};
+
+use super::data::encode;
+use super::request::MessageRouterRequest;
+use super::response::{MessageRouterResponse, ResponseStatusCode};
+use super::shared::ServiceCode;
+use crate::cip::message_router::MESSAGE_ROUTER_CLASS_ID;
+use crate::cip::path::CipPath;
+use crate::cip::types::CipUint;
+
+/// Size of the service, path size and 16-bit Message Router path that precede the packet data
+pub const MULTIPLE_SERVICE_HEADER_SIZE: usize = 10;
+
+/// Size of the service, reserved byte, general status and additional status size of a reply
+/// without additional status, which precede the reply data
+pub const REPLY_HEADER_SIZE: usize = 4;
+
+/// Size of the service count and of every entry of the offset table
+pub const SIZE_OF_OFFSET_ENTRY: usize = std::mem::size_of::<CipUint>();
+
+fn write_offset_table<W: Write + Seek>(
+    writer: &mut W,
+    endian: Endian,
+    encoded_services: &[Vec<u8>],
+) -> BinResult<()> {
+    let service_count = encoded_services.len() as CipUint;
+    service_count.write_options(writer, endian, ())?;
+
+    // Offsets are counted from the start of the service count
+    let mut offset = SIZE_OF_OFFSET_ENTRY * (1 + encoded_services.len());
+    for encoded_service in encoded_services {
+        (offset as CipUint).write_options(writer, endian, ())?;
+        offset += encoded_service.len();
+    }
+
+    for encoded_service in encoded_services {
+        writer.write_all(encoded_service)?;
+    }
+
+    Ok(())
+}
+
+/// Splits the rest of the reader into the services listed in the offset table
+fn read_offset_table<R: Read + Seek>(reader: &mut R, endian: Endian) -> BinResult<Vec<Vec<u8>>> {
+    let table_start = reader.stream_position()?;
+
+    let mut packet = Vec::new();
+    reader.read_to_end(&mut packet)?;
+    let mut packet_reader = std::io::Cursor::new(&packet);
+
+    let service_count = CipUint::read_options(&mut packet_reader, endian, ())?;
+    let mut offsets = (0..service_count)
+        .map(|_| CipUint::read_options(&mut packet_reader, endian, ()).map(usize::from))
+        .collect::<BinResult<Vec<_>>>()?;
+    offsets.push(packet.len());
+
+    offsets
+        .windows(2)
+        .map(
+            |service_bounds| match packet.get(service_bounds[0]..service_bounds[1]) {
+                Some(encoded_service) => Ok(encoded_service.to_vec()),
+                None => Err(binrw::Error::AssertFail {
+                    pos: table_start + service_bounds[0] as u64,
+                    message: format!(
+                        "Invalid offsets {}..{} in a {} byte packet",
+                        service_bounds[0],
+                        service_bounds[1],
+                        packet.len()
+                    ),
+                }),
+            },
+        )
+        .collect()
+}
+
+#[derive(Debug, PartialEq)]
+pub struct MultipleServiceRequest {
+    pub requests: Vec<MessageRouterRequest>,
+}
+
+// ======= Start of MultipleServiceRequest impl ========
+
+impl BinWrite for MultipleServiceRequest {
+    type Args<'a> = ();
+
+    fn write_options<W: Write + Seek>(
+        &self,
+        writer: &mut W,
+        endian: Endian,
+        _args: Self::Args<'_>,
+    ) -> BinResult<()> {
+        let encoded_requests = self
+            .requests
+            .iter()
+            .map(encode)
+            .collect::<BinResult<Vec<_>>>()?;
+
+        write_offset_table(writer, endian, &encoded_requests)
+    }
+}
+
+impl BinRead for MultipleServiceRequest {
+    type Args<'a> = ();
+
+    fn read_options<R: Read + Seek>(
+        reader: &mut R,
+        endian: Endian,
+        _args: Self::Args<'_>,
+    ) -> BinResult<Self> {
+        let requests = read_offset_table(reader, endian)?
+            .into_iter()
+            .map(|encoded_request| {
+                let request_length = encoded_request.len() as u16;
+                MessageRouterRequest::read_options(
+                    &mut std::io::Cursor::new(encoded_request),
+                    endian,
+                    (request_length,),
+                )
+            })
+            .collect::<BinResult<Vec<_>>>()?;
+
+        Ok(MultipleServiceRequest { requests })
+    }
+}
+
+impl MultipleServiceRequest {
+    pub fn new(requests: Vec<MessageRouterRequest>) -> Self {
+        MultipleServiceRequest { requests }
+    }
+
+    /// The Multiple Service Packet is always sent to the Message Router object
+    pub fn into_router_request(self) -> MessageRouterRequest {
+        MessageRouterRequest::new_data(
+            ServiceCode::MultipleServicePacket,
+            CipPath::new(MESSAGE_ROUTER_CLASS_ID, 0x1),
+            Some(Box::new(self)),
+        )
+    }
+
+    /// Groups the requests into as few packets as possible, keeping both each packet's request and
+    /// its reply within `max_message_size` bytes (e.g. the connection size, or 504 for unconnected
+    /// messages)
+    ///
+    /// Each request comes with the size of the reply data it is expected to return, e.g. 4 for a
+    /// Get Attribute Single of a UDINT. Replies are budgeted without the additional status that
+    /// some error replies carry.
+    pub fn plan(
+        requests: Vec<(MessageRouterRequest, usize)>,
+        max_message_size: usize,
+    ) -> BinResult<Vec<MultipleServiceRequest>> {
+        let empty_packet_size = MULTIPLE_SERVICE_HEADER_SIZE + SIZE_OF_OFFSET_ENTRY;
+        let empty_reply_size = REPLY_HEADER_SIZE + SIZE_OF_OFFSET_ENTRY;
+
+        let mut packets = Vec::new();
+        let mut packet_requests = Vec::new();
+        let mut packet_size = empty_packet_size;
+        let mut reply_size = empty_reply_size;
+
+        for (request, reply_data_size) in requests {
+            let request_size = encode(&request)?.len() + SIZE_OF_OFFSET_ENTRY;
+            let response_size = REPLY_HEADER_SIZE + reply_data_size + SIZE_OF_OFFSET_ENTRY;
+
+            if empty_packet_size + request_size > max_message_size
+                || empty_reply_size + response_size > max_message_size
+            {
+                return Err(binrw::Error::Custom {
+                    pos: 0,
+                    err: Box::new(format!(
+                        "A {} byte request with a {} byte reply does not fit in a {} byte message",
+                        request_size, response_size, max_message_size
+                    )),
+                });
+            }
+
+            if packet_size + request_size > max_message_size
+                || reply_size + response_size > max_message_size
+            {
+                packets.push(MultipleServiceRequest::new(std::mem::take(
+                    &mut packet_requests,
+                )));
+                packet_size = empty_packet_size;
+                reply_size = empty_reply_size;
+            }
+
+            packet_requests.push(request);
+            packet_size += request_size;
+            reply_size += response_size;
+        }
+
+        if !packet_requests.is_empty() {
+            packets.push(MultipleServiceRequest::new(packet_requests));
+        }
+
+        Ok(packets)
+    }
+}
+
+// ^^^^^^^^ End of MultipleServiceRequest impl ^^^^^^^^
+
+#[derive(Debug, PartialEq)]
+pub struct MultipleServiceReply {
+    pub responses: Vec<MessageRouterResponse>,
+}
+
+// ======= Start of MultipleServiceReply impl ========
+
+impl BinWrite for MultipleServiceReply {
+    type Args<'a> = ();
+
+    fn write_options<W: Write + Seek>(
+        &self,
+        writer: &mut W,
+        endian: Endian,
+        _args: Self::Args<'_>,
+    ) -> BinResult<()> {
+        let encoded_responses = self
+            .responses
+            .iter()
+            .map(encode)
+            .collect::<BinResult<Vec<_>>>()?;
+
+        write_offset_table(writer, endian, &encoded_responses)
+    }
+}
+
+impl BinRead for MultipleServiceReply {
+    type Args<'a> = ();
+
+    fn read_options<R: Read + Seek>(
+        reader: &mut R,
+        endian: Endian,
+        _args: Self::Args<'_>,
+    ) -> BinResult<Self> {
+        let responses = read_offset_table(reader, endian)?
+            .into_iter()
+            .map(|encoded_response| {
+                let response_length = encoded_response.len() as u16;
+                MessageRouterResponse::read_options(
+                    &mut std::io::Cursor::new(encoded_response),
+                    endian,
+                    (response_length,),
+                )
+            })
+            .collect::<BinResult<Vec<_>>>()?;
+
+        Ok(MultipleServiceReply { responses })
+    }
+}
+
+impl MultipleServiceReply {
+    /// Splits the reply into the responses of each packed request, in the order of the requests
+    ///
+    /// The individual responses keep their own status, so a failed request does not fail the others.
+    pub fn from_response(response: &MessageRouterResponse) -> BinResult<Self> {
+        if response.response_data.status != ResponseStatusCode::EmbeddedServiceError {
+            response.check_status()?;
+        }
+
+        response.response_data.read_reply()
+    }
+}
+
+// ^^^^^^^^ End of MultipleServiceReply impl ^^^^^^^^
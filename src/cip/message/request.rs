@@ -6,11 +6,11 @@ use binrw::{binrw, BinResult, BinWrite};
 use super::shared::{ServiceCode, ServiceContainer, SIZE_OF_SERVICE_CONTAINER};
 use crate::cip::message::data::{CipData, CipDataOpt};
 use crate::cip::message::shared::{BYTES_IN_A_WORD, SIZE_OF_CIP_USINT};
-use crate::cip::path::CipPath;
+use crate::cip::path::RequestPath;
 use crate::cip::types::CipUsint;
 
 #[binrw::writer(writer, endian)]
-fn write_cip_path_with_size(cip_path: &CipPath) -> BinResult<()> {
+fn write_cip_path_with_size(cip_path: &RequestPath) -> BinResult<()> {
     // Step 1: Write the `cip_path` field
     let mut temp_buffer = Vec::new();
     let mut temp_writer = std::io::Cursor::new(&mut temp_buffer);
@@ -39,9 +39,9 @@ fn write_cip_path_with_size(cip_path: &CipPath) -> BinResult<()> {
 pub struct RequestData {
     pub total_word_size: CipUsint,
     // override the total_word_size by seeking back before it
-    #[bw(seek_before = SeekFrom::Current(-1 * (mem::size_of::<CipUsint>() as i64)), write_with = write_cip_path_with_size)]
+    #[bw(seek_before = SeekFrom::Current(-(mem::size_of::<CipUsint>() as i64)), write_with = write_cip_path_with_size)]
     #[br(args(total_word_size))]
-    pub cip_path: CipPath,
+    pub cip_path: RequestPath,
 
     // Subtract the number of bytes taken up by the `total_word_size` field and the `cip_path``
     #[br(args(additional_data_length - (SIZE_OF_CIP_USINT as u16) - (BYTES_IN_A_WORD * total_word_size as u16)))]
@@ -53,12 +53,12 @@ pub struct RequestData {
 impl RequestData {
     pub fn new(
         word_size: Option<CipUsint>,
-        path: CipPath,
+        path: impl Into<RequestPath>,
         request_data_content: Option<Box<dyn CipData>>,
     ) -> Self {
         RequestData {
             total_word_size: word_size.unwrap_or(0),
-            cip_path: path,
+            cip_path: path.into(),
             additional_data: match request_data_content {
                 None => CipDataOpt::Raw(vec![]),
                 Some(content) => CipDataOpt::Typed(content),
@@ -82,13 +82,13 @@ pub struct MessageRouterRequest {
 // ======= Start of MessageRouterRequest impl ========
 
 impl MessageRouterRequest {
-    pub fn new(service_code: ServiceCode, path: CipPath) -> Self {
+    pub fn new(service_code: ServiceCode, path: impl Into<RequestPath>) -> Self {
         Self::new_data(service_code, path, None)
     }
 
     pub fn new_data(
         service_code: ServiceCode,
-        path: CipPath,
+        path: impl Into<RequestPath>,
         request_data_content: Option<Box<dyn CipData>>,
     ) -> Self {
         MessageRouterRequest {
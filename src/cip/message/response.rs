@@ -1,18 +1,110 @@
 use binrw::{
     binrw,
     BinRead,
+    BinResult,
     BinWrite, // BinRead,  // trait for reading
 };
 
-use crate::cip::{message::{data::CipDataOpt, shared::SIZE_OF_CIP_USINT}, types::CipUsint};
+use crate::cip::{message::{data::CipDataOpt, shared::SIZE_OF_CIP_USINT}, types::{CipUint, CipUsint}};
 
 use super::shared::{ServiceContainer, SIZE_OF_SERVICE_CONTAINER};
 
 #[derive(BinRead, BinWrite)]
-#[brw(little, repr = CipUsint)]
+#[brw(little)]
 #[derive(Debug, PartialEq, Copy, Clone)]
 pub enum ResponseStatusCode {
-    Success = 0x0000,
+    #[brw(magic = 0x00u8)]
+    Success,
+    #[brw(magic = 0x01u8)]
+    ConnectionFailure,
+    #[brw(magic = 0x02u8)]
+    ResourceUnavailable,
+    #[brw(magic = 0x03u8)]
+    InvalidParameterValue,
+    #[brw(magic = 0x04u8)]
+    PathSegmentError,
+    #[brw(magic = 0x05u8)]
+    PathDestinationUnknown,
+    #[brw(magic = 0x06u8)]
+    PartialTransfer,
+    #[brw(magic = 0x07u8)]
+    ConnectionLost,
+    #[brw(magic = 0x08u8)]
+    ServiceNotSupported,
+    #[brw(magic = 0x09u8)]
+    InvalidAttributeValue,
+    #[brw(magic = 0x0au8)]
+    AttributeListError,
+    #[brw(magic = 0x0bu8)]
+    AlreadyInRequestedMode,
+    #[brw(magic = 0x0cu8)]
+    ObjectStateConflict,
+    #[brw(magic = 0x0du8)]
+    ObjectAlreadyExists,
+    #[brw(magic = 0x0eu8)]
+    AttributeNotSettable,
+    #[brw(magic = 0x0fu8)]
+    PrivilegeViolation,
+    #[brw(magic = 0x10u8)]
+    DeviceStateConflict,
+    #[brw(magic = 0x11u8)]
+    ReplyDataTooLarge,
+    #[brw(magic = 0x12u8)]
+    FragmentationOfPrimitiveValue,
+    #[brw(magic = 0x13u8)]
+    NotEnoughData,
+    #[brw(magic = 0x14u8)]
+    AttributeNotSupported,
+    #[brw(magic = 0x15u8)]
+    TooMuchData,
+    #[brw(magic = 0x16u8)]
+    ObjectDoesNotExist,
+    #[brw(magic = 0x17u8)]
+    ServiceFragmentationSequenceNotInProgress,
+    #[brw(magic = 0x18u8)]
+    NoStoredAttributeData,
+    #[brw(magic = 0x19u8)]
+    StoreOperationFailure,
+    #[brw(magic = 0x1au8)]
+    RoutingFailureRequestTooLarge,
+    #[brw(magic = 0x1bu8)]
+    RoutingFailureResponseTooLarge,
+    #[brw(magic = 0x1cu8)]
+    MissingAttributeListEntryData,
+    #[brw(magic = 0x1du8)]
+    InvalidAttributeValueList,
+    #[brw(magic = 0x1eu8)]
+    EmbeddedServiceError,
+    #[brw(magic = 0x1fu8)]
+    VendorSpecificError,
+    #[brw(magic = 0x20u8)]
+    InvalidParameter,
+    #[brw(magic = 0x21u8)]
+    WriteOnceValueAlreadyWritten,
+    #[brw(magic = 0x22u8)]
+    InvalidReplyReceived,
+    #[brw(magic = 0x23u8)]
+    BufferOverflow,
+    #[brw(magic = 0x24u8)]
+    MessageFormatError,
+    #[brw(magic = 0x25u8)]
+    KeyFailureInPath,
+    #[brw(magic = 0x26u8)]
+    PathSizeInvalid,
+    #[brw(magic = 0x27u8)]
+    UnexpectedAttributeInList,
+    #[brw(magic = 0x28u8)]
+    InvalidMemberId,
+    #[brw(magic = 0x29u8)]
+    MemberNotSettable,
+    #[brw(magic = 0x2au8)]
+    GroupTwoOnlyServerFailure,
+    #[brw(magic = 0x2bu8)]
+    UnknownModbusError,
+    #[brw(magic = 0x2cu8)]
+    AttributeNotGettable,
+    /// A newer general status or an object specific status (0xD0-0xFF)
+    Other(CipUsint),
 }
 
 #[binrw]
@@ -42,3 +134,90 @@ pub struct MessageRouterResponse
     #[br(args(data_length - SIZE_OF_SERVICE_CONTAINER as u16))]
     pub response_data: ResponseData,
 }
+
+// ======= Start of ResponseData impl ========
+
+impl ResponseData {
+    fn data_bytes(&self) -> Vec<u8> {
+        match &self.data {
+            CipDataOpt::Raw(raw_data) => raw_data.clone(),
+            CipDataOpt::Typed(typed_data) => {
+                let mut buffer = Vec::new();
+                let mut writer = std::io::Cursor::new(&mut buffer);
+                let _ = typed_data.write_to(&mut writer, binrw::Endian::Little);
+                buffer
+            }
+        }
+    }
+
+    /// Whether the device does not support the requested attribute or service, which readers skip
+    pub fn is_attribute_unsupported(&self) -> bool {
+        matches!(
+            self.status,
+            ResponseStatusCode::AttributeNotSupported
+                | ResponseStatusCode::ServiceNotSupported
+                | ResponseStatusCode::AttributeNotGettable
+        )
+    }
+
+    /// Whether the object instance the request was sent to does not exist
+    pub fn is_object_missing(&self) -> bool {
+        matches!(
+            self.status,
+            ResponseStatusCode::ObjectDoesNotExist
+                | ResponseStatusCode::PathDestinationUnknown
+                | ResponseStatusCode::PathSegmentError
+        )
+    }
+
+    /// The additional status words that precede the reply data
+    pub fn additional_status(&self) -> Vec<CipUint> {
+        self.data_bytes()
+            .chunks_exact(2)
+            .take(self.additional_status_size as usize)
+            .map(|word| CipUint::from_le_bytes([word[0], word[1]]))
+            .collect()
+    }
+
+    /// The reply data without the leading additional status words
+    pub fn reply_data(&self) -> Vec<u8> {
+        let additional_status_bytes = 2 * self.additional_status_size as usize;
+        self.data_bytes()
+            .get(additional_status_bytes..)
+            .unwrap_or_default()
+            .to_vec()
+    }
+
+    /// Deserializes the reply data into the expected type
+    pub fn read_reply<T>(&self) -> BinResult<T>
+    where
+        T: for<'a> BinRead<Args<'a> = ()>,
+    {
+        let mut reply_reader = std::io::Cursor::new(self.reply_data());
+        T::read_le(&mut reply_reader)
+    }
+}
+
+// ^^^^^^^^ End of ResponseData impl ^^^^^^^^
+
+// ======= Start of MessageRouterResponse impl ========
+
+impl MessageRouterResponse {
+    /// Fails with the general status of the response unless it is a success
+    pub fn check_status(&self) -> BinResult<()> {
+        match self.response_data.status {
+            ResponseStatusCode::Success => Ok(()),
+            status => Err(binrw::Error::Custom {
+                pos: 0,
+                err: Box::new(format!(
+                    "{:?} failed with {:?} (additional status: {:04X?})",
+                    self.service_container.service(),
+                    status,
+                    self.response_data.additional_status()
+                )),
+            }),
+        }
+    }
+}
+
+// ^^^^^^^^ End of MessageRouterResponse impl ^^^^^^^^
@@ -0,0 +1,177 @@
+use std::collections::BTreeMap;
+
+use binrw::{
+    binrw, // #[binrw] attribute
+    BinResult,
+};
+
+use crate::cip::class::{ClassAttributes, ClassAttributesRead};
+use crate::cip::message::request::MessageRouterRequest;
+use crate::cip::message::response::MessageRouterResponse;
+use crate::cip::message::shared::ServiceCode;
+use crate::cip::path::CipPath;
+use crate::cip::types::CipUint;
+
+/// The Message Router object, which knows every object class the device implements
+pub const MESSAGE_ROUTER_CLASS_ID: u16 = 0x02;
+
+/*
+Message Router instance attributes
+    Attribute: 1 (Object List)
+        Number of Classes: 9
+        Classes: 0x0001, 0x0002, 0x0004, 0x0006, 0x00f4, 0x00f5, 0x00f6, ...
+    Attribute: 2 (Maximum Connections Supported)
+*/
+#[derive(Debug, PartialEq, Clone, Copy)]
+#[repr(u16)]
+pub enum MessageRouterAttributeId {
+    ObjectList = 0x01,
+    MaxConnections = 0x02,
+}
+
+#[binrw]
+#[brw(little)]
+#[derive(Debug, PartialEq, Clone)]
+pub struct ObjectList {
+    #[br(temp)]
+    #[bw(calc = class_ids.len() as CipUint)]
+    class_count: CipUint,
+
+    #[br(count = class_count)]
+    pub class_ids: Vec<CipUint>,
+}
+
+pub fn message_router_attribute_request(
+    attribute_id: MessageRouterAttributeId,
+) -> MessageRouterRequest {
+    MessageRouterRequest::new(
+        ServiceCode::GetAttributeSingle,
+        CipPath::new(MESSAGE_ROUTER_CLASS_ID, 0x01).with_attribute(attribute_id as u16),
+    )
+}
+
+/// Every object class a device implements, along with its class attributes
+#[derive(Debug, PartialEq, Clone, Default)]
+pub struct DeviceInventory {
+    pub max_connections: Option<CipUint>,
+    pub classes: BTreeMap<CipUint, ClassAttributes>,
+}
+
+// ======= Start of DeviceInventory impl ========
+
+impl DeviceInventory {
+    /// The revision of an object class, if the device reports one
+    pub fn revision(&self, class_id: CipUint) -> Option<CipUint> {
+        self.classes.get(&class_id)?.revision
+    }
+
+    /// The number of instances of an object class, if the device reports it
+    pub fn instance_count(&self, class_id: CipUint) -> Option<CipUint> {
+        self.classes.get(&class_id)?.number_of_instances
+    }
+}
+
+// ^^^^^^^^ End of DeviceInventory impl ^^^^^^^^
+
+#[derive(Debug)]
+enum InventoryStep {
+    ReadObjectList,
+    ReadMaxConnections,
+    ReadClass(ClassAttributesRead),
+    Complete,
+}
+
+/// Builds the inventory of a device from the Message Router object list
+///
+/// Reads the object list and the maximum number of connections, then the class attributes of every
+/// object class in the list.
+#[derive(Debug)]
+pub struct DeviceInventoryRead {
+    step: InventoryStep,
+    remaining_class_ids: Vec<CipUint>,
+    inventory: DeviceInventory,
+}
+
+// ======= Start of DeviceInventoryRead impl ========
+
+impl DeviceInventoryRead {
+    pub fn new() -> Self {
+        DeviceInventoryRead {
+            step: InventoryStep::ReadObjectList,
+            remaining_class_ids: Vec::new(),
+            inventory: DeviceInventory::default(),
+        }
+    }
+
+    pub fn is_complete(&self) -> bool {
+        matches!(self.step, InventoryStep::Complete)
+    }
+
+    pub fn next_request(&self) -> Option<MessageRouterRequest> {
+        match &self.step {
+            InventoryStep::ReadObjectList => Some(message_router_attribute_request(
+                MessageRouterAttributeId::ObjectList,
+            )),
+            InventoryStep::ReadMaxConnections => Some(message_router_attribute_request(
+                MessageRouterAttributeId::MaxConnections,
+            )),
+            InventoryStep::ReadClass(class_read) => class_read.next_request(),
+            InventoryStep::Complete => None,
+        }
+    }
+
+    /// Moves on to the class attributes of the next class in the object list
+    fn next_class_step(&mut self) -> InventoryStep {
+        match self.remaining_class_ids.pop() {
+            Some(class_id) => InventoryStep::ReadClass(ClassAttributesRead::new(class_id)),
+            None => InventoryStep::Complete,
+        }
+    }
+
+    pub fn handle_response(&mut self, response: &MessageRouterResponse) -> BinResult<()> {
+        match &mut self.step {
+            InventoryStep::ReadObjectList => {
+                response.check_status()?;
+                let object_list: ObjectList = response.response_data.read_reply()?;
+
+                // Classes are popped off the end, so keep them in reverse to read them in order
+                self.remaining_class_ids = object_list.class_ids.into_iter().rev().collect();
+                self.step = InventoryStep::ReadMaxConnections;
+            }
+            InventoryStep::ReadMaxConnections => {
+                // The attribute is optional, so a device without it still gets an inventory
+                if !response.response_data.is_attribute_unsupported() {
+                    response.check_status()?;
+                    self.inventory.max_connections = Some(response.response_data.read_reply()?);
+                }
+                self.step = self.next_class_step();
+            }
+            InventoryStep::ReadClass(class_read) => {
+                class_read.handle_response(response)?;
+
+                if class_read.is_complete() {
+                    self.inventory
+                        .classes
+                        .insert(class_read.class_id(), class_read.attributes().clone());
+                    self.step = self.next_class_step();
+                }
+            }
+            InventoryStep::Complete => (),
+        }
+
+        Ok(())
+    }
+
+    /// The device inventory, once every class has been read
+    pub fn into_inventory(self) -> Option<DeviceInventory> {
+        self.is_complete().then_some(self.inventory)
+    }
+}
+
+impl Default for DeviceInventoryRead {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// ^^^^^^^^ End of DeviceInventoryRead impl ^^^^^^^^
@@ -1,5 +1,22 @@
 // Make the cip types public
+pub mod ac_dc_drive;
+pub mod assembly;
+pub mod class;
+pub mod connection_manager;
+pub mod control_supervisor;
+pub mod dlr;
+pub mod drive;
+pub mod ethernet_link;
+pub mod file;
 pub mod identity;
 pub mod message;
+pub mod message_router;
+pub mod motor_data;
+pub mod parameter;
 pub mod path;
+pub mod port;
+pub mod qos;
+pub mod registry;
+pub mod tcp_ip_interface;
+pub mod time_sync;
 pub mod types;
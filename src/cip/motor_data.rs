@@ -0,0 +1,218 @@
+use binrw::{
+    binrw, // #[binrw] attribute
+    BinResult,
+};
+
+use crate::cip::message::request::MessageRouterRequest;
+use crate::cip::message::response::MessageRouterResponse;
+use crate::cip::message::shared::ServiceCode;
+use crate::cip::path::CipPath;
+use crate::cip::types::{CipUdint, CipUint, CipUsint};
+
+/// The Motor Data object of the drive profile, which holds the nameplate data of the motor
+pub const MOTOR_DATA_CLASS_ID: u16 = 0x28;
+
+/*
+Motor Data instance attributes
+    Attribute: 3 (MotorType)
+    Attribute: 6 (RatedCurrent)
+    Attribute: 7 (RatedVoltage)
+    Attribute: 8 (RatedPower)
+    Attribute: 9 (RatedFreq)
+    Attribute: 10 (RatedTemp)
+    Attribute: 11 (MaxSpeed)
+    Attribute: 12 (PoleCount)
+    Attribute: 13 (TorqConstant)
+    Attribute: 14 (Inertia)
+    Attribute: 15 (BaseSpeed)
+    Attribute: 19 (ServiceFactor)
+*/
+#[derive(Debug, PartialEq, Clone, Copy)]
+#[repr(u16)]
+pub enum MotorDataAttributeId {
+    MotorType = 0x03,
+    RatedCurrent = 0x06,
+    RatedVoltage = 0x07,
+    RatedPower = 0x08,
+    RatedFrequency = 0x09,
+    RatedTemperature = 0x0A,
+    MaxSpeed = 0x0B,
+    PoleCount = 0x0C,
+    TorqueConstant = 0x0D,
+    Inertia = 0x0E,
+    BaseSpeed = 0x0F,
+    ServiceFactor = 0x13,
+}
+
+#[binrw]
+#[brw(little)]
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum MotorType {
+    #[brw(magic = 0x00u8)]
+    NonStandard,
+    #[brw(magic = 0x01u8)]
+    PermanentMagnetDc,
+    #[brw(magic = 0x02u8)]
+    WoundFieldDc,
+    #[brw(magic = 0x03u8)]
+    PermanentMagnetSynchronous,
+    #[brw(magic = 0x04u8)]
+    WoundFieldSynchronous,
+    #[brw(magic = 0x05u8)]
+    SwitchedReluctance,
+    #[brw(magic = 0x06u8)]
+    WoundRotorInduction,
+    #[brw(magic = 0x07u8)]
+    SquirrelCageInduction,
+    #[brw(magic = 0x08u8)]
+    Stepper,
+    #[brw(magic = 0x09u8)]
+    SinusoidalPermanentMagnetBrushless,
+    #[brw(magic = 0x0Au8)]
+    TrapezoidalPermanentMagnetBrushless,
+    Reserved(u8),
+}
+
+/// A request reading a single attribute of the Motor Data instance
+pub fn motor_data_attribute_request(attribute_id: MotorDataAttributeId) -> MessageRouterRequest {
+    MessageRouterRequest::new(
+        ServiceCode::GetAttributeSingle,
+        CipPath::new(MOTOR_DATA_CLASS_ID, 0x01).with_attribute(attribute_id as u16),
+    )
+}
+
+/// The nameplate data of a motor, each left as `None` if the drive does not support it
+#[derive(Debug, PartialEq, Clone, Default)]
+pub struct MotorData {
+    pub motor_type: Option<MotorType>,
+    /// In 100 mA
+    pub rated_current: Option<CipUint>,
+    /// In volts
+    pub rated_voltage: Option<CipUint>,
+    /// In watts
+    pub rated_power: Option<CipUdint>,
+    /// In hertz
+    pub rated_frequency: Option<CipUint>,
+    /// In degrees Celsius
+    pub rated_temperature: Option<CipUint>,
+    /// In RPM
+    pub max_speed: Option<CipUint>,
+    pub pole_count: Option<CipUint>,
+    /// In 0.001 Nm/A
+    pub torque_constant: Option<CipUdint>,
+    /// In 0.001 kg m²
+    pub inertia: Option<CipUdint>,
+    /// In RPM
+    pub base_speed: Option<CipUint>,
+    /// In percent
+    pub service_factor: Option<CipUsint>,
+}
+
+const MOTOR_DATA_ATTRIBUTES: [MotorDataAttributeId; 12] = [
+    MotorDataAttributeId::MotorType,
+    MotorDataAttributeId::RatedCurrent,
+    MotorDataAttributeId::RatedVoltage,
+    MotorDataAttributeId::RatedPower,
+    MotorDataAttributeId::RatedFrequency,
+    MotorDataAttributeId::RatedTemperature,
+    MotorDataAttributeId::MaxSpeed,
+    MotorDataAttributeId::PoleCount,
+    MotorDataAttributeId::TorqueConstant,
+    MotorDataAttributeId::Inertia,
+    MotorDataAttributeId::BaseSpeed,
+    MotorDataAttributeId::ServiceFactor,
+];
+
+/// Reads the Motor Data of a drive one Get Attribute Single at a time
+#[derive(Debug)]
+pub struct MotorDataRead {
+    next_attribute: usize,
+    motor_data: MotorData,
+}
+
+// ======= Start of MotorDataRead impl ========
+
+impl MotorDataRead {
+    pub fn new() -> Self {
+        MotorDataRead {
+            next_attribute: 0,
+            motor_data: MotorData::default(),
+        }
+    }
+
+    pub fn is_complete(&self) -> bool {
+        self.next_attribute >= MOTOR_DATA_ATTRIBUTES.len()
+    }
+
+    pub fn next_request(&self) -> Option<MessageRouterRequest> {
+        MOTOR_DATA_ATTRIBUTES
+            .get(self.next_attribute)
+            .map(|attribute_id| motor_data_attribute_request(*attribute_id))
+    }
+
+    pub fn handle_response(&mut self, response: &MessageRouterResponse) -> BinResult<()> {
+        let Some(attribute_id) = MOTOR_DATA_ATTRIBUTES.get(self.next_attribute).copied() else {
+            return Ok(());
+        };
+        self.next_attribute += 1;
+
+        // Without a Motor Data object there is nothing else to read
+        if response.response_data.is_object_missing() {
+            self.next_attribute = MOTOR_DATA_ATTRIBUTES.len();
+            return Ok(());
+        }
+        if response.response_data.is_attribute_unsupported() {
+            return Ok(());
+        }
+        response.check_status()?;
+
+        let reply = &response.response_data;
+        let motor_data = &mut self.motor_data;
+        match attribute_id {
+            MotorDataAttributeId::MotorType => motor_data.motor_type = Some(reply.read_reply()?),
+            MotorDataAttributeId::RatedCurrent => {
+                motor_data.rated_current = Some(reply.read_reply()?)
+            }
+            MotorDataAttributeId::RatedVoltage => {
+                motor_data.rated_voltage = Some(reply.read_reply()?)
+            }
+            MotorDataAttributeId::RatedPower => motor_data.rated_power = Some(reply.read_reply()?),
+            MotorDataAttributeId::RatedFrequency => {
+                motor_data.rated_frequency = Some(reply.read_reply()?)
+            }
+            MotorDataAttributeId::RatedTemperature => {
+                motor_data.rated_temperature = Some(reply.read_reply()?)
+            }
+            MotorDataAttributeId::MaxSpeed => motor_data.max_speed = Some(reply.read_reply()?),
+            MotorDataAttributeId::PoleCount => motor_data.pole_count = Some(reply.read_reply()?),
+            MotorDataAttributeId::TorqueConstant => {
+                motor_data.torque_constant = Some(reply.read_reply()?)
+            }
+            MotorDataAttributeId::Inertia => motor_data.inertia = Some(reply.read_reply()?),
+            MotorDataAttributeId::BaseSpeed => motor_data.base_speed = Some(reply.read_reply()?),
+            MotorDataAttributeId::ServiceFactor => {
+                motor_data.service_factor = Some(reply.read_reply()?)
+            }
+        }
+
+        Ok(())
+    }
+
+    /// The motor data read so far
+    pub fn motor_data(&self) -> &MotorData {
+        &self.motor_data
+    }
+
+    /// The motor data, once the read is complete
+    pub fn into_motor_data(self) -> Option<MotorData> {
+        self.is_complete().then_some(self.motor_data)
+    }
+}
+
+impl Default for MotorDataRead {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// ^^^^^^^^ End of MotorDataRead impl ^^^^^^^^
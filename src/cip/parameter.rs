@@ -0,0 +1,616 @@
+use bilge::prelude::{bitsize, u7, u9, Bitsized, DebugBits, FromBits, Number};
+
+use binrw::{
+    binrw, // #[binrw] attribute
+    helpers::until_eof,
+    BinRead,
+    BinResult,
+    BinWrite,
+};
+
+use crate::cip::message::request::MessageRouterRequest;
+use crate::cip::message::response::{MessageRouterResponse, ResponseStatusCode};
+use crate::cip::message::shared::ServiceCode;
+use crate::cip::path::CipPath;
+use crate::cip::types::{CipByte, CipDataType, CipInt, CipShortString, CipUint, CipUsint, CipWord};
+
+/// The Parameter object, with one instance per configurable or monitored value of the device
+pub const PARAMETER_CLASS_ID: u16 = 0x0F;
+
+/// Object specific services of the Parameter object
+#[derive(Debug, PartialEq, Clone, Copy)]
+#[repr(u8)]
+pub enum ParameterServiceCode {
+    /// Reads the name of one value of an enumerated parameter
+    GetEnumString = 0x4B,
+}
+
+impl From<ParameterServiceCode> for ServiceCode {
+    fn from(service_code: ParameterServiceCode) -> Self {
+        ServiceCode::from(u7::new(service_code as u8))
+    }
+}
+
+/*
+Parameter instance attributes
+    Attribute: 1 (Parameter Value) -- settable, of the data type and size below
+    Attribute: 2 (Link Path Size)
+    Attribute: 3 (Link Path)
+    Attribute: 4 (Descriptor)
+    Attribute: 5 (Data Type)
+    Attribute: 6 (Data Size)
+    Attribute: 7 (Parameter Name String)
+    Attribute: 8 (Units String)
+    Attribute: 9 (Help String)
+    Attribute: 10 (Minimum Value)
+    Attribute: 11 (Maximum Value)
+    Attribute: 12 (Default Value)
+    Attribute: 13 (Scaling Multiplier)
+    Attribute: 14 (Scaling Divisor)
+    Attribute: 15 (Scaling Base)
+    Attribute: 16 (Scaling Offset)
+    Attribute: 17-20 (Multiplier, Divisor, Base and Offset Links)
+    Attribute: 21 (Decimal Precision)
+*/
+#[derive(Debug, PartialEq, Clone, Copy)]
+#[repr(u16)]
+pub enum ParameterAttributeId {
+    Value = 0x01,
+    LinkPathSize = 0x02,
+    LinkPath = 0x03,
+    Descriptor = 0x04,
+    DataType = 0x05,
+    DataSize = 0x06,
+    Name = 0x07,
+    Units = 0x08,
+    Help = 0x09,
+    Minimum = 0x0A,
+    Maximum = 0x0B,
+    Default = 0x0C,
+    ScalingMultiplier = 0x0D,
+    ScalingDivisor = 0x0E,
+    ScalingBase = 0x0F,
+    ScalingOffset = 0x10,
+    MultiplierLink = 0x11,
+    DivisorLink = 0x12,
+    BaseLink = 0x13,
+    OffsetLink = 0x14,
+    DecimalPrecision = 0x15,
+}
+
+/// What a parameter supports, also used by the parameters of an EDS
+#[bitsize(16)]
+#[derive(FromBits, PartialEq, DebugBits, BinRead, BinWrite, Copy, Clone)]
+#[br(map = CipWord::into)]
+#[bw(map = |&x| CipWord::from(x))]
+pub struct ParameterDescriptor {
+    pub settable_path: bool,
+    pub enumerated_strings: bool,
+    pub scaling: bool,
+    pub scaling_links: bool,
+    pub read_only: bool,
+    /// The value is refreshed regularly and should be monitored, e.g. a measurement
+    pub monitor: bool,
+    pub extended_precision_scaling: bool,
+    pub reserved: u9,
+}
+
+/// A parameter value, its encoding depends on the data type and size of the parameter
+#[derive(Debug, PartialEq, Clone)]
+pub enum ParameterValue {
+    /// Wide enough for any CIP integer, from LINT to ULINT
+    Integer(i128),
+    Real(f64),
+    /// Anything that is not a number, e.g. a string
+    Bytes(Vec<CipByte>),
+}
+
+// ======= Start of ParameterValue impl ========
+
+impl ParameterValue {
+    pub fn decode(data_type: CipDataType, bytes: &[CipByte]) -> Self {
+        let signed = matches!(
+            data_type,
+            CipDataType::Sint | CipDataType::Int | CipDataType::Dint | CipDataType::Lint
+        );
+        let real = matches!(data_type, CipDataType::Real | CipDataType::Lreal);
+
+        match (bytes.len(), signed, real) {
+            (size, _, _) if data_type.element_size() != Some(size) => {
+                ParameterValue::Bytes(bytes.to_vec())
+            }
+            (4, _, true) => {
+                ParameterValue::Real(f32::from_le_bytes(bytes.try_into().unwrap()) as f64)
+            }
+            (8, _, true) => ParameterValue::Real(f64::from_le_bytes(bytes.try_into().unwrap())),
+            (_, true, _) => {
+                // Sign extends from the top byte
+                let fill = if bytes[bytes.len() - 1] & 0x80 != 0 {
+                    0xFF
+                } else {
+                    0x00
+                };
+                let mut wide_bytes = [fill; 16];
+                wide_bytes[..bytes.len()].copy_from_slice(bytes);
+                ParameterValue::Integer(i128::from_le_bytes(wide_bytes))
+            }
+            _ => {
+                let mut wide_bytes = [0; 16];
+                wide_bytes[..bytes.len()].copy_from_slice(bytes);
+                ParameterValue::Integer(i128::from_le_bytes(wide_bytes))
+            }
+        }
+    }
+
+    /// The encoding of the value for a parameter, failing if it does not fit
+    pub fn encode(&self, data_type: CipDataType, data_size: usize) -> BinResult<Vec<CipByte>> {
+        let error = |message: String| binrw::Error::Custom {
+            pos: 0,
+            err: Box::new(message),
+        };
+
+        match (self, data_type) {
+            (ParameterValue::Real(real), CipDataType::Real) => {
+                Ok((*real as f32).to_le_bytes().to_vec())
+            }
+            (ParameterValue::Real(real), CipDataType::Lreal) => Ok(real.to_le_bytes().to_vec()),
+            (ParameterValue::Integer(integer), _)
+                if data_type.element_size() == Some(data_size) =>
+            {
+                let signed = matches!(
+                    data_type,
+                    CipDataType::Sint | CipDataType::Int | CipDataType::Dint | CipDataType::Lint
+                );
+                let bits = data_size as u32 * 8;
+                let (minimum, maximum) = match signed {
+                    true => (-(1i128 << (bits - 1)), (1i128 << (bits - 1)) - 1),
+                    false => (0, (1i128 << bits) - 1),
+                };
+
+                if !(minimum..=maximum).contains(integer) {
+                    return Err(error(format!(
+                        "{} does not fit in a {:?} parameter",
+                        integer, data_type
+                    )));
+                }
+                Ok(integer.to_le_bytes()[..data_size].to_vec())
+            }
+            (ParameterValue::Bytes(bytes), _) if bytes.len() == data_size => Ok(bytes.clone()),
+            (value, _) => Err(error(format!(
+                "{:?} is not a valid value for a {} byte {:?} parameter",
+                value, data_size, data_type
+            ))),
+        }
+    }
+
+    pub fn as_f64(&self) -> Option<f64> {
+        match *self {
+            ParameterValue::Integer(integer) => Some(integer as f64),
+            ParameterValue::Real(real) => Some(real),
+            ParameterValue::Bytes(_) => None,
+        }
+    }
+}
+
+// ^^^^^^^^ End of ParameterValue impl ^^^^^^^^
+
+/// The raw encoding of a parameter value, as read from or written to attribute 1
+#[binrw]
+#[brw(little)]
+#[derive(Debug, PartialEq, Clone)]
+pub struct ParameterData {
+    #[br(parse_with = until_eof)]
+    pub data: Vec<CipByte>,
+}
+
+/// The conversion between the value of a parameter and its engineering units
+///
+/// `engineering = ((value + offset) * multiplier * base) / (divisor * 10^decimal_precision)`
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct ParameterScaling {
+    pub multiplier: CipUint,
+    pub divisor: CipUint,
+    pub base: CipUint,
+    pub offset: CipInt,
+    pub decimal_precision: CipUsint,
+}
+
+// ======= Start of ParameterScaling impl ========
+
+impl Default for ParameterScaling {
+    fn default() -> Self {
+        ParameterScaling {
+            multiplier: 1,
+            divisor: 1,
+            base: 1,
+            offset: 0,
+            decimal_precision: 0,
+        }
+    }
+}
+
+impl ParameterScaling {
+    fn denominator(&self) -> f64 {
+        self.divisor as f64 * 10f64.powi(self.decimal_precision as i32)
+    }
+
+    pub fn to_engineering(&self, value: f64) -> f64 {
+        (value + self.offset as f64) * self.multiplier as f64 * self.base as f64
+            / self.denominator()
+    }
+
+    pub fn from_engineering(&self, engineering_value: f64) -> f64 {
+        engineering_value * self.denominator() / (self.multiplier as f64 * self.base as f64)
+            - self.offset as f64
+    }
+}
+
+// ^^^^^^^^ End of ParameterScaling impl ^^^^^^^^
+
+/// Everything the device reports about a parameter, optional attributes it lacks are left empty
+#[derive(Debug, PartialEq, Clone)]
+pub struct ParameterInfo {
+    pub instance_id: CipUint,
+    pub value: Option<ParameterValue>,
+    /// The packed path of the attribute the parameter stands for, empty if it stands for none
+    pub link_path: Vec<CipByte>,
+    pub descriptor: ParameterDescriptor,
+    pub data_type: CipDataType,
+    /// The size of the value in bytes
+    pub data_size: usize,
+    pub name: String,
+    pub units: String,
+    pub help: String,
+    pub minimum: Option<ParameterValue>,
+    pub maximum: Option<ParameterValue>,
+    pub default: Option<ParameterValue>,
+    pub scaling: ParameterScaling,
+    /// The names of the values of an enumerated parameter, in value order
+    pub enum_strings: Vec<(CipUsint, String)>,
+}
+
+// ======= Start of ParameterInfo impl ========
+
+impl ParameterInfo {
+    pub fn new(instance_id: CipUint, data_type: CipDataType, data_size: usize) -> Self {
+        ParameterInfo {
+            instance_id,
+            value: None,
+            link_path: Vec::new(),
+            descriptor: ParameterDescriptor::from(0),
+            data_type,
+            data_size,
+            name: String::new(),
+            units: String::new(),
+            help: String::new(),
+            minimum: None,
+            maximum: None,
+            default: None,
+            scaling: ParameterScaling::default(),
+            enum_strings: Vec::new(),
+        }
+    }
+
+    /// The linked class, instance and attribute, if the parameter stands for one
+    pub fn link_path(&self) -> Option<CipPath> {
+        if self.link_path.is_empty() {
+            return None;
+        }
+
+        let mut path_reader = std::io::Cursor::new(&self.link_path);
+        CipPath::read_le_args(&mut path_reader, ((self.link_path.len() / 2) as u8,)).ok()
+    }
+
+    /// The value in engineering units, scaled when the parameter supports scaling
+    pub fn engineering_value(&self, value: &ParameterValue) -> Option<f64> {
+        let value = value.as_f64()?;
+        match self.descriptor.scaling() {
+            true => Some(self.scaling.to_engineering(value)),
+            false => Some(value),
+        }
+    }
+
+    /// The parameter value for a value in engineering units, rounded for integer parameters
+    pub fn value_from_engineering(&self, engineering_value: f64) -> ParameterValue {
+        let value = match self.descriptor.scaling() {
+            true => self.scaling.from_engineering(engineering_value),
+            false => engineering_value,
+        };
+
+        match self.data_type {
+            CipDataType::Real | CipDataType::Lreal => ParameterValue::Real(value),
+            _ => ParameterValue::Integer(value.round() as i128),
+        }
+    }
+
+    /// The name of a value of an enumerated parameter
+    pub fn enum_string(&self, value: &ParameterValue) -> Option<&str> {
+        let ParameterValue::Integer(value) = *value else {
+            return None;
+        };
+
+        self.enum_strings
+            .iter()
+            .find(|(enum_value, _)| *enum_value as i128 == value)
+            .map(|(_, name)| name.as_str())
+    }
+
+    fn check_range(&self, value: &ParameterValue) -> BinResult<()> {
+        let (Some(value_number), minimum, maximum) = (
+            value.as_f64(),
+            self.minimum.as_ref().and_then(ParameterValue::as_f64),
+            self.maximum.as_ref().and_then(ParameterValue::as_f64),
+        ) else {
+            return Ok(());
+        };
+
+        let below_minimum = minimum.is_some_and(|minimum| value_number < minimum);
+        let above_maximum = maximum.is_some_and(|maximum| value_number > maximum);
+
+        match below_minimum || above_maximum {
+            true => Err(binrw::Error::Custom {
+                pos: 0,
+                err: Box::new(format!(
+                    "{} is outside the limits of parameter {} ({:?} to {:?})",
+                    value_number, self.name, minimum, maximum
+                )),
+            }),
+            false => Ok(()),
+        }
+    }
+
+    /// A request reading the current value, decode its response with `read_value()`
+    pub fn value_request(&self) -> MessageRouterRequest {
+        parameter_attribute_request(self.instance_id, ParameterAttributeId::Value)
+    }
+
+    pub fn read_value(&self, response: &MessageRouterResponse) -> BinResult<ParameterValue> {
+        response.check_status()?;
+        Ok(ParameterValue::decode(
+            self.data_type,
+            &response.response_data.reply_data(),
+        ))
+    }
+
+    /// A request writing a value, checked against the limits of the parameter
+    pub fn set_value_request(&self, value: &ParameterValue) -> BinResult<MessageRouterRequest> {
+        if self.descriptor.read_only() {
+            return Err(binrw::Error::Custom {
+                pos: 0,
+                err: Box::new(format!("Parameter {} is read only", self.name)),
+            });
+        }
+        self.check_range(value)?;
+
+        let parameter_data = ParameterData {
+            data: value.encode(self.data_type, self.data_size)?,
+        };
+
+        Ok(MessageRouterRequest::new_data(
+            ServiceCode::SetAttributeSingle,
+            parameter_path(self.instance_id).with_attribute(ParameterAttributeId::Value as u16),
+            Some(Box::new(parameter_data)),
+        ))
+    }
+
+    /// A request writing a value given in engineering units
+    pub fn set_engineering_value_request(
+        &self,
+        engineering_value: f64,
+    ) -> BinResult<MessageRouterRequest> {
+        self.set_value_request(&self.value_from_engineering(engineering_value))
+    }
+}
+
+// ^^^^^^^^ End of ParameterInfo impl ^^^^^^^^
+
+fn parameter_path(instance_id: CipUint) -> CipPath {
+    CipPath::new(PARAMETER_CLASS_ID, instance_id)
+}
+
+/// A request reading a single attribute of a Parameter instance
+pub fn parameter_attribute_request(
+    instance_id: CipUint,
+    attribute_id: ParameterAttributeId,
+) -> MessageRouterRequest {
+    MessageRouterRequest::new(
+        ServiceCode::GetAttributeSingle,
+        parameter_path(instance_id).with_attribute(attribute_id as u16),
+    )
+}
+
+#[binrw]
+#[brw(little)]
+#[derive(Debug, PartialEq)]
+pub struct GetEnumStringRequest {
+    pub enum_string_number: CipUsint,
+}
+
+/// A request reading the name of one value of an enumerated parameter, replied as a SHORT_STRING
+pub fn get_enum_string_request(instance_id: CipUint, value: CipUsint) -> MessageRouterRequest {
+    MessageRouterRequest::new_data(
+        ParameterServiceCode::GetEnumString.into(),
+        parameter_path(instance_id),
+        Some(Box::new(GetEnumStringRequest {
+            enum_string_number: value,
+        })),
+    )
+}
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+enum ParameterReadStep {
+    Attribute(usize),
+    EnumString(CipUsint),
+    Complete,
+}
+
+/// Reads everything about a parameter, ending with the names of its values when it is enumerated
+///
+/// The data type and size come first as the value attributes cannot be decoded without them.
+#[derive(Debug)]
+pub struct ParameterRead {
+    step: ParameterReadStep,
+    info: ParameterInfo,
+}
+
+// ======= Start of ParameterRead impl ========
+
+impl ParameterRead {
+    const ATTRIBUTES: [ParameterAttributeId; 16] = [
+        ParameterAttributeId::DataType,
+        ParameterAttributeId::DataSize,
+        ParameterAttributeId::Descriptor,
+        ParameterAttributeId::Value,
+        ParameterAttributeId::LinkPath,
+        ParameterAttributeId::Name,
+        ParameterAttributeId::Units,
+        ParameterAttributeId::Help,
+        ParameterAttributeId::Minimum,
+        ParameterAttributeId::Maximum,
+        ParameterAttributeId::Default,
+        ParameterAttributeId::ScalingMultiplier,
+        ParameterAttributeId::ScalingDivisor,
+        ParameterAttributeId::ScalingBase,
+        ParameterAttributeId::ScalingOffset,
+        ParameterAttributeId::DecimalPrecision,
+    ];
+
+    pub fn new(instance_id: CipUint) -> Self {
+        ParameterRead {
+            step: ParameterReadStep::Attribute(0),
+            info: ParameterInfo::new(instance_id, CipDataType::Unknown(0), 0),
+        }
+    }
+
+    pub fn is_complete(&self) -> bool {
+        self.step == ParameterReadStep::Complete
+    }
+
+    pub fn next_request(&self) -> Option<MessageRouterRequest> {
+        match self.step {
+            ParameterReadStep::Attribute(index) => Some(parameter_attribute_request(
+                self.info.instance_id,
+                Self::ATTRIBUTES[index],
+            )),
+            ParameterReadStep::EnumString(value) => {
+                Some(get_enum_string_request(self.info.instance_id, value))
+            }
+            ParameterReadStep::Complete => None,
+        }
+    }
+
+    /// The enumerated values to name, from the minimum to the maximum when they are known
+    fn enum_range(&self) -> (CipUsint, CipUsint) {
+        let limit = |limit: &Option<ParameterValue>, default: CipUsint| match limit {
+            Some(ParameterValue::Integer(integer)) => (*integer).clamp(0, 0xFF) as CipUsint,
+            _ => default,
+        };
+
+        (
+            limit(&self.info.minimum, 0),
+            limit(&self.info.maximum, 0xFF),
+        )
+    }
+
+    fn step_after_attributes(&self) -> ParameterReadStep {
+        match self.info.descriptor.enumerated_strings() {
+            true => ParameterReadStep::EnumString(self.enum_range().0),
+            false => ParameterReadStep::Complete,
+        }
+    }
+
+    fn handle_attribute(
+        &mut self,
+        attribute_id: ParameterAttributeId,
+        response: &MessageRouterResponse,
+    ) -> BinResult<()> {
+        // Every parameter has a data type and size, without them nothing else can be read
+        let required = matches!(
+            attribute_id,
+            ParameterAttributeId::DataType | ParameterAttributeId::DataSize
+        );
+        if !required && response.response_data.is_attribute_unsupported() {
+            return Ok(());
+        }
+        response.check_status()?;
+
+        let info = &mut self.info;
+        let reply_data = &response.response_data;
+        let value = || ParameterValue::decode(info.data_type, &reply_data.reply_data());
+        let text =
+            || -> BinResult<String> { Ok(reply_data.read_reply::<CipShortString>()?.into()) };
+
+        match attribute_id {
+            ParameterAttributeId::DataType => info.data_type = reply_data.read_reply()?,
+            ParameterAttributeId::DataSize => {
+                info.data_size = reply_data.read_reply::<CipUsint>()? as usize
+            }
+            ParameterAttributeId::Descriptor => info.descriptor = reply_data.read_reply()?,
+            ParameterAttributeId::Value => info.value = Some(value()),
+            ParameterAttributeId::LinkPath => info.link_path = reply_data.reply_data(),
+            ParameterAttributeId::Name => info.name = text()?,
+            ParameterAttributeId::Units => info.units = text()?,
+            ParameterAttributeId::Help => info.help = text()?,
+            ParameterAttributeId::Minimum => info.minimum = Some(value()),
+            ParameterAttributeId::Maximum => info.maximum = Some(value()),
+            ParameterAttributeId::Default => info.default = Some(value()),
+            ParameterAttributeId::ScalingMultiplier => {
+                info.scaling.multiplier = reply_data.read_reply()?
+            }
+            ParameterAttributeId::ScalingDivisor => {
+                info.scaling.divisor = reply_data.read_reply()?
+            }
+            ParameterAttributeId::ScalingBase => info.scaling.base = reply_data.read_reply()?,
+            ParameterAttributeId::ScalingOffset => info.scaling.offset = reply_data.read_reply()?,
+            ParameterAttributeId::DecimalPrecision => {
+                info.scaling.decimal_precision = reply_data.read_reply()?
+            }
+            _ => {}
+        }
+
+        Ok(())
+    }
+
+    pub fn handle_response(&mut self, response: &MessageRouterResponse) -> BinResult<()> {
+        match self.step {
+            ParameterReadStep::Attribute(index) => {
+                self.handle_attribute(Self::ATTRIBUTES[index], response)?;
+
+                self.step = match index + 1 < Self::ATTRIBUTES.len() {
+                    true => ParameterReadStep::Attribute(index + 1),
+                    false => self.step_after_attributes(),
+                };
+            }
+            ParameterReadStep::EnumString(value) => {
+                // The first value without a name ends the enumeration
+                if response.response_data.status != ResponseStatusCode::Success {
+                    self.step = ParameterReadStep::Complete;
+                    return Ok(());
+                }
+
+                let name: CipShortString = response.response_data.read_reply()?;
+                self.info.enum_strings.push((value, name.into()));
+
+                self.step = match value < self.enum_range().1 {
+                    true => ParameterReadStep::EnumString(value + 1),
+                    false => ParameterReadStep::Complete,
+                };
+            }
+            ParameterReadStep::Complete => {}
+        }
+
+        Ok(())
+    }
+
+    /// The parameter as read so far
+    pub fn info(&self) -> &ParameterInfo {
+        &self.info
+    }
+
+    /// The parameter, once the read is complete
+    pub fn into_info(self) -> Option<ParameterInfo> {
+        self.is_complete().then_some(self.info)
+    }
+}
+
+// ^^^^^^^^ End of ParameterRead impl ^^^^^^^^
@@ -1,6 +1,7 @@
 use binrw::{
     binrw,
     BinRead,
+    BinResult,
     BinWrite, // #[binrw] attribute
               // BinRead,  // trait for reading
               // BinWrite, // trait for writing
@@ -9,10 +10,14 @@ use binrw::{
 //  Tried to use Deku but that didn't support nested structs: https://github.com/sharksforarms/deku
 use bilge::prelude::{bitsize, u2, u3, Bitsized, DebugBits, FromBits, Number};
 
+use crate::cip::message::shared::BYTES_IN_A_WORD;
+use crate::cip::types::CipUsint;
+
 #[bitsize(3)]
 #[derive(Debug, Clone, FromBits, PartialEq)]
 #[repr(u8)]
 pub enum SegmentType {
+    PortSegment = 0x00,
     LogicalSegment = 0x01,
 
     #[fallback]
@@ -25,6 +30,8 @@ pub enum SegmentType {
 pub enum LogicalSegmentType {
     ClassId = 0x00,
     InstanceId = 0x01,
+    MemberId = 0x02,
+    ConnectionPoint = 0x03,
     AttributeId = 0x04,
 
     #[fallback]
@@ -37,9 +44,8 @@ pub enum LogicalSegmentType {
 pub enum LogicalSegmentFormat {
     FormatAsU8 = 0x00,
     FormatAsU16 = 0x01,
-
-    #[fallback]
-    Unknown(u2),
+    FormatAsU32 = 0x02,
+    Reserved = 0x03,
 }
 
 #[bitsize(8)]
@@ -60,7 +66,7 @@ pub struct LogicalPathDefinition {
 // #[bw(map = |&x| u32::from(x))]
 
 #[binrw]
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Clone)]
 #[br(import(segment_format: LogicalSegmentFormat))]
 pub enum PathData {
     #[br(pre_assert(segment_format == LogicalSegmentFormat::FormatAsU8))]
@@ -68,34 +74,50 @@ pub enum PathData {
 
     #[br(pre_assert(segment_format == LogicalSegmentFormat::FormatAsU16))]
     FormatAsU16(u16),
+
+    #[br(pre_assert(segment_format == LogicalSegmentFormat::FormatAsU32))]
+    FormatAsU32(u32),
 }
 
-impl Into<u16> for PathData {
+impl From<PathData> for u16 {
     /// Converts the path data to a u16, regardless of its underlying format.
-    fn into(self) -> u16 {
-        match self {
+    fn from(path_data: PathData) -> Self {
+        match path_data {
             PathData::FormatAsU8(data) => data as u16,
             PathData::FormatAsU16(data) => data,
+            PathData::FormatAsU32(data) => data as u16,
         }
     }
 }
 
-impl<'a> Into<u16> for &'a PathData {
-    fn into(self) -> u16 {
-        match self {
+impl From<&PathData> for u16 {
+    fn from(path_data: &PathData) -> Self {
+        match path_data {
             PathData::FormatAsU8(data) => *data as u16,
             PathData::FormatAsU16(data) => *data,
+            PathData::FormatAsU32(data) => *data as u16,
+        }
+    }
+}
+
+impl From<&PathData> for u32 {
+    fn from(path_data: &PathData) -> Self {
+        match path_data {
+            PathData::FormatAsU8(data) => *data as u32,
+            PathData::FormatAsU16(data) => *data as u32,
+            PathData::FormatAsU32(data) => *data,
         }
     }
 }
 
 #[binrw]
 #[brw(little)]
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Clone)]
 pub struct LogicalPathSegment {
     pub path_definition: LogicalPathDefinition,
 
-    #[br(if (path_definition.logical_segment_format() == LogicalSegmentFormat::FormatAsU16))]
+    // Both the 16-bit and 32-bit formats are padded to keep the value word aligned
+    #[br(if (path_definition.logical_segment_format() != LogicalSegmentFormat::FormatAsU8))]
     pub u16_padding: Option<u8>,
 
     #[br(args(path_definition.logical_segment_format(),))]
@@ -128,19 +150,53 @@ impl LogicalPathSegment {
             data: PathData::FormatAsU16(data),
         }
     }
+
+    pub fn new_u32(logical_segment_type: LogicalSegmentType, data: u32) -> Self {
+        LogicalPathSegment {
+            path_definition: LogicalPathDefinition::new(
+                LogicalSegmentFormat::FormatAsU32,
+                logical_segment_type,
+                SegmentType::LogicalSegment,
+            ),
+            u16_padding: Some(0x0),
+            data: PathData::FormatAsU32(data),
+        }
+    }
+
+    /// The number of bytes the segment takes up in a path
+    pub fn byte_size(&self) -> usize {
+        match self.data {
+            PathData::FormatAsU8(_) => 2,
+            PathData::FormatAsU16(_) => 4,
+            PathData::FormatAsU32(_) => 6,
+        }
+    }
+
+    /// Creates a member (element) segment using the smallest format that fits the index
+    pub fn new_element(index: u32) -> Self {
+        match index {
+            0..=0xFF => Self::new_u8(LogicalSegmentType::MemberId, index as u8),
+            0x100..=0xFFFF => Self::new_u16(LogicalSegmentType::MemberId, index as u16),
+            _ => Self::new_u32(LogicalSegmentType::MemberId, index),
+        }
+    }
 }
 
 // ^^^^^^^^ End of CipPath impl ^^^^^^^^
 
 #[binrw]
 #[brw(little)]
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Clone)]
 #[br(import(path_length: u8))]
 pub struct CipPath {
     pub class_id_segment: LogicalPathSegment,
     pub instance_id_segment: LogicalPathSegment,
 
-    #[br(if(path_length == 3))]
+    // The attribute is only present if the path is longer than the class and instance segments
+    #[br(if(
+        path_length as usize * BYTES_IN_A_WORD as usize
+            > class_id_segment.byte_size() + instance_id_segment.byte_size()
+    ))]
     pub attribute_id_segment: Option<LogicalPathSegment>,
 }
 
@@ -158,6 +214,22 @@ impl CipPath {
         }
     }
 
+    /// The path to the class itself, i.e. instance 0, which holds the class attributes
+    pub fn new_class(class_id: u16) -> Self {
+        Self::new(class_id, 0)
+    }
+
+    /// Adds an attribute segment, using the smallest format that fits the attribute ID
+    pub fn with_attribute(mut self, attribute_id: u16) -> Self {
+        self.attribute_id_segment = Some(match u8::try_from(attribute_id) {
+            Ok(attribute_id) => {
+                LogicalPathSegment::new_u8(LogicalSegmentType::AttributeId, attribute_id)
+            }
+            Err(_) => LogicalPathSegment::new_u16(LogicalSegmentType::AttributeId, attribute_id),
+        });
+        self
+    }
+
     pub fn new_full(class_id: u8, instance_id: u8, attribute_id: u8) -> Self {
         CipPath {
             class_id_segment: LogicalPathSegment::new_u8(LogicalSegmentType::ClassId, class_id),
@@ -175,6 +247,381 @@ impl CipPath {
 
 // ^^^^^^^^ End of CipPath impl ^^^^^^^^
 
+#[binrw]
+#[brw(little, magic = 0x91u8)]
+#[derive(Debug, PartialEq, Clone)]
+pub struct SymbolicSegment {
+    #[br(temp)]
+    #[bw(calc = symbol.len() as CipUsint)]
+    symbol_length: CipUsint,
+
+    #[br(count = symbol_length)]
+    pub symbol: Vec<CipUsint>,
+
+    // The symbol is padded with a single byte whenever its length is odd
+    #[br(temp, if(symbol_length % 2 == 1))]
+    #[bw(calc = (symbol.len() % 2 == 1).then_some(0))]
+    pad_byte: Option<CipUsint>,
+}
+
+// ======= Start of SymbolicSegment impl ========
+
+impl SymbolicSegment {
+    pub fn new(symbol: &str) -> Self {
+        SymbolicSegment {
+            symbol: symbol.as_bytes().to_vec(),
+        }
+    }
+}
+
+// ^^^^^^^^ End of SymbolicSegment impl ^^^^^^^^
+
+#[binrw]
+#[brw(little)]
+#[derive(Debug, PartialEq, Clone)]
+pub enum SymbolicPathSegment {
+    Symbol(SymbolicSegment),
+
+    Element(
+        #[br(assert(self_0.path_definition.logical_segment_type() == LogicalSegmentType::MemberId))]
+        LogicalPathSegment,
+    ),
+
+    // Class and instance segments following a symbol, e.g. to address a program's symbol table
+    Logical(LogicalPathSegment),
+}
+
+#[binrw::parser(reader, endian)]
+fn parse_symbolic_segments(path_length: u8) -> BinResult<Vec<SymbolicPathSegment>> {
+    let path_end = reader.stream_position()? + (path_length as u64 * BYTES_IN_A_WORD as u64);
+
+    let mut segments = Vec::new();
+    while reader.stream_position()? < path_end {
+        segments.push(SymbolicPathSegment::read_options(reader, endian, ())?);
+    }
+
+    Ok(segments)
+}
+
+/// A path made of ANSI extended symbol segments, i.e. the path to a Logix tag
+#[binrw]
+#[brw(little)]
+#[derive(Debug, PartialEq, Clone)]
+#[br(import(path_length: u8))]
+pub struct SymbolicPath {
+    #[br(
+        parse_with = parse_symbolic_segments,
+        args(path_length),
+        assert(matches!(segments.first(), Some(SymbolicPathSegment::Symbol(_))))
+    )]
+    pub segments: Vec<SymbolicPathSegment>,
+}
+
+// ======= Start of SymbolicPath impl ========
+
+impl SymbolicPath {
+    /// Parses a tag name such as `Program:MainProgram.Recipes[3].Speed` into its path segments
+    pub fn from_tag_name(tag_name: &str) -> BinResult<Self> {
+        let invalid_tag_name = || binrw::Error::Custom {
+            pos: 0,
+            err: Box::new(format!("Invalid tag name: {:?}", tag_name)),
+        };
+
+        let mut segments = Vec::new();
+
+        for member in tag_name.split('.') {
+            let (symbol, indices) = match member.split_once('[') {
+                None => (member, None),
+                Some((symbol, indices)) => (symbol, Some(indices)),
+            };
+
+            if symbol.is_empty() {
+                return Err(invalid_tag_name());
+            }
+            segments.push(SymbolicPathSegment::Symbol(SymbolicSegment::new(symbol)));
+
+            let Some(indices) = indices else {
+                continue;
+            };
+
+            // Multi-dimensional arrays are addressed as `Tag[1,2]`
+            let indices = indices.strip_suffix(']').ok_or_else(invalid_tag_name)?;
+            for index in indices.split(',') {
+                let index = index.trim().parse::<u32>().map_err(|_| invalid_tag_name())?;
+                segments.push(SymbolicPathSegment::Element(
+                    LogicalPathSegment::new_element(index),
+                ));
+            }
+        }
+
+        Ok(SymbolicPath { segments })
+    }
+}
+
+// ^^^^^^^^ End of SymbolicPath impl ^^^^^^^^
+
+/// The path of a Message Router request, which addresses either an object or a symbol
+#[binrw]
+#[brw(little)]
+#[derive(Debug, PartialEq, Clone)]
+#[br(import(path_length: u8))]
+pub enum RequestPath {
+    Symbolic(#[br(args(path_length))] SymbolicPath),
+    Logical(#[br(args(path_length))] CipPath),
+}
+
+// ======= Start of RequestPath impl ========
+
+impl From<CipPath> for RequestPath {
+    fn from(path: CipPath) -> Self {
+        RequestPath::Logical(path)
+    }
+}
+
+impl From<SymbolicPath> for RequestPath {
+    fn from(path: SymbolicPath) -> Self {
+        RequestPath::Symbolic(path)
+    }
+}
+
+// ^^^^^^^^ End of RequestPath impl ^^^^^^^^
+
+/// Port number used by ControlLogix and CompactLogix chassis for their backplane
+pub const BACKPLANE_PORT: u16 = 0x01;
+
+// Bit layout of the first byte of a port segment
+const PORT_SEGMENT_EXTENDED_LINK_ADDRESS: u8 = 0x10;
+const PORT_SEGMENT_PORT_MASK: u8 = 0x0F;
+const PORT_SEGMENT_EXTENDED_PORT: u8 = 0x0F;
+
+/// A port segment, which routes a message out of a port to the node at the link address
+///
+/// The link address is a single byte (e.g. a slot number) or an extended address such as the IP
+/// address of a device behind an Ethernet bridge.
+#[derive(Debug, PartialEq, Clone)]
+pub struct PortSegment {
+    pub port: u16,
+    pub link_address: Vec<u8>,
+}
+
+// ======= Start of PortSegment impl ========
+
+impl BinWrite for PortSegment {
+    type Args<'a> = ();
+
+    fn write_options<W: std::io::Write + std::io::Seek>(
+        &self,
+        writer: &mut W,
+        endian: binrw::Endian,
+        _args: Self::Args<'_>,
+    ) -> BinResult<()> {
+        let mut segment_header = if self.has_extended_port() {
+            PORT_SEGMENT_EXTENDED_PORT
+        } else {
+            self.port as u8
+        };
+        if self.has_extended_link_address() {
+            segment_header |= PORT_SEGMENT_EXTENDED_LINK_ADDRESS;
+        }
+        segment_header.write_options(writer, endian, ())?;
+
+        if self.has_extended_link_address() {
+            (self.link_address.len() as CipUsint).write_options(writer, endian, ())?;
+        }
+
+        if self.has_extended_port() {
+            self.port.write_options(writer, endian, ())?;
+        }
+
+        writer.write_all(&self.link_address)?;
+
+        if self.unpadded_byte_size() % 2 == 1 {
+            writer.write_all(&[0])?;
+        }
+
+        Ok(())
+    }
+}
+
+impl BinRead for PortSegment {
+    type Args<'a> = ();
+
+    fn read_options<R: std::io::Read + std::io::Seek>(
+        reader: &mut R,
+        endian: binrw::Endian,
+        _args: Self::Args<'_>,
+    ) -> BinResult<Self> {
+        let segment_position = reader.stream_position()?;
+        let segment_header = CipUsint::read_options(reader, endian, ())?;
+
+        if SegmentType::from(u3::new(segment_header >> 5)) != SegmentType::PortSegment
+        {
+            return Err(binrw::Error::AssertFail {
+                pos: segment_position,
+                message: format!("0x{:02X} is not a port segment", segment_header),
+            });
+        }
+
+        let link_address_size = match segment_header & PORT_SEGMENT_EXTENDED_LINK_ADDRESS {
+            0 => 1,
+            _ => CipUsint::read_options(reader, endian, ())?,
+        };
+
+        let port = match segment_header & PORT_SEGMENT_PORT_MASK {
+            PORT_SEGMENT_EXTENDED_PORT => u16::read_options(reader, endian, ())?,
+            port => port as u16,
+        };
+
+        let mut link_address = vec![0u8; link_address_size as usize];
+        reader.read_exact(&mut link_address)?;
+
+        let port_segment = PortSegment { port, link_address };
+        if port_segment.unpadded_byte_size() % 2 == 1 {
+            CipUsint::read_options(reader, endian, ())?;
+        }
+
+        Ok(port_segment)
+    }
+}
+
+impl PortSegment {
+    /// Routes to the node in the given slot (or node address) on the port
+    pub fn new_slot(port: u16, slot: u8) -> Self {
+        PortSegment {
+            port,
+            link_address: vec![slot],
+        }
+    }
+
+    /// Routes to the node with the given text address on the port, e.g. `"192.168.1.10"`
+    pub fn new_address(port: u16, address: &str) -> Self {
+        PortSegment {
+            port,
+            link_address: address.as_bytes().to_vec(),
+        }
+    }
+
+    fn has_extended_port(&self) -> bool {
+        self.port >= PORT_SEGMENT_EXTENDED_PORT as u16
+    }
+
+    fn has_extended_link_address(&self) -> bool {
+        self.link_address.len() != 1
+    }
+
+    fn unpadded_byte_size(&self) -> usize {
+        1 + usize::from(self.has_extended_link_address())
+            + if self.has_extended_port() { 2 } else { 0 }
+            + self.link_address.len()
+    }
+
+    /// The number of bytes the segment takes up in a path, including the pad byte
+    pub fn byte_size(&self) -> usize {
+        self.unpadded_byte_size().next_multiple_of(2)
+    }
+}
+
+// ^^^^^^^^ End of PortSegment impl ^^^^^^^^
+
+#[binrw::parser(reader, endian)]
+fn parse_port_segments(path_length: u8) -> BinResult<Vec<PortSegment>> {
+    let path_end = reader.stream_position()? + (path_length as u64 * BYTES_IN_A_WORD as u64);
+
+    let mut segments = Vec::new();
+    while reader.stream_position()? < path_end {
+        segments.push(PortSegment::read_options(reader, endian, ())?);
+    }
+
+    Ok(segments)
+}
+
+/// The route a message takes through backplanes and bridges to reach its target
+#[binrw]
+#[brw(little)]
+#[derive(Debug, PartialEq, Clone, Default)]
+#[br(import(path_length: u8))]
+pub struct RoutePath {
+    #[br(parse_with = parse_port_segments, args(path_length))]
+    pub segments: Vec<PortSegment>,
+}
+
+// ======= Start of RoutePath impl ========
+
+impl RoutePath {
+    pub fn new(segments: Vec<PortSegment>) -> Self {
+        RoutePath { segments }
+    }
+
+    /// Routes to the module in the given slot of the local chassis
+    pub fn new_backplane_slot(slot: u8) -> Self {
+        RoutePath::new(vec![PortSegment::new_slot(BACKPLANE_PORT, slot)])
+    }
+
+    /// Parses a route such as `"backplane 1, slot 3"` or `"1,3,2,192.168.1.10"`
+    ///
+    /// The comma separated entries alternate between a port and a link address. A port is a number,
+    /// optionally preceded by `port`, or `backplane` for port 1. A link address is a number,
+    /// optionally preceded by `slot` or `node`, or any other text address such as an IP address.
+    pub fn from_route(route: &str) -> BinResult<Self> {
+        let invalid_route = |reason: &str| binrw::Error::Custom {
+            pos: 0,
+            err: Box::new(format!("Invalid route {:?}: {}", route, reason)),
+        };
+
+        let entries: Vec<&str> = route
+            .split(',')
+            .map(str::trim)
+            .filter(|entry| !entry.is_empty())
+            .collect();
+
+        if entries.len() % 2 == 1 {
+            return Err(invalid_route("every port needs a link address"));
+        }
+
+        let mut segments = Vec::new();
+        for hop in entries.chunks_exact(2) {
+            let port_entry = hop[0].to_ascii_lowercase();
+            let port = match port_entry.split_whitespace().collect::<Vec<_>>()[..] {
+                ["backplane"] => BACKPLANE_PORT,
+                [port] | ["port" | "backplane", port] => port
+                    .parse::<u16>()
+                    .map_err(|_| invalid_route("the port must be a number"))?,
+                _ => return Err(invalid_route("unrecognized port")),
+            };
+
+            let link_entry = hop[1].to_ascii_lowercase();
+            let link_address = match link_entry.split_whitespace().collect::<Vec<_>>()[..] {
+                ["slot" | "node", slot] => slot
+                    .parse::<u8>()
+                    .map_err(|_| invalid_route("the slot must be a number up to 255"))?,
+                [address] => match address.parse::<u8>() {
+                    Ok(slot) => slot,
+                    Err(_) => {
+                        segments.push(PortSegment::new_address(port, hop[1]));
+                        continue;
+                    }
+                },
+                _ => return Err(invalid_route("unrecognized link address")),
+            };
+
+            segments.push(PortSegment::new_slot(port, link_address));
+        }
+
+        Ok(RoutePath::new(segments))
+    }
+
+    /// The number of 16-bit words the route takes up
+    pub fn word_size(&self) -> usize {
+        self.segments
+            .iter()
+            .map(PortSegment::byte_size)
+            .sum::<usize>()
+            / BYTES_IN_A_WORD as usize
+    }
+}
+
+// ^^^^^^^^ End of RoutePath impl ^^^^^^^^
+
 
 #[cfg(test)]
 mod tests {
@@ -0,0 +1,622 @@
+use std::collections::VecDeque;
+use std::ops::RangeInclusive;
+use std::time::Duration;
+
+use binrw::{
+    binrw, // #[binrw] attribute
+    helpers::until_eof,
+    BinRead,
+    BinResult,
+};
+
+use crate::cip::connection_manager::{ConnectionManagerServiceCode, UnconnectedSend};
+use crate::cip::identity::{identity_attribute_request, IdentityAttributeId};
+use crate::cip::message::request::MessageRouterRequest;
+use crate::cip::message::response::{MessageRouterResponse, ResponseStatusCode};
+use crate::cip::message::shared::ServiceCode;
+use crate::cip::path::{CipPath, PortSegment, RoutePath};
+use crate::cip::tcp_ip_interface::PhysicalLinkObject;
+use crate::cip::types::{CipByte, CipShortString, CipUint};
+
+/// The Port object, which describes the ports a device routes messages through
+pub const PORT_CLASS_ID: u16 = 0xF4;
+
+/// The slots probed on a backplane that does not report its node range
+pub const DEFAULT_MAX_SLOT: u8 = 16;
+
+/// The deepest route the discovery follows, counted in port segments
+pub const MAX_ROUTE_HOPS: usize = 4;
+
+/*
+Port class attributes
+    Attribute: 8 (Entry Port)
+    Attribute: 9 (Port Instance Info)
+*/
+#[derive(Debug, PartialEq, Clone, Copy)]
+#[repr(u16)]
+pub enum PortClassAttributeId {
+    EntryPort = 0x08,
+    PortInstanceInfo = 0x09,
+}
+
+/*
+Port instance attributes
+    Attribute: 1 (Port Type)
+    Attribute: 2 (Port Number)
+    Attribute: 3 (Link Object)
+    Attribute: 4 (Port Name)
+    Attribute: 5 (Port Type Name)
+    Attribute: 6 (Port Description)
+    Attribute: 7 (Node Address)
+    Attribute: 8 (Port Node Range)
+    Attribute: 9 (Port Key)
+*/
+#[derive(Debug, PartialEq, Clone, Copy)]
+#[repr(u16)]
+pub enum PortAttributeId {
+    PortType = 0x01,
+    PortNumber = 0x02,
+    LinkObject = 0x03,
+    PortName = 0x04,
+    PortTypeName = 0x05,
+    PortDescription = 0x06,
+    NodeAddress = 0x07,
+    PortNodeRange = 0x08,
+    PortKey = 0x09,
+}
+
+/// The kind of network or backplane a port connects to
+#[binrw]
+#[brw(little)]
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum PortType {
+    /// Connections end at the device, e.g. the unused entry of the port instance info
+    #[brw(magic = 0x0000u16)]
+    ConnectionTerminates,
+    #[brw(magic = 0x0001u16)]
+    Backplane,
+    #[brw(magic = 0x0002u16)]
+    ControlNet,
+    #[brw(magic = 0x0003u16)]
+    ControlNetRedundant,
+    #[brw(magic = 0x0004u16)]
+    EtherNetIp,
+    #[brw(magic = 0x0005u16)]
+    DeviceNet,
+    #[brw(magic = 0x00C8u16)]
+    CompoNet,
+    #[brw(magic = 0x00C9u16)]
+    ModbusTcp,
+    #[brw(magic = 0x00CAu16)]
+    ModbusSerial,
+    #[brw(magic = 0xFFFFu16)]
+    NotConfigured,
+    Other(u16),
+}
+
+/// The type and number of a port instance, as listed in the port instance info
+#[binrw]
+#[brw(little)]
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct PortInstanceEntry {
+    pub port_type: PortType,
+    pub port_number: CipUint,
+}
+
+/// The type and number of every port instance, indexed by instance
+///
+/// The entry of instance 0 and those of missing instances are zero. The entries take up the rest of
+/// the reply, there is no count.
+#[binrw]
+#[brw(little)]
+#[derive(Debug, PartialEq, Clone)]
+pub struct PortInstanceInfo {
+    #[br(parse_with = until_eof)]
+    pub entries: Vec<PortInstanceEntry>,
+}
+
+// ======= Start of PortInstanceInfo impl ========
+
+impl PortInstanceInfo {
+    /// The instance id and entry of every port the device has
+    pub fn ports(&self) -> Vec<(CipUint, PortInstanceEntry)> {
+        self.entries
+            .iter()
+            .enumerate()
+            .filter(|(_, entry)| entry.port_number != 0)
+            .map(|(instance_id, entry)| (instance_id as CipUint, *entry))
+            .collect()
+    }
+}
+
+// ^^^^^^^^ End of PortInstanceInfo impl ^^^^^^^^
+
+/// The address of the device on the network of a port, as a padded port segment
+#[binrw]
+#[brw(little)]
+#[derive(Debug, PartialEq, Clone)]
+pub struct NodeAddress {
+    #[br(temp)]
+    #[bw(calc = (path.len() / 2) as CipUint)]
+    path_size: CipUint,
+
+    #[br(count = path_size as usize * 2)]
+    pub path: Vec<CipByte>,
+}
+
+// ======= Start of NodeAddress impl ========
+
+impl NodeAddress {
+    /// The port segment holding the address, if the path holds one
+    pub fn port_segment(&self) -> Option<PortSegment> {
+        let mut path_reader = std::io::Cursor::new(&self.path);
+        PortSegment::read_le(&mut path_reader).ok()
+    }
+}
+
+// ^^^^^^^^ End of NodeAddress impl ^^^^^^^^
+
+/// The lowest and highest node address (e.g. slot) on the network of a port
+#[binrw]
+#[brw(little)]
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct PortNodeRange {
+    pub minimum: CipUint,
+    pub maximum: CipUint,
+}
+
+fn port_path(instance_id: CipUint) -> CipPath {
+    CipPath::new(PORT_CLASS_ID, instance_id)
+}
+
+/// A request reading a single attribute of the Port class
+pub fn port_class_attribute_request(attribute_id: PortClassAttributeId) -> MessageRouterRequest {
+    MessageRouterRequest::new(
+        ServiceCode::GetAttributeSingle,
+        CipPath::new_class(PORT_CLASS_ID).with_attribute(attribute_id as u16),
+    )
+}
+
+/// A request reading a single attribute of a Port instance
+pub fn port_attribute_request(
+    instance_id: CipUint,
+    attribute_id: PortAttributeId,
+) -> MessageRouterRequest {
+    MessageRouterRequest::new(
+        ServiceCode::GetAttributeSingle,
+        port_path(instance_id).with_attribute(attribute_id as u16),
+    )
+}
+
+/// A port of a device, each attribute left as `None` if the device does not support it
+#[derive(Debug, PartialEq, Clone)]
+pub struct PortInfo {
+    pub instance_id: CipUint,
+    pub port_type: Option<PortType>,
+    pub port_number: Option<CipUint>,
+    /// The object that manages the link, e.g. a TCP/IP Interface instance
+    pub link_object: Option<PhysicalLinkObject>,
+    pub name: Option<String>,
+    pub type_name: Option<String>,
+    pub node_address: Option<PortSegment>,
+    pub node_range: Option<PortNodeRange>,
+}
+
+// ======= Start of PortInfo impl ========
+
+impl PortInfo {
+    pub fn new(instance_id: CipUint) -> Self {
+        PortInfo {
+            instance_id,
+            port_type: None,
+            port_number: None,
+            link_object: None,
+            name: None,
+            type_name: None,
+            node_address: None,
+            node_range: None,
+        }
+    }
+
+    /// The number used in port segments, which is the instance id unless the device says otherwise
+    pub fn number(&self) -> CipUint {
+        self.port_number.unwrap_or(self.instance_id)
+    }
+
+    /// The node addresses (e.g. slots) that can be reached through the port
+    pub fn node_addresses(&self) -> RangeInclusive<u8> {
+        match self.node_range {
+            Some(range) => {
+                (range.minimum.min(u8::MAX as CipUint) as u8)
+                    ..=(range.maximum.min(u8::MAX as CipUint) as u8)
+            }
+            None => 0..=DEFAULT_MAX_SLOT,
+        }
+    }
+}
+
+// ^^^^^^^^ End of PortInfo impl ^^^^^^^^
+
+const PORT_ATTRIBUTES: [PortAttributeId; 7] = [
+    PortAttributeId::PortType,
+    PortAttributeId::PortNumber,
+    PortAttributeId::LinkObject,
+    PortAttributeId::PortName,
+    PortAttributeId::PortTypeName,
+    PortAttributeId::NodeAddress,
+    PortAttributeId::PortNodeRange,
+];
+
+/// Reads the attributes of a Port instance one Get Attribute Single at a time
+#[derive(Debug)]
+pub struct PortRead {
+    next_attribute: usize,
+    port: PortInfo,
+}
+
+// ======= Start of PortRead impl ========
+
+impl PortRead {
+    pub fn new(instance_id: CipUint) -> Self {
+        PortRead {
+            next_attribute: 0,
+            port: PortInfo::new(instance_id),
+        }
+    }
+
+    pub fn is_complete(&self) -> bool {
+        self.next_attribute >= PORT_ATTRIBUTES.len()
+    }
+
+    pub fn next_request(&self) -> Option<MessageRouterRequest> {
+        PORT_ATTRIBUTES
+            .get(self.next_attribute)
+            .map(|attribute_id| port_attribute_request(self.port.instance_id, *attribute_id))
+    }
+
+    pub fn handle_response(&mut self, response: &MessageRouterResponse) -> BinResult<()> {
+        let Some(attribute_id) = PORT_ATTRIBUTES.get(self.next_attribute).copied() else {
+            return Ok(());
+        };
+        self.next_attribute += 1;
+
+        // Without the instance there is nothing else to read
+        if response.response_data.is_object_missing() {
+            self.next_attribute = PORT_ATTRIBUTES.len();
+            return Ok(());
+        }
+        if response.response_data.is_attribute_unsupported() {
+            return Ok(());
+        }
+        response.check_status()?;
+
+        let reply = &response.response_data;
+        match attribute_id {
+            PortAttributeId::PortType => self.port.port_type = Some(reply.read_reply()?),
+            PortAttributeId::PortNumber => self.port.port_number = Some(reply.read_reply()?),
+            PortAttributeId::LinkObject => self.port.link_object = Some(reply.read_reply()?),
+            PortAttributeId::PortName => {
+                self.port.name = Some(reply.read_reply::<CipShortString>()?.into())
+            }
+            PortAttributeId::PortTypeName => {
+                self.port.type_name = Some(reply.read_reply::<CipShortString>()?.into())
+            }
+            PortAttributeId::NodeAddress => {
+                self.port.node_address = reply.read_reply::<NodeAddress>()?.port_segment()
+            }
+            PortAttributeId::PortNodeRange => self.port.node_range = Some(reply.read_reply()?),
+            _ => (),
+        }
+
+        Ok(())
+    }
+
+    /// The port read so far
+    pub fn port(&self) -> &PortInfo {
+        &self.port
+    }
+
+    /// The port, once the read is complete
+    pub fn into_port(self) -> Option<PortInfo> {
+        self.is_complete().then_some(self.port)
+    }
+}
+
+// ^^^^^^^^ End of PortRead impl ^^^^^^^^
+
+/// A device that answered at the end of a route
+#[derive(Debug, PartialEq, Clone)]
+pub struct RoutedDevice {
+    /// The route to pass to `UnconnectedSend::new()`, empty for the device we talk to
+    pub route: RoutePath,
+    pub product_name: Option<String>,
+}
+
+/// A network reached through a port of a routed device, e.g. the Ethernet port of a bridge module
+#[derive(Debug, PartialEq, Clone)]
+pub struct BridgedNetwork {
+    /// The route to the device that owns the port
+    pub route: RoutePath,
+    pub port: PortInfo,
+}
+
+// ======= Start of BridgedNetwork impl ========
+
+impl BridgedNetwork {
+    /// The route to the node with the given number on the network, e.g. a DeviceNet MAC ID
+    pub fn route_to_node(&self, node: u8) -> RoutePath {
+        self.route_through(PortSegment::new_slot(self.port.number(), node))
+    }
+
+    /// The route to the node with the given text address on the network, e.g. an IP address
+    pub fn route_to_address(&self, address: &str) -> RoutePath {
+        self.route_through(PortSegment::new_address(self.port.number(), address))
+    }
+
+    fn route_through(&self, segment: PortSegment) -> RoutePath {
+        let mut route = self.route.clone();
+        route.segments.push(segment);
+        route
+    }
+}
+
+// ^^^^^^^^ End of BridgedNetwork impl ^^^^^^^^
+
+/// Everything found by a `RouteDiscovery`
+#[derive(Debug, PartialEq, Clone, Default)]
+pub struct DiscoveredRoutes {
+    /// The device we talk to followed by every module found in a backplane slot
+    pub devices: Vec<RoutedDevice>,
+    pub networks: Vec<BridgedNetwork>,
+}
+
+#[derive(Debug, Clone)]
+struct PendingDevice {
+    route: RoutePath,
+    /// The type of the port the route ends on, used when the device does not report its entry port
+    arrived_over: Option<PortType>,
+}
+
+#[derive(Debug)]
+enum DiscoveryStep {
+    ReadProductName,
+    ReadPortInstances,
+    ReadEntryPort(Vec<CipUint>),
+    ReadPort {
+        entry_port: Option<CipUint>,
+        remaining_instances: Vec<CipUint>,
+        port_read: PortRead,
+    },
+    Complete,
+}
+
+/// Whether the response is an Unconnected Send failing to reach the end of the route
+fn is_routing_error(response: &MessageRouterResponse) -> bool {
+    response.service_container.service()
+        == ServiceCode::from(ConnectionManagerServiceCode::UnconnectedSend)
+        && response.response_data.status != ResponseStatusCode::Success
+}
+
+/// Walks from the device we talk to through its ports to find the modules and networks behind it
+///
+/// Every device is asked for its product name and ports. The slots of each backplane port are
+/// probed with Unconnected Sends, and the modules that answer are walked in turn. The other ports,
+/// except the one a request entered through, are listed as bridged networks. Devices without a Port
+/// object and slots that cannot be reached are left out instead of failing the discovery.
+#[derive(Debug)]
+pub struct RouteDiscovery {
+    step: DiscoveryStep,
+    device: PendingDevice,
+    pending_devices: VecDeque<PendingDevice>,
+    timeout: Option<Duration>,
+    routes: DiscoveredRoutes,
+}
+
+// ======= Start of RouteDiscovery impl ========
+
+impl RouteDiscovery {
+    pub fn new() -> Self {
+        RouteDiscovery {
+            step: DiscoveryStep::ReadProductName,
+            device: PendingDevice {
+                route: RoutePath::default(),
+                arrived_over: None,
+            },
+            pending_devices: VecDeque::new(),
+            timeout: None,
+            routes: DiscoveredRoutes::default(),
+        }
+    }
+
+    /// Routed requests give up after the timeout, which bounds the time spent on empty slots
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    pub fn is_complete(&self) -> bool {
+        matches!(self.step, DiscoveryStep::Complete)
+    }
+
+    pub fn next_request(&self) -> Option<MessageRouterRequest> {
+        let request = match &self.step {
+            DiscoveryStep::ReadProductName => {
+                identity_attribute_request(IdentityAttributeId::ProductName)
+            }
+            DiscoveryStep::ReadPortInstances => {
+                port_class_attribute_request(PortClassAttributeId::PortInstanceInfo)
+            }
+            DiscoveryStep::ReadEntryPort(_) => {
+                port_class_attribute_request(PortClassAttributeId::EntryPort)
+            }
+            DiscoveryStep::ReadPort { port_read, .. } => port_read.next_request()?,
+            DiscoveryStep::Complete => return None,
+        };
+
+        if self.device.route.segments.is_empty() {
+            return Some(request);
+        }
+
+        let mut unconnected_send = UnconnectedSend::new(self.device.route.clone());
+        if let Some(timeout) = self.timeout {
+            unconnected_send = unconnected_send.with_timeout(timeout);
+        }
+        Some(unconnected_send.wrap(request))
+    }
+
+    /// Moves on to the next device waiting to be walked
+    fn next_device_step(&mut self) -> DiscoveryStep {
+        match self.pending_devices.pop_front() {
+            Some(device) => {
+                self.device = device;
+                DiscoveryStep::ReadProductName
+            }
+            None => DiscoveryStep::Complete,
+        }
+    }
+
+    /// Moves on to the next port of the current device
+    fn next_port_step(
+        &mut self,
+        entry_port: Option<CipUint>,
+        mut remaining_instances: Vec<CipUint>,
+    ) -> DiscoveryStep {
+        match remaining_instances.pop() {
+            Some(instance_id) => DiscoveryStep::ReadPort {
+                entry_port,
+                remaining_instances,
+                port_read: PortRead::new(instance_id),
+            },
+            None => self.next_device_step(),
+        }
+    }
+
+    /// Queues the slots of a backplane port, or lists the network of any other port
+    fn add_port(&mut self, port: PortInfo, entry_port: Option<CipUint>) {
+        let Some(port_type) = port.port_type else {
+            return;
+        };
+
+        let is_entry_port = match entry_port {
+            Some(entry_port) => port.instance_id == entry_port,
+            None => self.device.arrived_over == Some(port_type),
+        };
+        if is_entry_port {
+            return;
+        }
+
+        match port_type {
+            PortType::Backplane => {
+                if self.device.route.segments.len() >= MAX_ROUTE_HOPS {
+                    return;
+                }
+
+                for slot in port.node_addresses() {
+                    let mut route = self.device.route.clone();
+                    route
+                        .segments
+                        .push(PortSegment::new_slot(port.number(), slot));
+                    self.pending_devices.push_back(PendingDevice {
+                        route,
+                        arrived_over: Some(port_type),
+                    });
+                }
+            }
+            PortType::ConnectionTerminates | PortType::NotConfigured => (),
+            _ => self.routes.networks.push(BridgedNetwork {
+                route: self.device.route.clone(),
+                port,
+            }),
+        }
+    }
+
+    pub fn handle_response(&mut self, response: &MessageRouterResponse) -> BinResult<()> {
+        if self.is_complete() {
+            return Ok(());
+        }
+
+        // An empty slot, or a device that stops answering, ends the walk of that device
+        if is_routing_error(response) {
+            self.step = self.next_device_step();
+            return Ok(());
+        }
+
+        if let DiscoveryStep::ReadPort { port_read, .. } = &mut self.step {
+            port_read.handle_response(response)?;
+            if !port_read.is_complete() {
+                return Ok(());
+            }
+        }
+
+        let reply = &response.response_data;
+        self.step = match std::mem::replace(&mut self.step, DiscoveryStep::Complete) {
+            DiscoveryStep::ReadProductName => {
+                // Any reply from the target shows there is a device at the end of the route
+                let product_name = match response.check_status() {
+                    Ok(()) => Some(reply.read_reply::<CipShortString>()?.into()),
+                    Err(_) => None,
+                };
+                self.routes.devices.push(RoutedDevice {
+                    route: self.device.route.clone(),
+                    product_name,
+                });
+                DiscoveryStep::ReadPortInstances
+            }
+            DiscoveryStep::ReadPortInstances => match response.check_status() {
+                Ok(()) => {
+                    let port_instances: PortInstanceInfo = reply.read_reply()?;
+
+                    // Ports are popped off the end, so keep them in reverse to read them in order
+                    DiscoveryStep::ReadEntryPort(
+                        port_instances
+                            .ports()
+                            .into_iter()
+                            .map(|(instance_id, _)| instance_id)
+                            .rev()
+                            .collect(),
+                    )
+                }
+                // Without a Port object the device is a dead end
+                Err(_) => self.next_device_step(),
+            },
+            DiscoveryStep::ReadEntryPort(instance_ids) => {
+                let entry_port = match response.check_status() {
+                    Ok(()) => Some(reply.read_reply()?),
+                    Err(_) => None,
+                };
+                self.next_port_step(entry_port, instance_ids)
+            }
+            DiscoveryStep::ReadPort {
+                entry_port,
+                remaining_instances,
+                port_read,
+            } => {
+                if let Some(port) = port_read.into_port() {
+                    self.add_port(port, entry_port);
+                }
+                self.next_port_step(entry_port, remaining_instances)
+            }
+            DiscoveryStep::Complete => DiscoveryStep::Complete,
+        };
+
+        Ok(())
+    }
+
+    /// The devices and networks found so far
+    pub fn routes(&self) -> &DiscoveredRoutes {
+        &self.routes
+    }
+
+    /// The devices and networks, once the discovery is complete
+    pub fn into_routes(self) -> Option<DiscoveredRoutes> {
+        self.is_complete().then_some(self.routes)
+    }
+}
+
+impl Default for RouteDiscovery {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// ^^^^^^^^ End of RouteDiscovery impl ^^^^^^^^
@@ -0,0 +1,251 @@
+use binrw::BinResult;
+
+use crate::cip::message::request::MessageRouterRequest;
+use crate::cip::message::response::MessageRouterResponse;
+use crate::cip::message::shared::ServiceCode;
+use crate::cip::path::CipPath;
+use crate::cip::types::{CipBool, CipUsint};
+
+/// The QoS object, which sets the 802.1Q priority tagging and DSCP marking of the traffic classes
+pub const QOS_CLASS_ID: u16 = 0x48;
+
+/*
+QoS instance attributes (all settable)
+    Attribute: 1 (802.1Q Tag Enable)
+    Attribute: 2 (DSCP PTP Event)
+    Attribute: 3 (DSCP PTP General)
+    Attribute: 4 (DSCP Urgent)
+    Attribute: 5 (DSCP Scheduled)
+    Attribute: 6 (DSCP High)
+    Attribute: 7 (DSCP Low)
+    Attribute: 8 (DSCP Explicit)
+*/
+#[derive(Debug, PartialEq, Clone, Copy)]
+#[repr(u16)]
+pub enum QosAttributeId {
+    TagEnable = 0x01,
+    DscpPtpEvent = 0x02,
+    DscpPtpGeneral = 0x03,
+    DscpUrgent = 0x04,
+    DscpScheduled = 0x05,
+    DscpHigh = 0x06,
+    DscpLow = 0x07,
+    DscpExplicit = 0x08,
+}
+
+/// The classes of traffic that are marked with their own DSCP value
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+pub enum TrafficClass {
+    PtpEvent,
+    PtpGeneral,
+    /// I/O connections with urgent priority, e.g. CIP Motion
+    Urgent,
+    /// I/O connections with scheduled priority
+    Scheduled,
+    /// I/O connections with high priority
+    High,
+    /// I/O connections with low priority
+    Low,
+    /// Explicit messages, including those over class 3 connections
+    Explicit,
+}
+
+// ======= Start of TrafficClass impl ========
+
+impl TrafficClass {
+    pub const ALL: [TrafficClass; 7] = [
+        TrafficClass::PtpEvent,
+        TrafficClass::PtpGeneral,
+        TrafficClass::Urgent,
+        TrafficClass::Scheduled,
+        TrafficClass::High,
+        TrafficClass::Low,
+        TrafficClass::Explicit,
+    ];
+
+    pub fn attribute_id(&self) -> QosAttributeId {
+        match self {
+            TrafficClass::PtpEvent => QosAttributeId::DscpPtpEvent,
+            TrafficClass::PtpGeneral => QosAttributeId::DscpPtpGeneral,
+            TrafficClass::Urgent => QosAttributeId::DscpUrgent,
+            TrafficClass::Scheduled => QosAttributeId::DscpScheduled,
+            TrafficClass::High => QosAttributeId::DscpHigh,
+            TrafficClass::Low => QosAttributeId::DscpLow,
+            TrafficClass::Explicit => QosAttributeId::DscpExplicit,
+        }
+    }
+
+    /// The DSCP value the specification recommends for the class
+    pub fn default_dscp(&self) -> CipUsint {
+        match self {
+            TrafficClass::PtpEvent => 59,
+            TrafficClass::PtpGeneral => 47,
+            TrafficClass::Urgent => 55,
+            TrafficClass::Scheduled => 47,
+            TrafficClass::High => 43,
+            TrafficClass::Low => 31,
+            TrafficClass::Explicit => 27,
+        }
+    }
+}
+
+// ^^^^^^^^ End of TrafficClass impl ^^^^^^^^
+
+fn qos_path(attribute_id: QosAttributeId) -> CipPath {
+    CipPath::new(QOS_CLASS_ID, 0x01).with_attribute(attribute_id as u16)
+}
+
+/// A request reading a single attribute of the QoS instance
+pub fn qos_attribute_request(attribute_id: QosAttributeId) -> MessageRouterRequest {
+    MessageRouterRequest::new(ServiceCode::GetAttributeSingle, qos_path(attribute_id))
+}
+
+/// A writable attribute of the QoS object, along with its new value
+///
+/// Devices apply new QoS values after a reset.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum QosSetting {
+    TagEnable(bool),
+    Dscp(TrafficClass, CipUsint),
+}
+
+// ======= Start of QosSetting impl ========
+
+impl QosSetting {
+    pub fn attribute_id(&self) -> QosAttributeId {
+        match self {
+            QosSetting::TagEnable(_) => QosAttributeId::TagEnable,
+            QosSetting::Dscp(traffic_class, _) => traffic_class.attribute_id(),
+        }
+    }
+
+    /// A Set Attribute Single request writing the setting to the QoS instance
+    pub fn into_router_request(self) -> MessageRouterRequest {
+        let value: CipUsint = match self {
+            QosSetting::TagEnable(enable) => enable as CipBool,
+            QosSetting::Dscp(_, dscp) => dscp,
+        };
+
+        MessageRouterRequest::new_data(
+            ServiceCode::SetAttributeSingle,
+            qos_path(self.attribute_id()),
+            Some(Box::new(value)),
+        )
+    }
+}
+
+// ^^^^^^^^ End of QosSetting impl ^^^^^^^^
+
+/// The QoS settings of a device, each left as `None` if the device does not support it
+#[derive(Debug, PartialEq, Clone, Default)]
+pub struct QosSettings {
+    pub tag_enable: Option<bool>,
+    /// The DSCP value of each traffic class the device reported, in `TrafficClass::ALL` order
+    pub dscp_values: Vec<(TrafficClass, CipUsint)>,
+}
+
+// ======= Start of QosSettings impl ========
+
+impl QosSettings {
+    pub fn dscp(&self, traffic_class: TrafficClass) -> Option<CipUsint> {
+        self.dscp_values
+            .iter()
+            .find(|(class, _)| *class == traffic_class)
+            .map(|(_, dscp)| *dscp)
+    }
+
+    /// The traffic classes that are not marked with the recommended DSCP value
+    pub fn non_default_dscp(&self) -> Vec<(TrafficClass, CipUsint)> {
+        self.dscp_values
+            .iter()
+            .filter(|(class, dscp)| class.default_dscp() != *dscp)
+            .copied()
+            .collect()
+    }
+}
+
+// ^^^^^^^^ End of QosSettings impl ^^^^^^^^
+
+/// Reads the QoS settings of a device one Get Attribute Single at a time
+#[derive(Debug)]
+pub struct QosRead {
+    next_attribute: usize,
+    settings: QosSettings,
+}
+
+// ======= Start of QosRead impl ========
+
+impl QosRead {
+    pub fn new() -> Self {
+        QosRead {
+            next_attribute: 0,
+            settings: QosSettings::default(),
+        }
+    }
+
+    /// The 802.1Q tag enable followed by the DSCP value of every traffic class
+    fn attribute_count() -> usize {
+        1 + TrafficClass::ALL.len()
+    }
+
+    pub fn is_complete(&self) -> bool {
+        self.next_attribute >= Self::attribute_count()
+    }
+
+    pub fn next_request(&self) -> Option<MessageRouterRequest> {
+        match self.next_attribute {
+            0 => Some(qos_attribute_request(QosAttributeId::TagEnable)),
+            index => TrafficClass::ALL
+                .get(index - 1)
+                .map(|traffic_class| qos_attribute_request(traffic_class.attribute_id())),
+        }
+    }
+
+    pub fn handle_response(&mut self, response: &MessageRouterResponse) -> BinResult<()> {
+        if self.is_complete() {
+            return Ok(());
+        }
+
+        let index = self.next_attribute;
+        self.next_attribute += 1;
+
+        // Without a QoS object there is nothing else to read
+        if response.response_data.is_object_missing() {
+            self.next_attribute = Self::attribute_count();
+            return Ok(());
+        }
+        if response.response_data.is_attribute_unsupported() {
+            return Ok(());
+        }
+        response.check_status()?;
+
+        let value: CipUsint = response.response_data.read_reply()?;
+        match index {
+            0 => self.settings.tag_enable = Some(value != 0),
+            index => self
+                .settings
+                .dscp_values
+                .push((TrafficClass::ALL[index - 1], value)),
+        }
+
+        Ok(())
+    }
+
+    /// The settings read so far
+    pub fn settings(&self) -> &QosSettings {
+        &self.settings
+    }
+
+    /// The settings, once the read is complete
+    pub fn into_settings(self) -> Option<QosSettings> {
+        self.is_complete().then_some(self.settings)
+    }
+}
+
+impl Default for QosRead {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// ^^^^^^^^ End of QosRead impl ^^^^^^^^
@@ -0,0 +1,62 @@
+// The vendor names, see examples/README.md to generate the full table with the vendor-table example
+include!("vendor_names.rs");
+
+/// Names of the CIP device types (device profiles), sorted by code
+pub static DEVICE_TYPE_NAMES: &[(u16, &str)] = &[
+    (0x00, "Generic Device (deprecated)"),
+    (0x02, "AC Drive"),
+    (0x03, "Motor Overload"),
+    (0x04, "Limit Switch"),
+    (0x05, "Inductive Proximity Switch"),
+    (0x06, "Photoelectric Sensor"),
+    (0x07, "General Purpose Discrete I/O"),
+    (0x09, "Resolver"),
+    (0x0C, "Communications Adapter"),
+    (0x0E, "Programmable Logic Controller"),
+    (0x10, "Position Controller"),
+    (0x13, "DC Drive"),
+    (0x15, "Contactor"),
+    (0x16, "Motor Starter"),
+    (0x17, "Soft Start"),
+    (0x18, "Human-Machine Interface"),
+    (0x1A, "Mass Flow Controller"),
+    (0x1B, "Pneumatic Valve"),
+    (0x1C, "Vacuum Pressure Gauge"),
+    (0x1D, "Process Control Value"),
+    (0x1E, "Residual Gas Analyzer"),
+    (0x1F, "DC Power Generator"),
+    (0x20, "RF Power Generator"),
+    (0x21, "Turbomolecular Vacuum Pump"),
+    (0x22, "Encoder"),
+    (0x23, "Safety Discrete I/O Device"),
+    (0x24, "Fluid Flow Controller"),
+    (0x25, "CIP Motion Drive"),
+    (0x26, "CompoNet Repeater"),
+    (0x27, "Mass Flow Controller, Enhanced"),
+    (0x28, "CIP Modbus Device"),
+    (0x29, "CIP Modbus Translator"),
+    (0x2A, "Safety Analog I/O Device"),
+    (0x2B, "Generic Device (keyable)"),
+    (0x2C, "Managed Ethernet Switch"),
+    (0x2D, "CIP Motion Safety Drive Device"),
+    (0x2E, "Safety Drive Device"),
+    (0x2F, "CIP Motion Encoder"),
+    (0xC8, "Embedded Component"),
+];
+
+fn lookup(table: &'static [(u16, &'static str)], id: u16) -> Option<&'static str> {
+    table
+        .binary_search_by_key(&id, |&(table_id, _)| table_id)
+        .ok()
+        .map(|index| table[index].1)
+}
+
+/// The registered name of a vendor, e.g. `Rockwell Automation/Allen-Bradley` for 1
+pub fn vendor_name(vendor_id: u16) -> Option<&'static str> {
+    lookup(VENDOR_NAMES, vendor_id)
+}
+
+/// The name of a device type, e.g. `Programmable Logic Controller` for 0x0E
+pub fn device_type_name(device_type: u16) -> Option<&'static str> {
+    lookup(DEVICE_TYPE_NAMES, device_type)
+}
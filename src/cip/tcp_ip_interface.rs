@@ -0,0 +1,380 @@
+use std::net::Ipv4Addr;
+
+use bilge::prelude::{bitsize, u22, u24, u27, u4, Bitsized, DebugBits, FromBits, Number};
+
+use binrw::{
+    binrw, // #[binrw] attribute
+    BinRead,
+    BinWrite,
+};
+
+use crate::cip::message::data::CipData;
+use crate::cip::message::request::MessageRouterRequest;
+use crate::cip::message::shared::ServiceCode;
+use crate::cip::path::CipPath;
+use crate::cip::types::{CipBool, CipByte, CipUdint, CipUint, CipUsint};
+
+/// The TCP/IP Interface object, which holds the IP configuration of an Ethernet port
+pub const TCP_IP_INTERFACE_CLASS_ID: u16 = 0xF5;
+
+/*
+TCP/IP Interface instance attributes
+    Attribute: 1 (Status)
+    Attribute: 2 (Configuration Capability)
+    Attribute: 3 (Configuration Control) -- settable
+    Attribute: 4 (Physical Link Object)
+    Attribute: 5 (Interface Configuration) -- settable
+    Attribute: 6 (Host Name) -- settable
+    Attribute: 8 (TTL Value) -- settable
+    Attribute: 9 (Multicast Address Configuration) -- settable
+    Attribute: 10 (Select ACD) -- settable
+    Attribute: 11 (Last Conflict Detected) -- settable, to clear it
+    Attribute: 12 (EtherNet/IP Quick Connect) -- settable
+    Attribute: 13 (Encapsulation Inactivity Timeout) -- settable
+*/
+#[derive(Debug, PartialEq, Clone, Copy)]
+#[repr(u16)]
+pub enum TcpIpAttributeId {
+    Status = 0x01,
+    ConfigurationCapability = 0x02,
+    ConfigurationControl = 0x03,
+    PhysicalLinkObject = 0x04,
+    InterfaceConfiguration = 0x05,
+    HostName = 0x06,
+    TtlValue = 0x08,
+    MulticastConfig = 0x09,
+    SelectAcd = 0x0A,
+    LastConflictDetected = 0x0B,
+    QuickConnect = 0x0C,
+    EncapsulationInactivityTimeout = 0x0D,
+}
+
+/// Where the interface got its current configuration from
+#[bitsize(4)]
+#[derive(FromBits, Debug, PartialEq, Clone, Copy)]
+pub enum InterfaceConfigurationStatus {
+    NotConfigured = 0,
+    Configured = 1,
+    HardwareConfigured = 2,
+    #[fallback]
+    Reserved,
+}
+
+#[bitsize(32)]
+#[derive(FromBits, PartialEq, DebugBits, BinRead, BinWrite, Copy, Clone)]
+#[br(map = CipUdint::into)]
+#[bw(map = |&x| CipUdint::from(x))]
+pub struct InterfaceStatus {
+    pub interface_configuration_status: InterfaceConfigurationStatus,
+    pub multicast_pending: bool,
+    pub interface_configuration_pending: bool,
+    pub acd_status: bool,
+    pub acd_fault: bool,
+    pub iana_port_admin_change_pending: bool,
+    pub iana_protocol_admin_change_pending: bool,
+    pub reserved: u22,
+}
+
+#[bitsize(32)]
+#[derive(FromBits, PartialEq, DebugBits, BinRead, BinWrite, Copy, Clone)]
+#[br(map = CipUdint::into)]
+#[bw(map = |&x| CipUdint::from(x))]
+pub struct ConfigurationCapability {
+    pub bootp_client: bool,
+    pub dns_client: bool,
+    pub dhcp_client: bool,
+    pub dhcp_dns_update: bool,
+    pub configuration_settable: bool,
+    pub hardware_configurable: bool,
+    pub configuration_change_requires_reset: bool,
+    pub acd_capable: bool,
+    pub reserved: u24,
+}
+
+/// How the interface gets its IP configuration at startup
+#[bitsize(4)]
+#[derive(FromBits, Debug, PartialEq, Clone, Copy)]
+pub enum ConfigurationMethod {
+    StaticIp = 0,
+    Bootp = 1,
+    Dhcp = 2,
+    #[fallback]
+    Reserved,
+}
+
+#[bitsize(32)]
+#[derive(FromBits, PartialEq, DebugBits, BinRead, BinWrite, Copy, Clone)]
+#[br(map = CipUdint::into)]
+#[bw(map = |&x| CipUdint::from(x))]
+pub struct ConfigurationControl {
+    pub configuration_method: ConfigurationMethod,
+    pub dns_enable: bool,
+    pub reserved: u27,
+}
+
+/// A STRING padded to an even number of bytes, as used for the domain and host names
+#[binrw]
+#[brw(little)]
+#[derive(Debug, PartialEq, Clone, Default)]
+pub struct PaddedString {
+    #[br(temp)]
+    #[bw(calc = value.len() as CipUint)]
+    length: CipUint,
+
+    #[br(count = length, pad_after = length % 2)]
+    #[bw(pad_after = length % 2)]
+    pub value: Vec<CipByte>,
+}
+
+// ======= Start of PaddedString impl ========
+
+impl From<&str> for PaddedString {
+    fn from(string_val: &str) -> Self {
+        PaddedString {
+            value: string_val.as_bytes().to_vec(),
+        }
+    }
+}
+
+impl From<PaddedString> for String {
+    fn from(string_val: PaddedString) -> Self {
+        String::from_utf8_lossy(&string_val.value).to_string()
+    }
+}
+
+// ^^^^^^^^ End of PaddedString impl ^^^^^^^^
+
+/// The path to the Ethernet Link object of the interface
+#[binrw]
+#[brw(little)]
+#[derive(Debug, PartialEq, Clone, Default)]
+pub struct PhysicalLinkObject {
+    #[br(temp)]
+    #[bw(calc = (path.len() / 2) as CipUint)]
+    path_size: CipUint,
+
+    #[br(count = path_size as usize * 2)]
+    pub path: Vec<CipByte>,
+}
+
+// ======= Start of PhysicalLinkObject impl ========
+
+impl PhysicalLinkObject {
+    /// The linked class and instance, if the path holds one
+    pub fn link_path(&self) -> Option<CipPath> {
+        if self.path.is_empty() {
+            return None;
+        }
+
+        let mut path_reader = std::io::Cursor::new(&self.path);
+        CipPath::read_le_args(&mut path_reader, ((self.path.len() / 2) as u8,)).ok()
+    }
+}
+
+// ^^^^^^^^ End of PhysicalLinkObject impl ^^^^^^^^
+
+/// The IP configuration of the interface, with every address zero when it is not configured
+#[binrw]
+#[brw(little)]
+#[derive(Debug, PartialEq, Clone)]
+pub struct InterfaceConfiguration {
+    #[br(map = |address: CipUdint| Ipv4Addr::from(address))]
+    #[bw(map = |address: &Ipv4Addr| CipUdint::from(*address))]
+    pub ip_address: Ipv4Addr,
+
+    #[br(map = |address: CipUdint| Ipv4Addr::from(address))]
+    #[bw(map = |address: &Ipv4Addr| CipUdint::from(*address))]
+    pub network_mask: Ipv4Addr,
+
+    #[br(map = |address: CipUdint| Ipv4Addr::from(address))]
+    #[bw(map = |address: &Ipv4Addr| CipUdint::from(*address))]
+    pub gateway_address: Ipv4Addr,
+
+    #[br(map = |address: CipUdint| Ipv4Addr::from(address))]
+    #[bw(map = |address: &Ipv4Addr| CipUdint::from(*address))]
+    pub name_server: Ipv4Addr,
+
+    #[br(map = |address: CipUdint| Ipv4Addr::from(address))]
+    #[bw(map = |address: &Ipv4Addr| CipUdint::from(*address))]
+    pub name_server_2: Ipv4Addr,
+
+    pub domain_name: PaddedString,
+}
+
+// ======= Start of InterfaceConfiguration impl ========
+
+impl InterfaceConfiguration {
+    /// A static configuration without name servers or a domain name
+    pub fn new(ip_address: Ipv4Addr, network_mask: Ipv4Addr, gateway_address: Ipv4Addr) -> Self {
+        InterfaceConfiguration {
+            ip_address,
+            network_mask,
+            gateway_address,
+            name_server: Ipv4Addr::UNSPECIFIED,
+            name_server_2: Ipv4Addr::UNSPECIFIED,
+            domain_name: PaddedString::default(),
+        }
+    }
+
+    pub fn with_name_servers(mut self, name_server: Ipv4Addr, name_server_2: Ipv4Addr) -> Self {
+        self.name_server = name_server;
+        self.name_server_2 = name_server_2;
+        self
+    }
+
+    pub fn with_domain_name(mut self, domain_name: &str) -> Self {
+        self.domain_name = PaddedString::from(domain_name);
+        self
+    }
+}
+
+// ^^^^^^^^ End of InterfaceConfiguration impl ^^^^^^^^
+
+/// How the multicast addresses of the interface are allocated
+#[derive(BinRead, BinWrite)]
+#[brw(little, repr = CipUsint)]
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum MulticastAllocation {
+    /// Addresses are generated from the IP address and network mask
+    Default = 0,
+    /// Addresses are taken from the configured start address and count
+    Configured = 1,
+}
+
+#[binrw]
+#[brw(little)]
+#[derive(Debug, PartialEq, Clone)]
+pub struct MulticastConfig {
+    pub allocation_control: MulticastAllocation,
+
+    #[br(temp)]
+    #[bw(calc = 0)]
+    _reserved: CipUsint,
+
+    pub num_multicast: CipUint,
+
+    #[br(map = |address: CipUdint| Ipv4Addr::from(address))]
+    #[bw(map = |address: &Ipv4Addr| CipUdint::from(*address))]
+    pub multicast_start_address: Ipv4Addr,
+}
+
+/// The address conflict detection activity when the last conflict was detected
+#[derive(BinRead, BinWrite)]
+#[brw(little, repr = CipUsint)]
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum AcdActivity {
+    NoConflictDetected = 0,
+    Probe = 1,
+    OngoingDetection = 2,
+    SemiActiveProbe = 3,
+}
+
+#[binrw]
+#[brw(little)]
+#[derive(Debug, PartialEq, Clone)]
+pub struct LastConflictDetected {
+    pub acd_activity: AcdActivity,
+    pub remote_mac: [CipByte; 6],
+    pub arp_pdu: [CipByte; 28],
+}
+
+// ======= Start of LastConflictDetected impl ========
+
+impl LastConflictDetected {
+    /// The value written to clear the last conflict
+    pub fn cleared() -> Self {
+        LastConflictDetected {
+            acd_activity: AcdActivity::NoConflictDetected,
+            remote_mac: [0; 6],
+            arp_pdu: [0; 28],
+        }
+    }
+}
+
+// ^^^^^^^^ End of LastConflictDetected impl ^^^^^^^^
+
+/// A request reading a single attribute of the first TCP/IP Interface instance
+pub fn tcp_ip_attribute_request(attribute_id: TcpIpAttributeId) -> MessageRouterRequest {
+    MessageRouterRequest::new(
+        ServiceCode::GetAttributeSingle,
+        CipPath::new(TCP_IP_INTERFACE_CLASS_ID, 0x01).with_attribute(attribute_id as u16),
+    )
+}
+
+/// A writable attribute of the TCP/IP Interface object, along with its new value
+#[derive(Debug, PartialEq, Clone)]
+pub enum TcpIpSetting {
+    ConfigurationControl(ConfigurationControl),
+    InterfaceConfiguration(InterfaceConfiguration),
+    HostName(String),
+    TtlValue(CipUsint),
+    MulticastConfig(MulticastConfig),
+    SelectAcd(bool),
+    ClearLastConflict,
+    QuickConnect(bool),
+    EncapsulationInactivityTimeout(CipUint),
+}
+
+// ======= Start of TcpIpSetting impl ========
+
+impl TcpIpSetting {
+    /// Switches the interface to DHCP
+    pub fn dhcp() -> Self {
+        TcpIpSetting::ConfigurationControl(ConfigurationControl::new(
+            ConfigurationMethod::Dhcp,
+            false,
+        ))
+    }
+
+    /// Switches the interface to a static IP configuration
+    ///
+    /// The configuration control has to be written before the interface configuration, devices
+    /// reject a new interface configuration while they are still using DHCP or BOOTP.
+    pub fn static_ip(configuration: InterfaceConfiguration) -> [Self; 2] {
+        [
+            TcpIpSetting::ConfigurationControl(ConfigurationControl::new(
+                ConfigurationMethod::StaticIp,
+                false,
+            )),
+            TcpIpSetting::InterfaceConfiguration(configuration),
+        ]
+    }
+
+    pub fn attribute_id(&self) -> TcpIpAttributeId {
+        match self {
+            TcpIpSetting::ConfigurationControl(_) => TcpIpAttributeId::ConfigurationControl,
+            TcpIpSetting::InterfaceConfiguration(_) => TcpIpAttributeId::InterfaceConfiguration,
+            TcpIpSetting::HostName(_) => TcpIpAttributeId::HostName,
+            TcpIpSetting::TtlValue(_) => TcpIpAttributeId::TtlValue,
+            TcpIpSetting::MulticastConfig(_) => TcpIpAttributeId::MulticastConfig,
+            TcpIpSetting::SelectAcd(_) => TcpIpAttributeId::SelectAcd,
+            TcpIpSetting::ClearLastConflict => TcpIpAttributeId::LastConflictDetected,
+            TcpIpSetting::QuickConnect(_) => TcpIpAttributeId::QuickConnect,
+            TcpIpSetting::EncapsulationInactivityTimeout(_) => {
+                TcpIpAttributeId::EncapsulationInactivityTimeout
+            }
+        }
+    }
+
+    /// A Set Attribute Single request writing the setting to the first TCP/IP Interface instance
+    pub fn into_router_request(self) -> MessageRouterRequest {
+        let path = CipPath::new(TCP_IP_INTERFACE_CLASS_ID, 0x01)
+            .with_attribute(self.attribute_id() as u16);
+
+        let value: Box<dyn CipData> = match self {
+            TcpIpSetting::ConfigurationControl(control) => Box::new(control),
+            TcpIpSetting::InterfaceConfiguration(configuration) => Box::new(configuration),
+            TcpIpSetting::HostName(host_name) => Box::new(PaddedString::from(host_name.as_str())),
+            TcpIpSetting::TtlValue(ttl) => Box::new(ttl),
+            TcpIpSetting::MulticastConfig(multicast_config) => Box::new(multicast_config),
+            TcpIpSetting::SelectAcd(select_acd) => Box::new(select_acd as CipBool),
+            TcpIpSetting::ClearLastConflict => Box::new(LastConflictDetected::cleared()),
+            TcpIpSetting::QuickConnect(quick_connect) => Box::new(quick_connect as CipBool),
+            TcpIpSetting::EncapsulationInactivityTimeout(timeout) => Box::new(timeout),
+        };
+
+        MessageRouterRequest::new_data(ServiceCode::SetAttributeSingle, path, Some(value))
+    }
+}
+
+// ^^^^^^^^ End of TcpIpSetting impl ^^^^^^^^
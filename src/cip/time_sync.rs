@@ -0,0 +1,484 @@
+use bilge::prelude::{bitsize, u10, Bitsized, DebugBits, FromBits, Number};
+
+use binrw::{
+    binrw, // #[binrw] attribute
+    BinRead,
+    BinResult,
+    BinWrite,
+};
+
+use crate::cip::message::request::MessageRouterRequest;
+use crate::cip::message::response::MessageRouterResponse;
+use crate::cip::message::shared::ServiceCode;
+use crate::cip::path::CipPath;
+use crate::cip::types::{CipBool, CipByte, CipDint, CipLint, CipUint, CipUlint, CipUsint, CipWord};
+
+/// The Time Sync object, which exposes the IEEE 1588 (PTP) clock used by CIP Sync
+pub const TIME_SYNC_CLASS_ID: u16 = 0x43;
+
+/*
+Time Sync instance attributes
+    Attribute: 1 (PTP Enable) -- settable
+    Attribute: 2 (Is Synchronized)
+    Attribute: 3 (System Time Microseconds)
+    Attribute: 4 (System Time Nanoseconds)
+    Attribute: 5 (Offset From Master)
+    Attribute: 6 (Max Offset From Master) -- settable
+    Attribute: 7 (Mean Path Delay To Master)
+    Attribute: 8 (Grandmaster Clock Info)
+    Attribute: 9 (Parent Clock Info)
+    Attribute: 10 (Local Clock Info)
+    Attribute: 11 (Number of Ports)
+    Attribute: 12 (Port State Info)
+    Attribute: 13 (Port Enable Cfg) -- settable
+    Attribute: 14 (Port Log Announcement Interval Cfg) -- settable
+    Attribute: 15 (Port Log Sync Interval Cfg) -- settable
+    Attribute: 16 (Priority 1) -- settable
+    Attribute: 17 (Priority 2) -- settable
+    Attribute: 18 (Domain Number) -- settable
+    Attribute: 19 (Clock Type)
+    Attribute: 20 (Manufacture Identity)
+    Attribute: 21 (Product Description)
+    Attribute: 22 (Revision Data)
+    Attribute: 23 (User Description) -- settable
+    Attribute: 24 (Port Profile Identity Info)
+    Attribute: 25 (Port Physical Address Info)
+    Attribute: 26 (Port Protocol Address Info)
+    Attribute: 27 (Steps Removed)
+    Attribute: 28 (System Time and Offset)
+*/
+#[derive(Debug, PartialEq, Clone, Copy)]
+#[repr(u16)]
+pub enum TimeSyncAttributeId {
+    PtpEnable = 0x01,
+    IsSynchronized = 0x02,
+    SystemTimeMicroseconds = 0x03,
+    SystemTimeNanoseconds = 0x04,
+    OffsetFromMaster = 0x05,
+    MaxOffsetFromMaster = 0x06,
+    MeanPathDelayToMaster = 0x07,
+    GrandmasterClockInfo = 0x08,
+    ParentClockInfo = 0x09,
+    LocalClockInfo = 0x0A,
+    NumberOfPorts = 0x0B,
+    PortStateInfo = 0x0C,
+    PortEnableCfg = 0x0D,
+    PortLogAnnouncementIntervalCfg = 0x0E,
+    PortLogSyncIntervalCfg = 0x0F,
+    Priority1 = 0x10,
+    Priority2 = 0x11,
+    DomainNumber = 0x12,
+    ClockType = 0x13,
+    ManufactureIdentity = 0x14,
+    ProductDescription = 0x15,
+    RevisionData = 0x16,
+    UserDescription = 0x17,
+    PortProfileIdentityInfo = 0x18,
+    PortPhysicalAddressInfo = 0x19,
+    PortProtocolAddressInfo = 0x1A,
+    StepsRemoved = 0x1B,
+    SystemTimeAndOffset = 0x1C,
+}
+
+/// The EUI-64 identifying a PTP clock
+#[binrw]
+#[brw(little)]
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy, Default)]
+pub struct ClockIdentity(pub [CipByte; 8]);
+
+// ======= Start of ClockIdentity impl ========
+
+impl std::fmt::Display for ClockIdentity {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let octets: Vec<String> = self
+            .0
+            .iter()
+            .map(|octet| format!("{:02x}", octet))
+            .collect();
+        write!(f, "{}", octets.join(":"))
+    }
+}
+
+// ^^^^^^^^ End of ClockIdentity impl ^^^^^^^^
+
+#[bitsize(16)]
+#[derive(FromBits, PartialEq, DebugBits, BinRead, BinWrite, Copy, Clone)]
+#[br(map = CipWord::into)]
+#[bw(map = |&x| CipWord::from(x))]
+pub struct TimePropertyFlags {
+    /// The last minute of the current UTC day has 61 seconds
+    pub leap_61: bool,
+    /// The last minute of the current UTC day has 59 seconds
+    pub leap_59: bool,
+    pub current_utc_offset_valid: bool,
+    /// The clock uses the PTP epoch instead of an arbitrary one
+    pub ptp_timescale: bool,
+    pub time_traceable: bool,
+    pub frequency_traceable: bool,
+    pub reserved: u10,
+}
+
+/// Where the time of a clock comes from
+#[binrw]
+#[brw(little)]
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum TimeSource {
+    #[brw(magic = 0x10u16)]
+    AtomicClock,
+    #[brw(magic = 0x20u16)]
+    Gps,
+    #[brw(magic = 0x30u16)]
+    TerrestrialRadio,
+    #[brw(magic = 0x40u16)]
+    Ptp,
+    #[brw(magic = 0x50u16)]
+    Ntp,
+    #[brw(magic = 0x60u16)]
+    HandSet,
+    #[brw(magic = 0x90u16)]
+    Other,
+    #[brw(magic = 0xA0u16)]
+    InternalOscillator,
+    Reserved(u16),
+}
+
+/// The grandmaster the device is synchronized to, as announced to it
+#[binrw]
+#[brw(little)]
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct GrandmasterClockInfo {
+    pub clock_identity: ClockIdentity,
+    pub clock_class: CipUint,
+    pub time_accuracy: CipUint,
+    pub offset_scaled_log_variance: CipUint,
+    /// The offset of TAI from UTC in seconds
+    pub current_utc_offset: CipUint,
+    pub time_property_flags: TimePropertyFlags,
+    pub time_source: TimeSource,
+    pub priority1: CipUint,
+    pub priority2: CipUint,
+}
+
+/// The clock the device synchronizes to directly, a boundary clock or the grandmaster itself
+#[binrw]
+#[brw(little)]
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct ParentClockInfo {
+    pub clock_identity: ClockIdentity,
+    pub port_number: CipUint,
+    pub observed_offset_scaled_log_variance: CipUint,
+    pub observed_phase_change_rate: CipDint,
+}
+
+#[binrw]
+#[brw(little)]
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct LocalClockInfo {
+    pub clock_identity: ClockIdentity,
+    pub clock_class: CipUint,
+    pub time_accuracy: CipUint,
+    pub offset_scaled_log_variance: CipUint,
+    pub current_utc_offset: CipUint,
+    pub time_property_flags: TimePropertyFlags,
+    pub time_source: TimeSource,
+}
+
+/// The IEEE 1588 state of a PTP port
+#[binrw]
+#[brw(little)]
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum PortState {
+    #[brw(magic = 0x01u16)]
+    Initializing,
+    #[brw(magic = 0x02u16)]
+    Faulty,
+    #[brw(magic = 0x03u16)]
+    Disabled,
+    #[brw(magic = 0x04u16)]
+    Listening,
+    #[brw(magic = 0x05u16)]
+    PreMaster,
+    #[brw(magic = 0x06u16)]
+    Master,
+    #[brw(magic = 0x07u16)]
+    Passive,
+    #[brw(magic = 0x08u16)]
+    Uncalibrated,
+    #[brw(magic = 0x09u16)]
+    Slave,
+    Reserved(u16),
+}
+
+#[binrw]
+#[brw(little)]
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct PortStateEntry {
+    pub port_number: CipUint,
+    pub port_state: PortState,
+}
+
+#[binrw]
+#[brw(little)]
+#[derive(Debug, PartialEq, Clone)]
+pub struct PortStateInfo {
+    #[br(temp)]
+    #[bw(calc = ports.len() as CipUint)]
+    number_of_ports: CipUint,
+
+    #[br(count = number_of_ports)]
+    pub ports: Vec<PortStateEntry>,
+}
+
+/// The system time and its offset, sampled together
+#[binrw]
+#[brw(little)]
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct SystemTimeAndOffset {
+    /// Microseconds since the PTP epoch
+    pub system_time: CipUlint,
+    pub system_offset: CipLint,
+}
+
+fn time_sync_path(attribute_id: TimeSyncAttributeId) -> CipPath {
+    CipPath::new(TIME_SYNC_CLASS_ID, 0x01).with_attribute(attribute_id as u16)
+}
+
+/// A request reading a single attribute of the Time Sync instance
+pub fn time_sync_attribute_request(attribute_id: TimeSyncAttributeId) -> MessageRouterRequest {
+    MessageRouterRequest::new(
+        ServiceCode::GetAttributeSingle,
+        time_sync_path(attribute_id),
+    )
+}
+
+/// The synchronization state of a device, each left as `None` if the device does not support it
+#[derive(Debug, PartialEq, Clone, Default)]
+pub struct TimeSyncStatus {
+    pub ptp_enable: Option<bool>,
+    pub is_synchronized: Option<bool>,
+    /// Microseconds since the PTP epoch
+    pub system_time: Option<CipUlint>,
+    /// The offset of the local clock from its master in nanoseconds
+    pub offset_from_master: Option<CipLint>,
+    /// The largest offset from master in nanoseconds since it was last cleared
+    pub max_offset_from_master: Option<CipUlint>,
+    pub mean_path_delay_to_master: Option<CipLint>,
+    pub grandmaster: Option<GrandmasterClockInfo>,
+    pub parent: Option<ParentClockInfo>,
+    pub local_clock: Option<LocalClockInfo>,
+    pub port_states: Option<Vec<PortStateEntry>>,
+    pub domain_number: Option<CipUsint>,
+    /// The number of boundary clocks between the device and the grandmaster
+    pub steps_removed: Option<CipUint>,
+}
+
+/// Why a device is not locked to the expected grandmaster
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum SyncProblem {
+    /// The device has no Time Sync object or did not report the attributes needed to check it
+    Unsupported,
+    PtpDisabled,
+    NotSynchronized,
+    OtherGrandmaster(ClockIdentity),
+}
+
+// ======= Start of SyncProblem impl ========
+
+impl std::fmt::Display for SyncProblem {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SyncProblem::Unsupported => write!(f, "does not report its time synchronization"),
+            SyncProblem::PtpDisabled => write!(f, "has PTP disabled"),
+            SyncProblem::NotSynchronized => write!(f, "is not synchronized"),
+            SyncProblem::OtherGrandmaster(clock_identity) => {
+                write!(f, "is locked to grandmaster {}", clock_identity)
+            }
+        }
+    }
+}
+
+// ^^^^^^^^ End of SyncProblem impl ^^^^^^^^
+
+// ======= Start of TimeSyncStatus impl ========
+
+impl TimeSyncStatus {
+    pub fn grandmaster_identity(&self) -> Option<ClockIdentity> {
+        self.grandmaster
+            .as_ref()
+            .map(|grandmaster| grandmaster.clock_identity)
+    }
+
+    /// Whether the device is synchronized to the grandmaster
+    pub fn is_locked_to(&self, grandmaster_identity: &ClockIdentity) -> bool {
+        self.sync_problem(grandmaster_identity).is_none()
+    }
+
+    /// What keeps the device from being synchronized to the grandmaster, if anything
+    pub fn sync_problem(&self, grandmaster_identity: &ClockIdentity) -> Option<SyncProblem> {
+        match (
+            self.ptp_enable,
+            self.is_synchronized,
+            self.grandmaster_identity(),
+        ) {
+            (Some(false), _, _) => Some(SyncProblem::PtpDisabled),
+            (_, Some(false), _) => Some(SyncProblem::NotSynchronized),
+            (_, None, _) | (_, _, None) => Some(SyncProblem::Unsupported),
+            (_, Some(true), Some(identity)) if identity != *grandmaster_identity => {
+                Some(SyncProblem::OtherGrandmaster(identity))
+            }
+            _ => None,
+        }
+    }
+}
+
+// ^^^^^^^^ End of TimeSyncStatus impl ^^^^^^^^
+
+/// The grandmaster most of the synchronized devices are locked to, which the others should follow
+pub fn common_grandmaster<'a>(
+    statuses: impl IntoIterator<Item = &'a TimeSyncStatus>,
+) -> Option<ClockIdentity> {
+    let mut grandmaster_counts: Vec<(ClockIdentity, usize)> = Vec::new();
+
+    for status in statuses {
+        let (Some(true), Some(identity)) = (status.is_synchronized, status.grandmaster_identity())
+        else {
+            continue;
+        };
+
+        match grandmaster_counts
+            .iter_mut()
+            .find(|(counted_identity, _)| *counted_identity == identity)
+        {
+            Some((_, count)) => *count += 1,
+            None => grandmaster_counts.push((identity, 1)),
+        }
+    }
+
+    // The first one seen wins a tie
+    grandmaster_counts
+        .iter()
+        .rev()
+        .max_by_key(|(_, count)| *count)
+        .map(|(identity, _)| *identity)
+}
+
+/// Reads the synchronization state of a device one Get Attribute Single at a time
+#[derive(Debug)]
+pub struct TimeSyncRead {
+    next_attribute: usize,
+    status: TimeSyncStatus,
+}
+
+// ======= Start of TimeSyncRead impl ========
+
+impl TimeSyncRead {
+    pub const ATTRIBUTES: [TimeSyncAttributeId; 12] = [
+        TimeSyncAttributeId::PtpEnable,
+        TimeSyncAttributeId::IsSynchronized,
+        TimeSyncAttributeId::SystemTimeMicroseconds,
+        TimeSyncAttributeId::OffsetFromMaster,
+        TimeSyncAttributeId::MaxOffsetFromMaster,
+        TimeSyncAttributeId::MeanPathDelayToMaster,
+        TimeSyncAttributeId::GrandmasterClockInfo,
+        TimeSyncAttributeId::ParentClockInfo,
+        TimeSyncAttributeId::LocalClockInfo,
+        TimeSyncAttributeId::PortStateInfo,
+        TimeSyncAttributeId::DomainNumber,
+        TimeSyncAttributeId::StepsRemoved,
+    ];
+
+    pub fn new() -> Self {
+        TimeSyncRead {
+            next_attribute: 0,
+            status: TimeSyncStatus::default(),
+        }
+    }
+
+    pub fn is_complete(&self) -> bool {
+        self.next_attribute >= Self::ATTRIBUTES.len()
+    }
+
+    pub fn next_request(&self) -> Option<MessageRouterRequest> {
+        let attribute_id = Self::ATTRIBUTES.get(self.next_attribute)?;
+
+        Some(time_sync_attribute_request(*attribute_id))
+    }
+
+    pub fn handle_response(&mut self, response: &MessageRouterResponse) -> BinResult<()> {
+        let Some(attribute_id) = Self::ATTRIBUTES.get(self.next_attribute) else {
+            return Ok(());
+        };
+        self.next_attribute += 1;
+
+        // Without a Time Sync object there is nothing else to read
+        if response.response_data.is_object_missing() {
+            self.next_attribute = Self::ATTRIBUTES.len();
+            return Ok(());
+        }
+        if response.response_data.is_attribute_unsupported() {
+            return Ok(());
+        }
+        response.check_status()?;
+
+        let status = &mut self.status;
+        let reply_data = &response.response_data;
+        match attribute_id {
+            TimeSyncAttributeId::PtpEnable => {
+                status.ptp_enable = Some(reply_data.read_reply::<CipBool>()? != 0)
+            }
+            TimeSyncAttributeId::IsSynchronized => {
+                status.is_synchronized = Some(reply_data.read_reply::<CipBool>()? != 0)
+            }
+            TimeSyncAttributeId::SystemTimeMicroseconds => {
+                status.system_time = Some(reply_data.read_reply()?)
+            }
+            TimeSyncAttributeId::OffsetFromMaster => {
+                status.offset_from_master = Some(reply_data.read_reply()?)
+            }
+            TimeSyncAttributeId::MaxOffsetFromMaster => {
+                status.max_offset_from_master = Some(reply_data.read_reply()?)
+            }
+            TimeSyncAttributeId::MeanPathDelayToMaster => {
+                status.mean_path_delay_to_master = Some(reply_data.read_reply()?)
+            }
+            TimeSyncAttributeId::GrandmasterClockInfo => {
+                status.grandmaster = Some(reply_data.read_reply()?)
+            }
+            TimeSyncAttributeId::ParentClockInfo => status.parent = Some(reply_data.read_reply()?),
+            TimeSyncAttributeId::LocalClockInfo => {
+                status.local_clock = Some(reply_data.read_reply()?)
+            }
+            TimeSyncAttributeId::PortStateInfo => {
+                let port_state_info: PortStateInfo = reply_data.read_reply()?;
+                status.port_states = Some(port_state_info.ports);
+            }
+            TimeSyncAttributeId::DomainNumber => {
+                status.domain_number = Some(reply_data.read_reply()?)
+            }
+            TimeSyncAttributeId::StepsRemoved => {
+                status.steps_removed = Some(reply_data.read_reply()?)
+            }
+            _ => {}
+        }
+
+        Ok(())
+    }
+
+    /// The state read so far
+    pub fn status(&self) -> &TimeSyncStatus {
+        &self.status
+    }
+
+    /// The synchronization state, once the read is complete
+    pub fn into_status(self) -> Option<TimeSyncStatus> {
+        self.is_complete().then_some(self.status)
+    }
+}
+
+impl Default for TimeSyncRead {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// ^^^^^^^^ End of TimeSyncRead impl ^^^^^^^^
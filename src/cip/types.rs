@@ -1,5 +1,8 @@
 use binrw::{
     binrw, // #[binrw] attribute
+    BinRead,
+    BinResult,
+    BinWrite,
 };
 
 // This file contains the basic types used in the CIP protocol
@@ -38,6 +41,16 @@ pub struct CipShortString {
     pub value: Vec<CipUsint>,
 }
 
+#[binrw]
+#[brw(little)]
+#[derive(Debug, PartialEq, Clone)]
+pub struct CipString {
+    pub length: CipUint,
+
+    #[br(count = length)]
+    pub value: Vec<CipUsint>,
+}
+
 // ======= Start of CipShortString impl ========
 
 impl From<String> for CipShortString {
@@ -57,3 +70,246 @@ impl From<CipShortString> for String {
 }
 
 // ^^^^^^^ End of CipShortString impl ^^^^^^^^
+
+// ======= Start of CipString impl ========
+
+impl From<String> for CipString {
+    fn from(string_val: String) -> Self {
+        CipString {
+            length: string_val.len() as CipUint,
+            value: string_val.as_bytes().to_vec(),
+        }
+    }
+}
+
+impl From<CipString> for String {
+    fn from(string_val: CipString) -> Self {
+        String::from_utf8_lossy(&string_val.value).to_string()
+    }
+}
+
+// ^^^^^^^ End of CipString impl ^^^^^^^^
+
+/// One language of an international string, only SHORT_STRING and STRING values are supported
+#[binrw]
+#[brw(little)]
+#[derive(Debug, PartialEq, Clone)]
+pub struct CipStringIEntry {
+    /// ISO 639-2 language code, e.g. `eng`
+    pub language: [CipByte; 3],
+
+    #[br(assert(
+        matches!(string_type, CipDataType::ShortString | CipDataType::String),
+        "Unsupported STRINGI string type {:?}",
+        string_type
+    ))]
+    pub string_type: CipDataType,
+
+    /// IANA MIB enum of the character set, e.g. 4 for ISO 8859-1
+    pub character_set: CipUint,
+
+    #[br(parse_with = read_string_value, args(string_type))]
+    #[bw(write_with = write_string_value, args(*string_type))]
+    pub value: Vec<CipByte>,
+}
+
+/// A string in one or more languages (STRINGI)
+#[binrw]
+#[brw(little)]
+#[derive(Debug, PartialEq, Clone)]
+pub struct CipStringI {
+    #[br(temp)]
+    #[bw(calc = entries.len() as CipUsint)]
+    entry_count: CipUsint,
+
+    #[br(count = entry_count)]
+    pub entries: Vec<CipStringIEntry>,
+}
+
+// The length of a STRINGI value is a USINT for a SHORT_STRING and a UINT for a STRING
+#[binrw::parser(reader, endian)]
+fn read_string_value(string_type: CipDataType) -> BinResult<Vec<CipByte>> {
+    let length = match string_type {
+        CipDataType::ShortString => CipUsint::read_options(reader, endian, ())? as usize,
+        _ => CipUint::read_options(reader, endian, ())? as usize,
+    };
+
+    Vec::<CipByte>::read_options(
+        reader,
+        endian,
+        binrw::VecArgs {
+            count: length,
+            inner: (),
+        },
+    )
+}
+
+#[binrw::writer(writer, endian)]
+fn write_string_value(value: &Vec<CipByte>, string_type: CipDataType) -> BinResult<()> {
+    match string_type {
+        CipDataType::ShortString => (value.len() as CipUsint).write_options(writer, endian, ())?,
+        _ => (value.len() as CipUint).write_options(writer, endian, ())?,
+    }
+
+    value.write_options(writer, endian, ())
+}
+
+// ======= Start of CipStringI impl ========
+
+impl From<String> for CipStringI {
+    fn from(string_val: String) -> Self {
+        CipStringI {
+            entries: vec![CipStringIEntry {
+                language: *b"eng",
+                string_type: CipDataType::ShortString,
+                character_set: 4,
+                value: string_val.as_bytes().to_vec(),
+            }],
+        }
+    }
+}
+
+impl From<CipStringI> for String {
+    fn from(string_val: CipStringI) -> Self {
+        // Only the first language is kept
+        string_val
+            .entries
+            .first()
+            .map(|entry| String::from_utf8_lossy(&entry.value).to_string())
+            .unwrap_or_default()
+    }
+}
+
+// ^^^^^^^ End of CipStringI impl ^^^^^^^^
+
+/// The code identifying an elementary data type, e.g. in an EDS or the Parameter object
+#[derive(BinRead, BinWrite)]
+#[br(map = |code: CipUsint| CipDataType::from_code(code))]
+#[bw(map = |data_type: &CipDataType| data_type.code())]
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum CipDataType {
+    Bool,
+    Sint,
+    Int,
+    Dint,
+    Lint,
+    Usint,
+    Uint,
+    Udint,
+    Ulint,
+    Real,
+    Lreal,
+    Stime,
+    Date,
+    TimeOfDay,
+    DateAndTime,
+    String,
+    Byte,
+    Word,
+    Dword,
+    Lword,
+    String2,
+    Ftime,
+    Ltime,
+    Itime,
+    StringN,
+    ShortString,
+    Time,
+    Epath,
+    EngUnit,
+    StringI,
+    Unknown(CipUsint),
+}
+
+// ======= Start of CipDataType impl ========
+
+impl CipDataType {
+    const CODES: [(CipUsint, CipDataType); 30] = [
+        (0xC1, CipDataType::Bool),
+        (0xC2, CipDataType::Sint),
+        (0xC3, CipDataType::Int),
+        (0xC4, CipDataType::Dint),
+        (0xC5, CipDataType::Lint),
+        (0xC6, CipDataType::Usint),
+        (0xC7, CipDataType::Uint),
+        (0xC8, CipDataType::Udint),
+        (0xC9, CipDataType::Ulint),
+        (0xCA, CipDataType::Real),
+        (0xCB, CipDataType::Lreal),
+        (0xCC, CipDataType::Stime),
+        (0xCD, CipDataType::Date),
+        (0xCE, CipDataType::TimeOfDay),
+        (0xCF, CipDataType::DateAndTime),
+        (0xD0, CipDataType::String),
+        (0xD1, CipDataType::Byte),
+        (0xD2, CipDataType::Word),
+        (0xD3, CipDataType::Dword),
+        (0xD4, CipDataType::Lword),
+        (0xD5, CipDataType::String2),
+        (0xD6, CipDataType::Ftime),
+        (0xD7, CipDataType::Ltime),
+        (0xD8, CipDataType::Itime),
+        (0xD9, CipDataType::StringN),
+        (0xDA, CipDataType::ShortString),
+        (0xDB, CipDataType::Time),
+        (0xDC, CipDataType::Epath),
+        (0xDD, CipDataType::EngUnit),
+        (0xDE, CipDataType::StringI),
+    ];
+
+    pub fn from_code(type_code: CipUsint) -> Self {
+        Self::CODES
+            .iter()
+            .find(|(code, _)| *code == type_code)
+            .map_or(CipDataType::Unknown(type_code), |&(_, data_type)| data_type)
+    }
+
+    pub fn code(&self) -> CipUsint {
+        match self {
+            CipDataType::Unknown(type_code) => *type_code,
+            data_type => Self::CODES
+                .iter()
+                .find(|(_, known_type)| known_type == data_type)
+                .map(|&(code, _)| code)
+                .unwrap_or_default(),
+        }
+    }
+
+    /// The number of bytes of a value of a fixed size type
+    pub fn element_size(&self) -> Option<usize> {
+        match self {
+            CipDataType::Bool | CipDataType::Sint | CipDataType::Usint | CipDataType::Byte => {
+                Some(1)
+            }
+            CipDataType::Int
+            | CipDataType::Uint
+            | CipDataType::Word
+            | CipDataType::Date
+            | CipDataType::Itime
+            | CipDataType::EngUnit => Some(2),
+            CipDataType::Dint
+            | CipDataType::Udint
+            | CipDataType::Real
+            | CipDataType::Dword
+            | CipDataType::Stime
+            | CipDataType::TimeOfDay
+            | CipDataType::Ftime
+            | CipDataType::Time => Some(4),
+            CipDataType::Lint
+            | CipDataType::Ulint
+            | CipDataType::Lreal
+            | CipDataType::Lword
+            | CipDataType::Ltime => Some(8),
+            CipDataType::DateAndTime => Some(6),
+            CipDataType::String
+            | CipDataType::String2
+            | CipDataType::StringN
+            | CipDataType::ShortString
+            | CipDataType::Epath
+            | CipDataType::StringI
+            | CipDataType::Unknown(_) => None,
+        }
+    }
+}
+
+// ^^^^^^^ End of CipDataType impl ^^^^^^^^
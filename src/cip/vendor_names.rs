@@ -0,0 +1,236 @@
+// A partial, hand kept copy of the ODVA vendor list that stops at ID 808. Replace it with the
+// output of `cargo run --example vendor-table` run on Wireshark's packet-cip.c, see
+// examples/README.md
+
+/// Names of some of the ODVA registered vendor IDs, sorted by ID
+///
+/// IDs missing from the table are reported as unknown rather than guessed at.
+pub static VENDOR_NAMES: &[(u16, &str)] = &[
+    (1, "Rockwell Automation/Allen-Bradley"),
+    (2, "Namco Controls Corp."),
+    (3, "Honeywell Inc."),
+    (4, "Parker Hannifin Corp. (Veriflo Division)"),
+    (5, "Rockwell Automation/Reliance Elec."),
+    (7, "SMC Corporation"),
+    (8, "Molex Incorporated"),
+    (9, "Western Reserve Controls Corp."),
+    (10, "Advanced Micro Controls Inc. (AMCI)"),
+    (11, "ASCO Pneumatic Controls"),
+    (12, "Banner Engineering Corp."),
+    (13, "Belden Wire & Cable Company"),
+    (14, "Cooper Interconnect"),
+    (16, "Daniel Woodhead Co. (Woodhead Connectivity)"),
+    (17, "Dearborn Group Inc."),
+    (19, "Helm Instrument Company"),
+    (20, "Huron Net Works"),
+    (21, "Lumberg, Inc."),
+    (22, "Online Development Inc. (Automation Value)"),
+    (23, "Vorne Industries, Inc."),
+    (24, "ODVA Special Reserve"),
+    (26, "Festo Corporation"),
+    (30, "Unico, Inc."),
+    (31, "Ross Controls"),
+    (34, "Hohner Corp."),
+    (35, "Micro Mo Electronics, Inc."),
+    (36, "MKS Instruments, Inc."),
+    (37, "Yaskawa Electric America formerly Magnetek Drives"),
+    (39, "AVG Automation (Uticor)"),
+    (40, "Wago Corporation"),
+    (41, "Kinetics (Unit Instruments)"),
+    (42, "IMI Norgren Limited"),
+    (43, "BALLUFF, Inc."),
+    (44, "Yaskawa Electric America, Inc."),
+    (45, "Eurotherm Controls Inc"),
+    (46, "ABB Industrial Systems"),
+    (47, "Omron Corporation"),
+    (48, "TURCk, Inc."),
+    (49, "Grayhill Inc."),
+    (50, "Real Time Automation (C&ID)"),
+    (52, "Numatics, Inc."),
+    (53, "Lutze, Inc."),
+    (56, "Softing GmbH"),
+    (57, "Pepperl + Fuchs"),
+    (58, "Spectrum Controls, Inc."),
+    (59, "D.I.P. Inc. MKS Inst."),
+    (60, "Applied Motion Products, Inc."),
+    (61, "Sencon Inc."),
+    (62, "High Country Tek"),
+    (63, "SWAC Automation Consult GmbH"),
+    (64, "Clippard Instrument Laboratory"),
+    (68, "Eaton Electrical"),
+    (71, "Toshiba International Corp."),
+    (72, "Control Technology Incorporated"),
+    (73, "TCS (NZ) Ltd."),
+    (74, "Hitachi, Ltd."),
+    (75, "ABB Robotics Products AB"),
+    (76, "NKE Corporation"),
+    (77, "Rockwell Software, Inc."),
+    (78, "Escort Memory Systems (A Datalogic Group Co.)"),
+    (80, "Industrial Devices Corporation"),
+    (81, "IXXAT Automation GmbH"),
+    (82, "Mitsubishi Electric Automation, Inc."),
+    (83, "OPTO-22"),
+    (86, "Horner Electric"),
+    (87, "Burkert Werke GmbH & Co. KG"),
+    (89, "Industrial Indexing Systems, Inc."),
+    (90, "HMS Industrial Networks AB"),
+    (91, "Robicon"),
+    (92, "Helix Technology (Granville-Phillips)"),
+    (93, "Arlington Laboratory"),
+    (94, "Advantech Co. Ltd."),
+    (95, "Square D Company"),
+    (96, "Digital Electronics Corp."),
+    (97, "Danfoss"),
+    (100, "Bosch Rexroth Corporation, Pneumatics"),
+    (101, "Applied Materials, Inc."),
+    (102, "Showa Electric Wire & Cable Co."),
+    (103, "Pacific Scientific (API Controls Inc.)"),
+    (104, "Sharp Manufacturing Systems Corp."),
+    (105, "Olflex Wire & Cable, Inc."),
+    (107, "Unitrode"),
+    (108, "Beckhoff Automation GmbH"),
+    (109, "National Instruments"),
+    (110, "Mykrolis Corporations (Millipore)"),
+    (111, "International Motion Controls Corp."),
+    (113, "SEG Kempen GmbH"),
+    (116, "MTS Systems Corp."),
+    (117, "Krones, Inc"),
+    (119, "EXOR Electronic R & D"),
+    (120, "SIEI S.p.A."),
+    (121, "KUKA Roboter GmbH"),
+    (123, "SEC (Samsung Electronics Co., Ltd)"),
+    (124, "Binary Electronics Ltd"),
+    (125, "Flexible Machine Controls"),
+    (127, "ABB Inc. (Entrelec)"),
+    (128, "MAC Valves, Inc."),
+    (129, "Auma Actuators Inc"),
+    (130, "Toyoda Machine Works, Ltd"),
+    (133, "Balogh T.A.G., Corporation"),
+    (134, "TR Systemtechnik GmbH"),
+    (135, "UNIPULSE Corporation"),
+    (138, "Conxall Corporation Inc."),
+    (141, "Kuramo Electric Co., Ltd."),
+    (142, "Creative Micro Designs"),
+    (143, "GE Industrial Systems"),
+    (144, "Leybold Vacuum GmbH"),
+    (145, "Siemens Energy & Automation/Drives"),
+    (146, "Kodensha Ltd"),
+    (147, "Motion Engineering, Inc."),
+    (148, "Honda Engineering Co., Ltd"),
+    (149, "EIM Valve Controls"),
+    (150, "Melec Inc."),
+    (151, "Sony Manufacturing Systems Corporation"),
+    (152, "North American Mfg."),
+    (153, "WATLOW"),
+    (154, "Japan Radio Co., Ltd"),
+    (155, "NADEX Co., Ltd"),
+    (156, "Ametek Automation & Process Technologies"),
+    (158, "KVASER AB"),
+    (159, "IDEC IZUMI Corporation"),
+    (160, "Mitsubishi Heavy Industries Ltd"),
+    (161, "Mitsubishi Electric Corporation"),
+    (162, "Horiba-STEC Inc."),
+    (163, "esd electronic system design gmbh"),
+    (164, "DAIHEN Corporation"),
+    (165, "Tyco Valves & Controls/Keystone"),
+    (166, "EBARA Corporation"),
+    (169, "Hokuyo Electric Co. Ltd"),
+    (170, "Pyramid Solutions, Inc."),
+    (171, "Denso Wave Incorporated"),
+    (172, "HLS Hard-Line Solutions Inc"),
+    (173, "Caterpillar, Inc."),
+    (174, "PDL Electronics Ltd."),
+    (176, "Red Lion Controls"),
+    (177, "ANELVA Corporation"),
+    (178, "Toyo Denki Seizo KK"),
+    (179, "Sanyo Denki Co., Ltd"),
+    (180, "Advanced Energy Japan K.K. (Aera Japan)"),
+    (181, "Pilz GmbH & Co"),
+    (182, "Marsh Bellofram-Bellofram PCD Division"),
+    (184, "M-SYSTEM Co. Ltd"),
+    (185, "Nissin Electric Co., Ltd"),
+    (186, "Hitachi Metals Ltd."),
+    (187, "Oriental Motor Company"),
+    (188, "A&D Co., Ltd"),
+    (189, "Phasetronics, Inc."),
+    (190, "Cummins Engine Company"),
+    (191, "Deltron Inc."),
+    (192, "Geneer Corporation"),
+    (193, "Anatol Automation, Inc."),
+    (196, "Medar, Inc."),
+    (197, "Comdel Inc."),
+    (198, "Advanced Energy Industries, Inc"),
+    (200, "DAIDEN Co., Ltd"),
+    (201, "CKD Corporation"),
+    (202, "Toyo Electric Corporation"),
+    (204, "AuCom Electronics Ltd"),
+    (205, "Shinko Electric Co., Ltd"),
+    (206, "Vector Informatik GmbH"),
+    (208, "Moog Inc."),
+    (209, "Contemporary Controls"),
+    (210, "Tokyo Sokki Kenkyujo Co., Ltd"),
+    (211, "Schenck-AccuRate, Inc."),
+    (212, "The Oilgear Company"),
+    (214, "ASM Japan K.K."),
+    (215, "HIRATA Corp."),
+    (216, "SUNX Limited"),
+    (217, "Meidensha Corp."),
+    (218, "NIDEC SANKYO CORPORATION (Sankyo Seiki Mfg. Co., Ltd)"),
+    (219, "KAMRO Corp."),
+    (220, "Nippon System Development Co., Ltd"),
+    (221, "EBARA Technologies Inc."),
+    (224, "SG Co., Ltd"),
+    (225, "Vaasa Institute of Technology"),
+    (226, "MKS Instruments (ENI Technology)"),
+    (227, "Tateyama System Laboratory Co., Ltd."),
+    (228, "QLOG Corporation"),
+    (229, "Matric Limited Inc."),
+    (230, "NSD Corporation"),
+    (232, "Sumitomo Wiring Systems, Ltd"),
+    (233, "Group 3 Technology Ltd"),
+    (234, "CTI Cryogenics"),
+    (235, "POLSYS CORP"),
+    (236, "Ampere Inc."),
+    (238, "Simplatroll Ltd"),
+    (241, "Leading Edge Design"),
+    (242, "Humphrey Products"),
+    (243, "Schneider Automation, Inc."),
+    (244, "Westlock Controls Corp."),
+    (245, "Nihon Weidmuller Co., Ltd"),
+    (246, "Brooks Instrument (Div. of Emerson)"),
+    (248, "Moeller GmbH"),
+    (249, "Varian Vacuum Products"),
+    (250, "Yokogawa Electric Corporation"),
+    (251, "Electrical Design Daiyu Co., Ltd"),
+    (252, "Omron Software Co., Ltd"),
+    (253, "BOC Edwards"),
+    (254, "Control Technology Corporation"),
+    (255, "Bosch Rexroth"),
+    (256, "Turck"),
+    (257, "Control Techniques PLC"),
+    (258, "Hardy Instruments, Inc."),
+    (259, "LS Industrial Systems"),
+    (260, "E.O.A. Systems Inc."),
+    (262, "New Cosmos Electric Co., Ltd."),
+    (263, "Sense Eletronica LTDA"),
+    (264, "Xycom, Inc."),
+    (265, "Baldor Electric"),
+    (267, "Patlite Corporation"),
+    (269, "Mogami Wire & Cable Corporation"),
+    (270, "Welding Technology Corporation (WTC)"),
+    (272, "Deutschmann Automation GmbH"),
+    (273, "ICP Panel-Tec Inc."),
+    (274, "Bray Controls USA"),
+    (276, "Status Technologies"),
+    (277, "Trio Motion Technology Ltd"),
+    (278, "Sherrex Systems Ltd"),
+    (279, "Adept Technology, Inc."),
+    (280, "Spang Power Electronics"),
+    (282, "Acrosser Technology Co., Ltd"),
+    (283, "Hilscher GmbH"),
+    (309, "ProSoft Technology"),
+    (356, "FANUC Robotics America"),
+    (424, "Teknic, Inc."),
+    (678, "Cognex Corporation"),
+    (808, "SICK AG"),
+];
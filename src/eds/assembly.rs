@@ -0,0 +1,86 @@
+use binrw::BinResult;
+
+use crate::eds::syntax::{parse_path_bytes, EdsEntry, EdsReference};
+
+/// A member of an assembly, in order from the start of the assembly data
+#[derive(Debug, PartialEq, Clone)]
+pub struct EdsAssemblyMember {
+    /// The size of the member in bits, `None` when it takes the size of its reference
+    pub size_bits: Option<usize>,
+    /// A parameter or nested assembly, `None` for padding
+    pub reference: Option<EdsReference>,
+}
+
+/*
+[Assembly]
+    Assem100 =
+        "Outputs",              $ name
+        "20 04 24 70 30 03",    $ path
+        4,                      $ size in bytes
+        0x0000,                 $ descriptor
+        ,,                      $ reserved
+        16,Param1,              $ member size in bits, member reference
+        8,,                     $ padding
+        8,Param2;
+*/
+#[derive(Debug, PartialEq, Clone)]
+pub struct EdsAssembly {
+    pub name: String,
+    pub path: Option<String>,
+    /// The size of the assembly data in bytes, if fixed
+    pub size: Option<usize>,
+    pub descriptor: Option<u16>,
+    pub members: Vec<EdsAssemblyMember>,
+}
+
+// ======= Start of EdsAssembly impl ========
+
+impl EdsAssembly {
+    const FIRST_MEMBER_FIELD: usize = 6;
+
+    pub fn from_entry(entry: &EdsEntry) -> BinResult<Self> {
+        let member_fields = entry.fields.len().saturating_sub(Self::FIRST_MEMBER_FIELD);
+
+        let members = (0..member_fields.div_ceil(2))
+            .map(|member_index| {
+                let size_field = Self::FIRST_MEMBER_FIELD + member_index * 2;
+                Ok(EdsAssemblyMember {
+                    size_bits: entry.integer_field(size_field)?,
+                    reference: EdsReference::from_field(entry.field(size_field + 1)),
+                })
+            })
+            .collect::<BinResult<_>>()?;
+
+        Ok(EdsAssembly {
+            name: entry.text_field(0),
+            path: entry
+                .str_field(1)
+                .filter(|path| !path.is_empty())
+                .map(str::to_string),
+            size: entry.integer_field(2)?,
+            descriptor: entry.integer_field(3)?,
+            members,
+        })
+    }
+
+    /// The Assembly object instance from the path, e.g. 0x70 for `"20 04 24 70 30 03"`
+    pub fn instance_id(&self) -> Option<u16> {
+        let path_bytes = parse_path_bytes(self.path.as_deref()?)?;
+
+        path_bytes
+            .iter()
+            .enumerate()
+            .find_map(|(index, segment)| match segment {
+                0x24 => path_bytes
+                    .get(index + 1)
+                    .map(|&instance_id| instance_id as u16),
+                0x25 => Some(u16::from_le_bytes([
+                    *path_bytes.get(index + 2)?,
+                    *path_bytes.get(index + 3)?,
+                ])),
+                _ => None,
+            })
+    }
+}
+
+// ^^^^^^^^ End of EdsAssembly impl ^^^^^^^^
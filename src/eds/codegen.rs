@@ -0,0 +1,478 @@
+use std::collections::BTreeSet;
+use std::fmt::Write;
+use std::path::Path;
+
+use binrw::BinResult;
+
+use crate::cip::types::CipDataType;
+use crate::eds::assembly::EdsAssembly;
+use crate::eds::file::EdsFile;
+use crate::eds::params::EdsParam;
+use crate::eds::syntax::{parse_path_bytes, EdsReference, EdsValue};
+
+/*
+Rust code generated from an EDS, for including from a build script
+
+    // build.rs
+    eipscanne_rs::eds::codegen::generate_rust_file("valve.eds", out_dir.join("valve.rs"))?;
+
+    // src/valve.rs
+    include!(concat!(env!("OUT_DIR"), "/valve.rs"));
+
+Every parameter becomes a binrw newtype, or a repr enum when it has enumerated values, so it can be
+sent as `CipData` to its link path. Every assembly with a fixed size becomes a `CipAssembly` struct
+with a field per parameter member. The generated code names `binrw` and `eipscanne_rs` by path, so
+the including crate needs both as dependencies.
+*/
+
+const RUST_KEYWORDS: [&str; 38] = [
+    "as", "async", "await", "break", "const", "continue", "crate", "dyn", "else", "enum", "extern",
+    "false", "fn", "for", "if", "impl", "in", "let", "loop", "match", "mod", "move", "mut", "pub",
+    "ref", "return", "self", "static", "struct", "super", "trait", "true", "type", "unsafe", "use",
+    "where", "while", "yield",
+];
+
+fn name_words(name: &str) -> Vec<String> {
+    name.split(|character: char| !character.is_ascii_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(str::to_ascii_lowercase)
+        .collect()
+}
+
+fn pascal_case(name: &str, fallback: &str) -> String {
+    let pascal_name: String = name_words(name)
+        .iter()
+        .map(|word| word[..1].to_ascii_uppercase() + &word[1..])
+        .collect();
+
+    match pascal_name.chars().next() {
+        Some(first) if first.is_ascii_alphabetic() => pascal_name,
+        Some(_) => format!("{}{}", fallback, pascal_name),
+        None => fallback.to_string(),
+    }
+}
+
+fn snake_case(name: &str, fallback: &str) -> String {
+    let snake_name = name_words(name).join("_");
+
+    match snake_name.chars().next() {
+        Some(first) if first.is_ascii_alphabetic() => match RUST_KEYWORDS.contains(&&*snake_name) {
+            true => format!("{}_", snake_name),
+            false => snake_name,
+        },
+        Some(_) => format!("{}_{}", fallback, snake_name),
+        None => fallback.to_string(),
+    }
+}
+
+/// Makes a name unique by appending a number, e.g. for two parameters with the same name
+fn unique_name(used_names: &mut BTreeSet<String>, name: String, number: u32) -> String {
+    let name = match used_names.contains(&name) {
+        true => format!("{}{}", name, number),
+        false => name,
+    };
+    used_names.insert(name.clone());
+    name
+}
+
+/// The Rust type of a parameter value, a byte array for anything without a primitive type
+fn rust_type(param: &EdsParam) -> String {
+    let primitive_type = match param.data_type {
+        CipDataType::Bool | CipDataType::Usint | CipDataType::Byte => Some("u8"),
+        CipDataType::Sint => Some("i8"),
+        CipDataType::Uint | CipDataType::Word => Some("u16"),
+        CipDataType::Int => Some("i16"),
+        CipDataType::Udint | CipDataType::Dword => Some("u32"),
+        CipDataType::Dint => Some("i32"),
+        CipDataType::Ulint | CipDataType::Lword => Some("u64"),
+        CipDataType::Lint => Some("i64"),
+        CipDataType::Real => Some("f32"),
+        CipDataType::Lreal => Some("f64"),
+        _ => None,
+    };
+
+    match primitive_type {
+        Some(primitive_type) if param.data_type.element_size() == Some(param.data_size) => {
+            primitive_type.to_string()
+        }
+        _ => format!("[u8; {}]", param.data_size),
+    }
+}
+
+/// A limit or default as a literal of the parameter type, if it fits
+fn value_literal(value: EdsValue, value_type: &str) -> Option<String> {
+    let integer_range: Option<(i128, i128)> = match value_type {
+        "u8" => Some((u8::MIN as i128, u8::MAX as i128)),
+        "i8" => Some((i8::MIN as i128, i8::MAX as i128)),
+        "u16" => Some((u16::MIN as i128, u16::MAX as i128)),
+        "i16" => Some((i16::MIN as i128, i16::MAX as i128)),
+        "u32" => Some((u32::MIN as i128, u32::MAX as i128)),
+        "i32" => Some((i32::MIN as i128, i32::MAX as i128)),
+        "u64" => Some((u64::MIN as i128, u64::MAX as i128)),
+        "i64" => Some((i64::MIN as i128, i64::MAX as i128)),
+        _ => None,
+    };
+
+    match (value, integer_range, value_type) {
+        (EdsValue::Integer(integer), Some((minimum, maximum)), _) => (minimum..=maximum)
+            .contains(&integer)
+            .then(|| integer.to_string()),
+        (value, None, "f32" | "f64") => Some(format!("{:?}", value.as_f64())),
+        _ => None,
+    }
+}
+
+/// A `CipPath` expression for a path like `"20 04 24 96 30 03"`
+fn path_expression(path: &str) -> Option<String> {
+    let path_bytes = parse_path_bytes(path)?;
+
+    // Class, instance and attribute, each as an 8-bit or a 16-bit segment
+    let mut segments = [None; 3];
+    let mut index = 0;
+    while index < path_bytes.len() {
+        let segment_index = match path_bytes[index] & 0xFC {
+            0x20 => 0,
+            0x24 => 1,
+            0x30 => 2,
+            _ => return None,
+        };
+        let (value, length) = match path_bytes[index] & 0x03 {
+            0 => (*path_bytes.get(index + 1)? as u16, 2),
+            1 => (
+                u16::from_le_bytes([*path_bytes.get(index + 2)?, *path_bytes.get(index + 3)?]),
+                4,
+            ),
+            _ => return None,
+        };
+        segments[segment_index] = Some(value);
+        index += length;
+    }
+
+    match segments {
+        [Some(class_id), Some(instance_id), Some(attribute_id)] => Some(format!(
+            "eipscanne_rs::cip::path::CipPath::new(0x{:02X}, 0x{:02X}).with_attribute(0x{:02X})",
+            class_id, instance_id, attribute_id
+        )),
+        _ => None,
+    }
+}
+
+/// Doc comment lines, with the first one as the summary
+fn write_doc_lines(code: &mut String, lines: &[String]) {
+    for (index, line) in lines.iter().filter(|line| !line.is_empty()).enumerate() {
+        if index == 1 {
+            writeln!(code, "///").unwrap();
+        }
+        writeln!(code, "/// {}", line).unwrap();
+    }
+}
+
+fn write_param(code: &mut String, param_number: u32, param: &EdsParam, type_name: &str) {
+    let value_type = rust_type(param);
+
+    let units = match param.units.is_empty() {
+        true => String::new(),
+        false => format!(" ({})", param.units),
+    };
+    write_doc_lines(
+        code,
+        &[
+            format!("Param{}: {}{}", param_number, param.name, units),
+            param.help.clone(),
+        ],
+    );
+
+    let enum_variants: Option<Vec<(String, String)>> = (!param.enum_values.is_empty())
+        .then(|| {
+            let mut used_variants = BTreeSet::new();
+            param
+                .enum_values
+                .iter()
+                .map(|&(value, ref name)| {
+                    let variant = pascal_case(name, &format!("Value{}", value));
+                    Some((
+                        unique_name(&mut used_variants, variant, value as u32),
+                        value_literal(EdsValue::Integer(value), &value_type)?,
+                    ))
+                })
+                .collect::<Option<_>>()
+        })
+        .flatten()
+        .filter(|_| !value_type.starts_with('['));
+
+    match &enum_variants {
+        Some(variants) => {
+            writeln!(code, "#[derive(binrw::BinRead, binrw::BinWrite)]").unwrap();
+            writeln!(code, "#[brw(little, repr = {})]", value_type).unwrap();
+            writeln!(code, "#[derive(Debug, PartialEq, Clone, Copy)]").unwrap();
+            writeln!(code, "pub enum {} {{", type_name).unwrap();
+            for (variant, value) in variants {
+                writeln!(code, "    {} = {},", variant, value).unwrap();
+            }
+            writeln!(code, "}}\n").unwrap();
+        }
+        None => {
+            writeln!(code, "#[binrw::binrw]").unwrap();
+            writeln!(code, "#[brw(little)]").unwrap();
+            writeln!(code, "#[derive(Debug, PartialEq, Clone, Copy)]").unwrap();
+            writeln!(code, "pub struct {}(pub {});\n", type_name, value_type).unwrap();
+        }
+    }
+
+    writeln!(code, "impl {} {{", type_name).unwrap();
+    writeln!(code, "    pub const PARAM_NUMBER: u32 = {};", param_number).unwrap();
+    if enum_variants.is_none() {
+        let limits = [
+            ("MINIMUM", param.minimum),
+            ("MAXIMUM", param.maximum),
+            ("DEFAULT", param.default),
+        ];
+        for (const_name, limit) in limits {
+            if let Some(literal) = limit.and_then(|limit| value_literal(limit, &value_type)) {
+                writeln!(
+                    code,
+                    "    pub const {}: {} = {};",
+                    const_name, value_type, literal
+                )
+                .unwrap();
+            }
+        }
+    }
+    if let Some(path) = param.link_path.as_deref().and_then(path_expression) {
+        writeln!(code).unwrap();
+        writeln!(code, "    /// The attribute holding the parameter value").unwrap();
+        writeln!(
+            code,
+            "    pub fn link_path() -> eipscanne_rs::cip::path::CipPath {{"
+        )
+        .unwrap();
+        writeln!(code, "        {}", path).unwrap();
+        writeln!(code, "    }}").unwrap();
+    }
+    writeln!(code, "}}\n").unwrap();
+}
+
+fn write_assembly(
+    code: &mut String,
+    eds: &EdsFile,
+    assembly_number: u32,
+    assembly: &EdsAssembly,
+    type_name: &str,
+) {
+    let mut fields: Vec<String> = Vec::new();
+    let mut used_fields = BTreeSet::new();
+    let mut bit_offset = 0;
+    let mut field_count = 0;
+
+    for member in &assembly.members {
+        let referenced_param = match member.reference {
+            Some(EdsReference::Param(param_number)) => eds
+                .params
+                .get(&param_number)
+                .map(|param| (param_number, param)),
+            _ => None,
+        };
+        let referenced_assembly = match member.reference {
+            Some(EdsReference::Assembly(nested_number)) => eds
+                .assemblies
+                .get(&nested_number)
+                .map(|nested| (nested_number, nested)),
+            _ => None,
+        };
+
+        let Some(size_bits) = member.size_bits.or_else(|| {
+            referenced_param
+                .map(|(_, param)| param.data_size * 8)
+                .or_else(|| {
+                    referenced_assembly
+                        .and_then(|(_, nested)| nested.size)
+                        .map(|size| size * 8)
+                })
+        }) else {
+            fields.push("    // A member without a size ends the generated layout".to_string());
+            break;
+        };
+
+        let (byte_offset, bit) = (bit_offset / 8, bit_offset % 8);
+        let byte_aligned = bit == 0 && size_bits % 8 == 0;
+
+        let field = match (referenced_param, referenced_assembly) {
+            (Some((param_number, param)), _) => {
+                let field_name = unique_name(
+                    &mut used_fields,
+                    snake_case(&param.name, "param"),
+                    param_number,
+                );
+                let value_type = rust_type(param);
+                let doc = format!("    /// Param{}: {}", param_number, param.name);
+
+                match size_bits {
+                    1 => Some(format!(
+                        "{}\n    #[cip(byte = {}, bit = {})]\n    pub {}: bool,",
+                        doc, byte_offset, bit, field_name
+                    )),
+                    _ if byte_aligned => {
+                        let field_type = match size_bits == param.data_size * 8 {
+                            true => value_type,
+                            false => format!("[u8; {}]", size_bits / 8),
+                        };
+                        Some(format!(
+                            "{}\n    #[cip(byte = {})]\n    pub {}: {},",
+                            doc, byte_offset, field_name, field_type
+                        ))
+                    }
+                    _ => None,
+                }
+            }
+            (None, Some((nested_number, nested))) if byte_aligned => {
+                let field_name = unique_name(
+                    &mut used_fields,
+                    snake_case(&nested.name, "assembly"),
+                    nested_number,
+                );
+                Some(format!(
+                    "    /// Assem{}: {}\n    #[cip(byte = {})]\n    pub {}: [u8; {}],",
+                    nested_number,
+                    nested.name,
+                    byte_offset,
+                    field_name,
+                    size_bits / 8
+                ))
+            }
+            (None, None) if member.reference.is_none() => Some(String::new()),
+            _ => None,
+        };
+
+        match field {
+            Some(field) if field.is_empty() => {}
+            Some(field) => {
+                fields.push(field);
+                field_count += 1;
+            }
+            None => fields.push(format!(
+                "    // Bits {} to {} are not a byte aligned parameter and are not generated",
+                bit_offset,
+                bit_offset + size_bits - 1
+            )),
+        }
+
+        bit_offset += size_bits;
+    }
+
+    let Some(size) = assembly
+        .size
+        .or((bit_offset > 0).then(|| bit_offset.div_ceil(8)))
+    else {
+        writeln!(
+            code,
+            "// Assem{} ({}) has no fixed size and is not generated\n",
+            assembly_number, assembly.name
+        )
+        .unwrap();
+        return;
+    };
+
+    // Without any parameter members the data is left as bytes
+    if field_count == 0 {
+        fields = vec![format!(
+            "    #[cip(byte = 0)]\n    pub data: [u8; {}],",
+            size
+        )];
+    }
+
+    write_doc_lines(
+        code,
+        &[format!("Assem{}: {}", assembly_number, assembly.name)],
+    );
+    writeln!(
+        code,
+        "#[derive(eipscanne_rs::cip::assembly::CipAssembly, Debug, PartialEq, Clone)]"
+    )
+    .unwrap();
+    writeln!(code, "#[cip(size = {})]", size).unwrap();
+    writeln!(code, "pub struct {} {{", type_name).unwrap();
+    for field in &fields {
+        writeln!(code, "{}", field).unwrap();
+    }
+    writeln!(code, "}}\n").unwrap();
+
+    writeln!(code, "impl {} {{", type_name).unwrap();
+    writeln!(
+        code,
+        "    pub const ASSEMBLY_NUMBER: u32 = {};",
+        assembly_number
+    )
+    .unwrap();
+    if let Some(instance_id) = assembly.instance_id() {
+        writeln!(
+            code,
+            "    pub const INSTANCE_ID: u16 = 0x{:02X};",
+            instance_id
+        )
+        .unwrap();
+    }
+    writeln!(code, "}}\n").unwrap();
+
+    writeln!(code, "impl Default for {} {{", type_name).unwrap();
+    writeln!(code, "    fn default() -> Self {{").unwrap();
+    writeln!(
+        code,
+        "        use eipscanne_rs::cip::assembly::CipAssembly;"
+    )
+    .unwrap();
+    writeln!(
+        code,
+        "        Self::read_fields(&[0; <Self as CipAssembly>::SIZE])"
+    )
+    .unwrap();
+    writeln!(code, "    }}").unwrap();
+    writeln!(code, "}}\n").unwrap();
+}
+
+/// Rust types for the parameters and assemblies of an EDS
+pub fn generate_rust(eds: &EdsFile) -> String {
+    let mut code = String::new();
+    let mut used_types = BTreeSet::new();
+
+    writeln!(
+        code,
+        "// Generated by eipscanne_rs from the EDS of {} ({}), revision {}.{}\n",
+        eds.device.product_name,
+        eds.device.vendor_name,
+        eds.device.revision.major,
+        eds.device.revision.minor
+    )
+    .unwrap();
+
+    for (&param_number, param) in &eds.params {
+        let type_name = unique_name(
+            &mut used_types,
+            pascal_case(&param.name, "Param"),
+            param_number,
+        );
+        write_param(&mut code, param_number, param, &type_name);
+    }
+
+    for (&assembly_number, assembly) in &eds.assemblies {
+        let type_name = unique_name(
+            &mut used_types,
+            pascal_case(&assembly.name, "Assembly") + "Assembly",
+            assembly_number,
+        );
+        write_assembly(&mut code, eds, assembly_number, assembly, &type_name);
+    }
+
+    code
+}
+
+/// Parses an EDS file and writes the Rust types for it, e.g. from a build script
+pub fn generate_rust_file(
+    eds_path: impl AsRef<Path>,
+    output_path: impl AsRef<Path>,
+) -> BinResult<()> {
+    let eds_text = std::fs::read_to_string(eds_path)?;
+    let eds = EdsFile::parse(&eds_text)?;
+
+    std::fs::write(output_path, generate_rust(&eds))?;
+    Ok(())
+}
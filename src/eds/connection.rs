@@ -0,0 +1,172 @@
+use binrw::BinResult;
+
+use crate::eds::syntax::{EdsEntry, EdsReference, EdsSection};
+
+/// The RPI, size and format of one direction of a connection, or of a configuration block
+#[derive(Debug, PartialEq, Clone, Default)]
+pub struct EdsConnectionData {
+    /// The requested packet interval in microseconds, or the parameter holding it
+    pub rpi: Option<EdsReference>,
+    pub size: Option<EdsReference>,
+    /// Usually the assembly that describes the data
+    pub format: Option<EdsReference>,
+}
+
+// ======= Start of EdsConnectionData impl ========
+
+impl EdsConnectionData {
+    /// The `AssemN` number of the format, if the data is described by an assembly
+    pub fn assembly(&self) -> Option<u32> {
+        match self.format {
+            Some(EdsReference::Assembly(assembly_number)) => Some(assembly_number),
+            _ => None,
+        }
+    }
+}
+
+// ^^^^^^^^ End of EdsConnectionData impl ^^^^^^^^
+
+/*
+[Connection Manager]
+    Connection1 =
+        0x04010002,             $ trigger and transport
+        0x44640405,             $ connection parameters
+        Param1,Assem100,,       $ O->T RPI, size, format
+        Param1,Assem101,,       $ T->O RPI, size, format
+        ,,                      $ proxy config size, format
+        ,,                      $ target config size, format
+        "Exclusive Owner",      $ connection name
+        "",                     $ help string
+        "20 04 24 96 2C 70 2C 64";  $ path
+*/
+#[derive(Debug, PartialEq, Clone)]
+pub struct EdsConnection {
+    pub trigger_and_transport: u32,
+    pub connection_parameters: u32,
+    pub originator_to_target: EdsConnectionData,
+    pub target_to_originator: EdsConnectionData,
+    pub proxy_config: EdsConnectionData,
+    pub target_config: EdsConnectionData,
+    pub name: String,
+    pub help: String,
+    pub path: String,
+}
+
+// ======= Start of EdsConnection impl ========
+
+impl EdsConnection {
+    pub fn from_entry(entry: &EdsEntry) -> BinResult<Self> {
+        let reference = |index: usize| EdsReference::from_field(entry.field(index));
+
+        Ok(EdsConnection {
+            trigger_and_transport: entry.required_integer(0)?,
+            connection_parameters: entry.required_integer(1)?,
+            originator_to_target: EdsConnectionData {
+                rpi: reference(2),
+                size: reference(3),
+                format: reference(4),
+            },
+            target_to_originator: EdsConnectionData {
+                rpi: reference(5),
+                size: reference(6),
+                format: reference(7),
+            },
+            proxy_config: EdsConnectionData {
+                rpi: None,
+                size: reference(8),
+                format: reference(9),
+            },
+            target_config: EdsConnectionData {
+                rpi: None,
+                size: reference(10),
+                format: reference(11),
+            },
+            name: entry.text_field(12),
+            help: entry.text_field(13),
+            path: entry.text_field(14),
+        })
+    }
+}
+
+// ^^^^^^^^ End of EdsConnection impl ^^^^^^^^
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum TSpecDirection {
+    Transmit,
+    Receive,
+    TransmitReceive,
+}
+
+/// The throughput the device supports for a data size
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct TSpec {
+    pub direction: TSpecDirection,
+    pub data_size: u32,
+    pub packets_per_second: u32,
+}
+
+// ======= Start of TSpec impl ========
+
+impl TSpec {
+    pub fn from_entry(entry: &EdsEntry) -> BinResult<Self> {
+        let direction = match entry.str_field(0) {
+            Some(direction) if direction.eq_ignore_ascii_case("Tx") => TSpecDirection::Transmit,
+            Some(direction) if direction.eq_ignore_ascii_case("Rx") => TSpecDirection::Receive,
+            Some(direction) if direction.eq_ignore_ascii_case("TxRx") => {
+                TSpecDirection::TransmitReceive
+            }
+            _ => return Err(entry.error("Expected a direction of Tx, Rx or TxRx")),
+        };
+
+        Ok(TSpec {
+            direction,
+            data_size: entry.required_integer(1)?,
+            packets_per_second: entry.required_integer(2)?,
+        })
+    }
+}
+
+// ^^^^^^^^ End of TSpec impl ^^^^^^^^
+
+/*
+[Capacity]
+    MaxMsgConnections = 6;
+    MaxIOProducers = 4;
+    MaxIOConsumers = 4;
+    MaxCIPConnections = 10;
+    TSpec1 = TxRx, 32, 2000;
+*/
+#[derive(Debug, PartialEq, Clone, Default)]
+pub struct EdsCapacity {
+    pub max_message_connections: Option<u32>,
+    pub max_io_producers: Option<u32>,
+    pub max_io_consumers: Option<u32>,
+    pub max_cip_connections: Option<u32>,
+    pub tspecs: Vec<TSpec>,
+}
+
+// ======= Start of EdsCapacity impl ========
+
+impl EdsCapacity {
+    pub fn from_section(section: &EdsSection) -> BinResult<Self> {
+        let count = |keyword: &str| -> BinResult<Option<u32>> {
+            match section.entry(keyword) {
+                Some(entry) => entry.integer_field(0),
+                None => Ok(None),
+            }
+        };
+
+        Ok(EdsCapacity {
+            max_message_connections: count("MaxMsgConnections")?,
+            max_io_producers: count("MaxIOProducers")?,
+            max_io_consumers: count("MaxIOConsumers")?,
+            max_cip_connections: count("MaxCIPConnections")?,
+            tspecs: section
+                .numbered_entries("TSpec")
+                .map(|(_, entry)| TSpec::from_entry(entry))
+                .collect::<BinResult<_>>()?,
+        })
+    }
+}
+
+// ^^^^^^^^ End of EdsCapacity impl ^^^^^^^^
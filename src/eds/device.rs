@@ -0,0 +1,211 @@
+use binrw::BinResult;
+
+use crate::cip::identity::{DeviceType, IdentityResponse, Revision, VendorId};
+use crate::eds::syntax::{EdsEntry, EdsSection};
+
+fn required_entry<'a>(section: &'a EdsSection, keyword: &str) -> BinResult<&'a EdsEntry> {
+    section.entry(keyword).ok_or_else(|| binrw::Error::Custom {
+        pos: 0,
+        err: Box::new(format!("[{}] is missing {}", section.name, keyword)),
+    })
+}
+
+fn optional_text(section: &EdsSection, keyword: &str) -> Option<String> {
+    section.entry(keyword).map(|entry| entry.text_field(0))
+}
+
+/*
+[File]
+    DescText = "Widget Valve Manifold";
+    CreateDate = 04-03-2024;
+    CreateTime = 17:51:44;
+    ModDate = 04-03-2024;
+    ModTime = 17:51:44;
+    Revision = 1.2;
+    HomeURL = "https://example.com/widget.eds";
+*/
+#[derive(Debug, PartialEq, Clone, Default)]
+pub struct EdsFileInfo {
+    pub description: String,
+    /// As written in the file, `MM-DD-YYYY`
+    pub create_date: String,
+    pub create_time: String,
+    pub modification_date: Option<String>,
+    pub modification_time: Option<String>,
+    /// The revision of the EDS file itself, not of the device
+    pub revision: String,
+    pub home_url: Option<String>,
+}
+
+// ======= Start of EdsFileInfo impl ========
+
+impl EdsFileInfo {
+    pub fn from_section(section: &EdsSection) -> BinResult<Self> {
+        Ok(EdsFileInfo {
+            description: optional_text(section, "DescText").unwrap_or_default(),
+            create_date: required_entry(section, "CreateDate")?.text_field(0),
+            create_time: required_entry(section, "CreateTime")?.text_field(0),
+            modification_date: optional_text(section, "ModDate"),
+            modification_time: optional_text(section, "ModTime"),
+            revision: required_entry(section, "Revision")?.text_field(0),
+            home_url: optional_text(section, "HomeURL"),
+        })
+    }
+}
+
+// ^^^^^^^^ End of EdsFileInfo impl ^^^^^^^^
+
+/*
+[Device]
+    VendCode = 424;
+    VendName = "Teknic, Inc.";
+    ProdType = 43;
+    ProdTypeStr = "Generic Device";
+    ProdCode = 1;
+    MajRev = 1;
+    MinRev = 9;
+    ProdName = "ClearLink";
+    Catalog = "CLNK-4-13";
+    Icon = "clearlink.ico";
+*/
+#[derive(Debug, PartialEq, Clone)]
+pub struct EdsDevice {
+    pub vendor_id: VendorId,
+    pub vendor_name: String,
+    pub device_type: DeviceType,
+    pub device_type_name: String,
+    pub product_code: u16,
+    pub revision: Revision,
+    pub product_name: String,
+    pub catalog: Option<String>,
+    pub icon: Option<String>,
+}
+
+/// A difference between the identity in an EDS and the one reported by a device
+#[derive(Debug, PartialEq, Clone)]
+pub enum IdentityMismatch {
+    VendorId { eds: VendorId, device: VendorId },
+    DeviceType { eds: DeviceType, device: DeviceType },
+    ProductCode { eds: u16, device: u16 },
+    MajorRevision { eds: u8, device: u8 },
+}
+
+// ======= Start of IdentityMismatch impl ========
+
+impl std::fmt::Display for IdentityMismatch {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            IdentityMismatch::VendorId { eds, device } => {
+                write!(f, "vendor is {} but the EDS is for {}", device, eds)
+            }
+            IdentityMismatch::DeviceType { eds, device } => {
+                write!(f, "device type is {} but the EDS is for {}", device, eds)
+            }
+            IdentityMismatch::ProductCode { eds, device } => {
+                write!(f, "product code is {} but the EDS is for {}", device, eds)
+            }
+            IdentityMismatch::MajorRevision { eds, device } => {
+                write!(f, "major revision is {} but the EDS is for {}", device, eds)
+            }
+        }
+    }
+}
+
+// ^^^^^^^^ End of IdentityMismatch impl ^^^^^^^^
+
+// ======= Start of EdsDevice impl ========
+
+impl EdsDevice {
+    pub fn from_section(section: &EdsSection) -> BinResult<Self> {
+        let major_revision = required_entry(section, "MajRev")?.required_integer(0)?;
+        let minor_revision = required_entry(section, "MinRev")?.required_integer(0)?;
+
+        Ok(EdsDevice {
+            vendor_id: VendorId::from(
+                required_entry(section, "VendCode")?.required_integer::<u16>(0)?,
+            ),
+            vendor_name: optional_text(section, "VendName").unwrap_or_default(),
+            device_type: DeviceType::from(
+                required_entry(section, "ProdType")?.required_integer::<u16>(0)?,
+            ),
+            device_type_name: optional_text(section, "ProdTypeStr").unwrap_or_default(),
+            product_code: required_entry(section, "ProdCode")?.required_integer(0)?,
+            revision: Revision {
+                major: major_revision,
+                minor: minor_revision,
+            },
+            product_name: required_entry(section, "ProdName")?.text_field(0),
+            catalog: optional_text(section, "Catalog"),
+            icon: optional_text(section, "Icon"),
+        })
+    }
+
+    /// How the identity of a device differs from the EDS, empty when the EDS describes the device
+    ///
+    /// The minor revision is not compared, an EDS applies to every minor revision of its major
+    /// revision.
+    pub fn identity_mismatches(&self, identity: &IdentityResponse) -> Vec<IdentityMismatch> {
+        let mut mismatches = Vec::new();
+
+        if self.vendor_id.id() != identity.vendor_id.id() {
+            mismatches.push(IdentityMismatch::VendorId {
+                eds: self.vendor_id,
+                device: identity.vendor_id,
+            });
+        }
+        if self.device_type.code() != identity.device_type.code() {
+            mismatches.push(IdentityMismatch::DeviceType {
+                eds: self.device_type,
+                device: identity.device_type,
+            });
+        }
+        if self.product_code != identity.product_code {
+            mismatches.push(IdentityMismatch::ProductCode {
+                eds: self.product_code,
+                device: identity.product_code,
+            });
+        }
+        if self.revision.major != identity.revision.major {
+            mismatches.push(IdentityMismatch::MajorRevision {
+                eds: self.revision.major,
+                device: identity.revision.major,
+            });
+        }
+
+        mismatches
+    }
+}
+
+// ^^^^^^^^ End of EdsDevice impl ^^^^^^^^
+
+/*
+[Port]
+    Port1 =
+        TCP,                    $ port type
+        "Port A",               $ port name
+        "20 F5 24 01",          $ path to the port's link object
+        2;                      $ port number
+*/
+#[derive(Debug, PartialEq, Clone)]
+pub struct EdsPort {
+    /// The port type keyword, e.g. `TCP`
+    pub port_type: String,
+    pub name: String,
+    pub link_path: Option<String>,
+    pub port_number: Option<u16>,
+}
+
+// ======= Start of EdsPort impl ========
+
+impl EdsPort {
+    pub fn from_entry(entry: &EdsEntry) -> BinResult<Self> {
+        Ok(EdsPort {
+            port_type: entry.text_field(0),
+            name: entry.text_field(1),
+            link_path: entry.str_field(2).map(str::to_string),
+            port_number: entry.integer_field(3)?,
+        })
+    }
+}
+
+// ^^^^^^^^ End of EdsPort impl ^^^^^^^^
@@ -0,0 +1,129 @@
+use std::collections::BTreeMap;
+
+use binrw::BinResult;
+
+use crate::cip::identity::IdentityResponse;
+use crate::eds::assembly::EdsAssembly;
+use crate::eds::connection::{EdsCapacity, EdsConnection};
+use crate::eds::device::{EdsDevice, EdsFileInfo, EdsPort, IdentityMismatch};
+use crate::eds::params::EdsParam;
+use crate::eds::syntax::{parse_sections, EdsEntry, EdsSection};
+
+/// An Electronic Data Sheet, describing the identity, parameters and connections of a device
+///
+/// Sections without a typed model are kept in `sections`, along with the ones that have one.
+#[derive(Debug, PartialEq, Clone)]
+pub struct EdsFile {
+    pub file: EdsFileInfo,
+    pub device: EdsDevice,
+    /// `ParamN` entries by number
+    pub params: BTreeMap<u32, EdsParam>,
+    /// `AssemN` entries by number
+    pub assemblies: BTreeMap<u32, EdsAssembly>,
+    /// `ConnectionN` entries by number
+    pub connections: BTreeMap<u32, EdsConnection>,
+    /// `PortN` entries by number
+    pub ports: BTreeMap<u32, EdsPort>,
+    pub capacity: Option<EdsCapacity>,
+    pub sections: Vec<EdsSection>,
+}
+
+fn read_numbered<T>(
+    section: Option<&EdsSection>,
+    prefix: &str,
+    read_entry: impl Fn(&EdsEntry) -> BinResult<T>,
+) -> BinResult<BTreeMap<u32, T>> {
+    let Some(section) = section else {
+        return Ok(BTreeMap::new());
+    };
+
+    section
+        .numbered_entries(prefix)
+        .map(|(number, entry)| Ok((number, read_entry(entry)?)))
+        .collect()
+}
+
+// ======= Start of EdsFile impl ========
+
+impl EdsFile {
+    pub fn parse(text: &str) -> BinResult<Self> {
+        let sections = parse_sections(text)?;
+
+        let find_section = |name: &str| {
+            sections
+                .iter()
+                .find(|section| section.name.eq_ignore_ascii_case(name))
+        };
+        let required_section = |name: &str| {
+            find_section(name).ok_or_else(|| binrw::Error::Custom {
+                pos: 0,
+                err: Box::new(format!("The EDS has no [{}] section", name)),
+            })
+        };
+
+        let params_section = find_section("Params");
+        let mut params = read_numbered(params_section, "Param", EdsParam::from_entry)?;
+        if let Some(params_section) = params_section {
+            for (param_number, enum_entry) in params_section.numbered_entries("Enum") {
+                let param = params
+                    .get_mut(&param_number)
+                    .ok_or_else(|| enum_entry.error("No parameter with this number"))?;
+                param.read_enum_entry(enum_entry)?;
+            }
+        }
+
+        Ok(EdsFile {
+            file: EdsFileInfo::from_section(required_section("File")?)?,
+            device: EdsDevice::from_section(required_section("Device")?)?,
+            params,
+            assemblies: read_numbered(find_section("Assembly"), "Assem", EdsAssembly::from_entry)?,
+            connections: read_numbered(
+                find_section("Connection Manager"),
+                "Connection",
+                EdsConnection::from_entry,
+            )?,
+            ports: read_numbered(find_section("Port"), "Port", EdsPort::from_entry)?,
+            capacity: find_section("Capacity")
+                .map(EdsCapacity::from_section)
+                .transpose()?,
+            sections: sections.clone(),
+        })
+    }
+
+    /// A section by name, section names are not case sensitive
+    pub fn section(&self, name: &str) -> Option<&EdsSection> {
+        self.sections
+            .iter()
+            .find(|section| section.name.eq_ignore_ascii_case(name))
+    }
+
+    /// Checks that the EDS describes a device, from its Identity object
+    pub fn validate_identity(
+        &self,
+        identity: &IdentityResponse,
+    ) -> Result<(), Vec<IdentityMismatch>> {
+        let mismatches = self.device.identity_mismatches(identity);
+        match mismatches.is_empty() {
+            true => Ok(()),
+            false => Err(mismatches),
+        }
+    }
+
+    /// The assembly at an Assembly object instance
+    pub fn assembly_by_instance(&self, instance_id: u16) -> Option<(u32, &EdsAssembly)> {
+        self.assemblies
+            .iter()
+            .find(|(_, assembly)| assembly.instance_id() == Some(instance_id))
+            .map(|(&assembly_number, assembly)| (assembly_number, assembly))
+    }
+}
+
+impl std::str::FromStr for EdsFile {
+    type Err = binrw::Error;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        EdsFile::parse(text)
+    }
+}
+
+// ^^^^^^^^ End of EdsFile impl ^^^^^^^^
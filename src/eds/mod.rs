@@ -0,0 +1,7 @@
+pub mod assembly;
+pub mod codegen;
+pub mod connection;
+pub mod device;
+pub mod file;
+pub mod params;
+pub mod syntax;
@@ -0,0 +1,128 @@
+use binrw::BinResult;
+
+use crate::cip::parameter::ParameterDescriptor;
+use crate::cip::types::CipDataType;
+use crate::eds::syntax::{parse_integer, EdsEntry, EdsField, EdsValue};
+
+/// The scaling of a parameter, `((value + offset) * multiplier * base) / (divisor * 10^decimal places)`
+/// gives the engineering value
+#[derive(Debug, PartialEq, Clone, Copy, Default)]
+pub struct ParamScaling {
+    pub multiplier: Option<EdsValue>,
+    pub divisor: Option<EdsValue>,
+    pub base: Option<EdsValue>,
+    pub offset: Option<EdsValue>,
+}
+
+/*
+[Params]
+    Param1 =
+        0,                      $ reserved, shall equal 0
+        6,"20 04 24 96 30 03",  $ link path size, link path
+        0x0000,                 $ descriptor
+        0xC7,                   $ data type
+        2,                      $ data size in bytes
+        "Filter Time",          $ name
+        "ms",                   $ units
+        "Input filter time",    $ help string
+        0,1000,20,              $ min, max, default values
+        ,,,,                    $ multiplier, divisor, base, offset scaling
+        ,,,,                    $ multiplier, divisor, base, offset links
+        ;                       $ decimal places
+    Enum1 = 0,"Off",1,"On";
+*/
+#[derive(Debug, PartialEq, Clone)]
+pub struct EdsParam {
+    pub link_path: Option<String>,
+    pub descriptor: ParameterDescriptor,
+    pub data_type: CipDataType,
+    /// The size of the value in bytes
+    pub data_size: usize,
+    pub name: String,
+    pub units: String,
+    pub help: String,
+    pub minimum: Option<EdsValue>,
+    pub maximum: Option<EdsValue>,
+    pub default: Option<EdsValue>,
+    pub scaling: ParamScaling,
+    pub decimal_places: Option<u8>,
+    /// The names of the values from the matching `EnumN` entry, in file order
+    pub enum_values: Vec<(i128, String)>,
+}
+
+fn value_field(entry: &EdsEntry, index: usize) -> BinResult<Option<EdsValue>> {
+    match entry.field(index) {
+        EdsField::Empty => Ok(None),
+        field => EdsValue::from_field(field)
+            .map(Some)
+            .ok_or_else(|| entry.error(&format!("Field {} is not a number", index + 1))),
+    }
+}
+
+// ======= Start of EdsParam impl ========
+
+impl EdsParam {
+    pub fn from_entry(entry: &EdsEntry) -> BinResult<Self> {
+        let data_type_code: u8 = entry.required_integer(4)?;
+
+        Ok(EdsParam {
+            link_path: entry
+                .str_field(2)
+                .filter(|path| !path.is_empty())
+                .map(str::to_string),
+            descriptor: ParameterDescriptor::from(entry.integer_field::<u16>(3)?.unwrap_or_default()),
+            data_type: CipDataType::from_code(data_type_code),
+            data_size: entry.required_integer(5)?,
+            name: entry.text_field(6),
+            units: entry.text_field(7),
+            help: entry.text_field(8),
+            minimum: value_field(entry, 9)?,
+            maximum: value_field(entry, 10)?,
+            default: value_field(entry, 11)?,
+            scaling: ParamScaling {
+                multiplier: value_field(entry, 12)?,
+                divisor: value_field(entry, 13)?,
+                base: value_field(entry, 14)?,
+                offset: value_field(entry, 15)?,
+            },
+            decimal_places: entry.integer_field(20)?,
+            enum_values: Vec::new(),
+        })
+    }
+
+    /// Reads the `EnumN` entry of the parameter, value and name pairs
+    pub fn read_enum_entry(&mut self, entry: &EdsEntry) -> BinResult<()> {
+        self.enum_values = entry
+            .fields
+            .chunks(2)
+            .enumerate()
+            .map(|(pair_index, pair)| {
+                let value = pair[0].as_str().and_then(parse_integer).ok_or_else(|| {
+                    entry.error(&format!("Enum value {} is missing", pair_index + 1))
+                })?;
+                let name = pair.get(1).and_then(EdsField::as_str).unwrap_or_default();
+                Ok((value, name.to_string()))
+            })
+            .collect::<BinResult<_>>()?;
+
+        Ok(())
+    }
+
+    /// The name of a value of an enumerated parameter
+    pub fn enum_name(&self, value: i128) -> Option<&str> {
+        self.enum_values
+            .iter()
+            .find(|(enum_value, _)| *enum_value == value)
+            .map(|(_, name)| name.as_str())
+    }
+
+    /// Whether a value is within the limits, open limits are not checked
+    pub fn is_in_range(&self, value: EdsValue) -> bool {
+        let above_minimum = self.minimum.is_none_or(|minimum| value >= minimum);
+        let below_maximum = self.maximum.is_none_or(|maximum| value <= maximum);
+
+        above_minimum && below_maximum
+    }
+}
+
+// ^^^^^^^^ End of EdsParam impl ^^^^^^^^
@@ -0,0 +1,446 @@
+use std::iter::Peekable;
+use std::str::CharIndices;
+
+use binrw::BinResult;
+
+/*
+The EDS syntax, shared by every section
+
+    $ Comments run to the end of the line
+    [Section Name]
+        Keyword = field, "quoted field", , field;
+
+Values span lines until the terminating semicolon, fields are separated by commas and may be empty,
+and adjacent quoted strings are joined into one field.
+*/
+
+/// A single comma separated field of an entry
+#[derive(Debug, PartialEq, Clone)]
+pub enum EdsField {
+    Empty,
+    /// A quoted string, with adjacent strings joined
+    Text(String),
+    /// An unquoted value, e.g. a number, a date or a reference like `Param1`
+    Token(String),
+}
+
+// ======= Start of EdsField impl ========
+
+impl EdsField {
+    pub fn is_empty(&self) -> bool {
+        matches!(self, EdsField::Empty)
+    }
+
+    /// The quoted or unquoted contents, `None` for an empty field
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            EdsField::Empty => None,
+            EdsField::Text(text) | EdsField::Token(text) => Some(text),
+        }
+    }
+}
+
+// ^^^^^^^^ End of EdsField impl ^^^^^^^^
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct EdsEntry {
+    pub keyword: String,
+    pub fields: Vec<EdsField>,
+    /// Byte offset of the keyword in the file
+    pub position: usize,
+    pub line: usize,
+}
+
+// ======= Start of EdsEntry impl ========
+
+impl EdsEntry {
+    /// The field at a zero-based index, missing trailing fields are treated as empty
+    pub fn field(&self, index: usize) -> &EdsField {
+        self.fields.get(index).unwrap_or(&EdsField::Empty)
+    }
+
+    pub fn str_field(&self, index: usize) -> Option<&str> {
+        self.field(index).as_str()
+    }
+
+    /// The text of a field, empty for an empty field
+    pub fn text_field(&self, index: usize) -> String {
+        self.str_field(index).unwrap_or_default().to_string()
+    }
+
+    /// The integer in a field, `None` for an empty field and an error for anything else
+    pub fn integer_field<T: TryFrom<i128>>(&self, index: usize) -> BinResult<Option<T>> {
+        let Some(token) = self.str_field(index) else {
+            return Ok(None);
+        };
+
+        parse_integer(token)
+            .and_then(|integer| T::try_from(integer).ok())
+            .map(Some)
+            .ok_or_else(|| {
+                self.error(&format!(
+                    "Field {} is not a valid {}: {:?}",
+                    index + 1,
+                    std::any::type_name::<T>(),
+                    token
+                ))
+            })
+    }
+
+    /// The integer in a field that must be present
+    pub fn required_integer<T: TryFrom<i128>>(&self, index: usize) -> BinResult<T> {
+        self.integer_field(index)?
+            .ok_or_else(|| self.error(&format!("Field {} is missing", index + 1)))
+    }
+
+    /// The number following a keyword prefix, e.g. 12 for `Param12` and the prefix `Param`
+    pub fn keyword_number(&self, prefix: &str) -> Option<u32> {
+        let keyword_prefix = self.keyword.get(..prefix.len())?;
+        if !keyword_prefix.eq_ignore_ascii_case(prefix) {
+            return None;
+        }
+        self.keyword[prefix.len()..].parse().ok()
+    }
+
+    pub fn error(&self, message: &str) -> binrw::Error {
+        binrw::Error::Custom {
+            pos: self.position as u64,
+            err: Box::new(format!(
+                "Line {} ({}): {}",
+                self.line, self.keyword, message
+            )),
+        }
+    }
+}
+
+// ^^^^^^^^ End of EdsEntry impl ^^^^^^^^
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct EdsSection {
+    pub name: String,
+    pub entries: Vec<EdsEntry>,
+}
+
+// ======= Start of EdsSection impl ========
+
+impl EdsSection {
+    /// The first entry with a keyword, keywords are not case sensitive
+    pub fn entry(&self, keyword: &str) -> Option<&EdsEntry> {
+        self.entries
+            .iter()
+            .find(|entry| entry.keyword.eq_ignore_ascii_case(keyword))
+    }
+
+    /// The entries numbered with a keyword prefix, e.g. `Param1`, `Param2`, ... for `Param`
+    pub fn numbered_entries<'a>(
+        &'a self,
+        prefix: &'a str,
+    ) -> impl Iterator<Item = (u32, &'a EdsEntry)> + 'a {
+        self.entries
+            .iter()
+            .filter_map(move |entry| Some((entry.keyword_number(prefix)?, entry)))
+    }
+}
+
+// ^^^^^^^^ End of EdsSection impl ^^^^^^^^
+
+struct EdsReader<'a> {
+    text: &'a str,
+    chars: Peekable<CharIndices<'a>>,
+    line: usize,
+}
+
+// ======= Start of EdsReader impl ========
+
+impl<'a> EdsReader<'a> {
+    fn new(text: &'a str) -> Self {
+        EdsReader {
+            text,
+            chars: text.char_indices().peekable(),
+            line: 1,
+        }
+    }
+
+    fn error(&mut self, message: &str) -> binrw::Error {
+        let position = self.position();
+        binrw::Error::Custom {
+            pos: position as u64,
+            err: Box::new(format!("Line {}: {}", self.line, message)),
+        }
+    }
+
+    fn position(&mut self) -> usize {
+        self.chars
+            .peek()
+            .map_or(self.text.len(), |&(position, _)| position)
+    }
+
+    fn peek(&mut self) -> Option<char> {
+        self.chars.peek().map(|&(_, character)| character)
+    }
+
+    fn next(&mut self) -> Option<char> {
+        let (_, character) = self.chars.next()?;
+        if character == '\n' {
+            self.line += 1;
+        }
+        Some(character)
+    }
+
+    /// Skips whitespace and comments up to the next meaningful character
+    fn skip_blank(&mut self) {
+        while let Some(character) = self.peek() {
+            match character {
+                '$' => {
+                    while self.peek().is_some_and(|character| character != '\n') {
+                        self.next();
+                    }
+                }
+                character if character.is_whitespace() => {
+                    self.next();
+                }
+                _ => break,
+            }
+        }
+    }
+
+    fn read_until(&mut self, stop: impl Fn(char) -> bool) -> &'a str {
+        let start = self.position();
+        while self.peek().is_some_and(|character| !stop(character)) {
+            self.next();
+        }
+        let end = self.position();
+        &self.text[start..end]
+    }
+
+    fn read_section_name(&mut self) -> BinResult<String> {
+        self.next(); // [
+        let name = self.read_until(|character| character == ']' || character == '\n');
+
+        match self.next() {
+            Some(']') => Ok(name.trim().to_string()),
+            _ => Err(self.error("Unterminated section name")),
+        }
+    }
+
+    fn read_quoted(&mut self) -> BinResult<String> {
+        let mut text = String::new();
+
+        // Adjacent strings, even on separate lines, are one field
+        while self.peek() == Some('"') {
+            self.next();
+            loop {
+                match self.next() {
+                    Some('"') => break,
+                    Some('\\') => match self.next() {
+                        Some('n') => text.push('\n'),
+                        Some('t') => text.push('\t'),
+                        Some(escaped) => text.push(escaped),
+                        None => return Err(self.error("Unterminated string")),
+                    },
+                    Some(character) => text.push(character),
+                    None => return Err(self.error("Unterminated string")),
+                }
+            }
+            self.skip_blank();
+        }
+
+        Ok(text)
+    }
+
+    fn read_field(&mut self) -> BinResult<EdsField> {
+        self.skip_blank();
+
+        match self.peek() {
+            Some(',') | Some(';') => Ok(EdsField::Empty),
+            Some('"') => Ok(EdsField::Text(self.read_quoted()?)),
+            Some(_) => {
+                let token = self.read_until(|character| matches!(character, ',' | ';' | '$'));
+                Ok(EdsField::Token(token.trim().to_string()))
+            }
+            None => Err(self.error("Missing ';' at the end of the entry")),
+        }
+    }
+
+    fn read_entry(&mut self) -> BinResult<EdsEntry> {
+        let position = self.position();
+        let line = self.line;
+
+        let keyword = self.read_until(|character| matches!(character, '=' | ';' | '[' | '\n'));
+        if self.next() != Some('=') {
+            return Err(self.error(&format!("Expected '=' after {:?}", keyword.trim())));
+        }
+
+        let mut fields = vec![self.read_field()?];
+        loop {
+            self.skip_blank();
+            match self.next() {
+                Some(',') => fields.push(self.read_field()?),
+                Some(';') => break,
+                _ => return Err(self.error("Expected ',' or ';' after a field")),
+            }
+        }
+
+        // `Keyword = ;` has no fields rather than a single empty one
+        if fields == [EdsField::Empty] {
+            fields.clear();
+        }
+
+        Ok(EdsEntry {
+            keyword: keyword.trim().to_string(),
+            fields,
+            position,
+            line,
+        })
+    }
+}
+
+// ^^^^^^^^ End of EdsReader impl ^^^^^^^^
+
+/// Splits the text of an EDS file into its sections and entries, without interpreting them
+pub fn parse_sections(text: &str) -> BinResult<Vec<EdsSection>> {
+    let mut reader = EdsReader::new(text);
+    let mut sections: Vec<EdsSection> = Vec::new();
+
+    loop {
+        reader.skip_blank();
+
+        match reader.peek() {
+            None => return Ok(sections),
+            Some('[') => {
+                let name = reader.read_section_name()?;
+                sections.push(EdsSection {
+                    name,
+                    entries: Vec::new(),
+                });
+            }
+            Some(_) => {
+                let entry = reader.read_entry()?;
+                match sections.last_mut() {
+                    Some(section) => section.entries.push(entry),
+                    None => return Err(entry.error("Entry outside of a section")),
+                }
+            }
+        }
+    }
+}
+
+/// Parses a decimal, `0x` hexadecimal or `0b` binary integer
+pub fn parse_integer(token: &str) -> Option<i128> {
+    let (negative, digits) = match token.trim().strip_prefix('-') {
+        Some(digits) => (true, digits),
+        None => (false, token.trim()),
+    };
+
+    let value = if let Some(hex_digits) = digits
+        .strip_prefix("0x")
+        .or_else(|| digits.strip_prefix("0X"))
+    {
+        i128::from_str_radix(hex_digits, 16).ok()?
+    } else if let Some(binary_digits) = digits
+        .strip_prefix("0b")
+        .or_else(|| digits.strip_prefix("0B"))
+    {
+        i128::from_str_radix(binary_digits, 2).ok()?
+    } else {
+        digits.parse().ok()?
+    };
+
+    Some(if negative { -value } else { value })
+}
+
+/// Parses a path written as hex bytes, e.g. `"20 04 24 64 30 03"`
+///
+/// Paths with symbolic parts, like `[Param1]` references, are not plain bytes and give `None`.
+pub fn parse_path_bytes(path: &str) -> Option<Vec<u8>> {
+    path.split_whitespace()
+        .map(|byte| u8::from_str_radix(byte, 16).ok())
+        .collect()
+}
+
+/// A numeric field, e.g. a parameter limit or default
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum EdsValue {
+    /// Wide enough for any CIP integer, from LINT to ULINT
+    Integer(i128),
+    Real(f64),
+}
+
+// ======= Start of EdsValue impl ========
+
+impl EdsValue {
+    pub fn parse(token: &str) -> Option<Self> {
+        match parse_integer(token) {
+            Some(integer) => Some(EdsValue::Integer(integer)),
+            None => token.trim().parse().ok().map(EdsValue::Real),
+        }
+    }
+
+    pub fn from_field(field: &EdsField) -> Option<Self> {
+        match field {
+            EdsField::Token(token) => Self::parse(token),
+            _ => None,
+        }
+    }
+
+    pub fn as_f64(&self) -> f64 {
+        match *self {
+            EdsValue::Integer(integer) => integer as f64,
+            EdsValue::Real(real) => real,
+        }
+    }
+}
+
+/// Integers compare exactly, anything involving a real compares as `f64`
+impl PartialOrd for EdsValue {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        match (self, other) {
+            (EdsValue::Integer(integer), EdsValue::Integer(other_integer)) => {
+                integer.partial_cmp(other_integer)
+            }
+            _ => self.as_f64().partial_cmp(&other.as_f64()),
+        }
+    }
+}
+
+// ^^^^^^^^ End of EdsValue impl ^^^^^^^^
+
+/// A field that refers to another entry of the file, or holds a value directly
+#[derive(Debug, PartialEq, Clone)]
+pub enum EdsReference {
+    /// `ParamN`, an entry of the `[Params]` section
+    Param(u32),
+    /// `AssemN`, an entry of the `[Assembly]` section
+    Assembly(u32),
+    Value(EdsValue),
+    Other(String),
+}
+
+// ======= Start of EdsReference impl ========
+
+impl EdsReference {
+    pub fn from_field(field: &EdsField) -> Option<Self> {
+        let text = field.as_str()?;
+
+        let numbered = |prefix: &str| {
+            let text_prefix = text.get(..prefix.len())?;
+            match text_prefix.eq_ignore_ascii_case(prefix) {
+                true => text[prefix.len()..].parse::<u32>().ok(),
+                false => None,
+            }
+        };
+
+        if let Some(param_number) = numbered("Param") {
+            Some(EdsReference::Param(param_number))
+        } else if let Some(assembly_number) = numbered("Assem") {
+            Some(EdsReference::Assembly(assembly_number))
+        } else {
+            match EdsValue::from_field(field) {
+                Some(value) => Some(EdsReference::Value(value)),
+                None => Some(EdsReference::Other(text.to_string())),
+            }
+        }
+    }
+}
+
+// ^^^^^^^^ End of EdsReference impl ^^^^^^^^
@@ -118,24 +118,15 @@ impl BinWrite for EnIpPacketDescription {
         let mut temp_buffer = Vec::new();
         let mut temp_writer = std::io::Cursor::new(&mut temp_buffer);
 
-        let data_write_result =
-            self.command_specific_data
-                .write_options(&mut temp_writer, endian, args);
-
-        if let Err(write_err) = data_write_result {
-            return Err(write_err);
-        };
+        self.command_specific_data
+            .write_options(&mut temp_writer, endian, args)?;
 
         // Step 2: Calculate the total data size after header
         let full_proceeding_data_length = (temp_buffer.len() as u16) + args.0;
 
         // Step 3: Write the full struct to the actual writer
-        if let Err(write_err) =
-            self.header
-                .write_options(writer, endian, (full_proceeding_data_length,))
-        {
-            return Err(write_err);
-        }
+        self.header
+            .write_options(writer, endian, (full_proceeding_data_length,))?;
 
         if let Err(write_err) = writer.write(&temp_buffer) {
             return Err(binrw::Error::Io(write_err));
@@ -1,3 +1,14 @@
+//! EtherNet/IP and CIP messages, without any I/O
+//!
+//! Reads and transfers that take more than one request, like `cip::class::ClassAttributesRead` or
+//! `logix::tag::FragmentedRead`, are state machines. Send their `next_request()` and pass its
+//! response to `handle_response()` until `is_complete()`. Attribute reads skip the attributes a
+//! device does not support instead of failing, and stop early when the object does not exist (see
+//! `ResponseData::is_attribute_unsupported()` and `ResponseData::is_object_missing()`).
+
 pub mod cip;
+pub mod eds;
 pub mod eip;
+pub mod logix;
 pub mod object_assembly;
+pub mod pccc;
@@ -0,0 +1,4 @@
+pub mod shared;
+pub mod symbol;
+pub mod tag;
+pub mod template;
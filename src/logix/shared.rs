@@ -0,0 +1,150 @@
+use binrw::{
+    binrw, // #[binrw] attribute
+};
+
+use bilge::prelude::u7;
+
+use crate::cip::message::shared::ServiceCode;
+use crate::cip::types::CipUint;
+
+/// Object specific services supported by Logix controllers
+///
+/// These codes are only meaningful on the paths they are sent to, which is why they are not part
+/// of the common `ServiceCode` enum.
+#[derive(Debug, PartialEq, Clone, Copy)]
+#[repr(u8)]
+pub enum LogixServiceCode {
+    ReadTag = 0x4C,
+    WriteTag = 0x4D,
+    ReadModifyWriteTag = 0x4E,
+    ReadTagFragmented = 0x52,
+    WriteTagFragmented = 0x53,
+    GetInstanceAttributeList = 0x55,
+}
+
+impl From<LogixServiceCode> for ServiceCode {
+    fn from(service_code: LogixServiceCode) -> Self {
+        ServiceCode::from(u7::new(service_code as u8))
+    }
+}
+
+#[binrw]
+#[brw(little)]
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum LogixDataType {
+    #[brw(magic = 0x00C1u16)]
+    Bool,
+    #[brw(magic = 0x00C2u16)]
+    Sint,
+    #[brw(magic = 0x00C3u16)]
+    Int,
+    #[brw(magic = 0x00C4u16)]
+    Dint,
+    #[brw(magic = 0x00C5u16)]
+    Lint,
+    #[brw(magic = 0x00C6u16)]
+    Usint,
+    #[brw(magic = 0x00C7u16)]
+    Uint,
+    #[brw(magic = 0x00C8u16)]
+    Udint,
+    #[brw(magic = 0x00C9u16)]
+    Ulint,
+    #[brw(magic = 0x00CAu16)]
+    Real,
+    #[brw(magic = 0x00CBu16)]
+    Lreal,
+    #[brw(magic = 0x00D1u16)]
+    Byte,
+    #[brw(magic = 0x00D2u16)]
+    Word,
+    #[brw(magic = 0x00D3u16)]
+    Dword,
+    #[brw(magic = 0x00D4u16)]
+    Lword,
+    #[brw(magic = 0x02A0u16)]
+    Structure,
+    Unknown(u16),
+}
+
+// ======= Start of LogixDataType impl ========
+
+impl LogixDataType {
+    pub fn from_code(type_code: u16) -> Self {
+        use LogixDataType::*;
+        match type_code {
+            0x00C1 => Bool,
+            0x00C2 => Sint,
+            0x00C3 => Int,
+            0x00C4 => Dint,
+            0x00C5 => Lint,
+            0x00C6 => Usint,
+            0x00C7 => Uint,
+            0x00C8 => Udint,
+            0x00C9 => Ulint,
+            0x00CA => Real,
+            0x00CB => Lreal,
+            0x00D1 => Byte,
+            0x00D2 => Word,
+            0x00D3 => Dword,
+            0x00D4 => Lword,
+            0x02A0 => Structure,
+            unknown_code => Unknown(unknown_code),
+        }
+    }
+
+    /// The number of bytes taken up by a single element of an atomic type
+    pub fn element_size(&self) -> Option<usize> {
+        use LogixDataType::*;
+        match self {
+            Bool | Sint | Usint | Byte => Some(1),
+            Int | Uint | Word => Some(2),
+            Dint | Udint | Real | Dword => Some(4),
+            Lint | Ulint | Lreal | Lword => Some(8),
+            Structure | Unknown(_) => None,
+        }
+    }
+
+    /// Whether the type can be modified bit by bit with the Read-Modify-Write Tag service
+    pub fn is_bitwise(&self) -> bool {
+        use LogixDataType::*;
+        matches!(
+            self,
+            Sint | Int | Dint | Lint | Usint | Uint | Udint | Ulint | Byte | Word | Dword | Lword
+        )
+    }
+}
+
+// ^^^^^^^^ End of LogixDataType impl ^^^^^^^^
+
+/// The type information that precedes tag data in read replies and write requests
+#[binrw]
+#[brw(little)]
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct TagType {
+    pub data_type: LogixDataType,
+
+    // Structures are followed by the handle (CRC) of their template
+    #[br(if(data_type == LogixDataType::Structure))]
+    pub structure_handle: Option<CipUint>,
+}
+
+// ======= Start of TagType impl ========
+
+impl TagType {
+    pub fn new_atomic(data_type: LogixDataType) -> Self {
+        TagType {
+            data_type,
+            structure_handle: None,
+        }
+    }
+
+    pub fn new_structure(structure_handle: CipUint) -> Self {
+        TagType {
+            data_type: LogixDataType::Structure,
+            structure_handle: Some(structure_handle),
+        }
+    }
+}
+
+// ^^^^^^^^ End of TagType impl ^^^^^^^^
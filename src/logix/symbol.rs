@@ -0,0 +1,220 @@
+use binrw::{
+    binrw,    // #[binrw] attribute
+    helpers::until_eof,
+    BinRead,  // trait for reading
+    BinResult,
+    BinWrite, // trait for writing
+};
+
+use bilge::prelude::{bitsize, u12, u2, Bitsized, DebugBits, FromBits, Number};
+
+use crate::cip::message::attribute_list::GetAttributeListRequest;
+use crate::cip::message::request::MessageRouterRequest;
+use crate::cip::message::response::{MessageRouterResponse, ResponseStatusCode};
+use crate::cip::path::{
+    CipPath, LogicalPathSegment, LogicalSegmentType, RequestPath, SymbolicPath,
+    SymbolicPathSegment, SymbolicSegment,
+};
+use crate::cip::types::{CipString, CipUdint, CipUint};
+
+use super::shared::{LogixDataType, LogixServiceCode};
+
+pub const SYMBOL_CLASS_ID: u16 = 0x6B;
+
+/// Prefix of the controller scoped symbols that represent programs
+pub const PROGRAM_PREFIX: &str = "Program:";
+
+/// The Symbol object attributes requested for every entry: name, type and array dimensions
+pub const SYMBOL_ATTRIBUTE_IDS: [CipUint; 3] = [0x01, 0x02, 0x08];
+
+#[bitsize(16)]
+#[derive(FromBits, PartialEq, DebugBits, BinRead, BinWrite, Copy, Clone)]
+#[br(map = u16::into)]
+#[bw(map = |&x| u16::from(x))]
+pub struct SymbolType {
+    // Atomic type code (with the bit position of BOOLs) or the template instance of a structure
+    pub type_code: u12,
+    pub system: bool,
+    pub dimension_count: u2,
+    pub structure: bool,
+}
+
+// ======= Start of SymbolType impl ========
+
+impl SymbolType {
+    pub fn data_type(&self) -> LogixDataType {
+        if self.structure() {
+            return LogixDataType::Structure;
+        }
+
+        LogixDataType::from_code(self.type_code().value() & 0x00FF)
+    }
+
+    /// The instance of the Template object describing the structure
+    pub fn template_instance_id(&self) -> Option<CipUint> {
+        self.structure().then(|| self.type_code().value())
+    }
+}
+
+// ^^^^^^^^ End of SymbolType impl ^^^^^^^^
+
+#[binrw]
+#[brw(little)]
+#[derive(Debug, PartialEq, Clone)]
+pub struct SymbolEntry {
+    pub instance_id: CipUdint,
+    pub name: CipString,
+    pub symbol_type: SymbolType,
+    pub array_dimensions: [CipUdint; 3],
+}
+
+// ======= Start of SymbolEntry impl ========
+
+impl SymbolEntry {
+    pub fn name(&self) -> String {
+        String::from(self.name.clone())
+    }
+
+    /// The size of each array dimension that is actually in use
+    pub fn dimensions(&self) -> Vec<CipUdint> {
+        let dimension_count = self.symbol_type.dimension_count().value() as usize;
+        self.array_dimensions[..dimension_count].to_vec()
+    }
+
+    /// The name of the program, if this controller scoped symbol is a program
+    pub fn program_name(&self) -> Option<String> {
+        self.name()
+            .strip_prefix(PROGRAM_PREFIX)
+            .map(|program_name| program_name.to_string())
+    }
+}
+
+// ^^^^^^^^ End of SymbolEntry impl ^^^^^^^^
+
+#[binrw]
+#[brw(little)]
+#[derive(Debug, PartialEq)]
+pub struct SymbolAttributeListReply {
+    #[br(parse_with = until_eof)]
+    pub entries: Vec<SymbolEntry>,
+}
+
+fn instance_segment(instance_id: CipUdint) -> LogicalPathSegment {
+    match instance_id {
+        0..=0xFFFF => {
+            LogicalPathSegment::new_u16(LogicalSegmentType::InstanceId, instance_id as u16)
+        }
+        _ => LogicalPathSegment::new_u32(LogicalSegmentType::InstanceId, instance_id),
+    }
+}
+
+/// Lists the symbols of the controller or of a single program, one page of instances at a time
+#[derive(Debug)]
+pub struct SymbolBrowse {
+    program_name: Option<String>,
+    next_instance_id: CipUdint,
+    symbols: Vec<SymbolEntry>,
+    complete: bool,
+}
+
+// ======= Start of SymbolBrowse impl ========
+
+impl SymbolBrowse {
+    pub fn new_controller_scope() -> Self {
+        SymbolBrowse {
+            program_name: None,
+            next_instance_id: 0,
+            symbols: Vec::new(),
+            complete: false,
+        }
+    }
+
+    pub fn new_program_scope(program_name: &str) -> Self {
+        SymbolBrowse {
+            program_name: Some(program_name.to_string()),
+            ..Self::new_controller_scope()
+        }
+    }
+
+    pub fn is_complete(&self) -> bool {
+        self.complete
+    }
+
+    fn request_path(&self) -> RequestPath {
+        let class_segment = match u8::try_from(SYMBOL_CLASS_ID) {
+            Ok(class_id) => LogicalPathSegment::new_u8(LogicalSegmentType::ClassId, class_id),
+            Err(_) => LogicalPathSegment::new_u16(LogicalSegmentType::ClassId, SYMBOL_CLASS_ID),
+        };
+
+        match &self.program_name {
+            None => CipPath {
+                class_id_segment: class_segment,
+                instance_id_segment: instance_segment(self.next_instance_id),
+                attribute_id_segment: None,
+            }
+            .into(),
+            Some(program_name) => SymbolicPath {
+                segments: vec![
+                    SymbolicPathSegment::Symbol(SymbolicSegment::new(&format!(
+                        "{}{}",
+                        PROGRAM_PREFIX, program_name
+                    ))),
+                    SymbolicPathSegment::Logical(class_segment),
+                    SymbolicPathSegment::Logical(instance_segment(self.next_instance_id)),
+                ],
+            }
+            .into(),
+        }
+    }
+
+    pub fn next_request(&self) -> Option<MessageRouterRequest> {
+        if self.complete {
+            return None;
+        }
+
+        Some(MessageRouterRequest::new_data(
+            LogixServiceCode::GetInstanceAttributeList.into(),
+            self.request_path(),
+            // Get Instance Attribute List takes the same attribute list as Get Attribute List
+            Some(Box::new(GetAttributeListRequest::new(
+                SYMBOL_ATTRIBUTE_IDS.to_vec(),
+            ))),
+        ))
+    }
+
+    pub fn handle_response(&mut self, response: &MessageRouterResponse) -> BinResult<()> {
+        // A partial transfer means there are more instances after the last one in this page
+        let partial_transfer = response.response_data.status == ResponseStatusCode::PartialTransfer;
+        if !partial_transfer {
+            response.check_status()?;
+        }
+
+        let page: SymbolAttributeListReply = response.response_data.read_reply()?;
+
+        match page.entries.last() {
+            Some(last_entry) => self.next_instance_id = last_entry.instance_id + 1,
+            None if partial_transfer => {
+                return Err(binrw::Error::Custom {
+                    pos: 0,
+                    err: Box::new("Partial transfer did not contain any symbols".to_string()),
+                });
+            }
+            None => {}
+        }
+
+        self.symbols.extend(page.entries);
+        self.complete = !partial_transfer;
+
+        Ok(())
+    }
+
+    pub fn symbols(&self) -> &[SymbolEntry] {
+        &self.symbols
+    }
+
+    pub fn into_symbols(self) -> Vec<SymbolEntry> {
+        self.symbols
+    }
+}
+
+// ^^^^^^^^ End of SymbolBrowse impl ^^^^^^^^
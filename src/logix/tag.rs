@@ -0,0 +1,460 @@
+use binrw::{
+    binrw,    // #[binrw] attribute
+    helpers::until_eof,
+    BinResult,
+};
+
+use crate::cip::message::request::MessageRouterRequest;
+use crate::cip::message::response::{MessageRouterResponse, ResponseStatusCode};
+use crate::cip::path::SymbolicPath;
+use crate::cip::types::{CipByte, CipUdint, CipUint};
+
+use super::shared::{LogixDataType, LogixServiceCode, TagType};
+
+/// Number of data bytes sent in each Write Tag Fragmented request
+///
+/// Unconnected messages are limited to 504 bytes, which leaves room for the tag path and header.
+pub const DEFAULT_FRAGMENT_SIZE: usize = 400;
+
+#[binrw]
+#[brw(little)]
+#[derive(Debug, PartialEq)]
+pub struct ReadTagRequest {
+    pub element_count: CipUint,
+}
+
+#[binrw]
+#[brw(little)]
+#[derive(Debug, PartialEq)]
+pub struct ReadTagFragmentedRequest {
+    pub element_count: CipUint,
+    pub byte_offset: CipUdint,
+}
+
+#[binrw]
+#[brw(little)]
+#[derive(Debug, PartialEq)]
+pub struct WriteTagRequest {
+    pub tag_type: TagType,
+    pub element_count: CipUint,
+
+    #[br(parse_with = until_eof)]
+    pub data: Vec<CipByte>,
+}
+
+#[binrw]
+#[brw(little)]
+#[derive(Debug, PartialEq)]
+pub struct WriteTagFragmentedRequest {
+    pub tag_type: TagType,
+    pub element_count: CipUint,
+    pub byte_offset: CipUdint,
+
+    #[br(parse_with = until_eof)]
+    pub data: Vec<CipByte>,
+}
+
+/// Atomically applies `(value | or_mask) & and_mask` to a tag inside the controller
+///
+/// Both masks are the size of the tag type, in the same little endian order as the tag data.
+#[binrw]
+#[brw(little)]
+#[derive(Debug, PartialEq)]
+pub struct ReadModifyWriteRequest {
+    #[br(temp)]
+    #[bw(calc = or_mask.len() as CipUint)]
+    mask_size: CipUint,
+
+    #[br(count = mask_size)]
+    pub or_mask: Vec<CipByte>,
+
+    #[br(count = mask_size)]
+    pub and_mask: Vec<CipByte>,
+}
+
+// ======= Start of ReadModifyWriteRequest impl ========
+
+impl ReadModifyWriteRequest {
+    /// Creates the masks for a tag of `data_type`, truncated to the size of the type
+    pub fn new(data_type: LogixDataType, or_mask: u64, and_mask: u64) -> BinResult<Self> {
+        let mask_size = match data_type.element_size() {
+            Some(mask_size) if data_type.is_bitwise() => mask_size,
+            _ => {
+                return Err(binrw::Error::Custom {
+                    pos: 0,
+                    err: Box::new(format!(
+                        "{:?} tags can not be modified with Read-Modify-Write",
+                        data_type
+                    )),
+                });
+            }
+        };
+
+        Ok(ReadModifyWriteRequest {
+            or_mask: or_mask.to_le_bytes()[..mask_size].to_vec(),
+            and_mask: and_mask.to_le_bytes()[..mask_size].to_vec(),
+        })
+    }
+
+    /// Creates the masks that set (or clear) a single bit and leave every other bit untouched
+    pub fn new_bit(data_type: LogixDataType, bit: u32, value: bool) -> BinResult<Self> {
+        let bit_count = data_type.element_size().unwrap_or(0) as u32 * 8;
+        if bit >= bit_count {
+            return Err(binrw::Error::Custom {
+                pos: 0,
+                err: Box::new(format!("Bit {} is outside of a {:?} tag", bit, data_type)),
+            });
+        }
+
+        let bit_mask = 1u64 << bit;
+        match value {
+            true => Self::new(data_type, bit_mask, u64::MAX),
+            false => Self::new(data_type, 0, !bit_mask),
+        }
+    }
+}
+
+// ^^^^^^^^ End of ReadModifyWriteRequest impl ^^^^^^^^
+
+/// The reply data of both the Read Tag and Read Tag Fragmented services
+#[binrw]
+#[brw(little)]
+#[derive(Debug, PartialEq)]
+pub struct TagData {
+    pub tag_type: TagType,
+
+    #[br(parse_with = until_eof)]
+    pub data: Vec<CipByte>,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct Tag {
+    pub path: SymbolicPath,
+}
+
+// ======= Start of Tag impl ========
+
+impl Tag {
+    pub fn new(tag_name: &str) -> BinResult<Self> {
+        Ok(Tag {
+            path: SymbolicPath::from_tag_name(tag_name)?,
+        })
+    }
+
+    pub fn read_request(&self, element_count: CipUint) -> MessageRouterRequest {
+        MessageRouterRequest::new_data(
+            LogixServiceCode::ReadTag.into(),
+            self.path.clone(),
+            Some(Box::new(ReadTagRequest { element_count })),
+        )
+    }
+
+    pub fn read_fragmented_request(
+        &self,
+        element_count: CipUint,
+        byte_offset: CipUdint,
+    ) -> MessageRouterRequest {
+        MessageRouterRequest::new_data(
+            LogixServiceCode::ReadTagFragmented.into(),
+            self.path.clone(),
+            Some(Box::new(ReadTagFragmentedRequest {
+                element_count,
+                byte_offset,
+            })),
+        )
+    }
+
+    pub fn write_request(
+        &self,
+        tag_type: TagType,
+        element_count: CipUint,
+        data: Vec<CipByte>,
+    ) -> MessageRouterRequest {
+        MessageRouterRequest::new_data(
+            LogixServiceCode::WriteTag.into(),
+            self.path.clone(),
+            Some(Box::new(WriteTagRequest {
+                tag_type,
+                element_count,
+                data,
+            })),
+        )
+    }
+
+    pub fn write_fragmented_request(
+        &self,
+        tag_type: TagType,
+        element_count: CipUint,
+        byte_offset: CipUdint,
+        data: Vec<CipByte>,
+    ) -> MessageRouterRequest {
+        MessageRouterRequest::new_data(
+            LogixServiceCode::WriteTagFragmented.into(),
+            self.path.clone(),
+            Some(Box::new(WriteTagFragmentedRequest {
+                tag_type,
+                element_count,
+                byte_offset,
+                data,
+            })),
+        )
+    }
+
+    pub fn read_modify_write_request(&self, masks: ReadModifyWriteRequest) -> MessageRouterRequest {
+        MessageRouterRequest::new_data(
+            LogixServiceCode::ReadModifyWriteTag.into(),
+            self.path.clone(),
+            Some(Box::new(masks)),
+        )
+    }
+
+    /// Sets (or clears) a single bit of an integer tag without racing the controller program
+    pub fn write_bit_request(
+        &self,
+        data_type: LogixDataType,
+        bit: u32,
+        value: bool,
+    ) -> BinResult<MessageRouterRequest> {
+        Ok(self.read_modify_write_request(ReadModifyWriteRequest::new_bit(data_type, bit, value)?))
+    }
+
+    /// Reads any number of elements, splitting the read into as many fragments as necessary
+    pub fn read(&self, element_count: CipUint) -> FragmentedRead {
+        FragmentedRead::new(self.clone(), element_count)
+    }
+
+    /// Writes any number of elements, splitting the write into as many fragments as necessary
+    pub fn write(
+        &self,
+        tag_type: TagType,
+        element_count: CipUint,
+        data: Vec<CipByte>,
+    ) -> FragmentedWrite {
+        FragmentedWrite::new(self.clone(), tag_type, element_count, data)
+    }
+}
+
+// ^^^^^^^^ End of Tag impl ^^^^^^^^
+
+/// Reassembles the data of a tag read with the Read Tag Fragmented service
+#[derive(Debug)]
+pub struct FragmentedRead {
+    tag: Tag,
+    element_count: CipUint,
+    tag_type: Option<TagType>,
+    data: Vec<CipByte>,
+    complete: bool,
+}
+
+// ======= Start of FragmentedRead impl ========
+
+impl FragmentedRead {
+    pub fn new(tag: Tag, element_count: CipUint) -> Self {
+        FragmentedRead {
+            tag,
+            element_count,
+            tag_type: None,
+            data: Vec::new(),
+            complete: false,
+        }
+    }
+
+    pub fn is_complete(&self) -> bool {
+        self.complete
+    }
+
+    pub fn next_request(&self) -> Option<MessageRouterRequest> {
+        if self.complete {
+            return None;
+        }
+
+        Some(
+            self.tag
+                .read_fragmented_request(self.element_count, self.data.len() as CipUdint),
+        )
+    }
+
+    pub fn handle_response(&mut self, response: &MessageRouterResponse) -> BinResult<()> {
+        // A partial transfer means the controller has more data after this fragment
+        let partial_transfer = response.response_data.status == ResponseStatusCode::PartialTransfer;
+        if !partial_transfer {
+            response.check_status()?;
+        }
+
+        let fragment: TagData = response.response_data.read_reply()?;
+
+        if partial_transfer && fragment.data.is_empty() {
+            return Err(binrw::Error::Custom {
+                pos: self.data.len() as u64,
+                err: Box::new("Partial transfer did not contain any data".to_string()),
+            });
+        }
+
+        match self.tag_type {
+            Some(tag_type) if tag_type != fragment.tag_type => {
+                return Err(binrw::Error::Custom {
+                    pos: self.data.len() as u64,
+                    err: Box::new(format!(
+                        "Tag type changed from {:?} to {:?} during the read",
+                        tag_type, fragment.tag_type
+                    )),
+                });
+            }
+            _ => self.tag_type = Some(fragment.tag_type),
+        }
+
+        self.data.extend(fragment.data);
+        self.complete = !partial_transfer;
+
+        Ok(())
+    }
+
+    /// The reassembled tag data, once the read is complete
+    pub fn into_tag_data(self) -> Option<TagData> {
+        match (self.complete, self.tag_type) {
+            (true, Some(tag_type)) => Some(TagData {
+                tag_type,
+                data: self.data,
+            }),
+            _ => None,
+        }
+    }
+}
+
+// ^^^^^^^^ End of FragmentedRead impl ^^^^^^^^
+
+/// Splits a tag write into Write Tag Fragmented requests
+#[derive(Debug)]
+pub struct FragmentedWrite {
+    tag: Tag,
+    tag_type: TagType,
+    element_count: CipUint,
+    data: Vec<CipByte>,
+    fragment_size: usize,
+    byte_offset: usize,
+}
+
+// ======= Start of FragmentedWrite impl ========
+
+impl FragmentedWrite {
+    pub fn new(tag: Tag, tag_type: TagType, element_count: CipUint, data: Vec<CipByte>) -> Self {
+        FragmentedWrite {
+            tag,
+            tag_type,
+            element_count,
+            data,
+            fragment_size: DEFAULT_FRAGMENT_SIZE,
+            byte_offset: 0,
+        }
+    }
+
+    /// Overrides the number of data bytes sent per request
+    ///
+    /// The size is rounded down to a whole number of elements for atomic types.
+    pub fn with_fragment_size(mut self, fragment_size: usize) -> Self {
+        let element_size = self.tag_type.data_type.element_size().unwrap_or(1);
+        self.fragment_size = (fragment_size / element_size).max(1) * element_size;
+        self
+    }
+
+    pub fn is_complete(&self) -> bool {
+        self.byte_offset >= self.data.len()
+    }
+
+    fn fragment_end(&self) -> usize {
+        (self.byte_offset + self.fragment_size).min(self.data.len())
+    }
+
+    pub fn next_request(&self) -> Option<MessageRouterRequest> {
+        if self.is_complete() {
+            return None;
+        }
+
+        Some(self.tag.write_fragmented_request(
+            self.tag_type,
+            self.element_count,
+            self.byte_offset as CipUdint,
+            self.data[self.byte_offset..self.fragment_end()].to_vec(),
+        ))
+    }
+
+    pub fn handle_response(&mut self, response: &MessageRouterResponse) -> BinResult<()> {
+        response.check_status()?;
+
+        self.byte_offset = self.fragment_end();
+
+        Ok(())
+    }
+}
+
+// ^^^^^^^^ End of FragmentedWrite impl ^^^^^^^^
+
+/// A decoded tag value
+#[derive(Debug, PartialEq, Clone)]
+pub enum TagValue {
+    Bool(bool),
+    Sint(i8),
+    Int(i16),
+    Dint(i32),
+    Lint(i64),
+    Usint(u8),
+    Uint(u16),
+    Udint(u32),
+    Ulint(u64),
+    Real(f32),
+    Lreal(f64),
+    Array(Vec<TagValue>),
+    Structure(Vec<(String, TagValue)>),
+}
+
+// ======= Start of TagValue impl ========
+
+impl TagValue {
+    /// Decodes a single atomic value from the start of `bytes`
+    pub fn decode_atomic(data_type: LogixDataType, bytes: &[u8]) -> Option<Self> {
+        use LogixDataType::*;
+
+        let element_size = data_type.element_size()?;
+        let bytes = bytes.get(..element_size)?;
+
+        let mut buffer = [0u8; 8];
+        buffer[..element_size].copy_from_slice(bytes);
+
+        let value = match data_type {
+            Bool => TagValue::Bool(buffer[0] != 0),
+            Sint => TagValue::Sint(buffer[0] as i8),
+            Usint | Byte => TagValue::Usint(buffer[0]),
+            Int => TagValue::Int(i16::from_le_bytes([buffer[0], buffer[1]])),
+            Uint | Word => TagValue::Uint(u16::from_le_bytes([buffer[0], buffer[1]])),
+            Dint => TagValue::Dint(i32::from_le_bytes([buffer[0], buffer[1], buffer[2], buffer[3]])),
+            Udint | Dword => {
+                TagValue::Udint(u32::from_le_bytes([buffer[0], buffer[1], buffer[2], buffer[3]]))
+            }
+            Real => TagValue::Real(f32::from_le_bytes([buffer[0], buffer[1], buffer[2], buffer[3]])),
+            Lint => TagValue::Lint(i64::from_le_bytes(buffer)),
+            Ulint | Lword => TagValue::Ulint(u64::from_le_bytes(buffer)),
+            Lreal => TagValue::Lreal(f64::from_le_bytes(buffer)),
+            Structure | Unknown(_) => return None,
+        };
+
+        Some(value)
+    }
+}
+
+// ^^^^^^^^ End of TagValue impl ^^^^^^^^
+
+// ======= Start of TagData impl ========
+
+impl TagData {
+    /// Decodes the elements of an atomic tag, or `None` for structures
+    pub fn atomic_values(&self) -> Option<Vec<TagValue>> {
+        let element_size = self.tag_type.data_type.element_size()?;
+
+        self.data
+            .chunks_exact(element_size)
+            .map(|element| TagValue::decode_atomic(self.tag_type.data_type, element))
+            .collect()
+    }
+}
+
+// ^^^^^^^^ End of TagData impl ^^^^^^^^
@@ -0,0 +1,426 @@
+use std::collections::HashMap;
+
+use binrw::{
+    binrw,   // #[binrw] attribute
+    BinRead, // trait for reading
+    BinResult,
+};
+
+use bilge::prelude::u7;
+
+use crate::cip::message::attribute_list::GetAttributeListRequest;
+use crate::cip::message::request::MessageRouterRequest;
+use crate::cip::message::response::{MessageRouterResponse, ResponseStatusCode};
+use crate::cip::message::shared::ServiceCode;
+use crate::cip::path::{CipPath, LogicalPathSegment, LogicalSegmentType};
+use crate::cip::types::{CipByte, CipUdint, CipUint};
+
+use super::shared::LogixDataType;
+use super::symbol::SymbolType;
+use super::tag::TagValue;
+
+pub const TEMPLATE_CLASS_ID: u16 = 0x6C;
+
+/// The size of each member info entry at the start of the template definition
+pub const MEMBER_INFO_SIZE: usize = 8;
+
+/// The object definition size (attribute 4) counts 32-bit words and includes a 21 byte header
+/// which is not returned by the Read Template service
+pub const TEMPLATE_HEADER_SIZE: u32 = 21;
+
+/// Object specific services of the Template object
+#[derive(Debug, PartialEq, Clone, Copy)]
+#[repr(u8)]
+pub enum TemplateServiceCode {
+    ReadTemplate = 0x4C,
+}
+
+impl From<TemplateServiceCode> for ServiceCode {
+    fn from(service_code: TemplateServiceCode) -> Self {
+        ServiceCode::from(u7::new(service_code as u8))
+    }
+}
+
+/// The Get Attribute List reply for attributes 4, 5, 2 and 1 (in that order)
+///
+/// Each magic is the attribute ID followed by a success status, so a failed attribute fails the read.
+#[binrw]
+#[brw(little)]
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct TemplateAttributes {
+    #[br(temp, assert(attribute_count == 4))]
+    #[bw(calc = 4)]
+    attribute_count: CipUint,
+
+    #[brw(magic = b"\x04\x00\x00\x00")]
+    pub object_definition_size: CipUdint,
+
+    #[brw(magic = b"\x05\x00\x00\x00")]
+    pub structure_size: CipUdint,
+
+    #[brw(magic = b"\x02\x00\x00\x00")]
+    pub member_count: CipUint,
+
+    #[brw(magic = b"\x01\x00\x00\x00")]
+    pub structure_handle: CipUint,
+}
+
+// ======= Start of TemplateAttributes impl ========
+
+impl TemplateAttributes {
+    pub const ATTRIBUTE_IDS: [CipUint; 4] = [0x04, 0x05, 0x02, 0x01];
+
+    /// The number of bytes returned by the Read Template service
+    pub fn definition_byte_size(&self) -> CipUdint {
+        (self.object_definition_size * 4).saturating_sub(TEMPLATE_HEADER_SIZE)
+    }
+}
+
+// ^^^^^^^^ End of TemplateAttributes impl ^^^^^^^^
+
+#[binrw]
+#[brw(little)]
+#[derive(Debug, PartialEq)]
+pub struct ReadTemplateRequest {
+    pub byte_offset: CipUdint,
+    pub byte_count: CipUint,
+}
+
+#[binrw]
+#[brw(little)]
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct MemberInfo {
+    // Array length for arrays, bit position for BOOLs
+    pub info: CipUint,
+    pub member_type: SymbolType,
+    pub offset: CipUdint,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct TemplateMember {
+    pub name: String,
+    pub info: MemberInfo,
+}
+
+// ======= Start of TemplateMember impl ========
+
+impl TemplateMember {
+    pub fn data_type(&self) -> LogixDataType {
+        self.info.member_type.data_type()
+    }
+
+    /// Hidden members hold the bits of the BOOL members that follow them
+    pub fn is_hidden(&self) -> bool {
+        self.name.starts_with("ZZZZZZZZZZ") || self.name.starts_with("__")
+    }
+
+    pub fn array_length(&self) -> Option<CipUint> {
+        (self.info.member_type.dimension_count().value() > 0).then_some(self.info.info)
+    }
+
+    pub fn bit_position(&self) -> Option<CipUint> {
+        (self.data_type() == LogixDataType::Bool && self.array_length().is_none())
+            .then_some(self.info.info)
+    }
+}
+
+// ^^^^^^^^ End of TemplateMember impl ^^^^^^^^
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct Template {
+    pub instance_id: CipUint,
+    pub name: String,
+    pub structure_handle: CipUint,
+    pub structure_size: CipUdint,
+    pub members: Vec<TemplateMember>,
+}
+
+fn decode_error(message: String) -> binrw::Error {
+    binrw::Error::Custom {
+        pos: 0,
+        err: Box::new(message),
+    }
+}
+
+// ======= Start of Template impl ========
+
+impl Template {
+    /// Parses the member info entries and the names that follow them in a template definition
+    pub fn from_definition(
+        instance_id: CipUint,
+        attributes: &TemplateAttributes,
+        definition: &[CipByte],
+    ) -> BinResult<Self> {
+        let member_count = attributes.member_count as usize;
+        let names_start = member_count * MEMBER_INFO_SIZE;
+
+        let Some(member_infos) = definition.get(..names_start) else {
+            return Err(decode_error(format!(
+                "Template definition is too short for {} members",
+                member_count
+            )));
+        };
+
+        // The template name ends at a ';' and is followed by the NULL terminated member names
+        let mut names = definition[names_start..]
+            .split(|byte| *byte == 0)
+            .map(|name| String::from_utf8_lossy(name).to_string());
+
+        let template_name = names.next().unwrap_or_default();
+        let template_name = match template_name.split_once(';') {
+            Some((name, _)) => name.to_string(),
+            None => template_name,
+        };
+
+        let members = member_infos
+            .chunks_exact(MEMBER_INFO_SIZE)
+            .map(|member_info| {
+                let info = MemberInfo::read_le(&mut std::io::Cursor::new(member_info))?;
+                Ok(TemplateMember {
+                    name: names.next().unwrap_or_default(),
+                    info,
+                })
+            })
+            .collect::<BinResult<Vec<_>>>()?;
+
+        Ok(Template {
+            instance_id,
+            name: template_name,
+            structure_handle: attributes.structure_handle,
+            structure_size: attributes.structure_size,
+            members,
+        })
+    }
+
+    /// Decodes a structure value into its named (visible) members
+    ///
+    /// Nested structures are decoded with the templates in `templates`, keyed by instance ID.
+    pub fn decode(
+        &self,
+        data: &[CipByte],
+        templates: &HashMap<CipUint, Template>,
+    ) -> BinResult<TagValue> {
+        let members = self
+            .members
+            .iter()
+            .filter(|member| !member.is_hidden())
+            .map(|member| Ok((member.name.clone(), self.decode_member(member, data, templates)?)))
+            .collect::<BinResult<Vec<_>>>()?;
+
+        Ok(TagValue::Structure(members))
+    }
+
+    fn decode_member(
+        &self,
+        member: &TemplateMember,
+        data: &[CipByte],
+        templates: &HashMap<CipUint, Template>,
+    ) -> BinResult<TagValue> {
+        let offset = member.info.offset as usize;
+        let out_of_range = || {
+            decode_error(format!(
+                "Member {} of {} is outside of the {} data bytes",
+                member.name,
+                self.name,
+                data.len()
+            ))
+        };
+
+        let member_data = data.get(offset..).ok_or_else(out_of_range)?;
+
+        if let Some(bit_position) = member.bit_position() {
+            let host_byte = member_data.first().ok_or_else(out_of_range)?;
+            return Ok(TagValue::Bool(host_byte & (1 << (bit_position % 8)) != 0));
+        }
+
+        match member.array_length() {
+            // BOOL arrays are packed into 32-bit words
+            Some(array_length) if member.data_type() == LogixDataType::Bool => (0..array_length
+                as usize)
+                .map(|bit| {
+                    let host_byte = member_data.get(bit / 8).ok_or_else(out_of_range)?;
+                    Ok(TagValue::Bool(host_byte & (1 << (bit % 8)) != 0))
+                })
+                .collect::<BinResult<Vec<_>>>()
+                .map(TagValue::Array),
+            Some(array_length) => {
+                let element_size = self.member_element_size(member, templates)?;
+                (0..array_length as usize)
+                    .map(|index| {
+                        let element_data = member_data
+                            .get(index * element_size..)
+                            .ok_or_else(out_of_range)?;
+                        self.decode_element(member, element_data, templates)
+                    })
+                    .collect::<BinResult<Vec<_>>>()
+                    .map(TagValue::Array)
+            }
+            None => self.decode_element(member, member_data, templates),
+        }
+    }
+
+    fn member_template<'a>(
+        &self,
+        member: &TemplateMember,
+        templates: &'a HashMap<CipUint, Template>,
+    ) -> BinResult<&'a Template> {
+        let template_instance_id = member.info.member_type.template_instance_id().unwrap_or(0);
+
+        templates.get(&template_instance_id).ok_or_else(|| {
+            decode_error(format!(
+                "Missing template {:#06x} for member {} of {}",
+                template_instance_id, member.name, self.name
+            ))
+        })
+    }
+
+    fn member_element_size(
+        &self,
+        member: &TemplateMember,
+        templates: &HashMap<CipUint, Template>,
+    ) -> BinResult<usize> {
+        match member.data_type().element_size() {
+            Some(element_size) => Ok(element_size),
+            None => Ok(self.member_template(member, templates)?.structure_size as usize),
+        }
+    }
+
+    fn decode_element(
+        &self,
+        member: &TemplateMember,
+        element_data: &[CipByte],
+        templates: &HashMap<CipUint, Template>,
+    ) -> BinResult<TagValue> {
+        if member.data_type() == LogixDataType::Structure {
+            return self
+                .member_template(member, templates)?
+                .decode(element_data, templates);
+        }
+
+        TagValue::decode_atomic(member.data_type(), element_data).ok_or_else(|| {
+            decode_error(format!(
+                "Unable to decode member {} of {} as {:?}",
+                member.name,
+                self.name,
+                member.data_type()
+            ))
+        })
+    }
+
+    /// The template instances of the structure members, which need to be read to decode them
+    pub fn nested_template_ids(&self) -> Vec<CipUint> {
+        self.members
+            .iter()
+            .filter_map(|member| member.info.member_type.template_instance_id())
+            .collect()
+    }
+}
+
+// ^^^^^^^^ End of Template impl ^^^^^^^^
+
+/// Reads the attributes and the definition of a template
+#[derive(Debug)]
+pub struct TemplateRead {
+    instance_id: CipUint,
+    attributes: Option<TemplateAttributes>,
+    definition: Vec<CipByte>,
+    complete: bool,
+}
+
+// ======= Start of TemplateRead impl ========
+
+impl TemplateRead {
+    pub fn new(instance_id: CipUint) -> Self {
+        TemplateRead {
+            instance_id,
+            attributes: None,
+            definition: Vec::new(),
+            complete: false,
+        }
+    }
+
+    pub fn is_complete(&self) -> bool {
+        self.complete
+    }
+
+    fn template_path(&self) -> CipPath {
+        CipPath {
+            class_id_segment: match u8::try_from(TEMPLATE_CLASS_ID) {
+                Ok(class_id) => LogicalPathSegment::new_u8(LogicalSegmentType::ClassId, class_id),
+                Err(_) => {
+                    LogicalPathSegment::new_u16(LogicalSegmentType::ClassId, TEMPLATE_CLASS_ID)
+                }
+            },
+            instance_id_segment: LogicalPathSegment::new_u16(
+                LogicalSegmentType::InstanceId,
+                self.instance_id,
+            ),
+            attribute_id_segment: None,
+        }
+    }
+
+    pub fn next_request(&self) -> Option<MessageRouterRequest> {
+        if self.complete {
+            return None;
+        }
+
+        let Some(attributes) = self.attributes else {
+            return Some(
+                GetAttributeListRequest::new(TemplateAttributes::ATTRIBUTE_IDS.to_vec())
+                    .into_router_request(self.template_path()),
+            );
+        };
+
+        let byte_offset = self.definition.len() as CipUdint;
+        let byte_count = attributes.definition_byte_size().saturating_sub(byte_offset);
+
+        Some(MessageRouterRequest::new_data(
+            TemplateServiceCode::ReadTemplate.into(),
+            self.template_path(),
+            Some(Box::new(ReadTemplateRequest {
+                byte_offset,
+                byte_count: byte_count as CipUint,
+            })),
+        ))
+    }
+
+    pub fn handle_response(&mut self, response: &MessageRouterResponse) -> BinResult<()> {
+        if self.attributes.is_none() {
+            response.check_status()?;
+            self.attributes = Some(response.response_data.read_reply()?);
+            return Ok(());
+        }
+
+        // A partial transfer means the definition continues after this fragment
+        let partial_transfer = response.response_data.status == ResponseStatusCode::PartialTransfer;
+        if !partial_transfer {
+            response.check_status()?;
+        }
+
+        let fragment = response.response_data.reply_data();
+        if partial_transfer && fragment.is_empty() {
+            return Err(decode_error(
+                "Partial transfer did not contain any data".to_string(),
+            ));
+        }
+
+        self.definition.extend(fragment);
+        self.complete = !partial_transfer;
+
+        Ok(())
+    }
+
+    /// The parsed template, once the read is complete
+    pub fn into_template(self) -> Option<BinResult<Template>> {
+        match (self.complete, self.attributes) {
+            (true, Some(attributes)) => Some(Template::from_definition(
+                self.instance_id,
+                &attributes,
+                &self.definition,
+            )),
+            _ => None,
+        }
+    }
+}
+
+// ^^^^^^^^ End of TemplateRead impl ^^^^^^^^
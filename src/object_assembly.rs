@@ -4,11 +4,12 @@ use binrw::{
     BinWrite, // trait for writing
 };
 
+use crate::cip::connection_manager::UnconnectedSend;
 use crate::cip::message::data::CipData;
 use crate::cip::message::{
     request::MessageRouterRequest, response::MessageRouterResponse, shared::ServiceCode,
 };
-use crate::cip::path::CipPath;
+use crate::cip::path::{CipPath, RequestPath};
 use crate::cip::types::CipUdint;
 use crate::eip::command::CommandSpecificData;
 use crate::eip::packet::EnIpPacketDescription;
@@ -52,24 +53,15 @@ impl BinWrite for RequestObjectAssembly {
         let mut temp_buffer = Vec::new();
         let mut temp_writer = std::io::Cursor::new(&mut temp_buffer);
 
-        let cip_message_write_result =
-            self.cip_message
-                .write_options(&mut temp_writer, endian, args);
-
-        if let Err(write_err) = cip_message_write_result {
-            return Err(write_err);
-        }
+        self.cip_message
+            .write_options(&mut temp_writer, endian, args)?;
 
         // Step 2: Calculate the packet size
         let packet_byte_size = temp_buffer.len() as u16;
 
         // Step 3: Write the full packet
-        if let Err(write_err) =
-            self.packet_description
-                .write_options(writer, endian, (packet_byte_size,))
-        {
-            return Err(write_err);
-        }
+        self.packet_description
+            .write_options(writer, endian, (packet_byte_size,))?;
 
         if let Err(write_err) = writer.write(&temp_buffer) {
             return Err(binrw::Error::Io(write_err));
@@ -111,19 +103,34 @@ impl RequestObjectAssembly {
 impl RequestObjectAssembly {
     pub fn new_service_request(
         session_handle: CipUdint,
-        request_path: CipPath,
+        request_path: impl Into<RequestPath>,
         service_code: ServiceCode,
         data: Option<Box<dyn CipData>>,
+    ) -> Self {
+        Self::new_router_request(
+            session_handle,
+            MessageRouterRequest::new_data(service_code, request_path, data),
+        )
+    }
+
+    pub fn new_router_request(
+        session_handle: CipUdint,
+        router_request: MessageRouterRequest,
     ) -> Self {
         Self {
             packet_description: EnIpPacketDescription::new_cip_description(session_handle, 0),
-            cip_message: Some(MessageRouterRequest::new_data(
-                service_code,
-                request_path,
-                data,
-            )),
+            cip_message: Some(router_request),
         }
     }
+
+    /// Sends the request to the device at the end of the route instead of the one we talk to
+    pub fn new_routed_request(
+        session_handle: CipUdint,
+        unconnected_send: &UnconnectedSend,
+        router_request: MessageRouterRequest,
+    ) -> Self {
+        Self::new_router_request(session_handle, unconnected_send.wrap(router_request))
+    }
 }
 
 #[binread]
@@ -170,24 +177,15 @@ impl BinWrite for ResponseObjectAssembly
         let mut temp_buffer = Vec::new();
         let mut temp_writer = std::io::Cursor::new(&mut temp_buffer);
 
-        let cip_message_write_result =
-            self.cip_message
-                .write_options(&mut temp_writer, endian, args);
-
-        if let Err(write_err) = cip_message_write_result {
-            return Err(write_err);
-        }
+        self.cip_message
+            .write_options(&mut temp_writer, endian, args)?;
 
         // Step 2: Calculate the packet size
         let packet_byte_size = temp_buffer.len() as u16;
 
         // Step 3: Write the full packet
-        if let Err(write_err) =
-            self.packet_description
-                .write_options(writer, endian, (packet_byte_size,))
-        {
-            return Err(write_err);
-        }
+        self.packet_description
+            .write_options(writer, endian, (packet_byte_size,))?;
 
         if let Err(write_err) = writer.write(&temp_buffer) {
             return Err(binrw::Error::Io(write_err));
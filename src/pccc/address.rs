@@ -0,0 +1,199 @@
+use binrw::{
+    binrw, // #[binrw] attribute
+    BinRead,
+    BinResult,
+    BinWrite,
+};
+
+use crate::cip::types::{CipUint, CipUsint};
+
+use super::shared::PcccFileType;
+
+/// Address fields above this value are written as a 0xFF marker followed by a 16-bit value
+const SHORT_ADDRESS_FIELD_MAX: u16 = 0xFE;
+const LONG_ADDRESS_FIELD_MARKER: CipUsint = 0xFF;
+
+/// Number of bits in a data table word
+const BITS_PER_WORD: u32 = 16;
+
+#[binrw::parser(reader, endian)]
+fn read_address_field() -> BinResult<u16> {
+    match CipUsint::read_options(reader, endian, ())? {
+        LONG_ADDRESS_FIELD_MARKER => CipUint::read_options(reader, endian, ()),
+        address_field => Ok(address_field as u16),
+    }
+}
+
+#[binrw::writer(writer, endian)]
+fn write_address_field(address_field: &u16) -> BinResult<()> {
+    if *address_field <= SHORT_ADDRESS_FIELD_MAX {
+        return (*address_field as CipUsint).write_options(writer, endian, ());
+    }
+
+    LONG_ADDRESS_FIELD_MARKER.write_options(writer, endian, ())?;
+    address_field.write_options(writer, endian, ())
+}
+
+/// The word and bit of a named sub-element of a timer, counter or control element
+fn named_sub_element(file_type: PcccFileType, name: &str) -> Option<(u16, Option<u8>)> {
+    use PcccFileType::*;
+    let sub_element = match (file_type, name) {
+        (Timer | Counter, "PRE") | (Control, "LEN") => (1, None),
+        (Timer | Counter, "ACC") | (Control, "POS") => (2, None),
+        (Timer | Control, "EN") | (Counter, "CU") => (0, Some(15)),
+        (Timer, "TT") | (Counter, "CD") | (Control, "EU") => (0, Some(14)),
+        (Timer | Counter | Control, "DN") => (0, Some(13)),
+        (Counter, "OV") | (Control, "EM") => (0, Some(12)),
+        (Counter, "UN") | (Control, "ER") => (0, Some(11)),
+        (Control, "UL") => (0, Some(10)),
+        (Control, "IN") => (0, Some(9)),
+        (Control, "FD") => (0, Some(8)),
+        _ => return None,
+    };
+
+    Some(sub_element)
+}
+
+/// A data table address of a SLC 500, PLC-5 or MicroLogix controller, e.g. `N7:0` or `B3/5`
+///
+/// Serializes as the file number, file type, element and sub-element address fields. The bit is
+/// not part of the PCCC address, the whole word is read or masked instead.
+#[binrw]
+#[brw(little)]
+#[derive(Debug, PartialEq, Clone)]
+pub struct PcccAddress {
+    #[br(parse_with = read_address_field)]
+    #[bw(write_with = write_address_field)]
+    pub file_number: u16,
+
+    pub file_type: PcccFileType,
+
+    #[br(parse_with = read_address_field)]
+    #[bw(write_with = write_address_field)]
+    pub element: u16,
+
+    #[br(parse_with = read_address_field)]
+    #[bw(write_with = write_address_field)]
+    pub sub_element: u16,
+
+    #[brw(ignore)]
+    pub bit: Option<u8>,
+}
+
+// ======= Start of PcccAddress impl ========
+
+impl PcccAddress {
+    /// Parses an address such as `N7:0`, `F8:3`, `B3/5`, `B3:1/2`, `T4:0.ACC` or `S:1/5`
+    pub fn parse(address: &str) -> BinResult<Self> {
+        let invalid_address = |reason: &str| binrw::Error::Custom {
+            pos: 0,
+            err: Box::new(format!("Invalid PCCC address {:?}: {}", address, reason)),
+        };
+
+        let address_text = address.trim().to_ascii_uppercase();
+        let prefix_length = address_text
+            .find(|c: char| !c.is_ascii_alphabetic())
+            .unwrap_or(address_text.len());
+        let (prefix, rest) = address_text.split_at(prefix_length);
+
+        let file_type = PcccFileType::from_prefix(prefix)
+            .ok_or_else(|| invalid_address("unknown file type"))?;
+
+        let (file_text, element_text) = match rest.split_once(':') {
+            Some((file_text, element_text)) => (file_text, Some(element_text)),
+            None => (rest, None),
+        };
+
+        // Bit files can also be addressed by their bit offset in the file, e.g. `B3/5`
+        let (file_text, file_bit_text) = match element_text {
+            Some(_) => (file_text, None),
+            None => match file_text.split_once('/') {
+                Some((file_text, bit_text)) => (file_text, Some(bit_text)),
+                None => (file_text, None),
+            },
+        };
+
+        let file_number = match file_text {
+            "" => file_type
+                .default_file_number()
+                .ok_or_else(|| invalid_address("missing file number"))?,
+            file_text => file_text
+                .parse::<u16>()
+                .map_err(|_| invalid_address("the file number must be a number"))?,
+        };
+
+        let (element, sub_element, bit) = match (element_text, file_bit_text) {
+            (None, None) => return Err(invalid_address("missing element")),
+            (None, Some(bit_text)) => {
+                let file_bit = bit_text
+                    .parse::<u32>()
+                    .map_err(|_| invalid_address("the bit must be a number"))?;
+                let element = u16::try_from(file_bit / BITS_PER_WORD)
+                    .map_err(|_| invalid_address("the bit is outside of the file"))?;
+
+                (element, 0, Some((file_bit % BITS_PER_WORD) as u8))
+            }
+            (Some(element_text), _) => {
+                let (element_text, bit_text) = match element_text.split_once('/') {
+                    Some((element_text, bit_text)) => (element_text, Some(bit_text)),
+                    None => (element_text, None),
+                };
+                let (element_text, sub_element_text) = match element_text.split_once('.') {
+                    Some((element_text, sub_element_text)) => {
+                        (element_text, Some(sub_element_text))
+                    }
+                    None => (element_text, None),
+                };
+
+                let element = element_text
+                    .parse::<u16>()
+                    .map_err(|_| invalid_address("the element must be a number"))?;
+
+                let (sub_element, named_bit) = match sub_element_text {
+                    None => (0, None),
+                    Some(sub_element_text) => match sub_element_text.parse::<u16>() {
+                        Ok(sub_element) => (sub_element, None),
+                        Err(_) => named_sub_element(file_type, sub_element_text)
+                            .ok_or_else(|| invalid_address("unknown sub-element"))?,
+                    },
+                };
+
+                let bit = match bit_text {
+                    None => named_bit,
+                    Some(bit_text) => Some(
+                        bit_text
+                            .parse::<u8>()
+                            .ok()
+                            .filter(|bit| (*bit as u32) < BITS_PER_WORD)
+                            .ok_or_else(|| invalid_address("the bit must be between 0 and 15"))?,
+                    ),
+                };
+
+                (element, sub_element, bit)
+            }
+        };
+
+        Ok(PcccAddress {
+            file_number,
+            file_type,
+            element,
+            sub_element,
+            bit,
+        })
+    }
+
+    /// Whether the address selects a single word of an element rather than the whole element
+    pub fn is_word_address(&self) -> bool {
+        self.bit.is_some() || self.sub_element != 0
+    }
+
+    /// The number of bytes of a single addressed value
+    pub fn element_size(&self) -> Option<usize> {
+        match self.is_word_address() {
+            true => Some(2),
+            false => self.file_type.element_size(),
+        }
+    }
+}
+
+// ^^^^^^^^ End of PcccAddress impl ^^^^^^^^
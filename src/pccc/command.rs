@@ -0,0 +1,395 @@
+use binrw::{
+    binrw, // #[binrw] attribute
+    helpers::until_eof,
+    BinResult,
+};
+
+use crate::cip::message::data::encode;
+use crate::cip::message::request::MessageRouterRequest;
+use crate::cip::message::response::MessageRouterResponse;
+use crate::cip::types::{CipByte, CipUdint, CipUint, CipUsint};
+
+use super::address::PcccAddress;
+use super::shared::{
+    pccc_object_path, PcccFileType, PcccFunctionCode, PcccServiceCode, PCCC_EXTENDED_STATUS,
+    PCCC_REPLY_FLAG, PCCC_TYPED_COMMAND,
+};
+
+/// The requestor ID is always sent in its short form: the length, vendor ID and serial number
+pub const REQUESTOR_ID_SIZE: CipUsint = 7;
+
+/// Number of characters stored in a string file element
+pub const PCCC_STRING_CAPACITY: usize = 82;
+
+/// Identifies the node that sent a PCCC command
+#[binrw]
+#[brw(little)]
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct RequestorId {
+    #[br(temp, assert(length == REQUESTOR_ID_SIZE))]
+    #[bw(calc = REQUESTOR_ID_SIZE)]
+    length: CipUsint,
+
+    pub vendor_id: CipUint,
+    pub serial_number: CipUdint,
+}
+
+/// The request data of the Execute PCCC service
+#[binrw]
+#[brw(little)]
+#[derive(Debug, PartialEq)]
+pub struct ExecutePcccRequest {
+    pub requestor_id: RequestorId,
+    pub command: CipUsint,
+    pub status: CipUsint,
+    pub transaction_number: CipUint,
+    pub function_code: PcccFunctionCode,
+
+    #[br(parse_with = until_eof)]
+    pub function_data: Vec<CipByte>,
+}
+
+/// The reply data of the Execute PCCC service
+#[binrw]
+#[brw(little)]
+#[derive(Debug, PartialEq)]
+pub struct ExecutePcccReply {
+    pub requestor_id: RequestorId,
+    pub command: CipUsint,
+    pub status: CipUsint,
+    pub transaction_number: CipUint,
+
+    #[br(if(status == PCCC_EXTENDED_STATUS))]
+    pub extended_status: Option<CipUsint>,
+
+    #[br(parse_with = until_eof)]
+    pub data: Vec<CipByte>,
+}
+
+// ======= Start of ExecutePcccReply impl ========
+
+impl ExecutePcccReply {
+    /// Fails with the PCCC status (and extended status) unless the command succeeded
+    pub fn check_status(&self) -> BinResult<()> {
+        if self.status == 0 {
+            return Ok(());
+        }
+
+        Err(binrw::Error::Custom {
+            pos: 0,
+            err: Box::new(format!(
+                "PCCC command 0x{:02X} failed with status 0x{:02X} (extended status: {:02X?})",
+                self.command & !PCCC_REPLY_FLAG,
+                self.status,
+                self.extended_status
+            )),
+        })
+    }
+}
+
+// ^^^^^^^^ End of ExecutePcccReply impl ^^^^^^^^
+
+#[binrw]
+#[brw(little)]
+#[derive(Debug, PartialEq)]
+pub struct TypedReadData {
+    pub byte_size: CipUsint,
+    pub address: PcccAddress,
+}
+
+#[binrw]
+#[brw(little)]
+#[derive(Debug, PartialEq)]
+pub struct TypedWriteData {
+    #[br(temp)]
+    #[bw(calc = data.len() as CipUsint)]
+    byte_size: CipUsint,
+
+    pub address: PcccAddress,
+
+    #[br(count = byte_size)]
+    pub data: Vec<CipByte>,
+}
+
+/// Applies `(word & !mask) | (value & mask)` to a single word of the data table
+#[binrw]
+#[brw(little)]
+#[derive(Debug, PartialEq)]
+pub struct MaskedWriteData {
+    #[br(temp, assert(byte_size == 2))]
+    #[bw(calc = 2)]
+    byte_size: CipUsint,
+
+    pub address: PcccAddress,
+    pub mask: CipUint,
+    pub value: CipUint,
+}
+
+/// A decoded data table value
+#[derive(Debug, PartialEq, Clone)]
+pub enum PcccValue {
+    Bool(bool),
+    Integer(i16),
+    Long(i32),
+    Float(f32),
+    String(String),
+}
+
+// ======= Start of PcccValue impl ========
+
+impl PcccValue {
+    /// The number of bytes of each value read from the address
+    fn value_size(address: &PcccAddress) -> Option<usize> {
+        match (address.is_word_address(), address.file_type) {
+            (true, _) => Some(2),
+            (false, PcccFileType::Float | PcccFileType::Long) => Some(4),
+            (false, PcccFileType::String) => address.file_type.element_size(),
+            (false, PcccFileType::Unknown(_)) => None,
+            (false, _) => Some(2),
+        }
+    }
+
+    /// Decodes the data read from the address
+    ///
+    /// Timer, counter and control elements are returned as their three words.
+    pub fn decode(address: &PcccAddress, data: &[u8]) -> BinResult<Vec<Self>> {
+        let value_size = Self::value_size(address).ok_or_else(|| binrw::Error::Custom {
+            pos: 0,
+            err: Box::new(format!("Can not decode {:?} files", address.file_type)),
+        })?;
+
+        if !data.len().is_multiple_of(value_size) {
+            return Err(binrw::Error::Custom {
+                pos: 0,
+                err: Box::new(format!(
+                    "{} bytes is not a whole number of {} byte values",
+                    data.len(),
+                    value_size
+                )),
+            });
+        }
+
+        let values = data.chunks_exact(value_size).map(|bytes| {
+            if let Some(bit) = address.bit {
+                let word = u16::from_le_bytes([bytes[0], bytes[1]]);
+                return PcccValue::Bool(word & (1 << bit) != 0);
+            }
+
+            match (value_size, address.file_type) {
+                (4, PcccFileType::Float) => {
+                    PcccValue::Float(f32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+                }
+                (4, _) => {
+                    PcccValue::Long(i32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+                }
+                (2, _) => PcccValue::Integer(i16::from_le_bytes([bytes[0], bytes[1]])),
+                _ => {
+                    // The characters of a string are stored with each pair of bytes swapped
+                    let length = (u16::from_le_bytes([bytes[0], bytes[1]]) as usize)
+                        .min(PCCC_STRING_CAPACITY);
+                    let characters: Vec<u8> = bytes[2..]
+                        .chunks_exact(2)
+                        .flat_map(|pair| [pair[1], pair[0]])
+                        .take(length)
+                        .collect();
+
+                    PcccValue::String(String::from_utf8_lossy(&characters).into_owned())
+                }
+            }
+        });
+
+        Ok(values.collect())
+    }
+
+    /// Encodes the values to write to the address, in the format they are read
+    pub fn encode(address: &PcccAddress, values: &[PcccValue]) -> BinResult<Vec<u8>> {
+        let value_size = Self::value_size(address);
+
+        let mut data = Vec::new();
+        for value in values {
+            match (value, value_size, address.file_type) {
+                (PcccValue::Integer(value), Some(2), _) if address.bit.is_none() => {
+                    data.extend(value.to_le_bytes())
+                }
+                (PcccValue::Float(value), Some(4), PcccFileType::Float) => {
+                    data.extend(value.to_le_bytes())
+                }
+                (PcccValue::Long(value), Some(4), PcccFileType::Long) => {
+                    data.extend(value.to_le_bytes())
+                }
+                (PcccValue::String(value), Some(_), PcccFileType::String)
+                    if !address.is_word_address() && value.len() <= PCCC_STRING_CAPACITY =>
+                {
+                    let mut characters = value.as_bytes().to_vec();
+                    characters.resize(PCCC_STRING_CAPACITY, 0);
+
+                    data.extend((value.len() as u16).to_le_bytes());
+                    data.extend(
+                        characters
+                            .chunks_exact(2)
+                            .flat_map(|pair| [pair[1], pair[0]]),
+                    );
+                }
+                _ => {
+                    return Err(binrw::Error::Custom {
+                        pos: 0,
+                        err: Box::new(format!(
+                            "{:?} can not be written to {:?} file address {:?}",
+                            value, address.file_type, address
+                        )),
+                    });
+                }
+            }
+        }
+
+        Ok(data)
+    }
+}
+
+// ^^^^^^^^ End of PcccValue impl ^^^^^^^^
+
+/// Builds PCCC commands for a SLC 500, PLC-5 or MicroLogix controller and checks their replies
+///
+/// Every command gets a new transaction number, which its reply must echo.
+#[derive(Debug)]
+pub struct Pccc {
+    pub requestor_id: RequestorId,
+    transaction_number: CipUint,
+}
+
+// ======= Start of Pccc impl ========
+
+impl Pccc {
+    pub fn new(vendor_id: CipUint, serial_number: CipUdint) -> Self {
+        Pccc {
+            requestor_id: RequestorId {
+                vendor_id,
+                serial_number,
+            },
+            transaction_number: 0,
+        }
+    }
+
+    /// The transaction number of the last command
+    pub fn transaction_number(&self) -> CipUint {
+        self.transaction_number
+    }
+
+    fn command_request<T>(
+        &mut self,
+        function_code: PcccFunctionCode,
+        function_data: &T,
+    ) -> BinResult<MessageRouterRequest>
+    where
+        T: for<'a> binrw::BinWrite<Args<'a> = ()>,
+    {
+        self.transaction_number = self.transaction_number.wrapping_add(1);
+
+        Ok(MessageRouterRequest::new_data(
+            PcccServiceCode::ExecutePccc.into(),
+            pccc_object_path(),
+            Some(Box::new(ExecutePcccRequest {
+                requestor_id: self.requestor_id,
+                command: PCCC_TYPED_COMMAND,
+                status: 0,
+                transaction_number: self.transaction_number,
+                function_code,
+                function_data: encode(function_data)?,
+            })),
+        ))
+    }
+
+    /// Reads `element_count` values starting at the address
+    pub fn read_request(
+        &mut self,
+        address: &PcccAddress,
+        element_count: u16,
+    ) -> BinResult<MessageRouterRequest> {
+        let byte_size = address.element_size().unwrap_or(0) * element_count as usize;
+        let byte_size = CipUsint::try_from(byte_size).map_err(|_| binrw::Error::Custom {
+            pos: 0,
+            err: Box::new(format!(
+                "A {} byte read does not fit in one command",
+                byte_size
+            )),
+        })?;
+
+        self.command_request(
+            PcccFunctionCode::TypedLogicalRead,
+            &TypedReadData {
+                byte_size,
+                address: address.clone(),
+            },
+        )
+    }
+
+    /// Writes the values starting at the address
+    pub fn write_request(
+        &mut self,
+        address: &PcccAddress,
+        values: &[PcccValue],
+    ) -> BinResult<MessageRouterRequest> {
+        let data = PcccValue::encode(address, values)?;
+        if data.len() > CipUsint::MAX as usize {
+            return Err(binrw::Error::Custom {
+                pos: 0,
+                err: Box::new(format!(
+                    "A {} byte write does not fit in one command",
+                    data.len()
+                )),
+            });
+        }
+
+        self.command_request(
+            PcccFunctionCode::TypedLogicalWrite,
+            &TypedWriteData {
+                address: address.clone(),
+                data,
+            },
+        )
+    }
+
+    /// Sets (or clears) the bit of a bit address, e.g. `B3/5`, leaving the rest of the word untouched
+    pub fn write_bit_request(
+        &mut self,
+        address: &PcccAddress,
+        value: bool,
+    ) -> BinResult<MessageRouterRequest> {
+        let bit = address.bit.ok_or_else(|| binrw::Error::Custom {
+            pos: 0,
+            err: Box::new(format!("{:?} is not a bit address", address)),
+        })?;
+
+        let mask = 1 << bit;
+        self.command_request(
+            PcccFunctionCode::TypedLogicalMaskedWrite,
+            &MaskedWriteData {
+                address: address.clone(),
+                mask,
+                value: if value { mask } else { 0 },
+            },
+        )
+    }
+
+    /// Extracts the PCCC reply of the last command, failing on CIP or PCCC errors
+    pub fn read_reply(&self, response: &MessageRouterResponse) -> BinResult<ExecutePcccReply> {
+        response.check_status()?;
+
+        let reply: ExecutePcccReply = response.response_data.read_reply()?;
+        if reply.transaction_number != self.transaction_number {
+            return Err(binrw::Error::Custom {
+                pos: 0,
+                err: Box::new(format!(
+                    "Received the reply to transaction {} while waiting for transaction {}",
+                    reply.transaction_number, self.transaction_number
+                )),
+            });
+        }
+
+        reply.check_status()?;
+
+        Ok(reply)
+    }
+}
+
+// ^^^^^^^^ End of Pccc impl ^^^^^^^^
@@ -0,0 +1,136 @@
+use binrw::{
+    binrw, // #[binrw] attribute
+    BinRead,
+    BinWrite,
+};
+
+use bilge::prelude::u7;
+
+use crate::cip::message::shared::ServiceCode;
+use crate::cip::path::{CipPath, LogicalPathSegment, LogicalSegmentType};
+use crate::cip::types::CipUsint;
+
+/// The PCCC object, which executes PCCC commands encapsulated in CIP
+pub const PCCC_CLASS_ID: u16 = 0x67;
+
+/// Command code of the protected typed logical commands
+pub const PCCC_TYPED_COMMAND: CipUsint = 0x0F;
+
+/// Set in the command code of every PCCC reply
+pub const PCCC_REPLY_FLAG: CipUsint = 0x40;
+
+/// Status indicating that an extended status byte follows the transaction number
+pub const PCCC_EXTENDED_STATUS: CipUsint = 0xF0;
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+#[repr(u8)]
+pub enum PcccServiceCode {
+    ExecutePccc = 0x4B,
+}
+
+impl From<PcccServiceCode> for ServiceCode {
+    fn from(service_code: PcccServiceCode) -> Self {
+        ServiceCode::from(u7::new(service_code as u8))
+    }
+}
+
+pub fn pccc_object_path() -> CipPath {
+    CipPath {
+        class_id_segment: match u8::try_from(PCCC_CLASS_ID) {
+            Ok(class_id) => LogicalPathSegment::new_u8(LogicalSegmentType::ClassId, class_id),
+            Err(_) => LogicalPathSegment::new_u16(LogicalSegmentType::ClassId, PCCC_CLASS_ID),
+        },
+        instance_id_segment: LogicalPathSegment::new_u8(LogicalSegmentType::InstanceId, 0x01),
+        attribute_id_segment: None,
+    }
+}
+
+/// Function codes of the protected typed logical commands, addressed with three address fields
+#[derive(BinRead, BinWrite)]
+#[brw(little, repr = CipUsint)]
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub enum PcccFunctionCode {
+    TypedLogicalRead = 0xA2,
+    TypedLogicalWrite = 0xAA,
+    TypedLogicalMaskedWrite = 0xAB,
+}
+
+#[binrw]
+#[brw(little)]
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum PcccFileType {
+    #[brw(magic = 0x84u8)]
+    Status,
+    #[brw(magic = 0x85u8)]
+    Bit,
+    #[brw(magic = 0x86u8)]
+    Timer,
+    #[brw(magic = 0x87u8)]
+    Counter,
+    #[brw(magic = 0x88u8)]
+    Control,
+    #[brw(magic = 0x89u8)]
+    Integer,
+    #[brw(magic = 0x8Au8)]
+    Float,
+    #[brw(magic = 0x8Bu8)]
+    Output,
+    #[brw(magic = 0x8Cu8)]
+    Input,
+    #[brw(magic = 0x8Du8)]
+    String,
+    #[brw(magic = 0x8Eu8)]
+    Ascii,
+    #[brw(magic = 0x91u8)]
+    Long,
+    Unknown(u8),
+}
+
+// ======= Start of PcccFileType impl ========
+
+impl PcccFileType {
+    /// The file type of a data table address prefix, e.g. `N` for `N7:0`
+    pub fn from_prefix(prefix: &str) -> Option<Self> {
+        use PcccFileType::*;
+        match prefix {
+            "S" => Some(Status),
+            "B" => Some(Bit),
+            "T" => Some(Timer),
+            "C" => Some(Counter),
+            "R" => Some(Control),
+            "N" => Some(Integer),
+            "F" => Some(Float),
+            "O" => Some(Output),
+            "I" => Some(Input),
+            "ST" => Some(String),
+            "A" => Some(Ascii),
+            "L" => Some(Long),
+            _ => None,
+        }
+    }
+
+    /// The file number used when an address leaves it out, e.g. `S:1`
+    pub fn default_file_number(&self) -> Option<u16> {
+        use PcccFileType::*;
+        match self {
+            Output => Some(0),
+            Input => Some(1),
+            Status => Some(2),
+            _ => None,
+        }
+    }
+
+    /// The number of bytes taken up by a single element of the file
+    pub fn element_size(&self) -> Option<usize> {
+        use PcccFileType::*;
+        match self {
+            Status | Bit | Integer | Output | Input | Ascii => Some(2),
+            Float | Long => Some(4),
+            Timer | Counter | Control => Some(6),
+            String => Some(84),
+            Unknown(_) => None,
+        }
+    }
+}
+
+// ^^^^^^^^ End of PcccFileType impl ^^^^^^^^
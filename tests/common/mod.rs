@@ -0,0 +1,39 @@
+// Each test crate only uses some of the helpers
+#![allow(dead_code)]
+
+use binrw::{BinRead, BinWrite};
+
+use eipscanne_rs::cip::message::request::MessageRouterRequest;
+use eipscanne_rs::cip::message::response::MessageRouterResponse;
+use eipscanne_rs::cip::types::CipByte;
+
+pub const ATTRIBUTE_NOT_SUPPORTED: u8 = 0x14;
+pub const OBJECT_DOES_NOT_EXIST: u8 = 0x16;
+
+pub fn write_request_bytes(request: &MessageRouterRequest) -> Vec<u8> {
+    let mut request_bytes: Vec<u8> = Vec::new();
+    let mut writer = std::io::Cursor::new(&mut request_bytes);
+
+    request.write(&mut writer).unwrap();
+
+    request_bytes
+}
+
+pub fn read_response(response_bytes: Vec<CipByte>) -> MessageRouterResponse {
+    let response_length = response_bytes.len() as u16;
+    let mut reader = std::io::Cursor::new(response_bytes);
+
+    MessageRouterResponse::read_args(&mut reader, (response_length,)).unwrap()
+}
+
+/// A Get Attribute Single reply holding the attribute data
+pub fn attribute_reply(data: &[u8]) -> MessageRouterResponse {
+    let mut response_bytes = vec![0x8e, 0x00, 0x00, 0x00];
+    response_bytes.extend_from_slice(data);
+    read_response(response_bytes)
+}
+
+/// A Get Attribute Single reply failing with the general status
+pub fn attribute_error(status: u8) -> MessageRouterResponse {
+    read_response(vec![0x8e, 0x00, status, 0x00])
+}
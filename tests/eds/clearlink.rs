@@ -0,0 +1,1154 @@
+// Generated by eipscanne_rs from the EDS of ClearLink (Teknic, Inc.), revision 2.93
+
+/// Param1: AI0 Range
+#[binrw::binrw]
+#[brw(little)]
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Ai0Range(pub u8);
+
+impl Ai0Range {
+    pub const PARAM_NUMBER: u32 = 1;
+    pub const DEFAULT: u8 = 100;
+}
+
+/// Param2: AI1 Range
+#[binrw::binrw]
+#[brw(little)]
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Ai1Range(pub u8);
+
+impl Ai1Range {
+    pub const PARAM_NUMBER: u32 = 2;
+    pub const DEFAULT: u8 = 100;
+}
+
+/// Param3: AI2 Range
+#[binrw::binrw]
+#[brw(little)]
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Ai2Range(pub u8);
+
+impl Ai2Range {
+    pub const PARAM_NUMBER: u32 = 3;
+    pub const DEFAULT: u8 = 100;
+}
+
+/// Param4: AI3 Range
+#[binrw::binrw]
+#[brw(little)]
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Ai3Range(pub u8);
+
+impl Ai3Range {
+    pub const PARAM_NUMBER: u32 = 4;
+    pub const DEFAULT: u8 = 100;
+}
+
+/// Param5: AO0 Range
+#[binrw::binrw]
+#[brw(little)]
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Ao0Range(pub u8);
+
+impl Ao0Range {
+    pub const PARAM_NUMBER: u32 = 5;
+    pub const DEFAULT: u8 = 100;
+}
+
+/// Param6: DOP PWM Frequency
+#[binrw::binrw]
+#[brw(little)]
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct DopPwmFrequency(pub u8);
+
+impl DopPwmFrequency {
+    pub const PARAM_NUMBER: u32 = 6;
+    pub const DEFAULT: u8 = 0;
+}
+
+/// Param7: CCIO Enable
+#[binrw::binrw]
+#[brw(little)]
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct CcioEnable(pub u8);
+
+impl CcioEnable {
+    pub const PARAM_NUMBER: u32 = 7;
+    pub const DEFAULT: u8 = 0;
+}
+
+/// Param8: AIP Filters (ms)
+#[binrw::binrw]
+#[brw(little)]
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct AipFilters(pub [u8; 4]);
+
+impl AipFilters {
+    pub const PARAM_NUMBER: u32 = 8;
+}
+
+/// Param9: DIP Filters (us)
+#[binrw::binrw]
+#[brw(little)]
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct DipFilters(pub [u8; 52]);
+
+impl DipFilters {
+    pub const PARAM_NUMBER: u32 = 9;
+}
+
+/// Param10: CCIO Filters (ms)
+#[binrw::binrw]
+#[brw(little)]
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct CcioFilters(pub [u8; 8]);
+
+impl CcioFilters {
+    pub const PARAM_NUMBER: u32 = 10;
+}
+
+/// Param11: Encoder Velocity Resolution (ms)
+#[binrw::binrw]
+#[brw(little)]
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct EncoderVelocityResolution(pub u32);
+
+impl EncoderVelocityResolution {
+    pub const PARAM_NUMBER: u32 = 11;
+    pub const DEFAULT: u32 = 100;
+}
+
+/// Param12: Encoder Reserved
+#[binrw::binrw]
+#[brw(little)]
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct EncoderReserved(pub u8);
+
+impl EncoderReserved {
+    pub const PARAM_NUMBER: u32 = 12;
+    pub const DEFAULT: u8 = 5;
+}
+
+/// Param13: Motor 0 Config Register
+#[binrw::binrw]
+#[brw(little)]
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Motor0ConfigRegister(pub u32);
+
+impl Motor0ConfigRegister {
+    pub const PARAM_NUMBER: u32 = 13;
+    pub const DEFAULT: u32 = 8;
+}
+
+/// Param14: Motor 0 Follow Divisor
+#[binrw::binrw]
+#[brw(little)]
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Motor0FollowDivisor(pub i32);
+
+impl Motor0FollowDivisor {
+    pub const PARAM_NUMBER: u32 = 14;
+    pub const DEFAULT: i32 = 1;
+}
+
+/// Param15: Motor 0 Follow Multiplier
+#[binrw::binrw]
+#[brw(little)]
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Motor0FollowMultiplier(pub i32);
+
+impl Motor0FollowMultiplier {
+    pub const PARAM_NUMBER: u32 = 15;
+    pub const DEFAULT: i32 = 1;
+}
+
+/// Param16: Motor 0 Max Deceleration (step/s^2)
+#[binrw::binrw]
+#[brw(little)]
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Motor0MaxDeceleration(pub i32);
+
+impl Motor0MaxDeceleration {
+    pub const PARAM_NUMBER: u32 = 16;
+    pub const DEFAULT: i32 = 10000000;
+}
+
+/// Param17: Motor 0 Soft Limit Position 1 (step)
+#[binrw::binrw]
+#[brw(little)]
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Motor0SoftLimitPosition1(pub i32);
+
+impl Motor0SoftLimitPosition1 {
+    pub const PARAM_NUMBER: u32 = 17;
+    pub const DEFAULT: i32 = 0;
+}
+
+/// Param18: Motor 0 Soft Limit Position 2 (step)
+#[binrw::binrw]
+#[brw(little)]
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Motor0SoftLimitPosition2(pub i32);
+
+impl Motor0SoftLimitPosition2 {
+    pub const PARAM_NUMBER: u32 = 18;
+    pub const DEFAULT: i32 = 0;
+}
+
+/// Param19: Motor 0 Positive Limit Connector
+#[binrw::binrw]
+#[brw(little)]
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Motor0PositiveLimitConnector(pub i8);
+
+impl Motor0PositiveLimitConnector {
+    pub const PARAM_NUMBER: u32 = 19;
+    pub const DEFAULT: i8 = -1;
+}
+
+/// Param20: Motor 0 Negative Limit Connector
+#[binrw::binrw]
+#[brw(little)]
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Motor0NegativeLimitConnector(pub i8);
+
+impl Motor0NegativeLimitConnector {
+    pub const PARAM_NUMBER: u32 = 20;
+    pub const DEFAULT: i8 = -1;
+}
+
+/// Param21: Motor 0 Home Sensor Connector
+#[binrw::binrw]
+#[brw(little)]
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Motor0HomeSensorConnector(pub i8);
+
+impl Motor0HomeSensorConnector {
+    pub const PARAM_NUMBER: u32 = 21;
+    pub const DEFAULT: i8 = -1;
+}
+
+/// Param22: Motor 0 Brake Output Connector
+#[binrw::binrw]
+#[brw(little)]
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Motor0BrakeOutputConnector(pub i8);
+
+impl Motor0BrakeOutputConnector {
+    pub const PARAM_NUMBER: u32 = 22;
+    pub const DEFAULT: i8 = -1;
+}
+
+/// Param23: Motor 0 Stop Sensor Connector
+#[binrw::binrw]
+#[brw(little)]
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Motor0StopSensorConnector(pub i8);
+
+impl Motor0StopSensorConnector {
+    pub const PARAM_NUMBER: u32 = 23;
+    pub const DEFAULT: i8 = -1;
+}
+
+/// Param24: Motor 0 Trigger Position Capture Connector
+#[binrw::binrw]
+#[brw(little)]
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Motor0TriggerPositionCaptureConnector(pub i8);
+
+impl Motor0TriggerPositionCaptureConnector {
+    pub const PARAM_NUMBER: u32 = 24;
+    pub const DEFAULT: i8 = -1;
+}
+
+/// Param25: Motor 0 Follow Axis
+#[binrw::binrw]
+#[brw(little)]
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Motor0FollowAxis(pub i8);
+
+impl Motor0FollowAxis {
+    pub const PARAM_NUMBER: u32 = 25;
+    pub const DEFAULT: i8 = -1;
+}
+
+/// Param26: Motor 1 Config Register
+#[binrw::binrw]
+#[brw(little)]
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Motor1ConfigRegister(pub u32);
+
+impl Motor1ConfigRegister {
+    pub const PARAM_NUMBER: u32 = 26;
+    pub const DEFAULT: u32 = 8;
+}
+
+/// Param27: Motor 1 Follow Divisor
+#[binrw::binrw]
+#[brw(little)]
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Motor1FollowDivisor(pub i32);
+
+impl Motor1FollowDivisor {
+    pub const PARAM_NUMBER: u32 = 27;
+    pub const DEFAULT: i32 = 1;
+}
+
+/// Param28: Motor 1 Follow Multiplier
+#[binrw::binrw]
+#[brw(little)]
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Motor1FollowMultiplier(pub i32);
+
+impl Motor1FollowMultiplier {
+    pub const PARAM_NUMBER: u32 = 28;
+    pub const DEFAULT: i32 = 1;
+}
+
+/// Param29: Motor 1 Max Deceleration (step/s^2)
+#[binrw::binrw]
+#[brw(little)]
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Motor1MaxDeceleration(pub i32);
+
+impl Motor1MaxDeceleration {
+    pub const PARAM_NUMBER: u32 = 29;
+    pub const DEFAULT: i32 = 10000000;
+}
+
+/// Param30: Motor 1 Soft Limit Position 1 (step)
+#[binrw::binrw]
+#[brw(little)]
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Motor1SoftLimitPosition1(pub i32);
+
+impl Motor1SoftLimitPosition1 {
+    pub const PARAM_NUMBER: u32 = 30;
+    pub const DEFAULT: i32 = 0;
+}
+
+/// Param31: Motor 1 Soft Limit Position 2 (step)
+#[binrw::binrw]
+#[brw(little)]
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Motor1SoftLimitPosition2(pub i32);
+
+impl Motor1SoftLimitPosition2 {
+    pub const PARAM_NUMBER: u32 = 31;
+    pub const DEFAULT: i32 = 0;
+}
+
+/// Param32: Motor 1 Positive Limit Connector
+#[binrw::binrw]
+#[brw(little)]
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Motor1PositiveLimitConnector(pub i8);
+
+impl Motor1PositiveLimitConnector {
+    pub const PARAM_NUMBER: u32 = 32;
+    pub const DEFAULT: i8 = -1;
+}
+
+/// Param33: Motor 1 Negative Limit Connector
+#[binrw::binrw]
+#[brw(little)]
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Motor1NegativeLimitConnector(pub i8);
+
+impl Motor1NegativeLimitConnector {
+    pub const PARAM_NUMBER: u32 = 33;
+    pub const DEFAULT: i8 = -1;
+}
+
+/// Param34: Motor 1 Home Sensor Connector
+#[binrw::binrw]
+#[brw(little)]
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Motor1HomeSensorConnector(pub i8);
+
+impl Motor1HomeSensorConnector {
+    pub const PARAM_NUMBER: u32 = 34;
+    pub const DEFAULT: i8 = -1;
+}
+
+/// Param35: Motor 1 Brake Output Connector
+#[binrw::binrw]
+#[brw(little)]
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Motor1BrakeOutputConnector(pub i8);
+
+impl Motor1BrakeOutputConnector {
+    pub const PARAM_NUMBER: u32 = 35;
+    pub const DEFAULT: i8 = -1;
+}
+
+/// Param36: Motor 1 Stop Sensor Connector
+#[binrw::binrw]
+#[brw(little)]
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Motor1StopSensorConnector(pub i8);
+
+impl Motor1StopSensorConnector {
+    pub const PARAM_NUMBER: u32 = 36;
+    pub const DEFAULT: i8 = -1;
+}
+
+/// Param37: Motor 1 Trigger Position Capture Connector
+#[binrw::binrw]
+#[brw(little)]
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Motor1TriggerPositionCaptureConnector(pub i8);
+
+impl Motor1TriggerPositionCaptureConnector {
+    pub const PARAM_NUMBER: u32 = 37;
+    pub const DEFAULT: i8 = -1;
+}
+
+/// Param38: Motor 1 Follow Axis
+#[binrw::binrw]
+#[brw(little)]
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Motor1FollowAxis(pub i8);
+
+impl Motor1FollowAxis {
+    pub const PARAM_NUMBER: u32 = 38;
+    pub const DEFAULT: i8 = -1;
+}
+
+/// Param39: Motor 2 Config Register
+#[binrw::binrw]
+#[brw(little)]
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Motor2ConfigRegister(pub u32);
+
+impl Motor2ConfigRegister {
+    pub const PARAM_NUMBER: u32 = 39;
+    pub const DEFAULT: u32 = 8;
+}
+
+/// Param40: Motor 2 Follow Divisor
+#[binrw::binrw]
+#[brw(little)]
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Motor2FollowDivisor(pub i32);
+
+impl Motor2FollowDivisor {
+    pub const PARAM_NUMBER: u32 = 40;
+    pub const DEFAULT: i32 = 1;
+}
+
+/// Param41: Motor 2 Follow Multiplier
+#[binrw::binrw]
+#[brw(little)]
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Motor2FollowMultiplier(pub i32);
+
+impl Motor2FollowMultiplier {
+    pub const PARAM_NUMBER: u32 = 41;
+    pub const DEFAULT: i32 = 1;
+}
+
+/// Param42: Motor 2 Max Deceleration (step/s^2)
+#[binrw::binrw]
+#[brw(little)]
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Motor2MaxDeceleration(pub i32);
+
+impl Motor2MaxDeceleration {
+    pub const PARAM_NUMBER: u32 = 42;
+    pub const DEFAULT: i32 = 10000000;
+}
+
+/// Param43: Motor 2 Soft Limit Position 1 (step)
+#[binrw::binrw]
+#[brw(little)]
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Motor2SoftLimitPosition1(pub i32);
+
+impl Motor2SoftLimitPosition1 {
+    pub const PARAM_NUMBER: u32 = 43;
+    pub const DEFAULT: i32 = 0;
+}
+
+/// Param44: Motor 2 Soft Limit Position 2 (step)
+#[binrw::binrw]
+#[brw(little)]
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Motor2SoftLimitPosition2(pub i32);
+
+impl Motor2SoftLimitPosition2 {
+    pub const PARAM_NUMBER: u32 = 44;
+    pub const DEFAULT: i32 = 0;
+}
+
+/// Param45: Motor 2 Positive Limit Connector
+#[binrw::binrw]
+#[brw(little)]
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Motor2PositiveLimitConnector(pub i8);
+
+impl Motor2PositiveLimitConnector {
+    pub const PARAM_NUMBER: u32 = 45;
+    pub const DEFAULT: i8 = -1;
+}
+
+/// Param46: Motor 2 Negative Limit Connector
+#[binrw::binrw]
+#[brw(little)]
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Motor2NegativeLimitConnector(pub i8);
+
+impl Motor2NegativeLimitConnector {
+    pub const PARAM_NUMBER: u32 = 46;
+    pub const DEFAULT: i8 = -1;
+}
+
+/// Param47: Motor 2 Home Sensor Connector
+#[binrw::binrw]
+#[brw(little)]
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Motor2HomeSensorConnector(pub i8);
+
+impl Motor2HomeSensorConnector {
+    pub const PARAM_NUMBER: u32 = 47;
+    pub const DEFAULT: i8 = -1;
+}
+
+/// Param48: Motor 2 Brake Output Connector
+#[binrw::binrw]
+#[brw(little)]
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Motor2BrakeOutputConnector(pub i8);
+
+impl Motor2BrakeOutputConnector {
+    pub const PARAM_NUMBER: u32 = 48;
+    pub const DEFAULT: i8 = -1;
+}
+
+/// Param49: Motor 2 Stop Sensor Connector
+#[binrw::binrw]
+#[brw(little)]
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Motor2StopSensorConnector(pub i8);
+
+impl Motor2StopSensorConnector {
+    pub const PARAM_NUMBER: u32 = 49;
+    pub const DEFAULT: i8 = -1;
+}
+
+/// Param50: Motor 2 Trigger Position Capture Connector
+#[binrw::binrw]
+#[brw(little)]
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Motor2TriggerPositionCaptureConnector(pub i8);
+
+impl Motor2TriggerPositionCaptureConnector {
+    pub const PARAM_NUMBER: u32 = 50;
+    pub const DEFAULT: i8 = -1;
+}
+
+/// Param51: Motor 2 Follow Axis
+#[binrw::binrw]
+#[brw(little)]
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Motor2FollowAxis(pub i8);
+
+impl Motor2FollowAxis {
+    pub const PARAM_NUMBER: u32 = 51;
+    pub const DEFAULT: i8 = -1;
+}
+
+/// Param52: Motor 3 Config Register
+#[binrw::binrw]
+#[brw(little)]
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Motor3ConfigRegister(pub u32);
+
+impl Motor3ConfigRegister {
+    pub const PARAM_NUMBER: u32 = 52;
+    pub const DEFAULT: u32 = 8;
+}
+
+/// Param53: Motor 3 Follow Divisor
+#[binrw::binrw]
+#[brw(little)]
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Motor3FollowDivisor(pub i32);
+
+impl Motor3FollowDivisor {
+    pub const PARAM_NUMBER: u32 = 53;
+    pub const DEFAULT: i32 = 1;
+}
+
+/// Param54: Motor 3 Follow Multiplier
+#[binrw::binrw]
+#[brw(little)]
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Motor3FollowMultiplier(pub i32);
+
+impl Motor3FollowMultiplier {
+    pub const PARAM_NUMBER: u32 = 54;
+    pub const DEFAULT: i32 = 1;
+}
+
+/// Param55: Motor 3 Max Deceleration (step/s^2)
+#[binrw::binrw]
+#[brw(little)]
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Motor3MaxDeceleration(pub i32);
+
+impl Motor3MaxDeceleration {
+    pub const PARAM_NUMBER: u32 = 55;
+    pub const DEFAULT: i32 = 10000000;
+}
+
+/// Param56: Motor 3 Soft Limit Position 1 (step)
+#[binrw::binrw]
+#[brw(little)]
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Motor3SoftLimitPosition1(pub i32);
+
+impl Motor3SoftLimitPosition1 {
+    pub const PARAM_NUMBER: u32 = 56;
+    pub const DEFAULT: i32 = 0;
+}
+
+/// Param57: Motor 3 Soft Limit Position 2 (step)
+#[binrw::binrw]
+#[brw(little)]
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Motor3SoftLimitPosition2(pub i32);
+
+impl Motor3SoftLimitPosition2 {
+    pub const PARAM_NUMBER: u32 = 57;
+    pub const DEFAULT: i32 = 0;
+}
+
+/// Param58: Motor 3 Positive Limit Connector
+#[binrw::binrw]
+#[brw(little)]
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Motor3PositiveLimitConnector(pub i8);
+
+impl Motor3PositiveLimitConnector {
+    pub const PARAM_NUMBER: u32 = 58;
+    pub const DEFAULT: i8 = -1;
+}
+
+/// Param59: Motor 3 Negative Limit Connector
+#[binrw::binrw]
+#[brw(little)]
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Motor3NegativeLimitConnector(pub i8);
+
+impl Motor3NegativeLimitConnector {
+    pub const PARAM_NUMBER: u32 = 59;
+    pub const DEFAULT: i8 = -1;
+}
+
+/// Param60: Motor 3 Home Sensor Connector
+#[binrw::binrw]
+#[brw(little)]
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Motor3HomeSensorConnector(pub i8);
+
+impl Motor3HomeSensorConnector {
+    pub const PARAM_NUMBER: u32 = 60;
+    pub const DEFAULT: i8 = -1;
+}
+
+/// Param61: Motor 3 Brake Output Connector
+#[binrw::binrw]
+#[brw(little)]
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Motor3BrakeOutputConnector(pub i8);
+
+impl Motor3BrakeOutputConnector {
+    pub const PARAM_NUMBER: u32 = 61;
+    pub const DEFAULT: i8 = -1;
+}
+
+/// Param62: Motor 3 Stop Sensor Connector
+#[binrw::binrw]
+#[brw(little)]
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Motor3StopSensorConnector(pub i8);
+
+impl Motor3StopSensorConnector {
+    pub const PARAM_NUMBER: u32 = 62;
+    pub const DEFAULT: i8 = -1;
+}
+
+/// Param63: Motor 3 Trigger Position Capture Connector
+#[binrw::binrw]
+#[brw(little)]
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Motor3TriggerPositionCaptureConnector(pub i8);
+
+impl Motor3TriggerPositionCaptureConnector {
+    pub const PARAM_NUMBER: u32 = 63;
+    pub const DEFAULT: i8 = -1;
+}
+
+/// Param64: Motor 3 Follow Axis
+#[binrw::binrw]
+#[brw(little)]
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Motor3FollowAxis(pub i8);
+
+impl Motor3FollowAxis {
+    pub const PARAM_NUMBER: u32 = 64;
+    pub const DEFAULT: i8 = -1;
+}
+
+/// Param65: Serial Baud Rate (baud)
+#[binrw::binrw]
+#[brw(little)]
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct SerialBaudRate(pub u32);
+
+impl SerialBaudRate {
+    pub const PARAM_NUMBER: u32 = 65;
+    pub const DEFAULT: u32 = 115200;
+}
+
+/// Param66: Serial Input Start Delimiter
+#[binrw::binrw]
+#[brw(little)]
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct SerialInputStartDelimiter(pub u32);
+
+impl SerialInputStartDelimiter {
+    pub const PARAM_NUMBER: u32 = 66;
+    pub const DEFAULT: u32 = 0;
+}
+
+/// Param67: Serial Input End Delimiter
+#[binrw::binrw]
+#[brw(little)]
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct SerialInputEndDelimiter(pub u32);
+
+impl SerialInputEndDelimiter {
+    pub const PARAM_NUMBER: u32 = 67;
+    pub const DEFAULT: u32 = 0;
+}
+
+/// Param68: Serial Output Start Delimiter
+#[binrw::binrw]
+#[brw(little)]
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct SerialOutputStartDelimiter(pub u32);
+
+impl SerialOutputStartDelimiter {
+    pub const PARAM_NUMBER: u32 = 68;
+    pub const DEFAULT: u32 = 0;
+}
+
+/// Param69: Serial Output End Delimiter
+#[binrw::binrw]
+#[brw(little)]
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct SerialOutputEndDelimiter(pub u32);
+
+impl SerialOutputEndDelimiter {
+    pub const PARAM_NUMBER: u32 = 69;
+    pub const DEFAULT: u32 = 0;
+}
+
+/// Param70: Serial Input Timeout (ms)
+#[binrw::binrw]
+#[brw(little)]
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct SerialInputTimeout(pub u32);
+
+impl SerialInputTimeout {
+    pub const PARAM_NUMBER: u32 = 70;
+    pub const DEFAULT: u32 = 10;
+}
+
+/// Param71: AOP Value
+#[binrw::binrw]
+#[brw(little)]
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct AopValue(pub i16);
+
+impl AopValue {
+    pub const PARAM_NUMBER: u32 = 71;
+    pub const DEFAULT: i16 = 0;
+}
+
+/// Param72: DOP0 Value
+#[binrw::binrw]
+#[brw(little)]
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Dop0Value(pub u8);
+
+impl Dop0Value {
+    pub const PARAM_NUMBER: u32 = 72;
+    pub const DEFAULT: u8 = 0;
+}
+
+/// Param73: DOP1 Value
+#[binrw::binrw]
+#[brw(little)]
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Dop1Value(pub u8);
+
+impl Dop1Value {
+    pub const PARAM_NUMBER: u32 = 73;
+    pub const DEFAULT: u8 = 0;
+}
+
+/// Param74: DOP2 Value
+#[binrw::binrw]
+#[brw(little)]
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Dop2Value(pub u8);
+
+impl Dop2Value {
+    pub const PARAM_NUMBER: u32 = 74;
+    pub const DEFAULT: u8 = 0;
+}
+
+/// Param75: DOP3 Value
+#[binrw::binrw]
+#[brw(little)]
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Dop3Value(pub u8);
+
+impl Dop3Value {
+    pub const PARAM_NUMBER: u32 = 75;
+    pub const DEFAULT: u8 = 0;
+}
+
+/// Param76: DOP4 Value
+#[binrw::binrw]
+#[brw(little)]
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Dop4Value(pub u8);
+
+impl Dop4Value {
+    pub const PARAM_NUMBER: u32 = 76;
+    pub const DEFAULT: u8 = 0;
+}
+
+/// Param77: DOP5 Value
+#[binrw::binrw]
+#[brw(little)]
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Dop5Value(pub u8);
+
+impl Dop5Value {
+    pub const PARAM_NUMBER: u32 = 77;
+    pub const DEFAULT: u8 = 0;
+}
+
+/// Param78: DOP PWM
+#[binrw::binrw]
+#[brw(little)]
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct DopPwm(pub [u8; 6]);
+
+impl DopPwm {
+    pub const PARAM_NUMBER: u32 = 78;
+}
+
+/// Param79: CCIO Output Data
+#[binrw::binrw]
+#[brw(little)]
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct CcioOutputData(pub u64);
+
+impl CcioOutputData {
+    pub const PARAM_NUMBER: u32 = 79;
+    pub const DEFAULT: u64 = 0;
+}
+
+/// Param80: Encoder Add To Position (count)
+#[binrw::binrw]
+#[brw(little)]
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct EncoderAddToPosition(pub i32);
+
+impl EncoderAddToPosition {
+    pub const PARAM_NUMBER: u32 = 80;
+    pub const DEFAULT: i32 = 0;
+}
+
+/// Assem112: Output
+#[derive(eipscanne_rs::cip::assembly::CipAssembly, Debug, PartialEq, Clone)]
+#[cip(size = 280)]
+pub struct OutputAssembly {
+    /// Param71: AOP Value
+    #[cip(byte = 0)]
+    pub aop_value: i16,
+    /// Param72: DOP0 Value
+    #[cip(byte = 2, bit = 0)]
+    pub dop0_value: bool,
+    /// Param73: DOP1 Value
+    #[cip(byte = 2, bit = 1)]
+    pub dop1_value: bool,
+    /// Param74: DOP2 Value
+    #[cip(byte = 2, bit = 2)]
+    pub dop2_value: bool,
+    /// Param75: DOP3 Value
+    #[cip(byte = 2, bit = 3)]
+    pub dop3_value: bool,
+    /// Param76: DOP4 Value
+    #[cip(byte = 2, bit = 4)]
+    pub dop4_value: bool,
+    /// Param77: DOP5 Value
+    #[cip(byte = 2, bit = 5)]
+    pub dop5_value: bool,
+    /// Param78: DOP PWM
+    #[cip(byte = 4)]
+    pub dop_pwm: [u8; 6],
+    /// Param79: CCIO Output Data
+    #[cip(byte = 12)]
+    pub ccio_output_data: u64,
+    /// Param80: Encoder Add To Position
+    #[cip(byte = 20)]
+    pub encoder_add_to_position: i32,
+}
+
+impl OutputAssembly {
+    pub const ASSEMBLY_NUMBER: u32 = 112;
+    pub const INSTANCE_ID: u16 = 0x70;
+}
+
+impl Default for OutputAssembly {
+    fn default() -> Self {
+        use eipscanne_rs::cip::assembly::CipAssembly;
+        Self::read_fields(&[0; <Self as CipAssembly>::SIZE])
+    }
+}
+
+/// Assem150: Config
+#[derive(eipscanne_rs::cip::assembly::CipAssembly, Debug, PartialEq, Clone)]
+#[cip(size = 232)]
+pub struct ConfigAssembly {
+    /// Param1: AI0 Range
+    #[cip(byte = 0)]
+    pub ai0_range: u8,
+    /// Param2: AI1 Range
+    #[cip(byte = 1)]
+    pub ai1_range: u8,
+    /// Param3: AI2 Range
+    #[cip(byte = 2)]
+    pub ai2_range: u8,
+    /// Param4: AI3 Range
+    #[cip(byte = 3)]
+    pub ai3_range: u8,
+    /// Param5: AO0 Range
+    #[cip(byte = 4)]
+    pub ao0_range: u8,
+    /// Param6: DOP PWM Frequency
+    #[cip(byte = 5)]
+    pub dop_pwm_frequency: u8,
+    /// Param7: CCIO Enable
+    #[cip(byte = 6)]
+    pub ccio_enable: u8,
+    /// Param8: AIP Filters
+    #[cip(byte = 8)]
+    pub aip_filters: [u8; 4],
+    /// Param9: DIP Filters
+    #[cip(byte = 12)]
+    pub dip_filters: [u8; 52],
+    /// Param10: CCIO Filters
+    #[cip(byte = 64)]
+    pub ccio_filters: [u8; 8],
+    /// Param11: Encoder Velocity Resolution
+    #[cip(byte = 72)]
+    pub encoder_velocity_resolution: u32,
+    /// Param12: Encoder Reserved
+    #[cip(byte = 76)]
+    pub encoder_reserved: u8,
+    /// Param13: Motor 0 Config Register
+    #[cip(byte = 80)]
+    pub motor_0_config_register: u32,
+    /// Param14: Motor 0 Follow Divisor
+    #[cip(byte = 84)]
+    pub motor_0_follow_divisor: i32,
+    /// Param15: Motor 0 Follow Multiplier
+    #[cip(byte = 88)]
+    pub motor_0_follow_multiplier: i32,
+    /// Param16: Motor 0 Max Deceleration
+    #[cip(byte = 92)]
+    pub motor_0_max_deceleration: i32,
+    /// Param17: Motor 0 Soft Limit Position 1
+    #[cip(byte = 96)]
+    pub motor_0_soft_limit_position_1: i32,
+    /// Param18: Motor 0 Soft Limit Position 2
+    #[cip(byte = 100)]
+    pub motor_0_soft_limit_position_2: i32,
+    /// Param19: Motor 0 Positive Limit Connector
+    #[cip(byte = 104)]
+    pub motor_0_positive_limit_connector: i8,
+    /// Param20: Motor 0 Negative Limit Connector
+    #[cip(byte = 105)]
+    pub motor_0_negative_limit_connector: i8,
+    /// Param21: Motor 0 Home Sensor Connector
+    #[cip(byte = 106)]
+    pub motor_0_home_sensor_connector: i8,
+    /// Param22: Motor 0 Brake Output Connector
+    #[cip(byte = 107)]
+    pub motor_0_brake_output_connector: i8,
+    /// Param23: Motor 0 Stop Sensor Connector
+    #[cip(byte = 108)]
+    pub motor_0_stop_sensor_connector: i8,
+    /// Param24: Motor 0 Trigger Position Capture Connector
+    #[cip(byte = 109)]
+    pub motor_0_trigger_position_capture_connector: i8,
+    /// Param25: Motor 0 Follow Axis
+    #[cip(byte = 110)]
+    pub motor_0_follow_axis: i8,
+    /// Param26: Motor 1 Config Register
+    #[cip(byte = 112)]
+    pub motor_1_config_register: u32,
+    /// Param27: Motor 1 Follow Divisor
+    #[cip(byte = 116)]
+    pub motor_1_follow_divisor: i32,
+    /// Param28: Motor 1 Follow Multiplier
+    #[cip(byte = 120)]
+    pub motor_1_follow_multiplier: i32,
+    /// Param29: Motor 1 Max Deceleration
+    #[cip(byte = 124)]
+    pub motor_1_max_deceleration: i32,
+    /// Param30: Motor 1 Soft Limit Position 1
+    #[cip(byte = 128)]
+    pub motor_1_soft_limit_position_1: i32,
+    /// Param31: Motor 1 Soft Limit Position 2
+    #[cip(byte = 132)]
+    pub motor_1_soft_limit_position_2: i32,
+    /// Param32: Motor 1 Positive Limit Connector
+    #[cip(byte = 136)]
+    pub motor_1_positive_limit_connector: i8,
+    /// Param33: Motor 1 Negative Limit Connector
+    #[cip(byte = 137)]
+    pub motor_1_negative_limit_connector: i8,
+    /// Param34: Motor 1 Home Sensor Connector
+    #[cip(byte = 138)]
+    pub motor_1_home_sensor_connector: i8,
+    /// Param35: Motor 1 Brake Output Connector
+    #[cip(byte = 139)]
+    pub motor_1_brake_output_connector: i8,
+    /// Param36: Motor 1 Stop Sensor Connector
+    #[cip(byte = 140)]
+    pub motor_1_stop_sensor_connector: i8,
+    /// Param37: Motor 1 Trigger Position Capture Connector
+    #[cip(byte = 141)]
+    pub motor_1_trigger_position_capture_connector: i8,
+    /// Param38: Motor 1 Follow Axis
+    #[cip(byte = 142)]
+    pub motor_1_follow_axis: i8,
+    /// Param39: Motor 2 Config Register
+    #[cip(byte = 144)]
+    pub motor_2_config_register: u32,
+    /// Param40: Motor 2 Follow Divisor
+    #[cip(byte = 148)]
+    pub motor_2_follow_divisor: i32,
+    /// Param41: Motor 2 Follow Multiplier
+    #[cip(byte = 152)]
+    pub motor_2_follow_multiplier: i32,
+    /// Param42: Motor 2 Max Deceleration
+    #[cip(byte = 156)]
+    pub motor_2_max_deceleration: i32,
+    /// Param43: Motor 2 Soft Limit Position 1
+    #[cip(byte = 160)]
+    pub motor_2_soft_limit_position_1: i32,
+    /// Param44: Motor 2 Soft Limit Position 2
+    #[cip(byte = 164)]
+    pub motor_2_soft_limit_position_2: i32,
+    /// Param45: Motor 2 Positive Limit Connector
+    #[cip(byte = 168)]
+    pub motor_2_positive_limit_connector: i8,
+    /// Param46: Motor 2 Negative Limit Connector
+    #[cip(byte = 169)]
+    pub motor_2_negative_limit_connector: i8,
+    /// Param47: Motor 2 Home Sensor Connector
+    #[cip(byte = 170)]
+    pub motor_2_home_sensor_connector: i8,
+    /// Param48: Motor 2 Brake Output Connector
+    #[cip(byte = 171)]
+    pub motor_2_brake_output_connector: i8,
+    /// Param49: Motor 2 Stop Sensor Connector
+    #[cip(byte = 172)]
+    pub motor_2_stop_sensor_connector: i8,
+    /// Param50: Motor 2 Trigger Position Capture Connector
+    #[cip(byte = 173)]
+    pub motor_2_trigger_position_capture_connector: i8,
+    /// Param51: Motor 2 Follow Axis
+    #[cip(byte = 174)]
+    pub motor_2_follow_axis: i8,
+    /// Param52: Motor 3 Config Register
+    #[cip(byte = 176)]
+    pub motor_3_config_register: u32,
+    /// Param53: Motor 3 Follow Divisor
+    #[cip(byte = 180)]
+    pub motor_3_follow_divisor: i32,
+    /// Param54: Motor 3 Follow Multiplier
+    #[cip(byte = 184)]
+    pub motor_3_follow_multiplier: i32,
+    /// Param55: Motor 3 Max Deceleration
+    #[cip(byte = 188)]
+    pub motor_3_max_deceleration: i32,
+    /// Param56: Motor 3 Soft Limit Position 1
+    #[cip(byte = 192)]
+    pub motor_3_soft_limit_position_1: i32,
+    /// Param57: Motor 3 Soft Limit Position 2
+    #[cip(byte = 196)]
+    pub motor_3_soft_limit_position_2: i32,
+    /// Param58: Motor 3 Positive Limit Connector
+    #[cip(byte = 200)]
+    pub motor_3_positive_limit_connector: i8,
+    /// Param59: Motor 3 Negative Limit Connector
+    #[cip(byte = 201)]
+    pub motor_3_negative_limit_connector: i8,
+    /// Param60: Motor 3 Home Sensor Connector
+    #[cip(byte = 202)]
+    pub motor_3_home_sensor_connector: i8,
+    /// Param61: Motor 3 Brake Output Connector
+    #[cip(byte = 203)]
+    pub motor_3_brake_output_connector: i8,
+    /// Param62: Motor 3 Stop Sensor Connector
+    #[cip(byte = 204)]
+    pub motor_3_stop_sensor_connector: i8,
+    /// Param63: Motor 3 Trigger Position Capture Connector
+    #[cip(byte = 205)]
+    pub motor_3_trigger_position_capture_connector: i8,
+    /// Param64: Motor 3 Follow Axis
+    #[cip(byte = 206)]
+    pub motor_3_follow_axis: i8,
+    /// Param65: Serial Baud Rate
+    #[cip(byte = 208)]
+    pub serial_baud_rate: u32,
+    /// Param66: Serial Input Start Delimiter
+    #[cip(byte = 212)]
+    pub serial_input_start_delimiter: u32,
+    /// Param67: Serial Input End Delimiter
+    #[cip(byte = 216)]
+    pub serial_input_end_delimiter: u32,
+    /// Param68: Serial Output Start Delimiter
+    #[cip(byte = 220)]
+    pub serial_output_start_delimiter: u32,
+    /// Param69: Serial Output End Delimiter
+    #[cip(byte = 224)]
+    pub serial_output_end_delimiter: u32,
+    /// Param70: Serial Input Timeout
+    #[cip(byte = 228)]
+    pub serial_input_timeout: u32,
+}
+
+impl ConfigAssembly {
+    pub const ASSEMBLY_NUMBER: u32 = 150;
+    pub const INSTANCE_ID: u16 = 0x96;
+}
+
+impl Default for ConfigAssembly {
+    fn default() -> Self {
+        use eipscanne_rs::cip::assembly::CipAssembly;
+        Self::read_fields(&[0; <Self as CipAssembly>::SIZE])
+    }
+}
+
@@ -0,0 +1,132 @@
+// Generated by eipscanne_rs from the EDS of Valve Manifold (Teknic, Inc.), revision 2.5
+
+/// Param1: RPI (microsecond)
+///
+/// Requested packet interval
+#[binrw::binrw]
+#[brw(little)]
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Rpi(pub u32);
+
+impl Rpi {
+    pub const PARAM_NUMBER: u32 = 1;
+    pub const MINIMUM: u32 = 2000;
+    pub const MAXIMUM: u32 = 3200000;
+    pub const DEFAULT: u32 = 10000;
+}
+
+/// Param2: Fault Mode
+#[derive(binrw::BinRead, binrw::BinWrite)]
+#[brw(little, repr = u8)]
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum FaultMode {
+    HoldLast = 0,
+    Clear = 1,
+    SafeState = 2,
+}
+
+impl FaultMode {
+    pub const PARAM_NUMBER: u32 = 2;
+
+    /// The attribute holding the parameter value
+    pub fn link_path() -> eipscanne_rs::cip::path::CipPath {
+        eipscanne_rs::cip::path::CipPath::new(0x04, 0x96).with_attribute(0x03)
+    }
+}
+
+/// Param3: Valve 1
+///
+/// Energizes valve 1
+#[binrw::binrw]
+#[brw(little)]
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Valve1(pub u8);
+
+impl Valve1 {
+    pub const PARAM_NUMBER: u32 = 3;
+    pub const MINIMUM: u8 = 0;
+    pub const MAXIMUM: u8 = 1;
+    pub const DEFAULT: u8 = 0;
+}
+
+/// Param4: Pressure (bar)
+#[binrw::binrw]
+#[brw(little)]
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Pressure(pub f32);
+
+impl Pressure {
+    pub const PARAM_NUMBER: u32 = 4;
+    pub const MINIMUM: f32 = 0.0;
+    pub const MAXIMUM: f32 = 10.5;
+}
+
+/// Assem100: Outputs
+#[derive(eipscanne_rs::cip::assembly::CipAssembly, Debug, PartialEq, Clone)]
+#[cip(size = 2)]
+pub struct OutputsAssembly {
+    /// Param2: Fault Mode
+    #[cip(byte = 0)]
+    pub fault_mode: u8,
+    /// Param3: Valve 1
+    #[cip(byte = 1, bit = 0)]
+    pub valve_1: bool,
+}
+
+impl OutputsAssembly {
+    pub const ASSEMBLY_NUMBER: u32 = 100;
+    pub const INSTANCE_ID: u16 = 0x64;
+}
+
+impl Default for OutputsAssembly {
+    fn default() -> Self {
+        use eipscanne_rs::cip::assembly::CipAssembly;
+        Self::read_fields(&[0; <Self as CipAssembly>::SIZE])
+    }
+}
+
+/// Assem101: Inputs
+#[derive(eipscanne_rs::cip::assembly::CipAssembly, Debug, PartialEq, Clone)]
+#[cip(size = 4)]
+pub struct InputsAssembly {
+    #[cip(byte = 0)]
+    pub data: [u8; 4],
+}
+
+impl InputsAssembly {
+    pub const ASSEMBLY_NUMBER: u32 = 101;
+    pub const INSTANCE_ID: u16 = 0x12C;
+}
+
+impl Default for InputsAssembly {
+    fn default() -> Self {
+        use eipscanne_rs::cip::assembly::CipAssembly;
+        Self::read_fields(&[0; <Self as CipAssembly>::SIZE])
+    }
+}
+
+/// Assem102: Status
+#[derive(eipscanne_rs::cip::assembly::CipAssembly, Debug, PartialEq, Clone)]
+#[cip(size = 10)]
+pub struct StatusAssembly {
+    /// Param4: Pressure
+    #[cip(byte = 0)]
+    pub pressure: f32,
+    /// Assem100: Outputs
+    #[cip(byte = 4)]
+    pub outputs: [u8; 2],
+    // Bits 48 to 51 are not a byte aligned parameter and are not generated
+}
+
+impl StatusAssembly {
+    pub const ASSEMBLY_NUMBER: u32 = 102;
+    pub const INSTANCE_ID: u16 = 0x65;
+}
+
+impl Default for StatusAssembly {
+    fn default() -> Self {
+        use eipscanne_rs::cip::assembly::CipAssembly;
+        Self::read_fields(&[0; <Self as CipAssembly>::SIZE])
+    }
+}
+
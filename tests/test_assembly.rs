@@ -0,0 +1,159 @@
+use binrw::binrw;
+
+use hex_test_macros::prelude::*;
+
+use eipscanne_rs::cip::assembly::{
+    assembly_path, read_assembly, read_assembly_size, write_assembly, AssemblyAttributeId,
+    ProcessImage,
+};
+use eipscanne_rs::cip::path::CipPath;
+use eipscanne_rs::cip::types::{CipByte, CipUint};
+
+mod common;
+use common::{read_response, write_request_bytes};
+
+#[binrw]
+#[brw(little)]
+#[derive(Debug, PartialEq, Clone, Default)]
+struct ValveOutputs {
+    outputs: u8,
+    setpoint: CipUint,
+}
+
+#[test]
+fn test_assembly_path() {
+    assert_eq!(
+        CipPath::new_full(0x4, 0x70, 0x3),
+        assembly_path(0x70, AssemblyAttributeId::Data)
+    );
+
+    // Instances above 255 need a 16-bit instance segment
+    let request_bytes = write_request_bytes(&read_assembly_size(0x0320).request());
+    assert_eq!(
+        vec![0x0e, 0x04, 0x20, 0x04, 0x25, 0x00, 0x20, 0x03, 0x30, 0x04],
+        request_bytes
+    );
+}
+
+#[test]
+fn test_serialize_write_assembly() {
+    /*
+    Common Industrial Protocol
+    Service: Set Attribute Single (Request)
+        0... .... = Request/Response: Request (0x0)
+        .001 0000 = Service: Set Attribute Single (0x10)
+    Request Path Size: 3 words
+    Request Path: Assembly, Instance: 0x96, Attribute: 0x03
+        Path Segment: 0x20 (8-Bit Class Segment)
+            Class: Assembly (0x04)
+        Path Segment: 0x24 (8-Bit Instance Segment)
+            Instance: 0x96
+        Path Segment: 0x30 (8-Bit Attribute Segment)
+            Attribute: 3
+    Set Attribute Single (Request)
+        Data: 05e803
+
+    -------------------------------------
+    Hex Dump:
+
+    0000   10 03 20 04 24 96 30 03 05 e8 03
+
+    */
+    let expected_byte_array: Vec<CipByte> = vec![
+        0x10, 0x03, 0x20, 0x04, 0x24, 0x96, 0x30, 0x03, 0x05, 0xe8, 0x03,
+    ];
+
+    let outputs = ValveOutputs {
+        outputs: 0x05,
+        setpoint: 1000,
+    };
+
+    let request_bytes = write_request_bytes(&write_assembly(0x96, &outputs).unwrap());
+    assert_eq_hex!(expected_byte_array, request_bytes);
+}
+
+#[test]
+fn test_deserialize_read_assembly() {
+    /*
+    Common Industrial Protocol
+    Service: Get Attribute Single (Response)
+        1... .... = Request/Response: Response (0x1)
+        .000 1110 = Service: Get Attribute Single (0x0e)
+    Status: Success:
+        General Status: Success (0x00)
+        Additional Status Size: 0 words
+    Get Attribute Single (Response)
+        Data: 05e803
+
+    -------------------------------------
+    Hex Dump:
+
+    0000   8e 00 00 00 05 e8 03
+
+    */
+    let assembly_read = read_assembly::<ValveOutputs>(0x70);
+
+    assert_eq!(
+        vec![0x0e, 0x03, 0x20, 0x04, 0x24, 0x70, 0x30, 0x03],
+        write_request_bytes(&assembly_read.request())
+    );
+
+    let response = read_response(vec![0x8e, 0x00, 0x00, 0x00, 0x05, 0xe8, 0x03]);
+
+    assert_eq!(
+        ValveOutputs {
+            outputs: 0x05,
+            setpoint: 1000,
+        },
+        assembly_read.read_reply(&response).unwrap()
+    );
+
+    // Object does not exist
+    let error_response = read_response(vec![0x8e, 0x00, 0x16, 0x00]);
+
+    assert!(assembly_read.read_reply(&error_response).is_err());
+}
+
+#[test]
+fn test_size_discovery_and_process_image() {
+    let size_response = read_response(vec![0x8e, 0x00, 0x00, 0x00, 0x06, 0x00]);
+    let assembly_size = read_assembly_size(0x70).read_reply(&size_response).unwrap();
+
+    let mut process_image = ProcessImage::new(assembly_size as usize);
+
+    process_image.write_at(0, &0x1234u16).unwrap();
+    process_image.write_at(2, &-2i32).unwrap();
+    process_image.set_bit(0, 7, true).unwrap();
+    process_image.set_bit(0, 4, false).unwrap();
+
+    assert_eq!(
+        &[0xa4, 0x12, 0xfe, 0xff, 0xff, 0xff],
+        process_image.as_bytes()
+    );
+    assert_eq!(0x12a4, process_image.read_at::<u16>(0).unwrap());
+    assert_eq!(-2, process_image.read_at::<i32>(2).unwrap());
+    assert!(process_image.bit(0, 2).unwrap());
+    assert!(!process_image.bit(1, 0).unwrap());
+
+    // Nothing is written past the end of the image
+    assert!(process_image.write_at(4, &0u32).is_err());
+    assert!(process_image.read_at::<u32>(4).is_err());
+    assert!(process_image.bit(6, 0).is_err());
+    assert!(process_image.set_bit(0, 8, true).is_err());
+    assert_eq!(
+        &[0xa4, 0x12, 0xfe, 0xff, 0xff, 0xff],
+        process_image.as_bytes()
+    );
+
+    // The process image reads and writes the whole assembly
+    let data_response = read_response(vec![0x8e, 0x00, 0x00, 0x00, 0x01, 0x02, 0x03]);
+    let read_image = read_assembly::<ProcessImage>(0x70)
+        .read_reply(&data_response)
+        .unwrap();
+
+    assert_eq!(ProcessImage::from(vec![0x01, 0x02, 0x03]), read_image);
+    assert_eq!(
+        vec![0x10, 0x03, 0x20, 0x04, 0x24, 0x96, 0x30, 0x03, 0x01, 0x02, 0x03],
+        write_request_bytes(&write_assembly(0x96, &read_image).unwrap())
+    );
+}
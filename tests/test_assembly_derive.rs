@@ -0,0 +1,177 @@
+use hex_test_macros::prelude::*;
+
+use eipscanne_rs::cip::assembly::{read_assembly, write_assembly, AssemblyField, CipAssembly};
+use eipscanne_rs::cip::types::CipByte;
+
+mod common;
+use common::{read_response, write_request_bytes};
+
+#[derive(CipAssembly, Debug, PartialEq, Clone, Default)]
+#[cip(size = 12)]
+struct IoOutputs {
+    #[cip(byte = 0, bit = 0)]
+    output_0: bool,
+    #[cip(byte = 0, bit = 3)]
+    output_3: bool,
+    #[cip(byte = 1, bit = 7)]
+    clear_faults: bool,
+    #[cip(byte = 2)]
+    analog_output: i16,
+    #[cip(byte = 4)]
+    pwm_duty_cycles: [u8; 3],
+    #[cip(byte = 8)]
+    setpoint: f32,
+}
+
+#[derive(CipAssembly, Debug, PartialEq, Clone, Default)]
+struct StatusWord {
+    #[cip(byte = 0)]
+    status: u16,
+    #[cip(byte = 2, bit = 1)]
+    ready: bool,
+}
+
+#[test]
+fn test_derived_layout() {
+    assert_eq!(12, IoOutputs::SIZE);
+    assert_eq!(3, StatusWord::SIZE);
+
+    assert_eq!(
+        &[
+            AssemblyField {
+                name: "status",
+                cip_type: "UINT",
+                byte_offset: 0,
+                bit_offset: None,
+                size: 2,
+            },
+            AssemblyField {
+                name: "ready",
+                cip_type: "BOOL",
+                byte_offset: 2,
+                bit_offset: Some(1),
+                size: 1,
+            },
+        ],
+        StatusWord::LAYOUT
+    );
+
+    assert_eq!(
+        " Byte Bit  Type   Size  Field\n    0   -  UINT      2  status\n    2   1  BOOL      1  ready\n",
+        StatusWord::layout_table()
+    );
+}
+
+#[test]
+fn test_serialize_derived_assembly() {
+    /*
+    Common Industrial Protocol
+    Service: Set Attribute Single (Request)
+        0... .... = Request/Response: Request (0x0)
+        .001 0000 = Service: Set Attribute Single (0x10)
+    Request Path Size: 3 words
+    Request Path: Assembly, Instance: 0x70, Attribute: 0x03
+    Set Attribute Single (Request)
+        Data: 0980f4ff0a141e000000c842
+
+    -------------------------------------
+    Hex Dump:
+
+    0000   10 03 20 04 24 70 30 03 09 80 f4 ff 0a 14 1e 00
+    0010   00 00 c8 42
+
+    */
+    let expected_byte_array: Vec<CipByte> = vec![
+        0x10, 0x03, 0x20, 0x04, 0x24, 0x70, 0x30, 0x03, 0x09, 0x80, 0xf4, 0xff, 0x0a, 0x14, 0x1e,
+        0x00, 0x00, 0x00, 0xc8, 0x42,
+    ];
+
+    let outputs = IoOutputs {
+        output_0: true,
+        output_3: true,
+        clear_faults: true,
+        analog_output: -12,
+        pwm_duty_cycles: [10, 20, 30],
+        setpoint: 100.0,
+    };
+
+    let request_bytes = write_request_bytes(&write_assembly(0x70, &outputs).unwrap());
+    assert_eq_hex!(expected_byte_array, request_bytes);
+}
+
+#[test]
+fn test_deserialize_derived_assembly() {
+    let response = read_response(vec![
+        0x8e, 0x00, 0x00, 0x00, 0x08, 0x00, 0xf4, 0xff, 0x0a, 0x14, 0x1e, 0x00, 0x00, 0x00, 0xc8,
+        0x42,
+    ]);
+
+    let outputs = read_assembly::<IoOutputs>(0x70)
+        .read_reply(&response)
+        .unwrap();
+
+    assert_eq!(
+        IoOutputs {
+            output_3: true,
+            analog_output: -12,
+            pwm_duty_cycles: [10, 20, 30],
+            setpoint: 100.0,
+            ..Default::default()
+        },
+        outputs
+    );
+
+    // Round trips through the raw bytes, padding included
+    assert_eq!(outputs, IoOutputs::decode(&outputs.encode()).unwrap());
+
+    // Short data is rejected instead of being read past
+    assert!(StatusWord::decode(&[0x01, 0x00]).is_err());
+
+    let short_response = read_response(vec![0x8e, 0x00, 0x00, 0x00, 0x01, 0x00]);
+    assert!(read_assembly::<StatusWord>(0x64)
+        .read_reply(&short_response)
+        .is_err());
+}
+
+#[derive(CipAssembly, Debug, PartialEq, Clone, Default)]
+struct AxisStatus {
+    #[cip(byte = 0)]
+    position: i32,
+    #[cip(byte = 4)]
+    axes: [StatusWord; 2],
+}
+
+#[test]
+fn test_nested_derived_assembly() {
+    assert_eq!(10, AxisStatus::SIZE);
+    assert_eq!(
+        AssemblyField {
+            name: "axes",
+            cip_type: "STRUCT",
+            byte_offset: 4,
+            bit_offset: None,
+            size: 6,
+        },
+        AxisStatus::LAYOUT[1]
+    );
+
+    let axis_status = AxisStatus {
+        position: -2,
+        axes: [
+            StatusWord {
+                status: 0x0102,
+                ready: true,
+            },
+            StatusWord {
+                status: 0x0304,
+                ready: false,
+            },
+        ],
+    };
+
+    let expected_byte_array: Vec<CipByte> =
+        vec![0xfe, 0xff, 0xff, 0xff, 0x02, 0x01, 0x02, 0x04, 0x03, 0x00];
+
+    assert_eq_hex!(expected_byte_array, axis_status.encode());
+    assert_eq!(axis_status, AxisStatus::decode(&expected_byte_array).unwrap());
+}
@@ -0,0 +1,219 @@
+use hex_test_macros::prelude::*;
+
+use eipscanne_rs::cip::message::attribute_list::{
+    AttributeListReply, AttributeReply, AttributeType, AttributeTypeMap, AttributeValue,
+    GetAttributeListRequest, SetAttributeListRequest,
+};
+use eipscanne_rs::cip::path::CipPath;
+use eipscanne_rs::cip::types::CipByte;
+
+mod common;
+use common::{read_response, write_request_bytes};
+
+fn identity_attribute_types() -> AttributeTypeMap {
+    AttributeTypeMap::from([
+        (0x01, AttributeType::Uint),
+        (
+            0x04,
+            AttributeType::Structure(vec![AttributeType::Usint, AttributeType::Usint]),
+        ),
+        (0x07, AttributeType::ShortString),
+    ])
+}
+
+#[test]
+fn test_serialize_get_attribute_list_request() {
+    /*
+    Common Industrial Protocol
+    Service: Get Attribute List (Request)
+        0... .... = Request/Response: Request (0x0)
+        .000 0011 = Service: Get Attribute List (0x03)
+    Request Path Size: 4 words
+    Request Path: Identity, Instance: 0x0001
+        Path Segment: 0x21 (16-Bit Class Segment)
+            Class: Identity (0x0001)
+        Path Segment: 0x25 (16-Bit Instance Segment)
+            Instance: 0x0001
+    Get Attribute List (Request)
+        Attribute Count: 3
+        Attribute: 1 (Vendor ID)
+        Attribute: 7 (Product Name)
+        Attribute: 4 (Revision)
+
+    -------------------------------------
+    Hex Dump:
+
+    0000   03 04 21 00 01 00 25 00 01 00 03 00 01 00 07 00
+    0010   04 00
+
+    */
+    let expected_byte_array: Vec<CipByte> = vec![
+        0x03, 0x04, 0x21, 0x00, 0x01, 0x00, 0x25, 0x00, 0x01, 0x00, 0x03, 0x00, 0x01, 0x00, 0x07,
+        0x00, 0x04, 0x00,
+    ];
+
+    let request = GetAttributeListRequest::new(vec![0x01, 0x07, 0x04])
+        .into_router_request(CipPath::new(0x1, 0x1));
+
+    let request_bytes = write_request_bytes(&request);
+    assert_eq_hex!(expected_byte_array, request_bytes);
+}
+
+#[test]
+fn test_deserialize_get_attribute_list_reply() {
+    /*
+    Common Industrial Protocol
+    Service: Get Attribute List (Response)
+        1... .... = Request/Response: Response (0x1)
+        .000 0011 = Service: Get Attribute List (0x03)
+    Status: Success:
+        General Status: Success (0x00)
+        Additional Status Size: 0 words
+    Get Attribute List (Response)
+        Attribute Count: 3
+        Attribute: 1 (Vendor ID)
+            Status: Success (0x00)
+            Vendor ID: Teknic, Inc. (0x01a8)
+        Attribute: 7 (Product Name)
+            Status: Success (0x00)
+            Product Name: ClearLink
+        Attribute: 4 (Revision)
+            Status: Success (0x00)
+            Major Revision: 2
+            Minor Revision: 93
+
+    -------------------------------------
+    Hex Dump:
+
+    0000   83 00 00 00 03 00 01 00 00 00 a8 01 07 00 00 00
+    0010   09 43 6c 65 61 72 4c 69 6e 6b 04 00 00 00 02 5d
+
+    */
+    let reply = AttributeListReply::from_get_response(
+        &read_response(vec![
+            0x83, 0x00, 0x00, 0x00, 0x03, 0x00, 0x01, 0x00, 0x00, 0x00, 0xa8, 0x01, 0x07, 0x00,
+            0x00, 0x00, 0x09, 0x43, 0x6c, 0x65, 0x61, 0x72, 0x4c, 0x69, 0x6e, 0x6b, 0x04, 0x00,
+            0x00, 0x00, 0x02, 0x5d,
+        ]),
+        &identity_attribute_types(),
+    )
+    .unwrap();
+
+    assert_eq!(3, reply.attributes.len());
+    assert!(reply.attributes.iter().all(AttributeReply::is_success));
+
+    assert_eq!(Some(&AttributeValue::Uint(0x01a8)), reply.value(0x01));
+    assert_eq!(
+        Some(&AttributeValue::ShortString("ClearLink".to_string())),
+        reply.value(0x07)
+    );
+    assert_eq!(
+        Some(&AttributeValue::Structure(vec![
+            AttributeValue::Usint(2),
+            AttributeValue::Usint(93)
+        ])),
+        reply.value(0x04)
+    );
+}
+
+#[test]
+fn test_deserialize_get_attribute_list_reply_with_errors() {
+    // Attribute 0x63 is not supported, so the general status is an attribute list error
+    let reply = AttributeListReply::from_get_response(
+        &read_response(vec![
+            0x83, 0x00, 0x0a, 0x00, 0x02, 0x00, 0x63, 0x00, 0x14, 0x00, 0x01, 0x00, 0x00, 0x00,
+            0xa8, 0x01,
+        ]),
+        &identity_attribute_types(),
+    )
+    .unwrap();
+
+    assert_eq!(
+        vec![
+            AttributeReply {
+                attribute_id: 0x63,
+                status: 0x14,
+                value: None,
+            },
+            AttributeReply {
+                attribute_id: 0x01,
+                status: 0x00,
+                value: Some(AttributeValue::Uint(0x01a8)),
+            },
+        ],
+        reply.attributes
+    );
+
+    // The last attribute can be left out of the type map and is returned as raw bytes
+    let untyped_reply = AttributeListReply::from_get_response(
+        &read_response(vec![
+            0x83, 0x00, 0x00, 0x00, 0x02, 0x00, 0x01, 0x00, 0x00, 0x00, 0xa8, 0x01, 0x06, 0x00,
+            0x00, 0x00, 0x32, 0x3d, 0xff, 0x01,
+        ]),
+        &identity_attribute_types(),
+    )
+    .unwrap();
+
+    assert_eq!(
+        Some(&AttributeValue::Bytes(vec![0x32, 0x3d, 0xff, 0x01])),
+        untyped_reply.value(0x06)
+    );
+
+    // But an untyped attribute in the middle of the reply can not be skipped
+    assert!(AttributeListReply::from_get_response(
+        &read_response(vec![
+            0x83, 0x00, 0x00, 0x00, 0x02, 0x00, 0x06, 0x00, 0x00, 0x00, 0x32, 0x3d, 0xff, 0x01,
+            0x01, 0x00, 0x00, 0x00, 0xa8, 0x01,
+        ]),
+        &identity_attribute_types(),
+    )
+    .is_err());
+}
+
+#[test]
+fn test_set_attribute_list() {
+    /*
+    Common Industrial Protocol
+    Service: Set Attribute List (Request)
+        0... .... = Request/Response: Request (0x0)
+        .000 0100 = Service: Set Attribute List (0x04)
+    Request Path Size: 4 words
+    Request Path: TCP/IP Interface, Instance: 0x0001
+    Set Attribute List (Request)
+        Attribute Count: 2
+        Attribute: 8 (TTL Value)
+            TTL Value: 1
+        Attribute: 13 (Encapsulation Inactivity Timeout)
+            Encapsulation Inactivity Timeout: 120
+
+    -------------------------------------
+    Hex Dump:
+
+    0000   04 04 21 00 f5 00 25 00 01 00 02 00 08 00 01 0d
+    0010   00 78 00
+
+    */
+    let expected_byte_array: Vec<CipByte> = vec![
+        0x04, 0x04, 0x21, 0x00, 0xf5, 0x00, 0x25, 0x00, 0x01, 0x00, 0x02, 0x00, 0x08, 0x00, 0x01,
+        0x0d, 0x00, 0x78, 0x00,
+    ];
+
+    let request = SetAttributeListRequest::new()
+        .with_attribute(0x08, AttributeValue::Usint(1))
+        .with_attribute(0x0d, AttributeValue::Uint(120))
+        .into_router_request(CipPath::new(0xf5, 0x1));
+
+    let request_bytes = write_request_bytes(&request);
+    assert_eq_hex!(expected_byte_array, request_bytes);
+
+    let reply = AttributeListReply::from_set_response(&read_response(vec![
+        0x84, 0x00, 0x0a, 0x00, 0x02, 0x00, 0x08, 0x00, 0x00, 0x00, 0x0d, 0x00, 0x09, 0x00,
+    ]))
+    .unwrap();
+
+    assert!(reply.attributes[0].is_success());
+    assert_eq!(
+        (0x0d, 0x09),
+        (reply.attributes[1].attribute_id, reply.attributes[1].status)
+    );
+}
@@ -0,0 +1,208 @@
+use hex_test_macros::prelude::*;
+
+use eipscanne_rs::cip::class::{ClassAttributes, ClassAttributesRead, InstanceEnumeration};
+use eipscanne_rs::cip::types::CipByte;
+
+mod common;
+use common::{read_response, write_request_bytes};
+
+#[test]
+fn test_serialize_class_attribute_request() {
+    /*
+    Common Industrial Protocol
+    Service: Get Attribute Single (Request)
+        0... .... = Request/Response: Request (0x0)
+        .000 1110 = Service: Get Attribute Single (0x0e)
+    Request Path Size: 5 words
+    Request Path: Assembly, Instance: 0x0000, Attribute: 0x01
+        Path Segment: 0x21 (16-Bit Class Segment)
+            Class: Assembly (0x0004)
+        Path Segment: 0x25 (16-Bit Instance Segment)
+            Instance: 0x0000
+        Path Segment: 0x30 (8-Bit Attribute Segment)
+            Attribute: 1
+
+    -------------------------------------
+    Hex Dump:
+
+    0000   0e 05 21 00 04 00 25 00 00 00 30 01
+
+    */
+    let expected_byte_array: Vec<CipByte> = vec![
+        0x0e, 0x05, 0x21, 0x00, 0x04, 0x00, 0x25, 0x00, 0x00, 0x00, 0x30, 0x01,
+    ];
+
+    let class_read = ClassAttributesRead::new(0x04);
+
+    let request_bytes = write_request_bytes(&class_read.next_request().unwrap());
+    assert_eq_hex!(expected_byte_array, request_bytes);
+}
+
+#[test]
+fn test_read_class_attributes() {
+    let mut class_read = ClassAttributesRead::new(0x04);
+
+    let responses = vec![
+        // Revision: 2
+        vec![0x8e, 0x00, 0x00, 0x00, 0x02, 0x00],
+        // Max Instance: 150
+        vec![0x8e, 0x00, 0x00, 0x00, 0x96, 0x00],
+        // Number of Instances: 3
+        vec![0x8e, 0x00, 0x00, 0x00, 0x03, 0x00],
+        // Optional Attribute List: not supported
+        vec![0x8e, 0x00, 0x14, 0x00],
+        // Max Class Attribute ID: 7
+        vec![0x8e, 0x00, 0x00, 0x00, 0x07, 0x00],
+        // Max Instance Attribute ID: 4
+        vec![0x8e, 0x00, 0x00, 0x00, 0x04, 0x00],
+    ];
+
+    for response_bytes in responses {
+        assert!(!class_read.is_complete());
+        assert!(class_read.next_request().is_some());
+
+        class_read
+            .handle_response(&read_response(response_bytes))
+            .unwrap();
+    }
+
+    assert!(class_read.next_request().is_none());
+    assert_eq!(
+        Some(ClassAttributes {
+            revision: Some(2),
+            max_instance: Some(150),
+            number_of_instances: Some(3),
+            optional_attributes: None,
+            max_class_attribute_id: Some(7),
+            max_instance_attribute_id: Some(4),
+        }),
+        class_read.into_attributes()
+    );
+}
+
+#[test]
+fn test_read_optional_attribute_list() {
+    let mut class_read = ClassAttributesRead::new(0xf5);
+
+    for response_bytes in [
+        vec![0x8e, 0x00, 0x00, 0x00, 0x04, 0x00],
+        vec![0x8e, 0x00, 0x00, 0x00, 0x01, 0x00],
+        vec![0x8e, 0x00, 0x00, 0x00, 0x01, 0x00],
+        // Optional Attribute List: 8, 13
+        vec![0x8e, 0x00, 0x00, 0x00, 0x02, 0x00, 0x08, 0x00, 0x0d, 0x00],
+    ] {
+        class_read
+            .handle_response(&read_response(response_bytes))
+            .unwrap();
+    }
+
+    assert!(!class_read.is_complete());
+
+    // An unexpected error still fails the read
+    assert!(class_read
+        .handle_response(&read_response(vec![0x8e, 0x00, 0x0c, 0x00]))
+        .is_err());
+}
+
+#[test]
+fn test_serialize_find_next_object_instance() {
+    /*
+    Common Industrial Protocol
+    Service: Find Next Object Instance (Request)
+        0... .... = Request/Response: Request (0x0)
+        .001 0001 = Service: Find Next Object Instance (0x11)
+    Request Path Size: 4 words
+    Request Path: Assembly, Instance: 0x0000
+        Path Segment: 0x21 (16-Bit Class Segment)
+            Class: Assembly (0x0004)
+        Path Segment: 0x25 (16-Bit Instance Segment)
+            Instance: 0x0000
+    Find Next Object Instance (Request)
+        Maximum Return: 100
+
+    -------------------------------------
+    Hex Dump:
+
+    0000   11 04 21 00 04 00 25 00 00 00 64
+
+    */
+    let expected_byte_array: Vec<CipByte> = vec![
+        0x11, 0x04, 0x21, 0x00, 0x04, 0x00, 0x25, 0x00, 0x00, 0x00, 0x64,
+    ];
+
+    let enumeration = InstanceEnumeration::new(0x04);
+
+    let request_bytes = write_request_bytes(&enumeration.next_request().unwrap());
+    assert_eq_hex!(expected_byte_array, request_bytes);
+}
+
+#[test]
+fn test_enumerate_instances_with_find_next() {
+    let mut enumeration = InstanceEnumeration::new(0x04).with_find_next_count(2);
+
+    // A full page continues from the last instance returned
+    enumeration
+        .handle_response(&read_response(vec![
+            0x91, 0x00, 0x00, 0x00, 0x02, 0x64, 0x00, 0x65, 0x00,
+        ]))
+        .unwrap();
+
+    assert!(!enumeration.is_complete());
+    assert_eq!(
+        vec![0x11, 0x04, 0x21, 0x00, 0x04, 0x00, 0x25, 0x00, 0x65, 0x00, 0x02],
+        write_request_bytes(&enumeration.next_request().unwrap())
+    );
+
+    // A short page ends the enumeration
+    enumeration
+        .handle_response(&read_response(vec![
+            0x91, 0x00, 0x00, 0x00, 0x01, 0x96, 0x00,
+        ]))
+        .unwrap();
+
+    assert!(enumeration.is_complete());
+    assert!(enumeration.next_request().is_none());
+    assert_eq!(vec![0x64, 0x65, 0x96], enumeration.into_instance_ids());
+}
+
+#[test]
+fn test_enumerate_instances_with_scan() {
+    let mut enumeration = InstanceEnumeration::new(0x04);
+
+    // Find Next Object Instance is not supported, so the max instance is read instead
+    enumeration
+        .handle_response(&read_response(vec![0x91, 0x00, 0x08, 0x00]))
+        .unwrap();
+
+    assert_eq!(
+        vec![0x0e, 0x05, 0x21, 0x00, 0x04, 0x00, 0x25, 0x00, 0x00, 0x00, 0x30, 0x02],
+        write_request_bytes(&enumeration.next_request().unwrap())
+    );
+
+    // Max Instance: 3
+    enumeration
+        .handle_response(&read_response(vec![0x8e, 0x00, 0x00, 0x00, 0x03, 0x00]))
+        .unwrap();
+
+    assert_eq!(
+        vec![0x0e, 0x05, 0x21, 0x00, 0x04, 0x00, 0x25, 0x00, 0x01, 0x00, 0x30, 0x01],
+        write_request_bytes(&enumeration.next_request().unwrap())
+    );
+
+    for response_bytes in [
+        // Instance 1 exists
+        vec![0x8e, 0x00, 0x00, 0x00, 0x02, 0x00],
+        // Instance 2 does not exist
+        vec![0x8e, 0x00, 0x16, 0x00],
+        // Instance 3 exists, but without attribute 1
+        vec![0x8e, 0x00, 0x14, 0x00],
+    ] {
+        assert!(!enumeration.is_complete());
+        enumeration
+            .handle_response(&read_response(response_bytes))
+            .unwrap();
+    }
+
+    assert!(enumeration.is_complete());
+    assert_eq!(&[0x01, 0x03], enumeration.instance_ids());
+}
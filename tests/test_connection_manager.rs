@@ -0,0 +1,171 @@
+use hex_test_macros::prelude::*;
+
+use eipscanne_rs::cip::connection_manager::{
+    ConnectionData, ConnectionManagerRead, ConnectionState, get_connection_data_request,
+};
+use eipscanne_rs::cip::types::CipByte;
+
+mod common;
+use common::{attribute_reply, read_response, write_request_bytes};
+
+const CONNECTION_DATA_REPLY: [CipByte; 60] = [
+    0xd6, 0x00, 0x00, 0x00, 0x03, 0x00, 0x03, 0x00, 0x02, 0x00, 0x01, 0x00, 0x34, 0x12, 0x01, 0x00,
+    0xc3, 0xb2, 0xa1, 0x00, 0x01, 0x00, 0x00, 0x10, 0x02, 0x00, 0x00, 0x20, 0x02, 0x00, 0x00, 0x00,
+    0x20, 0xa1, 0x07, 0x00, 0x20, 0xa1, 0x07, 0x00, 0x03, 0x00, 0x00, 0x10, 0x04, 0x00, 0x00, 0x20,
+    0x02, 0x00, 0x00, 0x00, 0x40, 0x42, 0x0f, 0x00, 0x40, 0x42, 0x0f, 0x00,
+];
+
+#[test]
+fn test_serialize_get_connection_data_request() {
+    /*
+    Common Industrial Protocol
+    Service: Get Connection Data (Request)
+        0... .... = Request/Response: Request (0x0)
+        .101 0110 = Service: Get Connection Data (0x56)
+    Request Path Size: 2 words
+    Request Path: Connection Manager, Instance: 0x01
+        Path Segment: 0x20 (8-Bit Class Segment)
+            Class: Connection Manager (0x06)
+        Path Segment: 0x24 (8-Bit Instance Segment)
+            Instance: 0x01
+    CIP Connection Manager
+        Get Connection Data (Request)
+            Connection Number: 3
+
+    -------------------------------------
+    Hex Dump:
+
+    0000   56 02 20 06 24 01 03 00
+
+    */
+    let expected_byte_array: Vec<CipByte> = vec![0x56, 0x02, 0x20, 0x06, 0x24, 0x01, 0x03, 0x00];
+
+    let request = get_connection_data_request(3);
+
+    assert_eq_hex!(expected_byte_array, write_request_bytes(&request));
+}
+
+#[test]
+fn test_deserialize_connection_data() {
+    /*
+    Common Industrial Protocol
+    Service: Get Connection Data (Response)
+        1... .... = Request/Response: Response (0x1)
+        .101 0110 = Service: Get Connection Data (0x56)
+    Status: Success:
+        General Status: Success (0x00)
+        Additional Status Size: 0 words
+    CIP Connection Manager
+        Get Connection Data (Response)
+            Connection Number: 3
+            Connection State: Established (3)
+            Originator Port: 2
+            Target Port: 1
+            Connection Serial Number: 0x1234
+            Originator Vendor ID: Rockwell Automation/Allen-Bradley (0x0001)
+            Originator Serial Number: 0x00a1b2c3
+            Originator O->T CID: 0x10000001
+            Target O->T CID: 0x20000002
+            Connection Timeout Multiplier O->T: *4 (2)
+            Reserved: 000000
+            Originator RPI O->T: 500.000ms
+            Originator API O->T: 500.000ms
+            Originator T->O CID: 0x10000003
+            Target T->O CID: 0x20000004
+            Connection Timeout Multiplier T->O: *4 (2)
+            Reserved: 000000
+            Originator RPI T->O: 1000.000ms
+            Originator API T->O: 1000.000ms
+
+    -------------------------------------
+    Hex Dump:
+
+    0000   d6 00 00 00 03 00 03 00 02 00 01 00 34 12 01 00
+    0010   c3 b2 a1 00 01 00 00 10 02 00 00 20 02 00 00 00
+    0020   20 a1 07 00 20 a1 07 00 03 00 00 10 04 00 00 20
+    0030   02 00 00 00 40 42 0f 00 40 42 0f 00
+
+    */
+    let response = read_response(CONNECTION_DATA_REPLY.to_vec());
+
+    let connection_data: ConnectionData = response.response_data.read_reply().unwrap();
+
+    assert_eq!(connection_data.connection_number, 3);
+    assert_eq!(
+        connection_data.connection_state,
+        ConnectionState::Established
+    );
+    assert_eq!(connection_data.originator_port, 2);
+    assert_eq!(connection_data.target_port, 1);
+    assert_eq!(connection_data.connection_serial_number, 0x1234);
+    assert_eq!(connection_data.originator_vendor_id, 0x0001);
+    assert_eq!(connection_data.originator_serial_number, 0x00a1b2c3);
+    assert_eq!(connection_data.originator_o_t_connection_id, 0x10000001);
+    assert_eq!(connection_data.target_o_t_connection_id, 0x20000002);
+    assert_eq!(connection_data.o_t_timeout_multiplier, 2);
+    assert_eq!(connection_data.originator_o_t_rpi, 500_000);
+    assert_eq!(connection_data.originator_t_o_connection_id, 0x10000003);
+    assert_eq!(connection_data.target_t_o_connection_id, 0x20000004);
+    assert_eq!(connection_data.originator_t_o_api, 1_000_000);
+}
+
+#[test]
+fn test_connection_manager_read_of_exhausted_device() {
+    let mut connection_manager_read = ConnectionManagerRead::new();
+
+    let responses = vec![
+        // Open Requests: 40
+        attribute_reply(&[0x28, 0x00]),
+        // Open Format Rejects: 0
+        attribute_reply(&[0x00, 0x00]),
+        // Open Resource Rejects: 7
+        attribute_reply(&[0x07, 0x00]),
+        // Open Other Rejects: 0
+        attribute_reply(&[0x00, 0x00]),
+        // Close Requests: 30
+        attribute_reply(&[0x1e, 0x00]),
+        // Close Format Rejects: not supported
+        read_response(vec![0x8e, 0x00, 0x14, 0x00]),
+        // Close Other Rejects: not supported
+        read_response(vec![0x8e, 0x00, 0x14, 0x00]),
+        // Connection Timeouts: 2
+        attribute_reply(&[0x02, 0x00]),
+        // CPU Utilization: not supported
+        read_response(vec![0x8e, 0x00, 0x14, 0x00]),
+        // Max Buffer Size: not supported
+        read_response(vec![0x8e, 0x00, 0x14, 0x00]),
+        // Buffer Size Remaining: not supported
+        read_response(vec![0x8e, 0x00, 0x14, 0x00]),
+        // Connection Entry List: 2 entries, both open
+        attribute_reply(&[0x02, 0x00, 0x03]),
+        // Connection 0 closed after the list was read
+        read_response(vec![0xd6, 0x00, 0x16, 0x00]),
+        // Connection 1
+        read_response(
+            CONNECTION_DATA_REPLY
+                .iter()
+                .enumerate()
+                .map(|(index, byte)| if index == 4 { 0x01 } else { *byte })
+                .collect(),
+        ),
+    ];
+
+    for response in responses {
+        assert!(connection_manager_read.next_request().is_some());
+        connection_manager_read.handle_response(&response).unwrap();
+    }
+
+    assert!(connection_manager_read.next_request().is_none());
+    let status = connection_manager_read.into_status().unwrap();
+
+    assert_eq!(status.open_requests, Some(40));
+    assert_eq!(status.open_resource_rejects, Some(7));
+    assert_eq!(status.close_format_rejects, None);
+    assert_eq!(status.connection_timeouts, Some(2));
+    assert_eq!(status.cpu_utilization, None);
+    assert!(status.has_resource_rejects());
+    assert!(status.is_exhausted());
+
+    assert_eq!(status.connections.len(), 1);
+    assert_eq!(status.connections[0].connection_number, 1);
+}
@@ -0,0 +1,152 @@
+use hex_test_macros::prelude::*;
+
+use eipscanne_rs::cip::class::ClassAttributes;
+use eipscanne_rs::cip::message_router::{DeviceInventoryRead, ObjectList};
+use eipscanne_rs::cip::types::CipByte;
+
+mod common;
+use common::{
+    attribute_error, read_response, write_request_bytes, ATTRIBUTE_NOT_SUPPORTED,
+    OBJECT_DOES_NOT_EXIST,
+};
+
+#[test]
+fn test_serialize_object_list_request() {
+    /*
+    Common Industrial Protocol
+    Service: Get Attribute Single (Request)
+        0... .... = Request/Response: Request (0x0)
+        .000 1110 = Service: Get Attribute Single (0x0e)
+    Request Path Size: 5 words
+    Request Path: Message Router, Instance: 0x0001, Attribute: 0x01
+        Path Segment: 0x21 (16-Bit Class Segment)
+            Class: Message Router (0x0002)
+        Path Segment: 0x25 (16-Bit Instance Segment)
+            Instance: 0x0001
+        Path Segment: 0x30 (8-Bit Attribute Segment)
+            Attribute: 1
+
+    -------------------------------------
+    Hex Dump:
+
+    0000   0e 05 21 00 02 00 25 00 01 00 30 01
+
+    */
+    let expected_byte_array: Vec<CipByte> = vec![
+        0x0e, 0x05, 0x21, 0x00, 0x02, 0x00, 0x25, 0x00, 0x01, 0x00, 0x30, 0x01,
+    ];
+
+    let inventory_read = DeviceInventoryRead::new();
+
+    let request_bytes = write_request_bytes(&inventory_read.next_request().unwrap());
+    assert_eq_hex!(expected_byte_array, request_bytes);
+}
+
+#[test]
+fn test_deserialize_object_list() {
+    /*
+    Common Industrial Protocol
+    Service: Get Attribute Single (Response)
+        1... .... = Request/Response: Response (0x1)
+        .000 1110 = Service: Get Attribute Single (0x0e)
+    Status: Success:
+        General Status: Success (0x00)
+        Additional Status Size: 0 words
+    Get Attribute Single (Response)
+        Number of Classes: 4
+        Class: Identity (0x0001)
+        Class: Message Router (0x0002)
+        Class: Assembly (0x0004)
+        Class: TCP/IP Interface (0x00f5)
+
+    -------------------------------------
+    Hex Dump:
+
+    0000   8e 00 00 00 04 00 01 00 02 00 04 00 f5 00
+
+    */
+    let response = read_response(vec![
+        0x8e, 0x00, 0x00, 0x00, 0x04, 0x00, 0x01, 0x00, 0x02, 0x00, 0x04, 0x00, 0xf5, 0x00,
+    ]);
+
+    let object_list: ObjectList = response.response_data.read_reply().unwrap();
+
+    assert_eq!(vec![0x01, 0x02, 0x04, 0xf5], object_list.class_ids);
+}
+
+#[test]
+fn test_read_device_inventory() {
+    let mut inventory_read = DeviceInventoryRead::new();
+
+    let responses = vec![
+        // Object List: Identity, Assembly
+        vec![0x8e, 0x00, 0x00, 0x00, 0x02, 0x00, 0x01, 0x00, 0x04, 0x00],
+        // Max Connections: 32
+        vec![0x8e, 0x00, 0x00, 0x00, 0x20, 0x00],
+        // Identity class: revision 1, then no other class attributes
+        vec![0x8e, 0x00, 0x00, 0x00, 0x01, 0x00],
+        vec![0x8e, 0x00, 0x14, 0x00],
+        vec![0x8e, 0x00, 0x14, 0x00],
+        vec![0x8e, 0x00, 0x14, 0x00],
+        vec![0x8e, 0x00, 0x14, 0x00],
+        vec![0x8e, 0x00, 0x14, 0x00],
+        // Assembly class: the class instance does not exist
+        vec![0x8e, 0x00, 0x16, 0x00],
+    ];
+
+    for response_bytes in responses {
+        assert!(!inventory_read.is_complete());
+        assert!(inventory_read.next_request().is_some());
+
+        inventory_read
+            .handle_response(&read_response(response_bytes))
+            .unwrap();
+    }
+
+    assert!(inventory_read.next_request().is_none());
+
+    let inventory = inventory_read.into_inventory().unwrap();
+
+    assert_eq!(Some(32), inventory.max_connections);
+    assert_eq!(Some(1), inventory.revision(0x01));
+    assert_eq!(None, inventory.instance_count(0x01));
+    assert_eq!(
+        Some(&ClassAttributes::default()),
+        inventory.classes.get(&0x04)
+    );
+    assert_eq!(
+        vec![&0x01, &0x04],
+        inventory.classes.keys().collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn test_read_device_inventory_without_max_connections() {
+    let mut inventory_read = DeviceInventoryRead::new();
+
+    // Object List: no classes
+    inventory_read
+        .handle_response(&read_response(vec![0x8e, 0x00, 0x00, 0x00, 0x00, 0x00]))
+        .unwrap();
+    inventory_read
+        .handle_response(&attribute_error(ATTRIBUTE_NOT_SUPPORTED))
+        .unwrap();
+
+    let inventory = inventory_read.into_inventory().unwrap();
+
+    assert_eq!(None, inventory.max_connections);
+}
+
+#[test]
+fn test_read_device_inventory_max_connections_error() {
+    let mut inventory_read = DeviceInventoryRead::new();
+
+    inventory_read
+        .handle_response(&read_response(vec![0x8e, 0x00, 0x00, 0x00, 0x00, 0x00]))
+        .unwrap();
+
+    assert!(inventory_read
+        .handle_response(&attribute_error(OBJECT_DOES_NOT_EXIST))
+        .is_err());
+    assert!(!inventory_read.is_complete());
+}
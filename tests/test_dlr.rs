@@ -0,0 +1,186 @@
+use std::net::Ipv4Addr;
+
+use hex_test_macros::prelude::*;
+
+use eipscanne_rs::cip::dlr::{
+    dlr_service_request, DlrRead, DlrServiceCode, NetworkStatus, NetworkTopology, RingNode,
+    RingSupervisorStatus,
+};
+use eipscanne_rs::cip::ethernet_link::PhysicalAddress;
+use eipscanne_rs::cip::types::CipByte;
+
+mod common;
+use common::{read_response, write_request_bytes};
+
+fn ring_node(host: u8) -> RingNode {
+    RingNode {
+        ip_address: Ipv4Addr::new(192, 168, 1, host),
+        mac_address: PhysicalAddress([0x00, 0x1d, 0x9c, 0x01, 0x02, host]),
+    }
+}
+
+#[test]
+fn test_serialize_verify_fault_location_request() {
+    /*
+    Common Industrial Protocol
+    Service: Verify Fault Location (Request)
+        0... .... = Request/Response: Request (0x0)
+        .100 1011 = Service: Verify Fault Location (0x4b)
+    Request Path Size: 4 words
+    Request Path: DLR, Instance: 0x0001
+        Path Segment: 0x21 (16-Bit Class Segment)
+            Class: DLR (0x0047)
+        Path Segment: 0x25 (16-Bit Instance Segment)
+            Instance: 0x0001
+
+    -------------------------------------
+    Hex Dump:
+
+    0000   4b 04 21 00 47 00 25 00 01 00
+
+    */
+    let expected_byte_array: Vec<CipByte> =
+        vec![0x4b, 0x04, 0x21, 0x00, 0x47, 0x00, 0x25, 0x00, 0x01, 0x00];
+
+    let request_bytes =
+        write_request_bytes(&dlr_service_request(DlrServiceCode::VerifyFaultLocation));
+    assert_eq_hex!(expected_byte_array, request_bytes);
+}
+
+#[test]
+fn test_read_ring_supervisor_status() {
+    let mut dlr_read = DlrRead::new();
+
+    let responses = vec![
+        // Network Topology: Ring
+        vec![0x8e, 0x00, 0x00, 0x00, 0x01],
+        // Network Status: Ring Fault
+        vec![0x8e, 0x00, 0x00, 0x00, 0x01],
+        // Ring Supervisor Status: Active Supervisor
+        vec![0x8e, 0x00, 0x00, 0x00, 0x01],
+        // Ring Supervisor Config: enabled, precedence 0, beacons every 400us, 1960us timeout
+        vec![
+            0x8e, 0x00, 0x00, 0x00, 0x01, 0x00, 0x90, 0x01, 0x00, 0x00, 0xa8, 0x07, 0x00, 0x00,
+            0x00, 0x00,
+        ],
+        // Ring Faults Count: 3
+        vec![0x8e, 0x00, 0x00, 0x00, 0x03, 0x00],
+        // Last Active Node on Port 1: 192.168.1.11
+        vec![
+            0x8e, 0x00, 0x00, 0x00, 0x0b, 0x01, 0xa8, 0xc0, 0x00, 0x1d, 0x9c, 0x01, 0x02, 0x0b,
+        ],
+        // Last Active Node on Port 2: 192.168.1.12
+        vec![
+            0x8e, 0x00, 0x00, 0x00, 0x0c, 0x01, 0xa8, 0xc0, 0x00, 0x1d, 0x9c, 0x01, 0x02, 0x0c,
+        ],
+        // Ring Protocol Participants Count: 3
+        vec![0x8e, 0x00, 0x00, 0x00, 0x03, 0x00],
+        // Ring Protocol Participants List: 192.168.1.10, 192.168.1.11, 192.168.1.12
+        vec![
+            0x8e, 0x00, 0x00, 0x00, 0x0a, 0x01, 0xa8, 0xc0, 0x00, 0x1d, 0x9c, 0x01, 0x02, 0x0a,
+            0x0b, 0x01, 0xa8, 0xc0, 0x00, 0x1d, 0x9c, 0x01, 0x02, 0x0b, 0x0c, 0x01, 0xa8, 0xc0,
+            0x00, 0x1d, 0x9c, 0x01, 0x02, 0x0c,
+        ],
+        // Active Supervisor Address: 192.168.1.10
+        vec![
+            0x8e, 0x00, 0x00, 0x00, 0x0a, 0x01, 0xa8, 0xc0, 0x00, 0x1d, 0x9c, 0x01, 0x02, 0x0a,
+        ],
+        // Active Supervisor Precedence: 0
+        vec![0x8e, 0x00, 0x00, 0x00, 0x00],
+        // Capability Flags: Beacon-based ring node, Supervisor capable
+        vec![0x8e, 0x00, 0x00, 0x00, 0x22, 0x00, 0x00, 0x00],
+    ];
+
+    for response_bytes in responses {
+        assert!(!dlr_read.is_complete());
+        assert!(dlr_read.next_request().is_some());
+
+        dlr_read
+            .handle_response(&read_response(response_bytes))
+            .unwrap();
+    }
+
+    assert!(dlr_read.next_request().is_none());
+
+    let status = dlr_read.into_status().unwrap();
+    assert_eq!(Some(NetworkTopology::Ring), status.network_topology);
+    assert_eq!(Some(NetworkStatus::RingFault), status.network_status);
+    assert_eq!(
+        Some(RingSupervisorStatus::ActiveSupervisor),
+        status.supervisor_status
+    );
+    assert_eq!(400, status.supervisor_config.unwrap().beacon_interval);
+    assert_eq!(1960, status.supervisor_config.unwrap().beacon_timeout);
+    assert_eq!(Some(3), status.ring_faults_count);
+    assert_eq!(
+        Some(vec![ring_node(10), ring_node(11), ring_node(12)]),
+        status.participants
+    );
+    assert_eq!(Some(ring_node(10)), status.active_supervisor);
+
+    let capability_flags = status.capability_flags.unwrap();
+    assert!(capability_flags.beacon_based_ring_node());
+    assert!(capability_flags.supervisor_capable());
+    assert!(!capability_flags.announce_based_ring_node());
+
+    assert!(!status.is_ring_normal());
+    assert_eq!(
+        Some((ring_node(11), ring_node(12))),
+        status.fault_location()
+    );
+    assert_eq!(
+        "192.168.1.11 (00:1d:9c:01:02:0b)",
+        ring_node(11).to_string()
+    );
+}
+
+#[test]
+fn test_read_ring_node_status() {
+    let mut dlr_read = DlrRead::new();
+
+    for response_bytes in [
+        // Network Topology: Ring, Network Status: Normal, Ring Supervisor Status: Ring Node
+        vec![0x8e, 0x00, 0x00, 0x00, 0x01],
+        vec![0x8e, 0x00, 0x00, 0x00, 0x00],
+        vec![0x8e, 0x00, 0x00, 0x00, 0x02],
+        // Ring Supervisor Config and Ring Faults Count: not supported by a plain ring node
+        vec![0x8e, 0x00, 0x14, 0x00],
+        vec![0x8e, 0x00, 0x14, 0x00],
+        // Last Active Nodes, Participants Count and List: only known to the active supervisor
+        vec![0x8e, 0x00, 0x0c, 0x00],
+        vec![0x8e, 0x00, 0x0c, 0x00],
+        vec![0x8e, 0x00, 0x0c, 0x00],
+        vec![0x8e, 0x00, 0x0c, 0x00],
+        // Active Supervisor Address: 192.168.1.10
+        vec![
+            0x8e, 0x00, 0x00, 0x00, 0x0a, 0x01, 0xa8, 0xc0, 0x00, 0x1d, 0x9c, 0x01, 0x02, 0x0a,
+        ],
+        // Active Supervisor Precedence: 0
+        vec![0x8e, 0x00, 0x00, 0x00, 0x00],
+        // Capability Flags: Beacon-based ring node
+        vec![0x8e, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00],
+    ] {
+        dlr_read
+            .handle_response(&read_response(response_bytes))
+            .unwrap();
+    }
+
+    let status = dlr_read.into_status().unwrap();
+    assert!(status.is_ring_normal());
+    assert_eq!(None, status.participants);
+    assert_eq!(None, status.fault_location());
+    assert_eq!(Some(ring_node(10)), status.active_supervisor);
+}
+
+#[test]
+fn test_read_dlr_without_object() {
+    let mut dlr_read = DlrRead::new();
+
+    // Object Does Not Exist
+    dlr_read
+        .handle_response(&read_response(vec![0x8e, 0x00, 0x16, 0x00]))
+        .unwrap();
+
+    assert!(dlr_read.is_complete());
+    assert_eq!(None, dlr_read.into_status().unwrap().network_topology);
+}
@@ -0,0 +1,229 @@
+use hex_test_macros::prelude::*;
+
+use eipscanne_rs::cip::ac_dc_drive::DriveMode;
+use eipscanne_rs::cip::control_supervisor::ControlSupervisorState;
+use eipscanne_rs::cip::drive::{Drive, DriveStatus};
+use eipscanne_rs::cip::motor_data::{MotorDataRead, MotorType};
+use eipscanne_rs::cip::types::CipByte;
+
+mod common;
+use common::{
+    attribute_error, attribute_reply, write_request_bytes, ATTRIBUTE_NOT_SUPPORTED,
+    OBJECT_DOES_NOT_EXIST,
+};
+
+#[test]
+fn test_serialize_start_forward_requests() {
+    /*
+    Common Industrial Protocol
+    Service: Set Attribute Single (Request)
+        0... .... = Request/Response: Request (0x0)
+        .001 0000 = Service: Set Attribute Single (0x10)
+    Request Path Size: 5 words
+    Request Path: Control Supervisor, Instance: 0x0001, Attribute: 0x03
+        Path Segment: 0x21 (16-Bit Class Segment)
+            Class: Control Supervisor (0x0029)
+        Path Segment: 0x25 (16-Bit Instance Segment)
+            Instance: 0x0001
+        Path Segment: 0x30 (8-Bit Attribute Segment)
+            Attribute: 3
+    Set Attribute Single (Request)
+        Data: 01
+
+    -------------------------------------
+    Hex Dump:
+
+    0000   10 05 21 00 29 00 25 00 01 00 30 03 01
+
+    */
+    let expected_byte_array: Vec<CipByte> = vec![
+        0x10, 0x05, 0x21, 0x00, 0x29, 0x00, 0x25, 0x00, 0x01, 0x00, 0x30, 0x03, 0x01,
+    ];
+
+    let requests = Drive::new().start_forward();
+
+    // Run2 is cleared before Run1 is set, so the drive never sees both
+    assert_eq!(requests.len(), 2);
+    assert_eq!(
+        write_request_bytes(&requests[0]),
+        vec![0x10, 0x05, 0x21, 0x00, 0x29, 0x00, 0x25, 0x00, 0x01, 0x00, 0x30, 0x04, 0x00]
+    );
+    assert_eq_hex!(expected_byte_array, write_request_bytes(&requests[1]));
+}
+
+#[test]
+fn test_serialize_speed_reference_request() {
+    /*
+    Common Industrial Protocol
+    Service: Set Attribute Single (Request)
+        0... .... = Request/Response: Request (0x0)
+        .001 0000 = Service: Set Attribute Single (0x10)
+    Request Path Size: 5 words
+    Request Path: AC/DC Drive, Instance: 0x0001, Attribute: 0x08
+        Path Segment: 0x21 (16-Bit Class Segment)
+            Class: AC/DC Drive (0x002a)
+        Path Segment: 0x25 (16-Bit Instance Segment)
+            Instance: 0x0001
+        Path Segment: 0x30 (8-Bit Attribute Segment)
+            Attribute: 8
+    Set Attribute Single (Request)
+        Data: 8403
+
+    -------------------------------------
+    Hex Dump:
+
+    0000   10 05 21 00 2a 00 25 00 01 00 30 08 84 03
+
+    */
+    let expected_byte_array: Vec<CipByte> = vec![
+        0x10, 0x05, 0x21, 0x00, 0x2a, 0x00, 0x25, 0x00, 0x01, 0x00, 0x30, 0x08, 0x84, 0x03,
+    ];
+
+    // With a SpeedScale of 1 the speed reference is in units of 2 RPM, ScaledSpeed = RPM / 2^1
+    let drive = Drive::from_status(&DriveStatus {
+        speed_scale: Some(1),
+        ..Default::default()
+    });
+    let request = drive.set_speed_request(1800.0).unwrap();
+
+    assert_eq_hex!(expected_byte_array, write_request_bytes(&request));
+    assert!(drive.set_speed_request(70000.0).is_err());
+}
+
+#[test]
+fn test_drive_status_read() {
+    let mut status_read = Drive::new().status_read();
+
+    let responses = vec![
+        // State: Enabled
+        attribute_reply(&[0x04]),
+        // Running1: true
+        attribute_reply(&[0x01]),
+        // Running2: false
+        attribute_reply(&[0x00]),
+        // Ready: true
+        attribute_reply(&[0x01]),
+        // Faulted: false
+        attribute_reply(&[0x00]),
+        // Warning: not supported
+        attribute_error(ATTRIBUTE_NOT_SUPPORTED),
+        // FaultCode: 0
+        attribute_reply(&[0x00, 0x00]),
+        // WarnCode: not supported
+        attribute_error(ATTRIBUTE_NOT_SUPPORTED),
+        // CtlFromNet: true
+        attribute_reply(&[0x01]),
+        // AtReference: true
+        attribute_reply(&[0x01]),
+        // RefFromNet: true
+        attribute_reply(&[0x01]),
+        // DriveMode: Open loop speed
+        attribute_reply(&[0x01]),
+        // SpeedActual: 1750
+        attribute_reply(&[0xd6, 0x06]),
+        // SpeedRef: 1750
+        attribute_reply(&[0xd6, 0x06]),
+        // CurrentActual: 42 (4.2 A)
+        attribute_reply(&[0x2a, 0x00]),
+        // SpeedScale: 0
+        attribute_reply(&[0x00]),
+        // CurrentScale: not supported
+        attribute_error(ATTRIBUTE_NOT_SUPPORTED),
+    ];
+
+    for response in responses {
+        assert!(status_read.next_request().is_some());
+        status_read.handle_response(&response).unwrap();
+    }
+
+    assert!(status_read.next_request().is_none());
+    let status = status_read.into_status().unwrap();
+
+    assert_eq!(status.state, Some(ControlSupervisorState::Enabled));
+    assert!(status.is_running());
+    assert!(!status.is_faulted());
+    assert_eq!(status.warning, None);
+    assert_eq!(status.control_from_net, Some(true));
+    assert_eq!(status.drive_mode, Some(DriveMode::OpenLoopSpeed));
+    assert_eq!(status.speed_actual_rpm(), Some(1750.0));
+    assert_eq!(status.current_actual_amps(), Some(4.2));
+
+    // With a SpeedScale of 1 each count of the speed is 2 RPM
+    let scaled_status = DriveStatus {
+        speed_actual: Some(900),
+        speed_scale: Some(1),
+        ..status
+    };
+    assert_eq!(scaled_status.speed_actual_rpm(), Some(1800.0));
+}
+
+#[test]
+fn test_drive_status_read_without_ac_dc_drive_object() {
+    let mut status_read = Drive::new().status_read();
+
+    // A drive with only a Control Supervisor, reporting a fault
+    let responses = vec![
+        // State: Faulted
+        attribute_reply(&[0x07]),
+        // Running1, Running2, Ready: false
+        attribute_reply(&[0x00]),
+        attribute_reply(&[0x00]),
+        attribute_reply(&[0x00]),
+        // Faulted: true
+        attribute_reply(&[0x01]),
+        // Warning: false
+        attribute_reply(&[0x00]),
+        // FaultCode: 0x2310 (output overcurrent)
+        attribute_reply(&[0x10, 0x23]),
+        // WarnCode: 0
+        attribute_reply(&[0x00, 0x00]),
+        // CtlFromNet: false
+        attribute_reply(&[0x00]),
+    ];
+
+    for response in responses {
+        status_read.handle_response(&response).unwrap();
+    }
+    status_read
+        .handle_response(&attribute_error(OBJECT_DOES_NOT_EXIST))
+        .unwrap();
+
+    assert!(status_read.is_complete());
+    let status = status_read.into_status().unwrap();
+    assert!(status.is_faulted());
+    assert_eq!(status.fault_code, Some(0x2310));
+    assert_eq!(status.speed_actual, None);
+}
+
+#[test]
+fn test_motor_data_read() {
+    let mut motor_data_read = MotorDataRead::new();
+
+    let responses = vec![
+        // MotorType: Squirrel cage induction
+        attribute_reply(&[0x07]),
+        // RatedCurrent: 5.2 A
+        attribute_reply(&[0x34, 0x00]),
+        // RatedVoltage: 460 V
+        attribute_reply(&[0xcc, 0x01]),
+    ];
+
+    for response in responses {
+        motor_data_read.handle_response(&response).unwrap();
+    }
+    // The rest of the nameplate is not supported
+    while !motor_data_read.is_complete() {
+        motor_data_read
+            .handle_response(&attribute_error(ATTRIBUTE_NOT_SUPPORTED))
+            .unwrap();
+    }
+
+    let motor_data = motor_data_read.into_motor_data().unwrap();
+    assert_eq!(
+        motor_data.motor_type,
+        Some(MotorType::SquirrelCageInduction)
+    );
+    assert_eq!(motor_data.rated_current, Some(52));
+    assert_eq!(motor_data.rated_voltage, Some(460));
+    assert_eq!(motor_data.base_speed, None);
+}
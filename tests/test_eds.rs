@@ -0,0 +1,270 @@
+use eipscanne_rs::cip::identity::{
+    DeviceType, IdentityResponse, IdentityStatus, IdentityStatusBits, Revision, VendorId,
+};
+use eipscanne_rs::cip::types::{CipDataType, CipShortString};
+use eipscanne_rs::eds::connection::{TSpec, TSpecDirection};
+use eipscanne_rs::eds::device::IdentityMismatch;
+use eipscanne_rs::eds::file::EdsFile;
+use eipscanne_rs::eds::syntax::{parse_sections, EdsField, EdsReference, EdsValue};
+
+const VALVE_EDS: &str = r#"
+$ Electronic Data Sheet for a valve manifold
+[File]
+    DescText = "Valve manifold "
+               "with 8 outputs";
+    CreateDate = 04-03-2024;
+    CreateTime = 17:51:44;
+    Revision = 1.2;             $ EDS revision, not the device revision
+
+[Device]
+    VendCode = 424;
+    VendName = "Teknic, Inc.";
+    ProdType = 43;
+    ProdTypeStr = "Generic Device";
+    ProdCode = 7;
+    MajRev = 2;
+    MinRev = 5;
+    ProdName = "Valve Manifold";
+    Catalog = "VM-8";
+
+[Device Classification]
+    Class1 = EtherNetIP;
+
+[Params]
+    Param1 =
+        0,                      $ reserved
+        ,,                      $ link path size, link path
+        0x0000,                 $ descriptor
+        0xC8,                   $ data type
+        4,                      $ data size
+        "RPI",                  $ name
+        "microsecond",          $ units
+        "Requested packet interval",
+        2000,3200000,10000,     $ min, max, default
+        ,,,,
+        ,,,,
+        ;
+    Param2 =
+        0,
+        6,"20 04 24 96 30 03",
+        0x0012,                 $ enumerated, read only
+        0xC6,
+        1,
+        "Fault Mode",
+        "",
+        "",
+        0,2,0,
+        ,,,,
+        ,,,,
+        0;
+    Enum2 = 0,"Hold last", 1,"Clear", 2,"Safe state";
+
+[Assembly]
+    Object_Name = "Assembly Object";
+    Object_Class_Code = 0x04;
+    Assem100 =
+        "Outputs",
+        "20 04 24 64 30 03",
+        2,
+        0x0000,
+        ,,
+        8,Param2,
+        8,;
+    Assem101 =
+        "Inputs",
+        "20 04 25 00 2C 01 30 03",
+        4,
+        ,
+        ,,
+        32,;
+
+[Connection Manager]
+    Object_Name = "Connection Manager Object";
+    Object_Class_Code = 0x06;
+    Connection1 =
+        0x04010002,             $ trigger and transport
+        0x44640405,             $ connection parameters
+        Param1,2,Assem100,      $ O->T RPI, size, format
+        Param1,4,Assem101,      $ T->O RPI, size, format
+        ,,                      $ proxy config size, format
+        ,,                      $ target config size, format
+        "Exclusive Owner",
+        "",
+        "20 04 24 96 2C 64 2C 65";
+
+[Port]
+    Port1 = TCP, "Port A", "20 F5 24 01", 2;
+
+[Capacity]
+    MaxMsgConnections = 6;
+    MaxIOProducers = 4;
+    MaxIOConsumers = 4;
+    TSpec1 = TxRx, 4, 2000;
+"#;
+
+fn valve_identity(product_code: u16, major_revision: u8) -> IdentityResponse {
+    IdentityResponse {
+        vendor_id: VendorId::TeknicInc,
+        device_type: DeviceType::GenericDevice,
+        product_code,
+        revision: Revision {
+            major: major_revision,
+            minor: 9,
+        },
+        status: IdentityStatus::from(IdentityStatusBits::from(0u16)),
+        serial_number: 0x01ff3d32,
+        product_name: CipShortString::from("Valve Manifold".to_string()),
+    }
+}
+
+#[test]
+fn test_parse_sections() {
+    let sections = parse_sections(VALVE_EDS).unwrap();
+
+    assert_eq!(8, sections.len());
+    assert_eq!("Device Classification", sections[2].name);
+
+    let description = sections[0].entry("desctext").unwrap();
+    assert_eq!(
+        vec![EdsField::Text("Valve manifold with 8 outputs".to_string())],
+        description.fields
+    );
+
+    // Empty fields are kept, in place
+    let param = sections[3].entry("Param1").unwrap();
+    assert_eq!(21, param.fields.len());
+    assert_eq!(&EdsField::Empty, param.field(1));
+    assert_eq!(&EdsField::Token("0xC8".to_string()), param.field(4));
+    assert_eq!(25, param.line);
+}
+
+#[test]
+fn test_parse_device_and_params() {
+    let eds = EdsFile::parse(VALVE_EDS).unwrap();
+
+    assert_eq!("Valve manifold with 8 outputs", eds.file.description);
+    assert_eq!("1.2", eds.file.revision);
+    assert_eq!(None, eds.file.home_url);
+
+    assert_eq!(VendorId::TeknicInc, eds.device.vendor_id);
+    assert_eq!(DeviceType::GenericDevice, eds.device.device_type);
+    assert_eq!(7, eds.device.product_code);
+    assert_eq!(Revision { major: 2, minor: 5 }, eds.device.revision);
+    assert_eq!(Some("VM-8".to_string()), eds.device.catalog);
+
+    let rpi = &eds.params[&1];
+    assert_eq!("RPI", rpi.name);
+    assert_eq!(CipDataType::Udint, rpi.data_type);
+    assert_eq!(4, rpi.data_size);
+    assert_eq!(None, rpi.link_path);
+    assert_eq!(Some(EdsValue::Integer(10000)), rpi.default);
+    assert!(rpi.is_in_range(EdsValue::Integer(2000)));
+    assert!(!rpi.is_in_range(EdsValue::Integer(1999)));
+
+    let fault_mode = &eds.params[&2];
+    assert!(fault_mode.descriptor.enumerated_strings());
+    assert!(fault_mode.descriptor.read_only());
+    assert!(!fault_mode.descriptor.scaling());
+    assert_eq!(Some("20 04 24 96 30 03".to_string()), fault_mode.link_path);
+    assert_eq!(Some(0), fault_mode.decimal_places);
+    assert_eq!(Some("Safe state"), fault_mode.enum_name(2));
+    assert_eq!(None, fault_mode.enum_name(3));
+}
+
+#[test]
+fn test_parse_assemblies_and_connections() {
+    let eds = EdsFile::parse(VALVE_EDS).unwrap();
+
+    let outputs = &eds.assemblies[&100];
+    assert_eq!("Outputs", outputs.name);
+    assert_eq!(Some(2), outputs.size);
+    assert_eq!(Some(0x64), outputs.instance_id());
+    assert_eq!(2, outputs.members.len());
+    assert_eq!(Some(EdsReference::Param(2)), outputs.members[0].reference);
+    assert_eq!(Some(8), outputs.members[1].size_bits);
+    assert_eq!(None, outputs.members[1].reference);
+
+    // A 16-bit instance segment
+    let (assembly_number, inputs) = eds.assembly_by_instance(0x012c).unwrap();
+    assert_eq!(101, assembly_number);
+    assert_eq!("Inputs", inputs.name);
+    assert_eq!(None, inputs.descriptor);
+
+    let connection = &eds.connections[&1];
+    assert_eq!("Exclusive Owner", connection.name);
+    assert_eq!(0x04010002, connection.trigger_and_transport);
+    assert_eq!(Some(100), connection.originator_to_target.assembly());
+    assert_eq!(Some(101), connection.target_to_originator.assembly());
+    assert_eq!(
+        Some(EdsReference::Value(EdsValue::Integer(4))),
+        connection.target_to_originator.size
+    );
+    assert_eq!(
+        Some(EdsReference::Param(1)),
+        connection.originator_to_target.rpi
+    );
+    assert_eq!(None, connection.target_config.format);
+    assert_eq!("20 04 24 96 2C 64 2C 65", connection.path);
+
+    let port = &eds.ports[&1];
+    assert_eq!("TCP", port.port_type);
+    assert_eq!("Port A", port.name);
+    assert_eq!(Some(2), port.port_number);
+
+    let capacity = eds.capacity.as_ref().unwrap();
+    assert_eq!(Some(6), capacity.max_message_connections);
+    assert_eq!(None, capacity.max_cip_connections);
+    assert_eq!(
+        vec![TSpec {
+            direction: TSpecDirection::TransmitReceive,
+            data_size: 4,
+            packets_per_second: 2000,
+        }],
+        capacity.tspecs
+    );
+
+    // Sections without a typed model are still available
+    let classification = eds.section("device classification").unwrap();
+    assert_eq!(Some("EtherNetIP"), classification.entries[0].str_field(0));
+}
+
+#[test]
+fn test_validate_identity() {
+    let eds = EdsFile::parse(VALVE_EDS).unwrap();
+
+    // The minor revision does not need to match
+    assert_eq!(Ok(()), eds.validate_identity(&valve_identity(7, 2)));
+
+    let mismatches = eds.validate_identity(&valve_identity(8, 3)).unwrap_err();
+    assert_eq!(
+        vec![
+            IdentityMismatch::ProductCode { eds: 7, device: 8 },
+            IdentityMismatch::MajorRevision { eds: 2, device: 3 },
+        ],
+        mismatches
+    );
+    assert_eq!(
+        "product code is 8 but the EDS is for 7",
+        mismatches[0].to_string()
+    );
+}
+
+#[test]
+fn test_parse_errors() {
+    let missing_device =
+        "[File]\n CreateDate = 01-01-2024;\n CreateTime = 00:00:00;\n Revision = 1.0;\n";
+    assert!(EdsFile::parse(missing_device)
+        .unwrap_err()
+        .to_string()
+        .contains("no [Device] section"));
+
+    let unterminated = "[File]\n DescText = \"Valve;\n";
+    assert!(parse_sections(unterminated)
+        .unwrap_err()
+        .to_string()
+        .contains("Unterminated string"));
+
+    let bad_number = VALVE_EDS.replace("ProdCode = 7;", "ProdCode = seven;");
+    let error = EdsFile::parse(&bad_number).unwrap_err().to_string();
+    assert!(error.contains("Line 15 (ProdCode)"), "{}", error);
+}
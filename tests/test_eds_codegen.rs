@@ -0,0 +1,172 @@
+use hex_test_macros::prelude::*;
+
+use eipscanne_rs::cip::assembly::{write_assembly, CipAssembly};
+use eipscanne_rs::cip::message::request::MessageRouterRequest;
+use eipscanne_rs::cip::message::shared::ServiceCode;
+use eipscanne_rs::cip::types::CipByte;
+use eipscanne_rs::eds::codegen::generate_rust;
+use eipscanne_rs::eds::file::EdsFile;
+
+// Regenerate with `cargo run --example eds-codegen -- tests/eds/valve.eds -o tests/eds/valve.rs`
+#[allow(dead_code)]
+mod valve {
+    include!("eds/valve.rs");
+}
+
+use valve::{FaultMode, OutputsAssembly, Pressure, Rpi, StatusAssembly};
+
+// Regenerate with `cargo run --example eds-codegen -- tests/eds/clearlink.eds -o tests/eds/clearlink.rs`
+#[allow(dead_code)]
+mod clearlink {
+    include!("eds/clearlink.rs");
+}
+
+mod common;
+use common::write_request_bytes;
+
+#[test]
+fn test_generated_code_is_current() {
+    let eds = EdsFile::parse(include_str!("eds/valve.eds")).unwrap();
+
+    assert_eq!(include_str!("eds/valve.rs"), generate_rust(&eds));
+
+    let clearlink_eds = EdsFile::parse(include_str!("eds/clearlink.eds")).unwrap();
+
+    assert_eq!(
+        include_str!("eds/clearlink.rs"),
+        generate_rust(&clearlink_eds)
+    );
+}
+
+#[test]
+fn test_generated_params() {
+    assert_eq!(1, Rpi::PARAM_NUMBER);
+    assert_eq!(10000, Rpi::DEFAULT);
+    assert_eq!(10.5, Pressure::MAXIMUM);
+
+    /*
+    Common Industrial Protocol
+    Service: Set Attribute Single (Request)
+        0... .... = Request/Response: Request (0x0)
+        .001 0000 = Service: Set Attribute Single (0x10)
+    Request Path Size: 5 words
+    Request Path: Assembly, Instance: 0x0096, Attribute: 0x03
+    Set Attribute Single (Request)
+        Data: 02
+
+    -------------------------------------
+    Hex Dump:
+
+    0000   10 05 21 00 04 00 25 00 96 00 30 03 02
+
+    */
+    let expected_byte_array: Vec<CipByte> = vec![
+        0x10, 0x05, 0x21, 0x00, 0x04, 0x00, 0x25, 0x00, 0x96, 0x00, 0x30, 0x03, 0x02,
+    ];
+
+    let request_bytes = write_request_bytes(&MessageRouterRequest::new_data(
+        ServiceCode::SetAttributeSingle,
+        FaultMode::link_path(),
+        Some(Box::new(FaultMode::SafeState)),
+    ));
+    assert_eq_hex!(expected_byte_array, request_bytes);
+}
+
+#[test]
+fn test_generated_assemblies() {
+    assert_eq!(2, OutputsAssembly::SIZE);
+    assert_eq!(0x64, OutputsAssembly::INSTANCE_ID);
+    assert_eq!(10, StatusAssembly::SIZE);
+
+    let outputs = OutputsAssembly {
+        fault_mode: 1,
+        valve_1: true,
+    };
+
+    assert_eq!(
+        vec![0x10, 0x03, 0x20, 0x04, 0x24, 0x64, 0x30, 0x03, 0x01, 0x01],
+        write_request_bytes(&write_assembly(OutputsAssembly::INSTANCE_ID, &outputs).unwrap())
+    );
+
+    let status =
+        StatusAssembly::decode(&[0x00, 0x00, 0x20, 0x40, 0x02, 0x01, 0x0f, 0x00, 0x00, 0x00])
+            .unwrap();
+
+    assert_eq!(2.5, status.pressure);
+    assert_eq!([0x02, 0x01], status.outputs);
+    assert_eq!(vec![0; 10], StatusAssembly::default().encode());
+}
+
+#[test]
+fn test_generated_clearlink_config_assembly() {
+    use clearlink::{
+        Ai0Range, ConfigAssembly, EncoderVelocityResolution, Motor3ConfigRegister,
+        Motor3FollowAxis, Motor3MaxDeceleration, SerialBaudRate, SerialInputTimeout,
+    };
+
+    // The config data the hand-written ConfigAssemblyObject of the write-teknic-io example sends
+    let config_bytes: Vec<CipByte> = vec![
+        0x64, 0x64, 0x64, 0x64, 0x64, 0x00, 0x00, 0x00, 0x0a, 0x0a, 0x0a, 0x0a, 0x10, 0x27, 0x10,
+        0x27, 0x10, 0x27, 0x10, 0x27, 0x10, 0x27, 0x10, 0x27, 0x10, 0x27, 0x10, 0x27, 0x10, 0x27,
+        0x10, 0x27, 0x10, 0x27, 0x10, 0x27, 0x10, 0x27, 0x10, 0x27, 0x10, 0x27, 0x10, 0x27, 0x10,
+        0x27, 0x10, 0x27, 0x10, 0x27, 0x10, 0x27, 0x10, 0x27, 0x10, 0x27, 0x10, 0x27, 0x10, 0x27,
+        0x10, 0x27, 0x10, 0x27, 0x0a, 0x0a, 0x0a, 0x0a, 0x0a, 0x0a, 0x0a, 0x0a, 0x64, 0x00, 0x00,
+        0x00, 0x05, 0x00, 0x00, 0x00, 0x08, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x01, 0x00,
+        0x00, 0x00, 0x80, 0x96, 0x98, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xff,
+        0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0x00, 0x08, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00,
+        0x01, 0x00, 0x00, 0x00, 0x80, 0x96, 0x98, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0x00, 0x08, 0x00, 0x00, 0x00, 0x01, 0x00,
+        0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x80, 0x96, 0x98, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0x00, 0x08, 0x00, 0x00, 0x00,
+        0x01, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x80, 0x96, 0x98, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0x00, 0x00, 0xc2,
+        0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x0a, 0x00, 0x00, 0x00,
+    ];
+
+    assert_eq!(150, ConfigAssembly::ASSEMBLY_NUMBER);
+    assert_eq!(0x96, ConfigAssembly::INSTANCE_ID);
+    assert_eq!(config_bytes.len(), ConfigAssembly::SIZE);
+
+    let config = ConfigAssembly::decode(&config_bytes).unwrap();
+
+    assert_eq!(Ai0Range::DEFAULT, config.ai0_range);
+    assert_eq!([10; 4], config.aip_filters);
+    assert_eq!(
+        EncoderVelocityResolution::DEFAULT,
+        config.encoder_velocity_resolution
+    );
+    assert_eq!(
+        Motor3ConfigRegister::DEFAULT,
+        config.motor_3_config_register
+    );
+    assert_eq!(
+        Motor3MaxDeceleration::DEFAULT,
+        config.motor_3_max_deceleration
+    );
+    assert_eq!(Motor3FollowAxis::DEFAULT, config.motor_3_follow_axis);
+    assert_eq!(SerialBaudRate::DEFAULT, config.serial_baud_rate);
+    assert_eq!(SerialInputTimeout::DEFAULT, config.serial_input_timeout);
+
+    assert_eq_hex!(config_bytes, config.encode());
+}
+
+#[test]
+fn test_generated_clearlink_output_assembly() {
+    use clearlink::OutputAssembly;
+
+    // The request of the hand-written OutputAssemblyObject with output 1 on
+    let mut expected_byte_array: Vec<CipByte> =
+        vec![0x10, 0x03, 0x20, 0x04, 0x24, 0x70, 0x30, 0x03];
+    expected_byte_array.extend_from_slice(&[0x00; 280]);
+    expected_byte_array[10] = 0x02;
+
+    let outputs = OutputAssembly {
+        dop1_value: true,
+        ..Default::default()
+    };
+
+    let request_bytes =
+        write_request_bytes(&write_assembly(OutputAssembly::INSTANCE_ID, &outputs).unwrap());
+    assert_eq_hex!(expected_byte_array, request_bytes);
+}
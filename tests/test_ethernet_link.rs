@@ -0,0 +1,169 @@
+use hex_test_macros::prelude::*;
+
+use eipscanne_rs::cip::ethernet_link::{
+    ethernet_link_attribute_request, get_and_clear_request, AdminState, EthernetLinkAttributeId,
+    EthernetLinkSetting, InterfaceControl, InterfaceCounters, InterfaceFlags, InterfaceType,
+    MediaCounters, NegotiationStatus, PhysicalAddress,
+};
+use eipscanne_rs::cip::types::CipByte;
+
+mod common;
+use common::{read_response, write_request_bytes};
+
+#[test]
+fn test_serialize_get_and_clear_request() {
+    /*
+    Common Industrial Protocol
+    Service: Get and Clear (Request)
+        0... .... = Request/Response: Request (0x0)
+        .100 1100 = Service: Get and Clear (0x4c)
+    Request Path Size: 5 words
+    Request Path: Ethernet Link, Instance: 0x0002, Attribute: 0x05
+        Path Segment: 0x21 (16-Bit Class Segment)
+            Class: Ethernet Link (0x00f6)
+        Path Segment: 0x25 (16-Bit Instance Segment)
+            Instance: 0x0002
+        Path Segment: 0x30 (8-Bit Attribute Segment)
+            Attribute: 5
+
+    -------------------------------------
+    Hex Dump:
+
+    0000   4c 05 21 00 f6 00 25 00 02 00 30 05
+
+    */
+    let expected_byte_array: Vec<CipByte> = vec![
+        0x4c, 0x05, 0x21, 0x00, 0xf6, 0x00, 0x25, 0x00, 0x02, 0x00, 0x30, 0x05,
+    ];
+
+    let request_bytes = write_request_bytes(&get_and_clear_request(
+        0x02,
+        EthernetLinkAttributeId::MediaCounters,
+    ));
+    assert_eq_hex!(expected_byte_array, request_bytes);
+}
+
+#[test]
+fn test_deserialize_media_counters() {
+    /*
+    Common Industrial Protocol
+    Service: Get Attribute Single (Response)
+        1... .... = Request/Response: Response (0x1)
+        .000 1110 = Service: Get Attribute Single (0x0e)
+    Status: Success:
+        General Status: Success (0x00)
+        Additional Status Size: 0 words
+    Get Attribute Single (Response)
+        Media Counters
+            Alignment Errors: 0
+            FCS Errors: 17
+            Single Collisions: 3
+            Multiple Collisions: 1
+            SQE Test Errors: 0
+            Deferred Transmission: 0
+            Late Collisions: 2
+            Excessive Collisions: 0
+            MAC Transmit Errors: 0
+            Carrier Sense Errors: 0
+            Frame Too Long: 0
+            MAC Receive Errors: 0
+
+    -------------------------------------
+    Hex Dump:
+
+    0000   8e 00 00 00 00 00 00 00 11 00 00 00 03 00 00 00
+    0010   01 00 00 00 00 00 00 00 00 00 00 00 02 00 00 00
+    0020   00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00
+    0030   00 00 00 00
+
+    */
+    let response = read_response(vec![
+        0x8e, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x11, 0x00, 0x00, 0x00, 0x03, 0x00, 0x00,
+        0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    ]);
+
+    let media_counters: MediaCounters = response.response_data.read_reply().unwrap();
+
+    assert_eq!(
+        MediaCounters {
+            fcs_errors: 17,
+            single_collisions: 3,
+            multiple_collisions: 1,
+            late_collisions: 2,
+            ..Default::default()
+        },
+        media_counters
+    );
+    assert_eq!(6, media_counters.total_collisions());
+}
+
+#[test]
+fn test_deserialize_interface_counters() {
+    let mut response_bytes = vec![0x8e, 0x00, 0x00, 0x00];
+    for counter in 1..=11u32 {
+        response_bytes.extend(counter.to_le_bytes());
+    }
+    let response = read_response(response_bytes);
+
+    let interface_counters: InterfaceCounters = response.response_data.read_reply().unwrap();
+
+    assert_eq!(1, interface_counters.in_octets);
+    assert_eq!(5, interface_counters.in_errors);
+    assert_eq!(11, interface_counters.out_errors);
+}
+
+#[test]
+fn test_deserialize_interface_flags_and_address() {
+    // Link up, full duplex, successfully negotiated
+    let response = read_response(vec![0x8e, 0x00, 0x00, 0x00, 0x0f, 0x00, 0x00, 0x00]);
+    let flags: InterfaceFlags = response.response_data.read_reply().unwrap();
+
+    assert!(flags.link_active());
+    assert!(flags.full_duplex());
+    assert_eq!(NegotiationStatus::Negotiated, flags.negotiation_status());
+    assert!(!flags.local_hardware_fault());
+
+    let response = read_response(vec![
+        0x8e, 0x00, 0x00, 0x00, 0x00, 0x1d, 0x9c, 0xc7, 0x2a, 0x01,
+    ]);
+    let address: PhysicalAddress = response.response_data.read_reply().unwrap();
+
+    assert_eq!("00:1d:9c:c7:2a:01", address.to_string());
+
+    let response = read_response(vec![0x8e, 0x00, 0x00, 0x00, 0x02]);
+    let interface_type: InterfaceType = response.response_data.read_reply().unwrap();
+
+    assert_eq!(InterfaceType::TwistedPair, interface_type);
+}
+
+#[test]
+fn test_serialize_ethernet_link_settings() {
+    assert_eq!(
+        vec![0x0e, 0x05, 0x21, 0x00, 0xf6, 0x00, 0x25, 0x00, 0x01, 0x00, 0x30, 0x06],
+        write_request_bytes(&ethernet_link_attribute_request(
+            0x01,
+            EthernetLinkAttributeId::InterfaceControl
+        ))
+    );
+
+    // Forced to 100 Mbps full duplex
+    assert_eq!(
+        vec![
+            0x10, 0x05, 0x21, 0x00, 0xf6, 0x00, 0x25, 0x00, 0x01, 0x00, 0x30, 0x06, 0x02, 0x00,
+            0x64, 0x00,
+        ],
+        write_request_bytes(
+            &EthernetLinkSetting::InterfaceControl(InterfaceControl::new_forced(100, true))
+                .into_router_request(0x01)
+        )
+    );
+
+    assert_eq!(
+        vec![0x10, 0x05, 0x21, 0x00, 0xf6, 0x00, 0x25, 0x00, 0x02, 0x00, 0x30, 0x09, 0x02],
+        write_request_bytes(
+            &EthernetLinkSetting::AdminState(AdminState::Disabled).into_router_request(0x02)
+        )
+    );
+}
@@ -0,0 +1,293 @@
+use hex_test_macros::prelude::*;
+
+use std::collections::VecDeque;
+
+use eipscanne_rs::cip::file::{
+    download_file, file_checksum, upload_file, FileDownload, FileUpload, EDS_FILE_INSTANCE,
+    MAX_TRANSFER_RETRIES,
+};
+use eipscanne_rs::cip::message::request::MessageRouterRequest;
+use eipscanne_rs::cip::message::response::MessageRouterResponse;
+use eipscanne_rs::cip::types::CipByte;
+
+mod common;
+use common::{read_response, write_request_bytes};
+
+// "[File]" sums to 0x0238, so its checksum is 0xfdc8
+const FILE_DATA: [CipByte; 6] = [0x5b, 0x46, 0x69, 0x6c, 0x65, 0x5d];
+
+#[test]
+fn test_file_checksum() {
+    assert_eq!(0xfdc8u16 as i16, file_checksum(&FILE_DATA));
+    assert_eq!(0, file_checksum(&[]));
+}
+
+#[test]
+fn test_serialize_initiate_upload_request() {
+    /*
+    Common Industrial Protocol
+    Service: Initiate Upload (Request)
+        0... .... = Request/Response: Request (0x0)
+        .100 1011 = Service: Initiate Upload (0x4b)
+    Request Path Size: 4 words
+    Request Path: File, Instance: 0x00c8
+        Path Segment: 0x21 (16-Bit Class Segment)
+            Class: File (0x0037)
+        Path Segment: 0x25 (16-Bit Instance Segment)
+            Instance: 0x00c8
+    Initiate Upload (Request)
+        Maximum File Size: 255
+
+    -------------------------------------
+    Hex Dump:
+
+    0000   4b 04 21 00 37 00 25 00 c8 00 ff
+
+    */
+    let expected_byte_array: Vec<CipByte> = vec![
+        0x4b, 0x04, 0x21, 0x00, 0x37, 0x00, 0x25, 0x00, 0xc8, 0x00, 0xff,
+    ];
+
+    let file_upload = FileUpload::new(EDS_FILE_INSTANCE);
+
+    let request_bytes = write_request_bytes(&file_upload.next_request().unwrap());
+    assert_eq_hex!(expected_byte_array, request_bytes);
+}
+
+#[test]
+fn test_upload_file() {
+    let mut file_upload = FileUpload::new(EDS_FILE_INSTANCE);
+
+    // Initiate Upload: 6 bytes, 4 bytes per transfer
+    file_upload
+        .handle_response(&read_response(vec![
+            0xcb, 0x00, 0x00, 0x00, 0x06, 0x00, 0x00, 0x00, 0x04,
+        ]))
+        .unwrap();
+
+    // Upload Transfer 0: First packet
+    file_upload
+        .handle_response(&read_response(vec![
+            0xcf, 0x00, 0x00, 0x00, 0x00, 0x00, 0x5b, 0x46, 0x69, 0x6c,
+        ]))
+        .unwrap();
+
+    // A repeated transfer 0 asks for transfer 1 again
+    file_upload
+        .handle_response(&read_response(vec![
+            0xcf, 0x00, 0x00, 0x00, 0x00, 0x00, 0x5b, 0x46, 0x69, 0x6c,
+        ]))
+        .unwrap();
+    assert_eq!(
+        vec![0x4f, 0x04, 0x21, 0x00, 0x37, 0x00, 0x25, 0x00, 0xc8, 0x00, 0x01],
+        write_request_bytes(&file_upload.next_request().unwrap())
+    );
+
+    // Upload Transfer 1: Last packet with the checksum
+    file_upload
+        .handle_response(&read_response(vec![
+            0xcf, 0x00, 0x00, 0x00, 0x01, 0x02, 0x65, 0x5d, 0xc8, 0xfd,
+        ]))
+        .unwrap();
+
+    assert!(file_upload.next_request().is_none());
+    assert_eq!(Some(FILE_DATA.to_vec()), file_upload.into_data());
+}
+
+#[test]
+fn test_upload_file_checksum_mismatch() {
+    let mut file_upload = FileUpload::new(EDS_FILE_INSTANCE);
+
+    let initiate_response = vec![0xcb, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0xff];
+    // First and Last packet with a wrong checksum
+    let transfer_response = vec![0xcf, 0x00, 0x00, 0x00, 0x00, 0x04, 0x5b, 0x46, 0x00, 0x00];
+
+    for _ in 0..MAX_TRANSFER_RETRIES {
+        file_upload
+            .handle_response(&read_response(initiate_response.clone()))
+            .unwrap();
+        file_upload
+            .handle_response(&read_response(transfer_response.clone()))
+            .unwrap();
+
+        // The upload starts over with Initiate Upload
+        assert_eq!(
+            0x4b,
+            write_request_bytes(&file_upload.next_request().unwrap())[0]
+        );
+    }
+
+    file_upload
+        .handle_response(&read_response(initiate_response))
+        .unwrap();
+    assert!(file_upload
+        .handle_response(&read_response(transfer_response))
+        .is_err());
+    assert!(!file_upload.is_complete());
+}
+
+#[test]
+fn test_upload_file_with_exchange() {
+    let mut responses = VecDeque::from([
+        // Initiate Upload: 6 bytes, 4 bytes per transfer
+        Some(vec![0xcb, 0x00, 0x00, 0x00, 0x06, 0x00, 0x00, 0x00, 0x04]),
+        // Upload Transfer 0: First packet
+        Some(vec![0xcf, 0x00, 0x00, 0x00, 0x00, 0x00, 0x5b, 0x46, 0x69, 0x6c]),
+        // The response to Upload Transfer 1 is lost
+        None,
+        // Upload Transfer 1: Last packet with the checksum
+        Some(vec![0xcf, 0x00, 0x00, 0x00, 0x01, 0x02, 0x65, 0x5d, 0xc8, 0xfd]),
+    ]);
+    let mut requests = Vec::new();
+
+    let file_data = upload_file(EDS_FILE_INSTANCE, |request| {
+        requests.push(write_request_bytes(&request));
+        match responses.pop_front().unwrap() {
+            Some(response_bytes) => Ok(read_response(response_bytes)),
+            None => Err(binrw::Error::Io(std::io::ErrorKind::TimedOut.into())),
+        }
+    })
+    .unwrap();
+
+    assert_eq!(FILE_DATA.to_vec(), file_data);
+    // Upload Transfer 1 was sent twice
+    assert_eq!(4, requests.len());
+    assert_eq!(requests[2], requests[3]);
+}
+
+#[test]
+fn test_download_file_with_exchange_aborts() {
+    let mut requests: Vec<MessageRouterRequest> = Vec::new();
+
+    // The device never answers the first packet
+    let result = download_file(0x01, "fw.bin", (1, 2), FILE_DATA.to_vec(), |request| {
+        requests.push(request);
+        match requests.len() {
+            // Initiate Download: 4 bytes per transfer
+            1 => Ok(read_response(vec![
+                0xcc, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x04,
+            ])),
+            _ => Err::<MessageRouterResponse, _>(binrw::Error::Io(
+                std::io::ErrorKind::TimedOut.into(),
+            )),
+        }
+    });
+
+    assert!(result.is_err());
+    // Initiate Download, the first packet and its retries, then the abort
+    assert_eq!(MAX_TRANSFER_RETRIES + 3, requests.len());
+    assert_eq!(
+        vec![0x50, 0x04, 0x21, 0x00, 0x37, 0x00, 0x25, 0x00, 0x01, 0x00, 0x00, 0x03],
+        write_request_bytes(requests.last().unwrap())
+    );
+}
+
+#[test]
+fn test_serialize_initiate_download_request() {
+    /*
+    Common Industrial Protocol
+    Service: Initiate Download (Request)
+        0... .... = Request/Response: Request (0x0)
+        .100 1100 = Service: Initiate Download (0x4c)
+    Request Path Size: 4 words
+    Request Path: File, Instance: 0x0001
+        Path Segment: 0x21 (16-Bit Class Segment)
+            Class: File (0x0037)
+        Path Segment: 0x25 (16-Bit Instance Segment)
+            Instance: 0x0001
+    Initiate Download (Request)
+        File Size: 6
+        File Revision: 1.2
+        File Name
+            Number of Strings: 1
+            Language: eng
+            String Type: SHORT_STRING (0xda)
+            Character Set: ISO-8859-1 (4)
+            String: fw.bin
+
+    -------------------------------------
+    Hex Dump:
+
+    0000   4c 04 21 00 37 00 25 00 01 00 06 00 00 00 01 02
+    0010   01 65 6e 67 da 04 00 06 66 77 2e 62 69 6e
+
+    */
+    let expected_byte_array: Vec<CipByte> = vec![
+        0x4c, 0x04, 0x21, 0x00, 0x37, 0x00, 0x25, 0x00, 0x01, 0x00, 0x06, 0x00, 0x00, 0x00, 0x01,
+        0x02, 0x01, 0x65, 0x6e, 0x67, 0xda, 0x04, 0x00, 0x06, 0x66, 0x77, 0x2e, 0x62, 0x69, 0x6e,
+    ];
+
+    let file_download = FileDownload::new(0x01, "fw.bin", (1, 2), FILE_DATA.to_vec());
+
+    let request_bytes = write_request_bytes(&file_download.next_request().unwrap());
+    assert_eq_hex!(expected_byte_array, request_bytes);
+}
+
+#[test]
+fn test_download_file() {
+    let mut file_download = FileDownload::new(0x01, "fw.bin", (1, 2), FILE_DATA.to_vec());
+
+    // Initiate Download: 4 bytes per transfer
+    file_download
+        .handle_response(&read_response(vec![
+            0xcc, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x04,
+        ]))
+        .unwrap();
+
+    // Download Transfer 0: First packet
+    assert_eq!(
+        vec![
+            0x50, 0x04, 0x21, 0x00, 0x37, 0x00, 0x25, 0x00, 0x01, 0x00, 0x00, 0x00, 0x5b, 0x46,
+            0x69, 0x6c,
+        ],
+        write_request_bytes(&file_download.next_request().unwrap())
+    );
+
+    // A lost response sends the same packet again
+    file_download.retry().unwrap();
+    file_download
+        .handle_response(&read_response(vec![0xd0, 0x00, 0x00, 0x00, 0x00]))
+        .unwrap();
+
+    // Download Transfer 1: Last packet with the checksum
+    assert_eq!(
+        vec![
+            0x50, 0x04, 0x21, 0x00, 0x37, 0x00, 0x25, 0x00, 0x01, 0x00, 0x01, 0x02, 0x65, 0x5d,
+            0xc8, 0xfd,
+        ],
+        write_request_bytes(&file_download.next_request().unwrap())
+    );
+    file_download
+        .handle_response(&read_response(vec![0xd0, 0x00, 0x00, 0x00, 0x01]))
+        .unwrap();
+
+    assert!(file_download.is_complete());
+    assert!(file_download.next_request().is_none());
+}
+
+#[test]
+fn test_download_file_retries_exhausted() {
+    let mut file_download = FileDownload::new(0x01, "fw.bin", (1, 2), FILE_DATA.to_vec());
+
+    file_download
+        .handle_response(&read_response(vec![
+            0xcc, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x04,
+        ]))
+        .unwrap();
+
+    // The device keeps acknowledging the wrong transfer
+    for _ in 0..MAX_TRANSFER_RETRIES {
+        file_download
+            .handle_response(&read_response(vec![0xd0, 0x00, 0x00, 0x00, 0x05]))
+            .unwrap();
+    }
+    assert!(file_download
+        .handle_response(&read_response(vec![0xd0, 0x00, 0x00, 0x00, 0x05]))
+        .is_err());
+
+    // Download Transfer 0: Abort
+    assert_eq!(
+        vec![0x50, 0x04, 0x21, 0x00, 0x37, 0x00, 0x25, 0x00, 0x01, 0x00, 0x00, 0x03],
+        write_request_bytes(&file_download.abort_request())
+    );
+}
@@ -2,21 +2,28 @@ use binrw::{BinRead, BinWrite};
 
 use bilge::prelude::u4;
 
+use hex_test_macros::prelude::*;
+
 use eipscanne_rs::cip::identity::{
-    DeviceType, IdentityResponse, IdentityStatusBits, Revision, VendorId,
+    identity_attribute_request, reset_request, DeviceState, DeviceType, ExtendedDeviceStatus,
+    IdentityAttributeId, IdentityResponse, IdentityStatus, IdentityStatusBits, Language,
+    ResetType, Revision, SupportedLanguages, VendorId,
 };
 use eipscanne_rs::cip::message::data::CipDataOpt;
 use eipscanne_rs::cip::message::response::{
     MessageRouterResponse, ResponseData, ResponseStatusCode,
 };
 use eipscanne_rs::cip::message::shared::{ServiceCode, ServiceContainer};
-use eipscanne_rs::cip::types::{CipByte, CipShortString};
+use eipscanne_rs::cip::types::{CipByte, CipShortString, CipUint};
 use eipscanne_rs::eip::command::{
     CommandSpecificData, EnIpCommand, EncapsStatusCode, RRPacketData,
 };
 use eipscanne_rs::eip::packet::{EnIpPacketDescription, EncapsulationHeader};
 use eipscanne_rs::object_assembly::{RequestObjectAssembly, ResponseObjectAssembly};
 
+mod common;
+use common::{read_response, write_request_bytes};
+
 #[test]
 fn test_deserialize_device_type() {
     let generic_device_type_bytes: Vec<CipByte> = vec![0x2b, 0x00];
@@ -164,7 +171,7 @@ fn test_deserialize_just_cip_identity_response() {
             false,
             false,
             false,
-            u4::new(0x0),
+            ExtendedDeviceStatus::SelfTestingOrUnknown,
             false,
             false,
             false,
@@ -266,7 +273,7 @@ fn test_deserialize_cip_identity_response() {
                     false,
                     false,
                     false,
-                    u4::new(0x0),
+                    ExtendedDeviceStatus::SelfTestingOrUnknown,
                     false,
                     false,
                     false,
@@ -398,7 +405,7 @@ fn test_deserialize_full_identity_response() {
                 ),
             },
             cip_message: Some(MessageRouterResponse {
-                service_container: ServiceContainer::new(ServiceCode::GetAttributeAll, true).into(),
+                service_container: ServiceContainer::new(ServiceCode::GetAttributeAll, true),
                 response_data: ResponseData {
                     status: ResponseStatusCode::Success,
                     additional_status_size: 0x0,
@@ -415,7 +422,7 @@ fn test_deserialize_full_identity_response() {
                             false,
                             false,
                             false,
-                            u4::new(0x0),
+                            ExtendedDeviceStatus::SelfTestingOrUnknown,
                             false,
                             false,
                             false,
@@ -433,3 +440,96 @@ fn test_deserialize_full_identity_response() {
     // Assert equality
     assert_eq!(expected_identity_response, identity_response);
 }
+
+#[test]
+fn test_decode_extended_device_status() {
+    // Status: 0x0024, configured with at least one faulted I/O connection
+    let status_bytes: Vec<CipByte> = vec![0x24, 0x00];
+    let status = IdentityStatus::read(&mut std::io::Cursor::new(status_bytes)).unwrap();
+
+    let status_bits = status.bits();
+    assert!(status_bits.configured());
+    assert_eq!(
+        ExtendedDeviceStatus::FaultedIoConnection,
+        status_bits.extended_device_status()
+    );
+    assert_eq!(
+        "at least one faulted I/O connection",
+        status_bits.extended_device_status().to_string()
+    );
+
+    // Vendor specific values are kept as they are
+    let vendor_status_bytes: Vec<CipByte> = vec![0xb0, 0x00];
+    let vendor_status =
+        IdentityStatus::read(&mut std::io::Cursor::new(vendor_status_bytes)).unwrap();
+
+    assert_eq!(
+        ExtendedDeviceStatus::Other(u4::new(0xb)),
+        vendor_status.bits().extended_device_status()
+    );
+}
+
+#[test]
+fn test_read_identity_attributes() {
+    /*
+    Common Industrial Protocol
+    Service: Get Attribute Single (Request)
+        0... .... = Request/Response: Request (0x0)
+        .000 1110 = Service: Get Attribute Single (0x0e)
+    Request Path Size: 5 words
+    Request Path: Identity, Instance: 0x0001, Attribute: 0x08
+        Path Segment: 0x21 (16-Bit Class Segment)
+            Class: Identity (0x0001)
+        Path Segment: 0x25 (16-Bit Instance Segment)
+            Instance: 0x0001
+        Path Segment: 0x30 (8-Bit Attribute Segment)
+            Attribute: 8
+
+    -------------------------------------
+    Hex Dump:
+
+    0000   0e 05 21 00 01 00 25 00 01 00 30 08
+
+    */
+    let expected_byte_array: Vec<CipByte> = vec![
+        0x0e, 0x05, 0x21, 0x00, 0x01, 0x00, 0x25, 0x00, 0x01, 0x00, 0x30, 0x08,
+    ];
+
+    let request_bytes =
+        write_request_bytes(&identity_attribute_request(IdentityAttributeId::State));
+    assert_eq_hex!(expected_byte_array, request_bytes);
+
+    let state_response = read_response(vec![0x8e, 0x00, 0x00, 0x00, 0x03]);
+    let state: DeviceState = state_response.response_data.read_reply().unwrap();
+    assert_eq!(DeviceState::Operational, state);
+
+    let consistency_response = read_response(vec![0x8e, 0x00, 0x00, 0x00, 0x34, 0x12]);
+    let consistency_value: CipUint = consistency_response.response_data.read_reply().unwrap();
+    assert_eq!(0x1234, consistency_value);
+
+    let language_response = read_response(vec![0x8e, 0x00, 0x00, 0x00, 0x65, 0x6e, 0x67]);
+    let language: Language = language_response.response_data.read_reply().unwrap();
+    assert_eq!("eng", language.to_string());
+
+    let languages_response = read_response(vec![
+        0x8e, 0x00, 0x00, 0x00, 0x65, 0x6e, 0x67, 0x64, 0x65, 0x75,
+    ]);
+    let languages: SupportedLanguages = languages_response.response_data.read_reply().unwrap();
+    assert_eq!(
+        vec!["eng".to_string(), "deu".to_string()],
+        languages
+            .languages
+            .iter()
+            .map(Language::to_string)
+            .collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn test_serialize_reset_request() {
+    // Reset (0x05) to factory defaults, keeping the communication settings
+    assert_eq!(
+        vec![0x05, 0x04, 0x21, 0x00, 0x01, 0x00, 0x25, 0x00, 0x01, 0x00, 0x02],
+        write_request_bytes(&reset_request(ResetType::FactoryDefaultsKeepCommunication))
+    );
+}
@@ -0,0 +1,183 @@
+use binrw::BinRead;
+
+use hex_test_macros::prelude::*;
+
+use eipscanne_rs::cip::message::request::MessageRouterRequest;
+use eipscanne_rs::cip::types::CipByte;
+use eipscanne_rs::logix::shared::LogixDataType;
+use eipscanne_rs::logix::symbol::SymbolBrowse;
+
+mod common;
+use common::{read_response, write_request_bytes};
+
+#[test]
+fn test_serialize_controller_symbol_list_request() {
+    /*
+    Common Industrial Protocol
+    Service: Get Instance Attribute List (Request)
+        0... .... = Request/Response: Request (0x0)
+        .101 0101 = Service: Get Instance Attribute List (0x55)
+    Request Path Size: 3 words
+    Request Path: Symbol, Instance: 0x0000
+        Path Segment: 0x20 (8-Bit Class Segment)
+            Class: Symbol (0x6b)
+        Path Segment: 0x25 (16-Bit Instance Segment)
+            Instance: 0x0000
+    CIP Class Generic
+        Attribute Count: 3
+        Attribute: 1 (Symbol Name)
+        Attribute: 2 (Symbol Type)
+        Attribute: 8 (Array Dimensions)
+
+    -------------------------------------
+    Hex Dump:
+
+    0000   55 03 20 6b 25 00 00 00 03 00 01 00 02 00 08 00
+
+    */
+    let expected_byte_array: Vec<CipByte> = vec![
+        0x55, 0x03, 0x20, 0x6b, 0x25, 0x00, 0x00, 0x00, 0x03, 0x00, 0x01, 0x00, 0x02, 0x00, 0x08,
+        0x00,
+    ];
+
+    let symbol_browse = SymbolBrowse::new_controller_scope();
+    let request = symbol_browse.next_request().unwrap();
+
+    assert_eq_hex!(expected_byte_array, write_request_bytes(&request));
+}
+
+#[test]
+fn test_serialize_program_symbol_list_request() {
+    /*
+    Common Industrial Protocol
+    Service: Get Instance Attribute List (Request)
+        0... .... = Request/Response: Request (0x0)
+        .101 0101 = Service: Get Instance Attribute List (0x55)
+    Request Path Size: 14 words
+    Request Path: Program:MainProgram, Symbol, Instance: 0x0000
+        Path Segment: 0x91 (ANSI Extended Symbol Segment)
+            Data Size: 19
+            ANSI Symbol: Program:MainProgram
+        Path Segment: 0x20 (8-Bit Class Segment)
+            Class: Symbol (0x6b)
+        Path Segment: 0x25 (16-Bit Instance Segment)
+            Instance: 0x0000
+    CIP Class Generic
+        Attribute Count: 3
+        Attribute: 1 (Symbol Name)
+        Attribute: 2 (Symbol Type)
+        Attribute: 8 (Array Dimensions)
+
+    -------------------------------------
+    Hex Dump:
+
+    0000   55 0e 91 13 50 72 6f 67 72 61 6d 3a 4d 61 69 6e
+    0010   50 72 6f 67 72 61 6d 00 20 6b 25 00 00 00 03 00
+    0020   01 00 02 00 08 00
+
+    */
+    let expected_byte_array: Vec<CipByte> = vec![
+        0x55, 0x0e, 0x91, 0x13, 0x50, 0x72, 0x6f, 0x67, 0x72, 0x61, 0x6d, 0x3a, 0x4d, 0x61, 0x69,
+        0x6e, 0x50, 0x72, 0x6f, 0x67, 0x72, 0x61, 0x6d, 0x00, 0x20, 0x6b, 0x25, 0x00, 0x00, 0x00,
+        0x03, 0x00, 0x01, 0x00, 0x02, 0x00, 0x08, 0x00,
+    ];
+
+    let symbol_browse = SymbolBrowse::new_program_scope("MainProgram");
+    let request_bytes = write_request_bytes(&symbol_browse.next_request().unwrap());
+
+    assert_eq_hex!(expected_byte_array, request_bytes);
+
+    // The mixed symbolic and logical path is recognized when reading the request back
+    let mut reader = std::io::Cursor::new(request_bytes);
+    let read_request =
+        MessageRouterRequest::read_args(&mut reader, (expected_byte_array.len() as u16,)).unwrap();
+
+    assert_eq!(
+        symbol_browse.next_request().unwrap().request_data.cip_path,
+        read_request.request_data.cip_path
+    );
+}
+
+#[test]
+fn test_symbol_browse_pages_by_instance() {
+    let mut symbol_browse = SymbolBrowse::new_controller_scope();
+
+    /*
+    Common Industrial Protocol
+    Service: Get Instance Attribute List (Response)
+        1... .... = Request/Response: Response (0x1)
+        .101 0101 = Service: Get Instance Attribute List (0x55)
+    Status: Partial transfer:
+        General Status: Partial transfer (0x06)
+        Additional Status Size: 0 words
+    CIP Class Generic
+        Instance: 5
+            Symbol Name: Counts
+            Symbol Type: 0x20c4 (DINT[10])
+            Array Dimensions: 10, 0, 0
+        Instance: 9
+            Symbol Name: Recipe
+            Symbol Type: 0x8f12 (Structure, Template Instance: 0x0f12)
+            Array Dimensions: 0, 0, 0
+    */
+    let first_page: Vec<CipByte> = vec![
+        0xd5, 0x00, 0x06, 0x00, 0x05, 0x00, 0x00, 0x00, 0x06, 0x00, 0x43, 0x6f, 0x75, 0x6e, 0x74,
+        0x73, 0xc4, 0x20, 0x0a, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x09, 0x00, 0x00, 0x00, 0x06, 0x00, 0x52, 0x65, 0x63, 0x69, 0x70, 0x65, 0x12, 0x8f, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    ];
+
+    symbol_browse
+        .handle_response(&read_response(first_page))
+        .unwrap();
+
+    assert!(!symbol_browse.is_complete());
+
+    // The next page starts after the last instance of the previous one
+    let expected_next_request: Vec<CipByte> = vec![
+        0x55, 0x03, 0x20, 0x6b, 0x25, 0x00, 0x0a, 0x00, 0x03, 0x00, 0x01, 0x00, 0x02, 0x00, 0x08,
+        0x00,
+    ];
+    assert_eq_hex!(
+        expected_next_request,
+        write_request_bytes(&symbol_browse.next_request().unwrap())
+    );
+
+    let last_page: Vec<CipByte> = vec![
+        0xd5, 0x00, 0x00, 0x00, 0x0c, 0x00, 0x00, 0x00, 0x13, 0x00, 0x50, 0x72, 0x6f, 0x67, 0x72,
+        0x61, 0x6d, 0x3a, 0x4d, 0x61, 0x69, 0x6e, 0x50, 0x72, 0x6f, 0x67, 0x72, 0x61, 0x6d, 0x68,
+        0x10, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    ];
+
+    symbol_browse
+        .handle_response(&read_response(last_page))
+        .unwrap();
+
+    assert!(symbol_browse.is_complete());
+    assert!(symbol_browse.next_request().is_none());
+
+    let symbols = symbol_browse.into_symbols();
+    assert_eq!(3, symbols.len());
+
+    assert_eq!(5, symbols[0].instance_id);
+    assert_eq!("Counts", symbols[0].name());
+    assert_eq!(LogixDataType::Dint, symbols[0].symbol_type.data_type());
+    assert_eq!(vec![10], symbols[0].dimensions());
+
+    assert_eq!("Recipe", symbols[1].name());
+    assert_eq!(LogixDataType::Structure, symbols[1].symbol_type.data_type());
+    assert_eq!(Some(0x0f12), symbols[1].symbol_type.template_instance_id());
+    assert!(symbols[1].dimensions().is_empty());
+
+    assert!(symbols[2].symbol_type.system());
+    assert_eq!(Some("MainProgram".to_string()), symbols[2].program_name());
+}
+
+#[test]
+fn test_symbol_browse_rejects_empty_partial_transfer() {
+    let mut symbol_browse = SymbolBrowse::new_controller_scope();
+
+    let response = read_response(vec![0xd5, 0x00, 0x06, 0x00]);
+
+    assert!(symbol_browse.handle_response(&response).is_err());
+}
@@ -0,0 +1,277 @@
+use binrw::BinRead;
+
+use hex_test_macros::prelude::*;
+
+use eipscanne_rs::cip::message::request::MessageRouterRequest;
+use eipscanne_rs::cip::path::{
+    LogicalPathSegment, SymbolicPath, SymbolicPathSegment, SymbolicSegment,
+};
+use eipscanne_rs::cip::types::CipByte;
+use eipscanne_rs::logix::shared::{LogixDataType, TagType};
+use eipscanne_rs::logix::tag::{ReadModifyWriteRequest, Tag, TagData};
+
+mod common;
+use common::{read_response, write_request_bytes};
+
+#[test]
+fn test_parse_program_scoped_tag_name() {
+    let tag_path = SymbolicPath::from_tag_name("Program:Main.Recipes[3,300]").unwrap();
+
+    let expected_tag_path = SymbolicPath {
+        segments: vec![
+            SymbolicPathSegment::Symbol(SymbolicSegment::new("Program:Main")),
+            SymbolicPathSegment::Symbol(SymbolicSegment::new("Recipes")),
+            SymbolicPathSegment::Element(LogicalPathSegment::new_element(3)),
+            SymbolicPathSegment::Element(LogicalPathSegment::new_element(300)),
+        ],
+    };
+
+    assert_eq!(expected_tag_path, tag_path);
+
+    assert!(SymbolicPath::from_tag_name("Recipes[3").is_err());
+    assert!(SymbolicPath::from_tag_name("Recipes[x]").is_err());
+    assert!(SymbolicPath::from_tag_name("Program:Main..Speed").is_err());
+}
+
+#[test]
+fn test_serialize_read_tag_fragmented_request() {
+    /*
+    Common Industrial Protocol
+    Service: Read Tag Fragmented Service (Request)
+        0... .... = Request/Response: Request (0x0)
+        .101 0010 = Service: Read Tag Fragmented Service (0x52)
+    Request Path Size: 6 words
+    Request Path: Recipes[3]
+        Path Segment: 0x91 (ANSI Extended Symbol Segment)
+            100. .... = Path Segment Type: Data Segment (4)
+            ...1 0001 = Data Segment Type: ANSI Extended Symbol Segment (0x11)
+            Data Size: 7
+            ANSI Symbol: Recipes
+        Path Segment: 0x28 (8-Bit Member Segment)
+            001. .... = Path Segment Type: Logical Segment (1)
+            ...0 10.. = Logical Segment Type: Member ID (2)
+            .... ..00 = Logical Segment Format: 8-bit Logical Segment (0)
+            Member: 3
+    CIP Class Generic
+        Number of Elements: 100
+        Offset: 200
+
+    -------------------------------------
+    Hex Dump:
+
+    0000   52 06 91 07 52 65 63 69 70 65 73 00 28 03 64 00
+    0010   c8 00 00 00
+
+    */
+    let expected_byte_array: Vec<CipByte> = vec![
+        0x52, 0x06, 0x91, 0x07, 0x52, 0x65, 0x63, 0x69, 0x70, 0x65, 0x73, 0x00, 0x28, 0x03, 0x64,
+        0x00, 0xc8, 0x00, 0x00, 0x00,
+    ];
+
+    let tag = Tag::new("Recipes[3]").unwrap();
+    let request_bytes = write_request_bytes(&tag.read_fragmented_request(100, 200));
+
+    assert_eq_hex!(expected_byte_array, request_bytes);
+
+    // The symbolic path is recognized when reading the request back
+    let mut reader = std::io::Cursor::new(request_bytes);
+    let read_request =
+        MessageRouterRequest::read_args(&mut reader, (expected_byte_array.len() as u16,)).unwrap();
+
+    let expected_request = tag.read_fragmented_request(100, 200);
+
+    assert_eq!(6, read_request.request_data.total_word_size);
+    assert_eq!(
+        expected_request.request_data.cip_path,
+        read_request.request_data.cip_path
+    );
+    assert_eq!(
+        expected_request.request_data.additional_data,
+        read_request.request_data.additional_data
+    );
+}
+
+#[test]
+fn test_fragmented_read_reassembles_partial_transfers() {
+    let tag = Tag::new("Counts").unwrap();
+    let mut fragmented_read = tag.read(3);
+
+    assert_eq!(
+        Some(tag.read_fragmented_request(3, 0)),
+        fragmented_read.next_request()
+    );
+
+    /*
+    Common Industrial Protocol
+    Service: Read Tag Fragmented Service (Response)
+        1... .... = Request/Response: Response (0x1)
+        .101 0010 = Service: Read Tag Fragmented Service (0x52)
+    Status: Partial transfer:
+        General Status: Partial transfer (0x06)
+        Additional Status Size: 0 words
+    CIP Class Generic
+        Data Type: DINT (0x00c4)
+        Data: 0100000002000000
+    */
+    fragmented_read
+        .handle_response(&read_response(vec![
+            0xd2, 0x00, 0x06, 0x00, 0xc4, 0x00, 0x01, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00,
+        ]))
+        .unwrap();
+
+    assert!(!fragmented_read.is_complete());
+    assert_eq!(
+        Some(tag.read_fragmented_request(3, 8)),
+        fragmented_read.next_request()
+    );
+
+    fragmented_read
+        .handle_response(&read_response(vec![
+            0xd2, 0x00, 0x00, 0x00, 0xc4, 0x00, 0x03, 0x00, 0x00, 0x00,
+        ]))
+        .unwrap();
+
+    assert!(fragmented_read.is_complete());
+    assert_eq!(None, fragmented_read.next_request());
+
+    let expected_tag_data = TagData {
+        tag_type: TagType::new_atomic(LogixDataType::Dint),
+        data: vec![
+            0x01, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x03, 0x00, 0x00, 0x00,
+        ],
+    };
+
+    assert_eq!(Some(expected_tag_data), fragmented_read.into_tag_data());
+}
+
+#[test]
+fn test_fragmented_read_fails_on_error_status() {
+    let mut fragmented_read = Tag::new("Missing").unwrap().read(1);
+
+    // Path destination unknown (0x05) with no additional status
+    let response = read_response(vec![0xd2, 0x00, 0x05, 0x00]);
+
+    assert!(fragmented_read.handle_response(&response).is_err());
+    assert!(!fragmented_read.is_complete());
+}
+
+#[test]
+fn test_deserialize_structure_tag_data() {
+    let tag_data_bytes: Vec<CipByte> = vec![0xa0, 0x02, 0x34, 0x12, 0xaa, 0xbb];
+
+    let tag_data = TagData::read(&mut std::io::Cursor::new(tag_data_bytes)).unwrap();
+
+    let expected_tag_data = TagData {
+        tag_type: TagType::new_structure(0x1234),
+        data: vec![0xaa, 0xbb],
+    };
+
+    assert_eq!(expected_tag_data, tag_data);
+}
+
+#[test]
+fn test_fragmented_write_splits_on_element_boundaries() {
+    let tag = Tag::new("Counts").unwrap();
+    let dint_type = TagType::new_atomic(LogixDataType::Dint);
+    let data: Vec<u8> = (0..12).collect();
+
+    // 10 bytes does not fit a whole number of DINTs so it is rounded down to 8
+    let mut fragmented_write = tag.write(dint_type, 3, data.clone()).with_fragment_size(10);
+
+    assert_eq!(
+        Some(tag.write_fragmented_request(dint_type, 3, 0, data[0..8].to_vec())),
+        fragmented_write.next_request()
+    );
+
+    let success_response = read_response(vec![0xd3, 0x00, 0x00, 0x00]);
+    fragmented_write.handle_response(&success_response).unwrap();
+
+    /*
+    Common Industrial Protocol
+    Service: Write Tag Fragmented Service (Request)
+        0... .... = Request/Response: Request (0x0)
+        .101 0011 = Service: Write Tag Fragmented Service (0x53)
+    Request Path Size: 4 words
+    Request Path: Counts
+        Path Segment: 0x91 (ANSI Extended Symbol Segment)
+            Data Size: 6
+            ANSI Symbol: Counts
+    CIP Class Generic
+        Data Type: DINT (0x00c4)
+        Number of Elements: 3
+        Offset: 8
+        Data: 08090a0b
+
+    -------------------------------------
+    Hex Dump:
+
+    0000   53 04 91 06 43 6f 75 6e 74 73 c4 00 03 00 08 00
+    0010   00 00 08 09 0a 0b
+
+    */
+    let expected_byte_array: Vec<CipByte> = vec![
+        0x53, 0x04, 0x91, 0x06, 0x43, 0x6f, 0x75, 0x6e, 0x74, 0x73, 0xc4, 0x00, 0x03, 0x00, 0x08,
+        0x00, 0x00, 0x00, 0x08, 0x09, 0x0a, 0x0b,
+    ];
+
+    let second_request = fragmented_write.next_request().unwrap();
+    assert_eq_hex!(expected_byte_array, write_request_bytes(&second_request));
+
+    fragmented_write.handle_response(&success_response).unwrap();
+
+    assert!(fragmented_write.is_complete());
+    assert_eq!(None, fragmented_write.next_request());
+}
+
+#[test]
+fn test_serialize_set_bit_request() {
+    /*
+    Common Industrial Protocol
+    Service: Read Modify Write Tag Service (Request)
+        0... .... = Request/Response: Request (0x0)
+        .100 1110 = Service: Read Modify Write Tag Service (0x4e)
+    Request Path Size: 4 words
+    Request Path: Flags
+        Path Segment: 0x91 (ANSI Extended Symbol Segment)
+            Data Size: 5
+            ANSI Symbol: Flags
+    CIP Class Generic
+        Mask Size: 4
+        OR Mask: 20000000
+        AND Mask: ffffffff
+
+    -------------------------------------
+    Hex Dump:
+
+    0000   4e 04 91 05 46 6c 61 67 73 00 04 00 20 00 00 00
+    0010   ff ff ff ff
+
+    */
+    let expected_byte_array: Vec<CipByte> = vec![
+        0x4e, 0x04, 0x91, 0x05, 0x46, 0x6c, 0x61, 0x67, 0x73, 0x00, 0x04, 0x00, 0x20, 0x00, 0x00,
+        0x00, 0xff, 0xff, 0xff, 0xff,
+    ];
+
+    let tag = Tag::new("Flags").unwrap();
+    let set_bit_request = tag.write_bit_request(LogixDataType::Dint, 5, true).unwrap();
+
+    assert_eq_hex!(expected_byte_array, write_request_bytes(&set_bit_request));
+}
+
+#[test]
+fn test_read_modify_write_masks_are_sized_to_the_tag_type() {
+    let clear_bit = ReadModifyWriteRequest::new_bit(LogixDataType::Int, 15, false).unwrap();
+
+    assert_eq!(vec![0x00, 0x00], clear_bit.or_mask);
+    assert_eq!(vec![0xff, 0x7f], clear_bit.and_mask);
+
+    let set_bit = ReadModifyWriteRequest::new_bit(LogixDataType::Lint, 63, true).unwrap();
+
+    assert_eq!(vec![0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x80], set_bit.or_mask);
+    assert_eq!(vec![0xff; 8], set_bit.and_mask);
+
+    // Bits outside of the type and non-integer types are rejected
+    assert!(ReadModifyWriteRequest::new_bit(LogixDataType::Sint, 8, true).is_err());
+    assert!(ReadModifyWriteRequest::new_bit(LogixDataType::Real, 0, true).is_err());
+    assert!(ReadModifyWriteRequest::new(TagType::new_structure(0x1234).data_type, 1, 1).is_err());
+}
@@ -0,0 +1,261 @@
+use std::collections::HashMap;
+
+use binrw::BinRead;
+
+use hex_test_macros::prelude::*;
+
+use eipscanne_rs::cip::types::CipByte;
+use eipscanne_rs::logix::shared::LogixDataType;
+use eipscanne_rs::logix::symbol::SymbolType;
+use eipscanne_rs::logix::tag::TagValue;
+use eipscanne_rs::logix::template::{MemberInfo, Template, TemplateMember, TemplateRead};
+
+mod common;
+use common::{read_response, write_request_bytes};
+
+fn read_motor_template() -> Template {
+    let mut template_read = TemplateRead::new(0x1234);
+
+    /*
+    Common Industrial Protocol
+    Service: Get Attribute List (Response)
+        1... .... = Request/Response: Response (0x1)
+        .000 0011 = Service: Get Attribute List (0x03)
+    Status: Success:
+        General Status: Success (0x00)
+        Additional Status Size: 0 words
+    Get Attribute List (Response)
+        Attribute Count: 4
+        Attribute: 4 (Object Definition Size)
+            Status: Success (0x00)
+            Object Definition Size: 29
+        Attribute: 5 (Structure Size)
+            Status: Success (0x00)
+            Structure Size: 16
+        Attribute: 2 (Member Count)
+            Status: Success (0x00)
+            Member Count: 5
+        Attribute: 1 (Structure Handle)
+            Status: Success (0x00)
+            Structure Handle: 0xa5b6
+
+    -------------------------------------
+    Hex Dump:
+
+    0000   83 00 00 00 04 00 04 00 00 00 1d 00 00 00 05 00
+    0010   00 00 10 00 00 00 02 00 00 00 05 00 01 00 00 00
+    0020   b6 a5
+
+    */
+    template_read
+        .handle_response(&read_response(vec![
+            0x83, 0x00, 0x00, 0x00, 0x04, 0x00, 0x04, 0x00, 0x00, 0x00, 0x1d, 0x00, 0x00, 0x00,
+            0x05, 0x00, 0x00, 0x00, 0x10, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x05, 0x00,
+            0x01, 0x00, 0x00, 0x00, 0xb6, 0xa5,
+        ]))
+        .unwrap();
+
+    // The member info entries are returned first as a partial transfer
+    template_read
+        .handle_response(&read_response(vec![
+            0xcc, 0x00, 0x06, 0x00, 0x00, 0x00, 0xc2, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0xc1, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0x00, 0xc1, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0xca, 0x00, 0x04, 0x00, 0x00, 0x00, 0x02, 0x00, 0xc4, 0x20, 0x08, 0x00,
+            0x00, 0x00,
+        ]))
+        .unwrap();
+
+    // Followed by the template name and the member names
+    template_read
+        .handle_response(&read_response(vec![
+            0xcc, 0x00, 0x00, 0x00, 0x4d, 0x6f, 0x74, 0x6f, 0x72, 0x3b, 0x6e, 0x00, 0x5a, 0x5a,
+            0x5a, 0x5a, 0x5a, 0x5a, 0x5a, 0x5a, 0x5a, 0x5a, 0x4d, 0x6f, 0x74, 0x6f, 0x72, 0x30,
+            0x00, 0x52, 0x75, 0x6e, 0x6e, 0x69, 0x6e, 0x67, 0x00, 0x46, 0x61, 0x75, 0x6c, 0x74,
+            0x65, 0x64, 0x00, 0x53, 0x70, 0x65, 0x65, 0x64, 0x00, 0x43, 0x6f, 0x75, 0x6e, 0x74,
+            0x73, 0x00,
+        ]))
+        .unwrap();
+
+    assert!(template_read.is_complete());
+    template_read.into_template().unwrap().unwrap()
+}
+
+#[test]
+fn test_serialize_read_template_request() {
+    let mut template_read = TemplateRead::new(0x1234);
+
+    let expected_attributes_request: Vec<CipByte> = vec![
+        0x03, 0x03, 0x20, 0x6c, 0x25, 0x00, 0x34, 0x12, 0x04, 0x00, 0x04, 0x00, 0x05, 0x00, 0x02,
+        0x00, 0x01, 0x00,
+    ];
+    assert_eq!(
+        expected_attributes_request,
+        write_request_bytes(&template_read.next_request().unwrap())
+    );
+
+    template_read
+        .handle_response(&read_response(vec![
+            0x83, 0x00, 0x00, 0x00, 0x04, 0x00, 0x04, 0x00, 0x00, 0x00, 0x1d, 0x00, 0x00, 0x00,
+            0x05, 0x00, 0x00, 0x00, 0x10, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x05, 0x00,
+            0x01, 0x00, 0x00, 0x00, 0xb6, 0xa5,
+        ]))
+        .unwrap();
+
+    /*
+    Common Industrial Protocol
+    Service: Read Template (Request)
+        0... .... = Request/Response: Request (0x0)
+        .100 1100 = Service: Read Template (0x4c)
+    Request Path Size: 3 words
+    Request Path: Template, Instance: 0x1234
+        Path Segment: 0x20 (8-Bit Class Segment)
+            Class: Template (0x6c)
+        Path Segment: 0x25 (16-Bit Instance Segment)
+            Instance: 0x1234
+    CIP Class Generic
+        Offset: 0
+        Number of Bytes: 95
+
+    -------------------------------------
+    Hex Dump:
+
+    0000   4c 03 20 6c 25 00 34 12 00 00 00 00 5f 00
+
+    */
+    let expected_read_template_request: Vec<CipByte> = vec![
+        0x4c, 0x03, 0x20, 0x6c, 0x25, 0x00, 0x34, 0x12, 0x00, 0x00, 0x00, 0x00, 0x5f, 0x00,
+    ];
+    assert_eq_hex!(
+        expected_read_template_request,
+        write_request_bytes(&template_read.next_request().unwrap())
+    );
+}
+
+#[test]
+fn test_parse_template_definition() {
+    let template = read_motor_template();
+
+    assert_eq!("Motor", template.name);
+    assert_eq!(0xa5b6, template.structure_handle);
+    assert_eq!(16, template.structure_size);
+
+    let member_names: Vec<&str> = template.members.iter().map(|m| m.name.as_str()).collect();
+    assert_eq!(
+        vec!["ZZZZZZZZZZMotor0", "Running", "Faulted", "Speed", "Counts"],
+        member_names
+    );
+
+    assert!(template.members[0].is_hidden());
+    assert_eq!(Some(1), template.members[2].bit_position());
+    assert_eq!(LogixDataType::Real, template.members[3].data_type());
+    assert_eq!(4, template.members[3].info.offset);
+    assert_eq!(Some(2), template.members[4].array_length());
+}
+
+#[test]
+fn test_decode_structure_value() {
+    let template = read_motor_template();
+
+    let motor_data: Vec<CipByte> = vec![
+        0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0xc0, 0x3f, 0x07, 0x00, 0x00, 0x00, 0xff, 0xff, 0xff,
+        0xff,
+    ];
+
+    let expected_motor_value = TagValue::Structure(vec![
+        ("Running".to_string(), TagValue::Bool(false)),
+        ("Faulted".to_string(), TagValue::Bool(true)),
+        ("Speed".to_string(), TagValue::Real(1.5)),
+        (
+            "Counts".to_string(),
+            TagValue::Array(vec![TagValue::Dint(7), TagValue::Dint(-1)]),
+        ),
+    ]);
+
+    assert_eq!(
+        expected_motor_value,
+        template.decode(&motor_data, &HashMap::new()).unwrap()
+    );
+
+    // Data that is too short for the members fails instead of panicking
+    assert!(template.decode(&motor_data[..8], &HashMap::new()).is_err());
+}
+
+#[test]
+fn test_decode_nested_structure_value() {
+    // Template instances referenced by a member type are limited to 12 bits
+    let motor_template = Template {
+        instance_id: 0x0234,
+        ..read_motor_template()
+    };
+
+    // A structure with a DINT and a Motor member, i.e. the structure bit and the template instance
+    let cell_template = Template {
+        instance_id: 0x0042,
+        name: "Cell".to_string(),
+        structure_handle: 0x0101,
+        structure_size: 20,
+        members: vec![
+            TemplateMember {
+                name: "Id".to_string(),
+                info: MemberInfo {
+                    info: 0,
+                    member_type: SymbolType::from(0x00c4),
+                    offset: 0,
+                },
+            },
+            TemplateMember {
+                name: "Drive".to_string(),
+                info: MemberInfo {
+                    info: 0,
+                    member_type: SymbolType::from(0x8234),
+                    offset: 4,
+                },
+            },
+        ],
+    };
+
+    assert_eq!(vec![0x0234], cell_template.nested_template_ids());
+
+    let mut cell_data: Vec<CipByte> = vec![0x0c, 0x00, 0x00, 0x00];
+    cell_data.extend([
+        0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x80, 0xbf, 0x00, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00,
+        0x00,
+    ]);
+
+    // The nested template must be provided to decode the member
+    assert!(cell_template.decode(&cell_data, &HashMap::new()).is_err());
+
+    let templates = HashMap::from([(motor_template.instance_id, motor_template)]);
+
+    let expected_cell_value = TagValue::Structure(vec![
+        ("Id".to_string(), TagValue::Dint(12)),
+        (
+            "Drive".to_string(),
+            TagValue::Structure(vec![
+                ("Running".to_string(), TagValue::Bool(true)),
+                ("Faulted".to_string(), TagValue::Bool(false)),
+                ("Speed".to_string(), TagValue::Real(-1.0)),
+                (
+                    "Counts".to_string(),
+                    TagValue::Array(vec![TagValue::Dint(0), TagValue::Dint(1)]),
+                ),
+            ]),
+        ),
+    ]);
+
+    assert_eq!(
+        expected_cell_value,
+        cell_template.decode(&cell_data, &templates).unwrap()
+    );
+}
+
+#[test]
+fn test_deserialize_member_info() {
+    let member_info_bytes: Vec<CipByte> = vec![0x02, 0x00, 0xc4, 0x20, 0x08, 0x00, 0x00, 0x00];
+
+    let member_info = MemberInfo::read(&mut std::io::Cursor::new(member_info_bytes)).unwrap();
+
+    assert_eq!(2, member_info.info);
+    assert_eq!(LogixDataType::Dint, member_info.member_type.data_type());
+    assert_eq!(8, member_info.offset);
+}
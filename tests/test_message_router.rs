@@ -17,7 +17,7 @@ fn test_serialize_service_container() {
     let expected_byte_array: Vec<CipByte> = vec![0x01];
 
     let service_container_bits = ServiceContainer::new(ServiceCode::GetAttributeAll, false);
-    let service_container = ServiceContainer::from(service_container_bits);
+    let service_container = service_container_bits;
 
     let mut service_container_bytes: Vec<u8> = Vec::new();
     let mut writer = std::io::Cursor::new(&mut service_container_bytes);
@@ -112,10 +112,10 @@ fn test_deserialize_empty_response() {
     let message_router_response = MessageRouterResponse::read_args(&mut buf_reader, (message_router_response_length,)).unwrap();
 
     let expected_message_router_response = MessageRouterResponse {
-        service_container: ServiceContainer::from(ServiceContainer::new(
+        service_container: ServiceContainer::new(
             ServiceCode::GetAttributeAll,
             true,
-        )),
+        ),
         response_data: ResponseData {
             status: ResponseStatusCode::Success,
             additional_status_size: 0x0,
@@ -127,13 +127,54 @@ fn test_deserialize_empty_response() {
     assert_eq!(expected_message_router_response, message_router_response);
 }
 
+#[test]
+fn test_deserialize_object_specific_status_response() {
+    /*
+    Common Industrial Protocol
+    Service: Get Attribute Single (Response)
+        1... .... = Request/Response: Response (0x1)
+        .000 1110 = Service: Get Attribute Single (0x0e)
+    Status: Unknown (0xd0):
+        General Status: Unknown (0xd0)
+        Additional Status Size: 0 words
+    */
+    let raw_byte_array: Vec<CipByte> = vec![0x8e, 0x00, 0xd0, 0x00];
+
+    let byte_cursor = std::io::Cursor::new(raw_byte_array);
+    let mut buf_reader = std::io::BufReader::new(byte_cursor);
+
+    let message_router_response = MessageRouterResponse::read_args(&mut buf_reader, (4,)).unwrap();
+
+    assert_eq!(
+        message_router_response.response_data.status,
+        ResponseStatusCode::Other(0xd0)
+    );
+    assert!(message_router_response.check_status().is_err());
+    assert!(!message_router_response.response_data.is_attribute_unsupported());
+    assert!(!message_router_response.response_data.is_object_missing());
+}
+
+#[test]
+fn test_response_status_classification() {
+    // Attribute not supported (0x14), then object does not exist (0x16)
+    let unsupported_response =
+        MessageRouterResponse::read_args(&mut std::io::Cursor::new(vec![0x8e, 0x00, 0x14, 0x00]), (4,)).unwrap();
+    let missing_response =
+        MessageRouterResponse::read_args(&mut std::io::Cursor::new(vec![0x8e, 0x00, 0x16, 0x00]), (4,)).unwrap();
+
+    assert!(unsupported_response.response_data.is_attribute_unsupported());
+    assert!(!unsupported_response.response_data.is_object_missing());
+    assert!(missing_response.response_data.is_object_missing());
+    assert!(!missing_response.response_data.is_attribute_unsupported());
+}
+
 #[test]
 fn test_message_cip_path_byte_size() {
     let message_router_request = MessageRouterRequest {
-        service_container: ServiceContainer::from(ServiceContainer::new(
+        service_container: ServiceContainer::new(
             ServiceCode::GetAttributeAll,
             false,
-        )),
+        ),
         request_data: RequestData::new(None, CipPath::new(0x1, 0x1), None),
     };
 
@@ -0,0 +1,223 @@
+use binrw::{BinRead, BinWrite};
+
+use hex_test_macros::prelude::*;
+
+use eipscanne_rs::cip::message::multiple_service::{MultipleServiceReply, MultipleServiceRequest};
+use eipscanne_rs::cip::message::request::MessageRouterRequest;
+use eipscanne_rs::cip::message::response::{MessageRouterResponse, ResponseStatusCode};
+use eipscanne_rs::cip::message::shared::ServiceCode;
+use eipscanne_rs::cip::path::CipPath;
+use eipscanne_rs::cip::types::{CipByte, CipShortString};
+
+fn product_name_and_vendor_requests() -> Vec<MessageRouterRequest> {
+    vec![
+        MessageRouterRequest::new(
+            ServiceCode::GetAttributeSingle,
+            CipPath::new_full(0x1, 0x1, 0x7),
+        ),
+        MessageRouterRequest::new(
+            ServiceCode::GetAttributeSingle,
+            CipPath::new_full(0x1, 0x1, 0x1),
+        ),
+    ]
+}
+
+#[test]
+fn test_serialize_multiple_service_request() {
+    /*
+    Common Industrial Protocol
+    Service: Multiple Service Packet (Request)
+        0... .... = Request/Response: Request (0x0)
+        .000 1010 = Service: Multiple Service Packet (0x0a)
+    Request Path Size: 4 words
+    Request Path: Message Router, Instance: 0x0001
+        Path Segment: 0x21 (16-Bit Class Segment)
+            Class: Message Router (0x0002)
+        Path Segment: 0x25 (16-Bit Instance Segment)
+            Instance: 0x0001
+    Multiple Service Packet (Request)
+        Number of Services: 2
+        Offset List
+            Offset: 6
+            Offset: 14
+        Service Packet #1: Identity, Instance: 0x01, Attribute: 0x07
+            Service: Get Attribute Single (Request)
+        Service Packet #2: Identity, Instance: 0x01, Attribute: 0x01
+            Service: Get Attribute Single (Request)
+
+    -------------------------------------
+    Hex Dump:
+
+    0000   0a 04 21 00 02 00 25 00 01 00 02 00 06 00 0e 00
+    0010   0e 03 20 01 24 01 30 07 0e 03 20 01 24 01 30 01
+
+    */
+    let expected_byte_array: Vec<CipByte> = vec![
+        0x0a, 0x04, 0x21, 0x00, 0x02, 0x00, 0x25, 0x00, 0x01, 0x00, 0x02, 0x00, 0x06, 0x00, 0x0e,
+        0x00, 0x0e, 0x03, 0x20, 0x01, 0x24, 0x01, 0x30, 0x07, 0x0e, 0x03, 0x20, 0x01, 0x24, 0x01,
+        0x30, 0x01,
+    ];
+
+    let multiple_service_request =
+        MultipleServiceRequest::new(product_name_and_vendor_requests()).into_router_request();
+
+    let mut request_bytes: Vec<u8> = Vec::new();
+    let mut writer = std::io::Cursor::new(&mut request_bytes);
+    multiple_service_request.write(&mut writer).unwrap();
+
+    assert_eq_hex!(expected_byte_array, request_bytes);
+}
+
+#[test]
+fn test_deserialize_multiple_service_request() {
+    let packet_bytes: Vec<CipByte> = vec![
+        0x02, 0x00, 0x06, 0x00, 0x0e, 0x00, 0x0e, 0x03, 0x20, 0x01, 0x24, 0x01, 0x30, 0x07, 0x0e,
+        0x03, 0x20, 0x01, 0x24, 0x01, 0x30, 0x01,
+    ];
+
+    let multiple_service_request =
+        MultipleServiceRequest::read_le(&mut std::io::Cursor::new(packet_bytes)).unwrap();
+
+    assert_eq!(2, multiple_service_request.requests.len());
+    assert_eq!(
+        CipPath::new_full(0x1, 0x1, 0x7),
+        match &multiple_service_request.requests[0].request_data.cip_path {
+            eipscanne_rs::cip::path::RequestPath::Logical(cip_path) => cip_path.clone(),
+            symbolic_path => panic!("Unexpected path {:?}", symbolic_path),
+        }
+    );
+}
+
+#[test]
+fn test_deserialize_multiple_service_reply() {
+    /*
+    Common Industrial Protocol
+    Service: Multiple Service Packet (Response)
+        1... .... = Request/Response: Response (0x1)
+        .000 1010 = Service: Multiple Service Packet (0x0a)
+    Status: Embedded service error:
+        General Status: Embedded service error (0x1e)
+        Additional Status Size: 0 words
+    Multiple Service Packet (Response)
+        Number of Services: 2
+        Offset List
+            Offset: 6
+            Offset: 20
+        Service Packet #1: Identity, Instance: 0x01, Attribute: 0x07
+            Service: Get Attribute Single (Response)
+            Status: Success:
+            Product Name: ClearLink
+        Service Packet #2: Identity, Instance: 0x01, Attribute: 0x01
+            Service: Get Attribute Single (Response)
+            Status: Attribute not supported:
+
+    -------------------------------------
+    Hex Dump:
+
+    0000   8a 00 1e 00 02 00 06 00 14 00 8e 00 00 00 09 43
+    0010   6c 65 61 72 4c 69 6e 6b 8e 00 14 00
+
+    */
+    let reply_bytes: Vec<CipByte> = vec![
+        0x8a, 0x00, 0x1e, 0x00, 0x02, 0x00, 0x06, 0x00, 0x14, 0x00, 0x8e, 0x00, 0x00, 0x00, 0x09,
+        0x43, 0x6c, 0x65, 0x61, 0x72, 0x4c, 0x69, 0x6e, 0x6b, 0x8e, 0x00, 0x14, 0x00,
+    ];
+
+    let reply_length = reply_bytes.len() as u16;
+    let router_response =
+        MessageRouterResponse::read_args(&mut std::io::Cursor::new(reply_bytes), (reply_length,))
+            .unwrap();
+
+    let multiple_service_reply = MultipleServiceReply::from_response(&router_response).unwrap();
+
+    assert_eq!(2, multiple_service_reply.responses.len());
+
+    let product_name_response = &multiple_service_reply.responses[0];
+    assert_eq!(
+        ServiceCode::GetAttributeSingle,
+        product_name_response.service_container.service()
+    );
+    assert_eq!(
+        CipShortString::from("ClearLink".to_string()),
+        product_name_response
+            .response_data
+            .read_reply::<CipShortString>()
+            .unwrap()
+    );
+
+    let vendor_response = &multiple_service_reply.responses[1];
+    assert_eq!(
+        ResponseStatusCode::AttributeNotSupported,
+        vendor_response.response_data.status
+    );
+    assert!(vendor_response.check_status().is_err());
+}
+
+#[test]
+fn test_plan_splits_requests_into_packets() {
+    // UINT attributes, whose 2 byte replies take 8 bytes with their header and offset
+    let requests: Vec<(MessageRouterRequest, usize)> = (0..5)
+        .map(|attribute_id| {
+            let request = MessageRouterRequest::new(
+                ServiceCode::GetAttributeSingle,
+                CipPath::new_full(0x1, 0x1, attribute_id + 1),
+            );
+            (request, 2)
+        })
+        .collect();
+
+    // Each 8 byte request takes 10 bytes with its offset, after the 12 byte packet header
+    let packets = MultipleServiceRequest::plan(requests, 32).unwrap();
+
+    let packet_sizes: Vec<usize> = packets.iter().map(|packet| packet.requests.len()).collect();
+    assert_eq!(vec![2, 2, 1], packet_sizes);
+
+    for packet in packets {
+        let mut request_bytes: Vec<u8> = Vec::new();
+        let mut writer = std::io::Cursor::new(&mut request_bytes);
+        packet.into_router_request().write(&mut writer).unwrap();
+
+        assert!(request_bytes.len() <= 32);
+    }
+
+    // A request that can never fit is reported instead of being silently dropped
+    let oversized_request = vec![(
+        MessageRouterRequest::new(
+            ServiceCode::GetAttributeSingle,
+            CipPath::new_full(0x1, 0x1, 0x1),
+        ),
+        2,
+    )];
+    assert!(MultipleServiceRequest::plan(oversized_request, 21).is_err());
+}
+
+#[test]
+fn test_plan_budgets_replies() {
+    // Small requests for 20 byte attributes, whose replies take 26 bytes with their header and
+    // offset, after the 6 byte reply header
+    let requests: Vec<(MessageRouterRequest, usize)> = (0..3)
+        .map(|attribute_id| {
+            let request = MessageRouterRequest::new(
+                ServiceCode::GetAttributeSingle,
+                CipPath::new_full(0x1, 0x1, attribute_id + 1),
+            );
+            (request, 20)
+        })
+        .collect();
+
+    // The requests alone would fit two to a packet, the replies only fit one
+    let packets = MultipleServiceRequest::plan(requests, 32).unwrap();
+
+    let packet_sizes: Vec<usize> = packets.iter().map(|packet| packet.requests.len()).collect();
+    assert_eq!(vec![1, 1, 1], packet_sizes);
+
+    // A reply that can never fit is reported even though its request does
+    let oversized_reply = vec![(
+        MessageRouterRequest::new(
+            ServiceCode::GetAttributeSingle,
+            CipPath::new_full(0x1, 0x1, 0x1),
+        ),
+        21,
+    )];
+    assert!(MultipleServiceRequest::plan(oversized_reply, 32).is_err());
+}
@@ -0,0 +1,222 @@
+use hex_test_macros::prelude::*;
+
+use eipscanne_rs::cip::parameter::{
+    get_enum_string_request, ParameterDescriptor, ParameterInfo, ParameterRead, ParameterScaling,
+    ParameterValue,
+};
+use eipscanne_rs::cip::types::{CipByte, CipDataType};
+
+mod common;
+use common::{read_response, write_request_bytes};
+
+fn scaled_speed_parameter() -> ParameterInfo {
+    let mut info = ParameterInfo::new(0x03, CipDataType::Int, 2);
+    info.name = String::from("Speed");
+    info.descriptor = ParameterDescriptor::from(0x0004);
+    info.minimum = Some(ParameterValue::Integer(-1000));
+    info.maximum = Some(ParameterValue::Integer(1000));
+    info.scaling = ParameterScaling {
+        multiplier: 3,
+        divisor: 2,
+        base: 1,
+        offset: 10,
+        decimal_precision: 1,
+    };
+
+    info
+}
+
+#[test]
+fn test_serialize_get_enum_string_request() {
+    /*
+    Common Industrial Protocol
+    Service: Unknown Service (0x4b) (Request)
+        0... .... = Request/Response: Request (0x0)
+        .100 1011 = Service: Unknown (0x4b)
+    Request Path Size: 4 words
+    Request Path: Parameter, Instance: 0x0001
+        Path Segment: 0x21 (16-Bit Class Segment)
+            Class: Parameter (0x000f)
+        Path Segment: 0x25 (16-Bit Instance Segment)
+            Instance: 0x0001
+    Get Enum String (Request)
+        Enum String Number: 2
+
+    -------------------------------------
+    Hex Dump:
+
+    0000   4b 04 21 00 0f 00 25 00 01 00 02
+
+    */
+    let expected_byte_array: Vec<CipByte> = vec![
+        0x4b, 0x04, 0x21, 0x00, 0x0f, 0x00, 0x25, 0x00, 0x01, 0x00, 0x02,
+    ];
+
+    let request_bytes = write_request_bytes(&get_enum_string_request(0x01, 2));
+    assert_eq_hex!(expected_byte_array, request_bytes);
+}
+
+#[test]
+fn test_read_enumerated_parameter() {
+    let mut parameter_read = ParameterRead::new(0x01);
+
+    let responses = vec![
+        // Data Type: UINT
+        vec![0x8e, 0x00, 0x00, 0x00, 0xc7],
+        // Data Size: 2
+        vec![0x8e, 0x00, 0x00, 0x00, 0x02],
+        // Descriptor: enumerated strings
+        vec![0x8e, 0x00, 0x00, 0x00, 0x02, 0x00],
+        // Value: 1
+        vec![0x8e, 0x00, 0x00, 0x00, 0x01, 0x00],
+        // Link Path: not supported
+        vec![0x8e, 0x00, 0x14, 0x00],
+        // Name: "Mode"
+        vec![0x8e, 0x00, 0x00, 0x00, 0x04, 0x4d, 0x6f, 0x64, 0x65],
+        // Units: ""
+        vec![0x8e, 0x00, 0x00, 0x00, 0x00],
+        // Help: not supported
+        vec![0x8e, 0x00, 0x14, 0x00],
+        // Minimum: 0, Maximum: 2, Default: 0
+        vec![0x8e, 0x00, 0x00, 0x00, 0x00, 0x00],
+        vec![0x8e, 0x00, 0x00, 0x00, 0x02, 0x00],
+        vec![0x8e, 0x00, 0x00, 0x00, 0x00, 0x00],
+        // Scaling and Decimal Precision: not supported
+        vec![0x8e, 0x00, 0x14, 0x00],
+        vec![0x8e, 0x00, 0x14, 0x00],
+        vec![0x8e, 0x00, 0x14, 0x00],
+        vec![0x8e, 0x00, 0x14, 0x00],
+        vec![0x8e, 0x00, 0x14, 0x00],
+        // Get Enum String: "Off", "Slow", "Fast"
+        vec![0xcb, 0x00, 0x00, 0x00, 0x03, 0x4f, 0x66, 0x66],
+        vec![0xcb, 0x00, 0x00, 0x00, 0x04, 0x53, 0x6c, 0x6f, 0x77],
+        vec![0xcb, 0x00, 0x00, 0x00, 0x04, 0x46, 0x61, 0x73, 0x74],
+    ];
+
+    for response_bytes in responses {
+        assert!(!parameter_read.is_complete());
+        assert!(parameter_read.next_request().is_some());
+
+        parameter_read
+            .handle_response(&read_response(response_bytes))
+            .unwrap();
+    }
+
+    assert!(parameter_read.next_request().is_none());
+
+    let info = parameter_read.into_info().unwrap();
+    assert_eq!(CipDataType::Uint, info.data_type);
+    assert_eq!("Mode", info.name);
+    assert_eq!(Some(ParameterValue::Integer(1)), info.value);
+    assert_eq!(None, info.link_path());
+    assert_eq!(Some("Slow"), info.enum_string(info.value.as_ref().unwrap()));
+    assert_eq!(
+        vec![
+            (0, String::from("Off")),
+            (1, String::from("Slow")),
+            (2, String::from("Fast")),
+        ],
+        info.enum_strings
+    );
+}
+
+#[test]
+fn test_read_parameter_requires_data_type() {
+    let mut parameter_read = ParameterRead::new(0x01);
+
+    // The data type cannot be skipped like the optional attributes
+    assert!(parameter_read
+        .handle_response(&read_response(vec![0x8e, 0x00, 0x14, 0x00]))
+        .is_err());
+}
+
+#[test]
+fn test_decode_parameter_values() {
+    assert_eq!(
+        ParameterValue::Integer(-2),
+        ParameterValue::decode(CipDataType::Int, &[0xfe, 0xff])
+    );
+    assert_eq!(
+        ParameterValue::Integer(0xfffe),
+        ParameterValue::decode(CipDataType::Uint, &[0xfe, 0xff])
+    );
+    assert_eq!(
+        ParameterValue::Real(1.5),
+        ParameterValue::decode(CipDataType::Real, &[0x00, 0x00, 0xc0, 0x3f])
+    );
+    assert_eq!(
+        ParameterValue::Bytes(vec![0x01, 0x02, 0x03]),
+        ParameterValue::decode(CipDataType::Uint, &[0x01, 0x02, 0x03])
+    );
+
+    assert!(ParameterValue::Integer(0x10000)
+        .encode(CipDataType::Uint, 2)
+        .is_err());
+    assert!(ParameterValue::Integer(-1)
+        .encode(CipDataType::Uint, 2)
+        .is_err());
+}
+
+#[test]
+fn test_parameter_engineering_value() {
+    let info = scaled_speed_parameter();
+
+    // ((110 + 10) * 3 * 1) / (2 * 10^1)
+    assert_eq!(
+        Some(18.0),
+        info.engineering_value(&ParameterValue::Integer(110))
+    );
+    assert_eq!(
+        ParameterValue::Integer(110),
+        info.value_from_engineering(18.0)
+    );
+
+    // Unscaled parameters are already in engineering units
+    let mut unscaled_info = info.clone();
+    unscaled_info.descriptor = ParameterDescriptor::from(0x0000);
+    assert_eq!(
+        Some(110.0),
+        unscaled_info.engineering_value(&ParameterValue::Integer(110))
+    );
+}
+
+#[test]
+fn test_serialize_set_engineering_value_request() {
+    /*
+    Common Industrial Protocol
+    Service: Set Attribute Single (Request)
+        0... .... = Request/Response: Request (0x0)
+        .001 0000 = Service: Set Attribute Single (0x10)
+    Request Path Size: 5 words
+    Request Path: Parameter, Instance: 0x0003, Attribute: 0x01
+        Path Segment: 0x21 (16-Bit Class Segment)
+            Class: Parameter (0x000f)
+        Path Segment: 0x25 (16-Bit Instance Segment)
+            Instance: 0x0003
+        Path Segment: 0x30 (8-Bit Attribute Segment)
+            Attribute: 1
+    Set Attribute Single (Request)
+        Data: 6e00
+
+    -------------------------------------
+    Hex Dump:
+
+    0000   10 05 21 00 0f 00 25 00 03 00 30 01 6e 00
+
+    */
+    let expected_byte_array: Vec<CipByte> = vec![
+        0x10, 0x05, 0x21, 0x00, 0x0f, 0x00, 0x25, 0x00, 0x03, 0x00, 0x30, 0x01, 0x6e, 0x00,
+    ];
+
+    let info = scaled_speed_parameter();
+
+    let request_bytes = write_request_bytes(&info.set_engineering_value_request(18.0).unwrap());
+    assert_eq_hex!(expected_byte_array, request_bytes);
+
+    // Beyond the maximum of 1000
+    assert!(info.set_engineering_value_request(200.0).is_err());
+
+    let mut read_only_info = info.clone();
+    read_only_info.descriptor = ParameterDescriptor::from(0x0014);
+    assert!(read_only_info.set_engineering_value_request(18.0).is_err());
+}
@@ -17,7 +17,7 @@ fn test_serialize_path_segment() {
 
     let expected_bytes = vec![0x21, 0x0, 0x01, 0x0];
 
-    let logical_path_segment = LogicalPathSegment::from(sample_path_segment_bits);
+    let logical_path_segment = sample_path_segment_bits;
 
     let mut sample_path_bytes: Vec<u8> = Vec::new();
     let mut writer = std::io::Cursor::new(&mut sample_path_bytes);
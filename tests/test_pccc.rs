@@ -0,0 +1,264 @@
+use binrw::BinWrite;
+
+use hex_test_macros::prelude::*;
+
+use eipscanne_rs::cip::types::CipByte;
+use eipscanne_rs::pccc::address::PcccAddress;
+use eipscanne_rs::pccc::command::{Pccc, PcccValue};
+use eipscanne_rs::pccc::shared::PcccFileType;
+
+mod common;
+use common::{read_response, write_request_bytes};
+
+#[test]
+fn test_parse_pccc_addresses() {
+    let integer_address = PcccAddress::parse("N7:0").unwrap();
+    assert_eq!(PcccFileType::Integer, integer_address.file_type);
+    assert_eq!(
+        (7, 0, 0, None),
+        (
+            integer_address.file_number,
+            integer_address.element,
+            integer_address.sub_element,
+            integer_address.bit
+        )
+    );
+
+    let float_address = PcccAddress::parse("f8:3").unwrap();
+    assert_eq!(PcccFileType::Float, float_address.file_type);
+    assert_eq!(3, float_address.element);
+    assert_eq!(Some(4), float_address.element_size());
+
+    // Bit offsets into a bit file are split into the word and the bit
+    let file_bit_address = PcccAddress::parse("B3/21").unwrap();
+    assert_eq!(
+        (3, 1, Some(5)),
+        (
+            file_bit_address.file_number,
+            file_bit_address.element,
+            file_bit_address.bit
+        )
+    );
+    assert_eq!(file_bit_address, PcccAddress::parse("B3:1/5").unwrap());
+
+    let accumulator_address = PcccAddress::parse("T4:2.ACC").unwrap();
+    assert_eq!(
+        (PcccFileType::Timer, 2, 2),
+        (
+            accumulator_address.file_type,
+            accumulator_address.element,
+            accumulator_address.sub_element
+        )
+    );
+    assert_eq!(Some(2), accumulator_address.element_size());
+
+    let done_address = PcccAddress::parse("C5:0.DN").unwrap();
+    assert_eq!((0, Some(13)), (done_address.sub_element, done_address.bit));
+
+    let status_address = PcccAddress::parse("S:1/5").unwrap();
+    assert_eq!(
+        (PcccFileType::Status, 2, 1, Some(5)),
+        (
+            status_address.file_type,
+            status_address.file_number,
+            status_address.element,
+            status_address.bit
+        )
+    );
+
+    assert!(PcccAddress::parse("X7:0").is_err());
+    assert!(PcccAddress::parse("N:0").is_err());
+    assert!(PcccAddress::parse("N7").is_err());
+    assert!(PcccAddress::parse("N7:0/16").is_err());
+    assert!(PcccAddress::parse("N7:0.ACC").is_err());
+}
+
+#[test]
+fn test_serialize_long_address_fields() {
+    // Fields above 254 are written as 0xFF followed by a 16-bit value
+    let address = PcccAddress::parse("N300:1000").unwrap();
+
+    let mut address_bytes: Vec<u8> = Vec::new();
+    address
+        .write(&mut std::io::Cursor::new(&mut address_bytes))
+        .unwrap();
+
+    assert_eq!(
+        vec![0xff, 0x2c, 0x01, 0x89, 0xff, 0xe8, 0x03, 0x00],
+        address_bytes
+    );
+}
+
+#[test]
+fn test_pccc_typed_read() {
+    let mut pccc = Pccc::new(0x004d, 0x12345678);
+    let address = PcccAddress::parse("N7:0").unwrap();
+
+    /*
+    Common Industrial Protocol
+    Service: Execute PCCC (Request)
+        0... .... = Request/Response: Request (0x0)
+        .100 1011 = Service: Execute PCCC (0x4b)
+    Request Path Size: 2 words
+    Request Path: PCCC Class, Instance: 0x01
+        Path Segment: 0x20 (8-Bit Class Segment)
+            Class: PCCC Class (0x67)
+        Path Segment: 0x24 (8-Bit Instance Segment)
+            Instance: 0x01
+    PCCC Command Data
+        Requestor ID
+            Length: 7
+            CIP Vendor ID: Rockwell Automation/Allen-Bradley (0x004d)
+            CIP Serial Number: 0x12345678
+        Command Code: Protected Typed Logical (0x0f)
+        Status Code: Success (0x00)
+        Transaction Code: 0x0001
+        Function Code: Protected Typed Logical Read with 3 Address Fields (0xa2)
+        Byte Size: 2
+        File Number: 7
+        File Type: Integer (0x89)
+        Element Number: 0
+        Sub-Element Number: 0
+
+    -------------------------------------
+    Hex Dump:
+
+    0000   4b 02 20 67 24 01 07 4d 00 78 56 34 12 0f 00 01
+    0010   00 a2 02 07 89 00 00
+
+    */
+    let expected_byte_array: Vec<CipByte> = vec![
+        0x4b, 0x02, 0x20, 0x67, 0x24, 0x01, 0x07, 0x4d, 0x00, 0x78, 0x56, 0x34, 0x12, 0x0f, 0x00,
+        0x01, 0x00, 0xa2, 0x02, 0x07, 0x89, 0x00, 0x00,
+    ];
+
+    let request_bytes = write_request_bytes(&pccc.read_request(&address, 1).unwrap());
+    assert_eq_hex!(expected_byte_array, request_bytes);
+
+    /*
+    Common Industrial Protocol
+    Service: Execute PCCC (Response)
+        1... .... = Request/Response: Response (0x1)
+        .100 1011 = Service: Execute PCCC (0x4b)
+    Status: Success:
+        General Status: Success (0x00)
+        Additional Status Size: 0 words
+    PCCC Response Data
+        Requestor ID
+            Length: 7
+            CIP Vendor ID: Rockwell Automation/Allen-Bradley (0x004d)
+            CIP Serial Number: 0x12345678
+        Command Code: Protected Typed Logical Reply (0x4f)
+        Status Code: Success (0x00)
+        Transaction Code: 0x0001
+        Data: 2a00
+
+    -------------------------------------
+    Hex Dump:
+
+    0000   cb 00 00 00 07 4d 00 78 56 34 12 4f 00 01 00 2a
+    0010   00
+
+    */
+    let reply = pccc
+        .read_reply(&read_response(vec![
+            0xcb, 0x00, 0x00, 0x00, 0x07, 0x4d, 0x00, 0x78, 0x56, 0x34, 0x12, 0x4f, 0x00, 0x01,
+            0x00, 0x2a, 0x00,
+        ]))
+        .unwrap();
+
+    assert_eq!(
+        vec![PcccValue::Integer(42)],
+        PcccValue::decode(&address, &reply.data).unwrap()
+    );
+}
+
+#[test]
+fn test_pccc_typed_and_masked_writes() {
+    let mut pccc = Pccc::new(0x004d, 0x12345678);
+
+    let float_write = pccc
+        .write_request(
+            &PcccAddress::parse("F8:3").unwrap(),
+            &[PcccValue::Float(1.5)],
+        )
+        .unwrap();
+
+    let expected_float_write: Vec<CipByte> = vec![
+        0x4b, 0x02, 0x20, 0x67, 0x24, 0x01, 0x07, 0x4d, 0x00, 0x78, 0x56, 0x34, 0x12, 0x0f, 0x00,
+        0x01, 0x00, 0xaa, 0x04, 0x08, 0x8a, 0x03, 0x00, 0x00, 0x00, 0xc0, 0x3f,
+    ];
+    assert_eq!(expected_float_write, write_request_bytes(&float_write));
+
+    // Only the addressed bit is masked into the word
+    let bit_write = pccc
+        .write_bit_request(&PcccAddress::parse("B3/5").unwrap(), true)
+        .unwrap();
+
+    let expected_bit_write: Vec<CipByte> = vec![
+        0x4b, 0x02, 0x20, 0x67, 0x24, 0x01, 0x07, 0x4d, 0x00, 0x78, 0x56, 0x34, 0x12, 0x0f, 0x00,
+        0x02, 0x00, 0xab, 0x02, 0x03, 0x85, 0x00, 0x00, 0x20, 0x00, 0x20, 0x00,
+    ];
+    assert_eq_hex!(expected_bit_write, write_request_bytes(&bit_write));
+
+    // Values must match the file they are written to
+    assert!(pccc
+        .write_request(
+            &PcccAddress::parse("N7:0").unwrap(),
+            &[PcccValue::Float(1.5)]
+        )
+        .is_err());
+    assert!(pccc
+        .write_bit_request(&PcccAddress::parse("N7:0").unwrap(), true)
+        .is_err());
+}
+
+#[test]
+fn test_pccc_error_replies() {
+    let mut pccc = Pccc::new(0x004d, 0x12345678);
+    pccc.read_request(&PcccAddress::parse("N7:0").unwrap(), 1)
+        .unwrap();
+
+    // The extended status follows a 0xF0 status, e.g. 0x06 for an address that does not exist
+    let extended_error_reply = vec![
+        0xcb, 0x00, 0x00, 0x00, 0x07, 0x4d, 0x00, 0x78, 0x56, 0x34, 0x12, 0x4f, 0xf0, 0x01, 0x00,
+        0x06,
+    ];
+    let reply_error = pccc
+        .read_reply(&read_response(extended_error_reply))
+        .unwrap_err();
+    assert!(reply_error.to_string().contains("0xF0"));
+
+    // The reply to a different transaction is rejected
+    let stale_reply = vec![
+        0xcb, 0x00, 0x00, 0x00, 0x07, 0x4d, 0x00, 0x78, 0x56, 0x34, 0x12, 0x4f, 0x00, 0x09, 0x00,
+        0x2a, 0x00,
+    ];
+    assert!(pccc.read_reply(&read_response(stale_reply)).is_err());
+}
+
+#[test]
+fn test_decode_pccc_values() {
+    let bit_address = PcccAddress::parse("B3:0/4").unwrap();
+    assert_eq!(
+        vec![PcccValue::Bool(true), PcccValue::Bool(false)],
+        PcccValue::decode(&bit_address, &[0x10, 0x00, 0xef, 0xff]).unwrap()
+    );
+
+    let string_address = PcccAddress::parse("ST9:0").unwrap();
+    let string_data =
+        PcccValue::encode(&string_address, &[PcccValue::String("Hello".to_string())]).unwrap();
+
+    assert_eq!(84, string_data.len());
+    assert_eq!(
+        vec![0x05, 0x00, 0x65, 0x48, 0x6c, 0x6c, 0x00, 0x6f],
+        string_data[..8].to_vec()
+    );
+    assert_eq!(
+        vec![PcccValue::String("Hello".to_string())],
+        PcccValue::decode(&string_address, &string_data).unwrap()
+    );
+
+    // Partial values are rejected
+    assert!(PcccValue::decode(&PcccAddress::parse("L10:0").unwrap(), &[0x01, 0x00]).is_err());
+}
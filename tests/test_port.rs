@@ -0,0 +1,307 @@
+use hex_test_macros::prelude::*;
+
+use eipscanne_rs::cip::path::{PortSegment, RoutePath};
+use eipscanne_rs::cip::port::{
+    port_class_attribute_request, PortClassAttributeId, PortInstanceInfo, PortRead, PortType,
+    RouteDiscovery,
+};
+use eipscanne_rs::cip::types::CipByte;
+
+mod common;
+use common::{
+    attribute_error, attribute_reply, read_response, write_request_bytes, ATTRIBUTE_NOT_SUPPORTED,
+    OBJECT_DOES_NOT_EXIST,
+};
+
+#[test]
+fn test_serialize_port_instance_info_request() {
+    /*
+    Common Industrial Protocol
+    Service: Get Attribute Single (Request)
+        0... .... = Request/Response: Request (0x0)
+        .000 1110 = Service: Get Attribute Single (0x0e)
+    Request Path Size: 5 words
+    Request Path: Port, Instance: 0x0000, Attribute: 0x09
+        Path Segment: 0x21 (16-Bit Class Segment)
+            Class: Port (0x00f4)
+        Path Segment: 0x25 (16-Bit Instance Segment)
+            Instance: 0x0000
+        Path Segment: 0x30 (8-Bit Attribute Segment)
+            Attribute: 9
+
+    -------------------------------------
+    Hex Dump:
+
+    0000   0e 05 21 00 f4 00 25 00 00 00 30 09
+
+    */
+    let expected_byte_array: Vec<CipByte> = vec![
+        0x0e, 0x05, 0x21, 0x00, 0xf4, 0x00, 0x25, 0x00, 0x00, 0x00, 0x30, 0x09,
+    ];
+
+    let request = port_class_attribute_request(PortClassAttributeId::PortInstanceInfo);
+
+    assert_eq_hex!(expected_byte_array, write_request_bytes(&request));
+}
+
+#[test]
+fn test_deserialize_port_instance_info() {
+    /*
+    Common Industrial Protocol
+    Service: Get Attribute Single (Response)
+        1... .... = Request/Response: Response (0x1)
+        .000 1110 = Service: Get Attribute Single (0x0e)
+    Status: Success:
+        General Status: Success (0x00)
+        Additional Status Size: 0 words
+    Get Attribute Single (Response)
+        Port Instance Info
+            Instance 0
+                Port Type: Connection terminates (0)
+                Port Number: 0
+            Instance 1
+                Port Type: Backplane (1)
+                Port Number: 1
+            Instance 2
+                Port Type: EtherNet/IP (4)
+                Port Number: 2
+
+    -------------------------------------
+    Hex Dump:
+
+    0000   8e 00 00 00 00 00 00 00 01 00 01 00 04 00 02 00
+
+    */
+    let response = read_response(vec![
+        0x8e, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0x00, 0x01, 0x00, 0x04, 0x00, 0x02,
+        0x00,
+    ]);
+
+    let port_instances: PortInstanceInfo = response.response_data.read_reply().unwrap();
+    let ports = port_instances.ports();
+
+    assert_eq!(ports.len(), 2);
+    assert_eq!(ports[0].0, 1);
+    assert_eq!(ports[0].1.port_type, PortType::Backplane);
+    assert_eq!(ports[0].1.port_number, 1);
+    assert_eq!(ports[1].0, 2);
+    assert_eq!(ports[1].1.port_type, PortType::EtherNetIp);
+    assert_eq!(ports[1].1.port_number, 2);
+}
+
+#[test]
+fn test_port_read_skips_unsupported_attributes() {
+    let mut port_read = PortRead::new(2);
+
+    let responses = vec![
+        // Port Type: EtherNet/IP (4)
+        attribute_reply(&[0x04, 0x00]),
+        // Port Number: 2
+        attribute_reply(&[0x02, 0x00]),
+        // Link Object: TCP/IP Interface, Instance: 0x01
+        attribute_reply(&[0x02, 0x00, 0x20, 0xf5, 0x24, 0x01]),
+        // Port Name: "A"
+        attribute_reply(&[0x01, 0x41]),
+        // Port Type Name
+        attribute_error(ATTRIBUTE_NOT_SUPPORTED),
+        // Node Address: Port: 2, Address: 192.168.1.10
+        attribute_reply(&[
+            0x07, 0x00, 0x12, 0x0c, 0x31, 0x39, 0x32, 0x2e, 0x31, 0x36, 0x38, 0x2e, 0x31, 0x2e,
+            0x31, 0x30,
+        ]),
+        // Port Node Range
+        attribute_error(ATTRIBUTE_NOT_SUPPORTED),
+    ];
+
+    for response in responses {
+        assert!(port_read.next_request().is_some());
+        port_read.handle_response(&response).unwrap();
+    }
+
+    assert!(port_read.next_request().is_none());
+    let port = port_read.into_port().unwrap();
+
+    assert_eq!(port.port_type, Some(PortType::EtherNetIp));
+    assert_eq!(port.number(), 2);
+    assert_eq!(port.link_object.unwrap().path, vec![0x20, 0xf5, 0x24, 0x01]);
+    assert_eq!(port.name.as_deref(), Some("A"));
+    assert_eq!(port.type_name, None);
+    assert_eq!(
+        port.node_address,
+        Some(PortSegment::new_address(2, "192.168.1.10"))
+    );
+    assert_eq!(port.node_range, None);
+}
+
+#[test]
+fn test_route_discovery_through_backplane() {
+    /*
+    Common Industrial Protocol
+    Service: Unconnected Send (Request)
+        0... .... = Request/Response: Request (0x0)
+        .101 0010 = Service: Unconnected Send (0x52)
+    Request Path Size: 2 words
+    Request Path: Connection Manager, Instance: 0x01
+        Path Segment: 0x20 (8-Bit Class Segment)
+            Class: Connection Manager (0x06)
+        Path Segment: 0x24 (8-Bit Instance Segment)
+            Instance: 0x01
+    CIP Connection Manager
+        Priority/Time_tick: 0x0a
+            ...0 .... = Priority: Normal
+            .... 1010 = Tick time: 10
+        Time-out_ticks: 5
+        Actual Time Out: 5120ms
+        Message Size: 12
+        Message Request
+            Service: Get Attribute Single (Request)
+            Request Path Size: 5 words
+            Request Path: Identity, Instance: 0x0001, Attribute: 0x07
+        Route Path Size: 1 words
+        Reserved: 0x00
+        Route Path: Port: Backplane, Address: 0
+            Path Segment: 0x01 (Port Segment)
+                000. .... = Path Segment Type: Port Segment (0)
+                ...0 .... = Extended Link Address: False
+                .... 0001 = Port: Backplane (1)
+                Link Address: 0
+
+    -------------------------------------
+    Hex Dump:
+
+    0000   52 02 20 06 24 01 0a 05 0c 00 0e 05 21 00 01 00
+    0010   25 00 01 00 30 07 01 00 01 00
+
+    */
+    let expected_probe_bytes: Vec<CipByte> = vec![
+        0x52, 0x02, 0x20, 0x06, 0x24, 0x01, 0x0a, 0x05, 0x0c, 0x00, 0x0e, 0x05, 0x21, 0x00, 0x01,
+        0x00, 0x25, 0x00, 0x01, 0x00, 0x30, 0x07, 0x01, 0x00, 0x01, 0x00,
+    ];
+
+    let mut discovery = RouteDiscovery::new();
+
+    let adapter_responses = vec![
+        // Product Name: "1756-EN2T"
+        attribute_reply(&[0x09, 0x31, 0x37, 0x35, 0x36, 0x2d, 0x45, 0x4e, 0x32, 0x54]),
+        // Port Instance Info: Backplane (1), EtherNet/IP (2)
+        attribute_reply(&[
+            0x00, 0x00, 0x00, 0x00, 0x01, 0x00, 0x01, 0x00, 0x04, 0x00, 0x02, 0x00,
+        ]),
+        // Entry Port: 2
+        attribute_reply(&[0x02, 0x00]),
+        // Port 1: a backplane with slots 0 and 1
+        attribute_reply(&[0x01, 0x00]),
+        attribute_reply(&[0x01, 0x00]),
+        attribute_error(ATTRIBUTE_NOT_SUPPORTED),
+        attribute_error(ATTRIBUTE_NOT_SUPPORTED),
+        attribute_error(ATTRIBUTE_NOT_SUPPORTED),
+        attribute_reply(&[0x01, 0x00, 0x01, 0x02]),
+        attribute_reply(&[0x00, 0x00, 0x01, 0x00]),
+        // Port 2: the Ethernet port the requests come in through
+        attribute_reply(&[0x04, 0x00]),
+        attribute_reply(&[0x02, 0x00]),
+        attribute_error(OBJECT_DOES_NOT_EXIST),
+    ];
+
+    for response in adapter_responses {
+        assert!(discovery.next_request().is_some());
+        discovery.handle_response(&response).unwrap();
+    }
+
+    assert_eq_hex!(
+        expected_probe_bytes,
+        write_request_bytes(&discovery.next_request().unwrap())
+    );
+
+    let slot_responses = vec![
+        // Slot 0, Product Name: "1756-CN2R"
+        attribute_reply(&[0x09, 0x31, 0x37, 0x35, 0x36, 0x2d, 0x43, 0x4e, 0x32, 0x52]),
+        // Port Instance Info: Backplane (1), ControlNet (2)
+        attribute_reply(&[
+            0x00, 0x00, 0x00, 0x00, 0x01, 0x00, 0x01, 0x00, 0x02, 0x00, 0x02, 0x00,
+        ]),
+        // Entry Port is not supported, so the backplane is taken as the way in
+        attribute_error(ATTRIBUTE_NOT_SUPPORTED),
+        attribute_reply(&[0x01, 0x00]),
+        attribute_error(OBJECT_DOES_NOT_EXIST),
+        attribute_reply(&[0x02, 0x00]),
+        attribute_reply(&[0x02, 0x00]),
+        attribute_error(OBJECT_DOES_NOT_EXIST),
+        // Slot 1 is empty
+        read_response(vec![0xd2, 0x00, 0x01, 0x01, 0x04, 0x02, 0x01, 0x00]),
+    ];
+
+    for response in slot_responses {
+        assert!(discovery.next_request().is_some());
+        discovery.handle_response(&response).unwrap();
+    }
+
+    assert!(discovery.next_request().is_none());
+    let routes = discovery.into_routes().unwrap();
+
+    assert_eq!(routes.devices.len(), 2);
+    assert_eq!(routes.devices[0].route, RoutePath::default());
+    assert_eq!(routes.devices[0].product_name.as_deref(), Some("1756-EN2T"));
+    assert_eq!(routes.devices[1].route, RoutePath::new_backplane_slot(0));
+    assert_eq!(routes.devices[1].product_name.as_deref(), Some("1756-CN2R"));
+
+    assert_eq!(routes.networks.len(), 1);
+    assert_eq!(
+        routes.networks[0].port.port_type,
+        Some(PortType::ControlNet)
+    );
+    assert_eq!(
+        routes.networks[0].route_to_node(5),
+        RoutePath::from_route("1, 0, 2, 5").unwrap()
+    );
+}
+
+#[test]
+fn test_route_discovery_matches_entry_port_instance() {
+    let mut discovery = RouteDiscovery::new();
+
+    let adapter_responses = vec![
+        // Product Name: "A"
+        attribute_reply(&[0x01, 0x41]),
+        // Port Instance Info: instance 1 is EtherNet/IP (2), instance 2 is Backplane (1)
+        attribute_reply(&[
+            0x00, 0x00, 0x00, 0x00, 0x04, 0x00, 0x02, 0x00, 0x01, 0x00, 0x01, 0x00,
+        ]),
+        // Entry Port: instance 1, not port number 1
+        attribute_reply(&[0x01, 0x00]),
+        // Instance 1: the Ethernet port the requests come in through
+        attribute_reply(&[0x04, 0x00]),
+        attribute_reply(&[0x02, 0x00]),
+        attribute_error(OBJECT_DOES_NOT_EXIST),
+        // Instance 2: port 1, a backplane with slot 0
+        attribute_reply(&[0x01, 0x00]),
+        attribute_reply(&[0x01, 0x00]),
+        attribute_error(ATTRIBUTE_NOT_SUPPORTED),
+        attribute_error(ATTRIBUTE_NOT_SUPPORTED),
+        attribute_error(ATTRIBUTE_NOT_SUPPORTED),
+        attribute_reply(&[0x01, 0x00, 0x01, 0x02]),
+        attribute_reply(&[0x00, 0x00, 0x00, 0x00]),
+    ];
+
+    for response in adapter_responses {
+        assert!(discovery.next_request().is_some());
+        discovery.handle_response(&response).unwrap();
+    }
+
+    // The Ethernet port is not walked back out of, the backplane is probed instead
+    assert!(discovery.routes().networks.is_empty());
+    let probe_bytes = write_request_bytes(&discovery.next_request().unwrap());
+    assert_eq!(probe_bytes[0], 0x52);
+    assert_eq!(probe_bytes[probe_bytes.len() - 2..], [0x01, 0x00]);
+
+    // Slot 0 is empty
+    discovery
+        .handle_response(&read_response(vec![
+            0xd2, 0x00, 0x01, 0x01, 0x04, 0x02, 0x01, 0x00,
+        ]))
+        .unwrap();
+
+    let routes = discovery.into_routes().unwrap();
+    assert_eq!(routes.devices.len(), 1);
+    assert!(routes.networks.is_empty());
+}
@@ -0,0 +1,94 @@
+use hex_test_macros::prelude::*;
+
+use eipscanne_rs::cip::qos::{QosRead, QosSetting, TrafficClass};
+use eipscanne_rs::cip::types::CipByte;
+
+mod common;
+use common::{read_response, write_request_bytes};
+
+#[test]
+fn test_serialize_set_dscp_request() {
+    /*
+    Common Industrial Protocol
+    Service: Set Attribute Single (Request)
+        0... .... = Request/Response: Request (0x0)
+        .001 0000 = Service: Set Attribute Single (0x10)
+    Request Path Size: 5 words
+    Request Path: QoS, Instance: 0x0001, Attribute: 0x04
+        Path Segment: 0x21 (16-Bit Class Segment)
+            Class: QoS (0x0048)
+        Path Segment: 0x25 (16-Bit Instance Segment)
+            Instance: 0x0001
+        Path Segment: 0x30 (8-Bit Attribute Segment)
+            Attribute: 4
+    Set Attribute Single (Request)
+        DSCP Urgent: 46
+
+    -------------------------------------
+    Hex Dump:
+
+    0000   10 05 21 00 48 00 25 00 01 00 30 04 2e
+
+    */
+    let expected_byte_array: Vec<CipByte> = vec![
+        0x10, 0x05, 0x21, 0x00, 0x48, 0x00, 0x25, 0x00, 0x01, 0x00, 0x30, 0x04, 0x2e,
+    ];
+
+    let request_bytes =
+        write_request_bytes(&QosSetting::Dscp(TrafficClass::Urgent, 46).into_router_request());
+    assert_eq_hex!(expected_byte_array, request_bytes);
+}
+
+#[test]
+fn test_read_qos_settings() {
+    let mut qos_read = QosRead::new();
+
+    let responses = vec![
+        // 802.1Q Tag Enable: disabled
+        vec![0x8e, 0x00, 0x00, 0x00, 0x00],
+        // DSCP PTP Event: 59, PTP General: 47
+        vec![0x8e, 0x00, 0x00, 0x00, 0x3b],
+        vec![0x8e, 0x00, 0x00, 0x00, 0x2f],
+        // DSCP Urgent: 46 (changed from 55)
+        vec![0x8e, 0x00, 0x00, 0x00, 0x2e],
+        // DSCP Scheduled: 47, High: 43, Low: 31
+        vec![0x8e, 0x00, 0x00, 0x00, 0x2f],
+        vec![0x8e, 0x00, 0x00, 0x00, 0x2b],
+        vec![0x8e, 0x00, 0x00, 0x00, 0x1f],
+        // DSCP Explicit: not supported
+        vec![0x8e, 0x00, 0x14, 0x00],
+    ];
+
+    for response_bytes in responses {
+        assert!(!qos_read.is_complete());
+        assert!(qos_read.next_request().is_some());
+
+        qos_read
+            .handle_response(&read_response(response_bytes))
+            .unwrap();
+    }
+
+    assert!(qos_read.next_request().is_none());
+
+    let settings = qos_read.into_settings().unwrap();
+    assert_eq!(Some(false), settings.tag_enable);
+    assert_eq!(Some(59), settings.dscp(TrafficClass::PtpEvent));
+    assert_eq!(None, settings.dscp(TrafficClass::Explicit));
+    assert_eq!(
+        vec![(TrafficClass::Urgent, 46)],
+        settings.non_default_dscp()
+    );
+}
+
+#[test]
+fn test_read_qos_without_object() {
+    let mut qos_read = QosRead::new();
+
+    // Path Destination Unknown
+    qos_read
+        .handle_response(&read_response(vec![0x8e, 0x00, 0x05, 0x00]))
+        .unwrap();
+
+    assert!(qos_read.is_complete());
+    assert_eq!(None, qos_read.into_settings().unwrap().tag_enable);
+}
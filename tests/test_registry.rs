@@ -0,0 +1,60 @@
+use binrw::BinRead;
+
+use eipscanne_rs::cip::identity::{DeviceType, VendorId};
+use eipscanne_rs::cip::registry::{device_type_name, vendor_name, DEVICE_TYPE_NAMES, VENDOR_NAMES};
+use eipscanne_rs::cip::types::CipByte;
+
+#[test]
+fn test_registry_tables_are_sorted() {
+    // The lookups binary search the tables, so they must stay sorted without duplicates
+    assert!(VENDOR_NAMES.windows(2).all(|pair| pair[0].0 < pair[1].0));
+    assert!(DEVICE_TYPE_NAMES
+        .windows(2)
+        .all(|pair| pair[0].0 < pair[1].0));
+}
+
+#[test]
+fn test_registry_lookup() {
+    assert_eq!(
+        Some("Rockwell Automation/Allen-Bradley"),
+        vendor_name(0x0001)
+    );
+    assert_eq!(Some("Teknic, Inc."), vendor_name(0x01a8));
+    assert_eq!(None, vendor_name(0x0000));
+    assert_eq!(None, vendor_name(0xffff));
+
+    assert_eq!(
+        Some("Programmable Logic Controller"),
+        device_type_name(0x0e)
+    );
+    assert_eq!(Some("Generic Device (keyable)"), device_type_name(0x2b));
+    assert_eq!(None, device_type_name(0x01));
+}
+
+#[test]
+fn test_display_identity_values() {
+    let vendor_bytes: Vec<CipByte> = vec![0x01, 0x00];
+    let vendor_id = VendorId::read(&mut std::io::Cursor::new(vendor_bytes)).unwrap();
+
+    let device_type_bytes: Vec<CipByte> = vec![0x0e, 0x00];
+    let device_type = DeviceType::read(&mut std::io::Cursor::new(device_type_bytes)).unwrap();
+
+    // Vendors without a variant of their own still get their registered name
+    assert_eq!(VendorId::Unknown(0x0001), vendor_id);
+    assert_eq!(
+        "Rockwell Automation/Allen-Bradley, Programmable Logic Controller",
+        format!("{}, {}", vendor_id, device_type)
+    );
+
+    assert_eq!("Teknic, Inc.", VendorId::TeknicInc.to_string());
+    assert_eq!(VendorId::TeknicInc, VendorId::from(0x01a8));
+    assert_eq!(DeviceType::GenericDevice, DeviceType::from(0x002b));
+    assert_eq!(
+        "Unknown vendor (0xfffe)",
+        VendorId::from(0xfffe).to_string()
+    );
+    assert_eq!(
+        "Unknown device type (0x0001)",
+        DeviceType::Unknown(0x01).to_string()
+    );
+}
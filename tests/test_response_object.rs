@@ -5,7 +5,7 @@ use bilge::prelude::u4;
 use hex_test_macros::prelude::*;
 
 use eipscanne_rs::cip::identity::{
-    DeviceType, IdentityResponse, IdentityStatusBits, Revision, VendorId,
+    DeviceType, ExtendedDeviceStatus, IdentityResponse, IdentityStatusBits, Revision, VendorId,
 };
 use eipscanne_rs::cip::message::response::{
     MessageRouterResponse, ResponseData, ResponseStatusCode,
@@ -137,7 +137,7 @@ fn test_serialize_generic_response() {
             ),
         },
         cip_message: Some(MessageRouterResponse {
-            service_container: ServiceContainer::new(ServiceCode::GetAttributeAll, true).into(),
+            service_container: ServiceContainer::new(ServiceCode::GetAttributeAll, true),
             response_data: ResponseData {
                 status: ResponseStatusCode::Success,
                 additional_status_size: 0x0,
@@ -154,7 +154,7 @@ fn test_serialize_generic_response() {
                         false,
                         false,
                         false,
-                        u4::new(0x0),
+                        ExtendedDeviceStatus::SelfTestingOrUnknown,
                         false,
                         false,
                         false,
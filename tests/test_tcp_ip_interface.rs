@@ -0,0 +1,225 @@
+use std::net::Ipv4Addr;
+
+use binrw::BinRead;
+
+use hex_test_macros::prelude::*;
+
+use eipscanne_rs::cip::path::CipPath;
+use eipscanne_rs::cip::tcp_ip_interface::{
+    tcp_ip_attribute_request, ConfigurationCapability, ConfigurationControl, ConfigurationMethod,
+    InterfaceConfiguration, InterfaceConfigurationStatus, InterfaceStatus, PaddedString,
+    PhysicalLinkObject, TcpIpAttributeId, TcpIpSetting,
+};
+use eipscanne_rs::cip::types::CipByte;
+
+mod common;
+use common::{read_response, write_request_bytes};
+
+#[test]
+fn test_serialize_interface_configuration_request() {
+    /*
+    Common Industrial Protocol
+    Service: Get Attribute Single (Request)
+        0... .... = Request/Response: Request (0x0)
+        .000 1110 = Service: Get Attribute Single (0x0e)
+    Request Path Size: 5 words
+    Request Path: TCP/IP Interface, Instance: 0x0001, Attribute: 0x05
+        Path Segment: 0x21 (16-Bit Class Segment)
+            Class: TCP/IP Interface (0x00f5)
+        Path Segment: 0x25 (16-Bit Instance Segment)
+            Instance: 0x0001
+        Path Segment: 0x30 (8-Bit Attribute Segment)
+            Attribute: 5
+
+    -------------------------------------
+    Hex Dump:
+
+    0000   0e 05 21 00 f5 00 25 00 01 00 30 05
+
+    */
+    let expected_byte_array: Vec<CipByte> = vec![
+        0x0e, 0x05, 0x21, 0x00, 0xf5, 0x00, 0x25, 0x00, 0x01, 0x00, 0x30, 0x05,
+    ];
+
+    let request_bytes = write_request_bytes(&tcp_ip_attribute_request(
+        TcpIpAttributeId::InterfaceConfiguration,
+    ));
+    assert_eq_hex!(expected_byte_array, request_bytes);
+}
+
+#[test]
+fn test_deserialize_interface_configuration() {
+    /*
+    Common Industrial Protocol
+    Service: Get Attribute Single (Response)
+        1... .... = Request/Response: Response (0x1)
+        .000 1110 = Service: Get Attribute Single (0x0e)
+    Status: Success:
+        General Status: Success (0x00)
+        Additional Status Size: 0 words
+    Get Attribute Single (Response)
+        Interface Configuration
+            IP Address: 192.168.1.50
+            Subnet Mask: 255.255.255.0
+            Gateway: 192.168.1.1
+            Name Server: 0.0.0.0
+            Name Server2: 0.0.0.0
+            Domain Name: plant
+
+    -------------------------------------
+    Hex Dump:
+
+    0000   8e 00 00 00 32 01 a8 c0 00 ff ff ff 01 01 a8 c0
+    0010   00 00 00 00 00 00 00 00 05 00 70 6c 61 6e 74 00
+
+    */
+    let response = read_response(vec![
+        0x8e, 0x00, 0x00, 0x00, 0x32, 0x01, 0xa8, 0xc0, 0x00, 0xff, 0xff, 0xff, 0x01, 0x01, 0xa8,
+        0xc0, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x05, 0x00, 0x70, 0x6c, 0x61, 0x6e,
+        0x74, 0x00,
+    ]);
+
+    let configuration: InterfaceConfiguration = response.response_data.read_reply().unwrap();
+
+    assert_eq!(
+        InterfaceConfiguration::new(
+            Ipv4Addr::new(192, 168, 1, 50),
+            Ipv4Addr::new(255, 255, 255, 0),
+            Ipv4Addr::new(192, 168, 1, 1),
+        )
+        .with_domain_name("plant"),
+        configuration
+    );
+}
+
+#[test]
+fn test_deserialize_status_and_capability() {
+    // Status: configured from BOOTP/DHCP/NV, with an ACD conflict
+    let response = read_response(vec![0x8e, 0x00, 0x00, 0x00, 0x41, 0x00, 0x00, 0x00]);
+    let status: InterfaceStatus = response.response_data.read_reply().unwrap();
+
+    assert_eq!(
+        InterfaceConfigurationStatus::Configured,
+        status.interface_configuration_status()
+    );
+    assert!(status.acd_status());
+    assert!(!status.interface_configuration_pending());
+
+    // Capability: BOOTP, DNS, DHCP, settable and ACD capable
+    let response = read_response(vec![0x8e, 0x00, 0x00, 0x00, 0x97, 0x00, 0x00, 0x00]);
+    let capability: ConfigurationCapability = response.response_data.read_reply().unwrap();
+
+    assert!(capability.dhcp_client());
+    assert!(capability.configuration_settable());
+    assert!(capability.acd_capable());
+    assert!(!capability.hardware_configurable());
+
+    // Control: DHCP with DNS enabled
+    let response = read_response(vec![0x8e, 0x00, 0x00, 0x00, 0x12, 0x00, 0x00, 0x00]);
+    let control: ConfigurationControl = response.response_data.read_reply().unwrap();
+
+    assert_eq!(ConfigurationMethod::Dhcp, control.configuration_method());
+    assert!(control.dns_enable());
+}
+
+#[test]
+fn test_deserialize_physical_link_object() {
+    // Path Size: 2 words, Path: Ethernet Link, Instance: 0x01
+    let response = read_response(vec![
+        0x8e, 0x00, 0x00, 0x00, 0x02, 0x00, 0x20, 0xf6, 0x24, 0x01,
+    ]);
+    let physical_link: PhysicalLinkObject = response.response_data.read_reply().unwrap();
+
+    assert_eq!(
+        Some(CipPath::new_full(0xf6, 0x01, 0x00).class_id_segment),
+        physical_link.link_path().map(|path| path.class_id_segment)
+    );
+
+    let empty_response = read_response(vec![0x8e, 0x00, 0x00, 0x00, 0x00, 0x00]);
+    let empty_link: PhysicalLinkObject = empty_response.response_data.read_reply().unwrap();
+
+    assert_eq!(None, empty_link.link_path());
+}
+
+#[test]
+fn test_serialize_switch_to_dhcp() {
+    /*
+    Common Industrial Protocol
+    Service: Set Attribute Single (Request)
+        0... .... = Request/Response: Request (0x0)
+        .001 0000 = Service: Set Attribute Single (0x10)
+    Request Path Size: 5 words
+    Request Path: TCP/IP Interface, Instance: 0x0001, Attribute: 0x03
+    Set Attribute Single (Request)
+        Configuration Control: 0x00000002
+            .... .... .... .... .... .... .... 0010 = Configuration Method: DHCP (0x2)
+            .... .... .... .... .... .... ...0 .... = DNS Enable: 0
+
+    -------------------------------------
+    Hex Dump:
+
+    0000   10 05 21 00 f5 00 25 00 01 00 30 03 02 00 00 00
+
+    */
+    let expected_byte_array: Vec<CipByte> = vec![
+        0x10, 0x05, 0x21, 0x00, 0xf5, 0x00, 0x25, 0x00, 0x01, 0x00, 0x30, 0x03, 0x02, 0x00, 0x00,
+        0x00,
+    ];
+
+    let request_bytes = write_request_bytes(&TcpIpSetting::dhcp().into_router_request());
+    assert_eq_hex!(expected_byte_array, request_bytes);
+}
+
+#[test]
+fn test_serialize_switch_to_static_ip() {
+    let [control, configuration] = TcpIpSetting::static_ip(
+        InterfaceConfiguration::new(
+            Ipv4Addr::new(192, 168, 1, 50),
+            Ipv4Addr::new(255, 255, 255, 0),
+            Ipv4Addr::new(192, 168, 1, 1),
+        )
+        .with_name_servers(Ipv4Addr::new(8, 8, 8, 8), Ipv4Addr::UNSPECIFIED),
+    );
+
+    assert_eq!(
+        vec![
+            0x10, 0x05, 0x21, 0x00, 0xf5, 0x00, 0x25, 0x00, 0x01, 0x00, 0x30, 0x03, 0x00, 0x00,
+            0x00, 0x00
+        ],
+        write_request_bytes(&control.into_router_request())
+    );
+
+    assert_eq!(
+        vec![
+            0x10, 0x05, 0x21, 0x00, 0xf5, 0x00, 0x25, 0x00, 0x01, 0x00, 0x30, 0x05, 0x32, 0x01,
+            0xa8, 0xc0, 0x00, 0xff, 0xff, 0xff, 0x01, 0x01, 0xa8, 0xc0, 0x08, 0x08, 0x08, 0x08,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        ],
+        write_request_bytes(&configuration.into_router_request())
+    );
+}
+
+#[test]
+fn test_serialize_host_name_and_timeout() {
+    // The odd length host name is padded to an even number of bytes
+    assert_eq!(
+        vec![
+            0x10, 0x05, 0x21, 0x00, 0xf5, 0x00, 0x25, 0x00, 0x01, 0x00, 0x30, 0x06, 0x03, 0x00,
+            0x70, 0x6c, 0x63, 0x00,
+        ],
+        write_request_bytes(&TcpIpSetting::HostName("plc".to_string()).into_router_request())
+    );
+
+    assert_eq!(
+        vec![0x10, 0x05, 0x21, 0x00, 0xf5, 0x00, 0x25, 0x00, 0x01, 0x00, 0x30, 0x0d, 0x78, 0x00],
+        write_request_bytes(
+            &TcpIpSetting::EncapsulationInactivityTimeout(120).into_router_request()
+        )
+    );
+
+    let mut reader = std::io::Cursor::new(vec![0x03, 0x00, 0x70, 0x6c, 0x63, 0x00]);
+    let host_name = PaddedString::read(&mut reader).unwrap();
+
+    assert_eq!("plc", String::from(host_name));
+    assert_eq!(6, reader.position());
+}
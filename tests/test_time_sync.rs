@@ -0,0 +1,230 @@
+use binrw::BinRead;
+use hex_test_macros::prelude::*;
+
+use eipscanne_rs::cip::time_sync::{
+    common_grandmaster, time_sync_attribute_request, ClockIdentity, GrandmasterClockInfo,
+    ParentClockInfo, PortState, PortStateEntry, SyncProblem, TimePropertyFlags, TimeSource,
+    TimeSyncAttributeId, TimeSyncRead, TimeSyncStatus,
+};
+use eipscanne_rs::cip::types::CipByte;
+
+mod common;
+use common::{read_response, write_request_bytes};
+
+const GRANDMASTER: ClockIdentity = ClockIdentity([0x00, 0x1d, 0x9c, 0xff, 0xfe, 0x01, 0x02, 0x03]);
+
+fn synchronized_status(grandmaster_identity: ClockIdentity) -> TimeSyncStatus {
+    TimeSyncStatus {
+        ptp_enable: Some(true),
+        is_synchronized: Some(true),
+        grandmaster: Some(GrandmasterClockInfo {
+            clock_identity: grandmaster_identity,
+            clock_class: 6,
+            time_accuracy: 0x21,
+            offset_scaled_log_variance: 0x4e5d,
+            current_utc_offset: 37,
+            time_property_flags: TimePropertyFlags::from(0x000c),
+            time_source: TimeSource::Gps,
+            priority1: 128,
+            priority2: 128,
+        }),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn test_serialize_grandmaster_clock_info_request() {
+    /*
+    Common Industrial Protocol
+    Service: Get Attribute Single (Request)
+        0... .... = Request/Response: Request (0x0)
+        .000 1110 = Service: Get Attribute Single (0x0e)
+    Request Path Size: 5 words
+    Request Path: Time Sync, Instance: 0x0001, Attribute: 0x08
+        Path Segment: 0x21 (16-Bit Class Segment)
+            Class: Time Sync (0x0043)
+        Path Segment: 0x25 (16-Bit Instance Segment)
+            Instance: 0x0001
+        Path Segment: 0x30 (8-Bit Attribute Segment)
+            Attribute: 8
+
+    -------------------------------------
+    Hex Dump:
+
+    0000   0e 05 21 00 43 00 25 00 01 00 30 08
+
+    */
+    let expected_byte_array: Vec<CipByte> = vec![
+        0x0e, 0x05, 0x21, 0x00, 0x43, 0x00, 0x25, 0x00, 0x01, 0x00, 0x30, 0x08,
+    ];
+
+    let request_bytes = write_request_bytes(&time_sync_attribute_request(
+        TimeSyncAttributeId::GrandmasterClockInfo,
+    ));
+    assert_eq_hex!(expected_byte_array, request_bytes);
+}
+
+#[test]
+fn test_read_time_sync_status() {
+    let mut time_sync_read = TimeSyncRead::new();
+
+    let responses = vec![
+        // PTP Enable: true
+        vec![0x8e, 0x00, 0x00, 0x00, 0x01],
+        // Is Synchronized: true
+        vec![0x8e, 0x00, 0x00, 0x00, 0x01],
+        // System Time Microseconds: 1000000000000
+        vec![
+            0x8e, 0x00, 0x00, 0x00, 0x00, 0x10, 0xa5, 0xd4, 0xe8, 0x00, 0x00, 0x00,
+        ],
+        // Offset From Master: -25
+        vec![
+            0x8e, 0x00, 0x00, 0x00, 0xe7, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+        ],
+        // Max Offset From Master: not supported
+        vec![0x8e, 0x00, 0x14, 0x00],
+        // Mean Path Delay To Master: 500
+        vec![
+            0x8e, 0x00, 0x00, 0x00, 0xf4, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        ],
+        // Grandmaster Clock Info: class 6, GPS, priorities 128
+        vec![
+            0x8e, 0x00, 0x00, 0x00, 0x00, 0x1d, 0x9c, 0xff, 0xfe, 0x01, 0x02, 0x03, 0x06, 0x00,
+            0x21, 0x00, 0x5d, 0x4e, 0x25, 0x00, 0x0c, 0x00, 0x20, 0x00, 0x80, 0x00, 0x80, 0x00,
+        ],
+        // Parent Clock Info: the grandmaster, port 1
+        vec![
+            0x8e, 0x00, 0x00, 0x00, 0x00, 0x1d, 0x9c, 0xff, 0xfe, 0x01, 0x02, 0x03, 0x01, 0x00,
+            0xff, 0xff, 0xff, 0xff, 0xff, 0x7f,
+        ],
+        // Local Clock Info: not supported
+        vec![0x8e, 0x00, 0x14, 0x00],
+        // Port State Info: port 1 Slave, port 2 Master
+        vec![
+            0x8e, 0x00, 0x00, 0x00, 0x02, 0x00, 0x01, 0x00, 0x09, 0x00, 0x02, 0x00, 0x06, 0x00,
+        ],
+        // Domain Number: 0
+        vec![0x8e, 0x00, 0x00, 0x00, 0x00],
+        // Steps Removed: 1
+        vec![0x8e, 0x00, 0x00, 0x00, 0x01, 0x00],
+    ];
+
+    for response_bytes in responses {
+        assert!(!time_sync_read.is_complete());
+        assert!(time_sync_read.next_request().is_some());
+
+        time_sync_read
+            .handle_response(&read_response(response_bytes))
+            .unwrap();
+    }
+
+    assert!(time_sync_read.next_request().is_none());
+
+    let status = time_sync_read.into_status().unwrap();
+    assert_eq!(Some(true), status.is_synchronized);
+    assert_eq!(Some(1_000_000_000_000), status.system_time);
+    assert_eq!(Some(-25), status.offset_from_master);
+    assert_eq!(None, status.max_offset_from_master);
+    assert_eq!(Some(500), status.mean_path_delay_to_master);
+    assert_eq!(None, status.local_clock);
+    assert_eq!(Some(1), status.steps_removed);
+
+    let grandmaster = status.grandmaster.unwrap();
+    assert_eq!(
+        "00:1d:9c:ff:fe:01:02:03",
+        grandmaster.clock_identity.to_string()
+    );
+    assert_eq!(TimeSource::Gps, grandmaster.time_source);
+    assert!(grandmaster.time_property_flags.ptp_timescale());
+    assert!(grandmaster.time_property_flags.current_utc_offset_valid());
+    assert_eq!(37, grandmaster.current_utc_offset);
+
+    assert_eq!(1, status.parent.unwrap().port_number);
+    assert_eq!(
+        Some(vec![
+            PortStateEntry {
+                port_number: 1,
+                port_state: PortState::Slave,
+            },
+            PortStateEntry {
+                port_number: 2,
+                port_state: PortState::Master,
+            },
+        ]),
+        status.port_states
+    );
+    assert!(status.is_locked_to(&GRANDMASTER));
+}
+
+#[test]
+fn test_deserialize_parent_clock_info() {
+    // The grandmaster on port 1, its clock running slower than the local clock
+    let parent_clock_bytes: Vec<CipByte> = vec![
+        0x00, 0x1d, 0x9c, 0xff, 0xfe, 0x01, 0x02, 0x03, 0x01, 0x00, 0xff, 0xff, 0x9c, 0xff, 0xff,
+        0xff,
+    ];
+
+    let parent_clock =
+        ParentClockInfo::read(&mut std::io::Cursor::new(parent_clock_bytes)).unwrap();
+
+    assert_eq!(
+        ParentClockInfo {
+            clock_identity: GRANDMASTER,
+            port_number: 1,
+            observed_offset_scaled_log_variance: 0xffff,
+            observed_phase_change_rate: -100,
+        },
+        parent_clock
+    );
+}
+
+#[test]
+fn test_read_time_sync_without_object() {
+    let mut time_sync_read = TimeSyncRead::new();
+
+    // Path Destination Unknown
+    time_sync_read
+        .handle_response(&read_response(vec![0x8e, 0x00, 0x05, 0x00]))
+        .unwrap();
+
+    assert!(time_sync_read.is_complete());
+    let status = time_sync_read.into_status().unwrap();
+    assert_eq!(TimeSyncStatus::default(), status);
+    assert_eq!(
+        Some(SyncProblem::Unsupported),
+        status.sync_problem(&GRANDMASTER)
+    );
+}
+
+#[test]
+fn test_common_grandmaster() {
+    let other_grandmaster = ClockIdentity([0x00, 0x1d, 0x9c, 0xff, 0xfe, 0x0a, 0x0b, 0x0c]);
+
+    let mut unsynchronized = synchronized_status(other_grandmaster);
+    unsynchronized.is_synchronized = Some(false);
+
+    let statuses = vec![
+        synchronized_status(GRANDMASTER),
+        synchronized_status(other_grandmaster),
+        synchronized_status(GRANDMASTER),
+        unsynchronized.clone(),
+        unsynchronized.clone(),
+    ];
+
+    // Devices that are not synchronized do not vote
+    assert_eq!(Some(GRANDMASTER), common_grandmaster(&statuses));
+    assert_eq!(None, common_grandmaster(&[]));
+
+    assert_eq!(
+        Some(SyncProblem::OtherGrandmaster(other_grandmaster)),
+        statuses[1].sync_problem(&GRANDMASTER)
+    );
+    assert_eq!(
+        Some(SyncProblem::NotSynchronized),
+        unsynchronized.sync_problem(&GRANDMASTER)
+    );
+    assert_eq!(
+        "is locked to grandmaster 00:1d:9c:ff:fe:0a:0b:0c",
+        SyncProblem::OtherGrandmaster(other_grandmaster).to_string()
+    );
+}
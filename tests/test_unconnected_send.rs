@@ -0,0 +1,243 @@
+use std::time::Duration;
+
+use binrw::{BinRead, BinWrite};
+
+use hex_test_macros::prelude::*;
+
+use eipscanne_rs::cip::connection_manager::{
+    RoutingError, UnconnectedSend, UnconnectedSendRequest,
+};
+use eipscanne_rs::cip::message::request::MessageRouterRequest;
+use eipscanne_rs::cip::message::response::ResponseStatusCode;
+use eipscanne_rs::cip::message::shared::ServiceCode;
+use eipscanne_rs::cip::path::{CipPath, PortSegment, RoutePath};
+use eipscanne_rs::cip::types::CipByte;
+use eipscanne_rs::logix::shared::LogixServiceCode;
+
+mod common;
+use common::{read_response, write_request_bytes};
+
+#[test]
+fn test_serialize_unconnected_send_request() {
+    /*
+    Common Industrial Protocol
+    Service: Unconnected Send (Request)
+        0... .... = Request/Response: Request (0x0)
+        .101 0010 = Service: Unconnected Send (0x52)
+    Request Path Size: 2 words
+    Request Path: Connection Manager, Instance: 0x01
+        Path Segment: 0x20 (8-Bit Class Segment)
+            Class: Connection Manager (0x06)
+        Path Segment: 0x24 (8-Bit Instance Segment)
+            Instance: 0x01
+    CIP Connection Manager
+        Priority/Time_tick: 0x0a
+            ...0 .... = Priority: Normal
+            .... 1010 = Tick time: 10
+        Time-out_ticks: 5
+        Actual Time Out: 5120ms
+        Message Size: 10
+        Message Request
+            Service: Get Attributes All (Request)
+            Request Path Size: 4 words
+            Request Path: Identity, Instance: 0x0001
+        Route Path Size: 1 words
+        Reserved: 0x00
+        Route Path: Port: Backplane, Address: 3
+            Path Segment: 0x01 (Port Segment)
+                000. .... = Path Segment Type: Port Segment (0)
+                ...0 .... = Extended Link Address: False
+                .... 0001 = Port: Backplane (1)
+                Link Address: 3
+
+    -------------------------------------
+    Hex Dump:
+
+    0000   52 02 20 06 24 01 0a 05 0a 00 01 04 21 00 01 00
+    0010   25 00 01 00 01 00 01 03
+
+    */
+    let expected_byte_array: Vec<CipByte> = vec![
+        0x52, 0x02, 0x20, 0x06, 0x24, 0x01, 0x0a, 0x05, 0x0a, 0x00, 0x01, 0x04, 0x21, 0x00, 0x01,
+        0x00, 0x25, 0x00, 0x01, 0x00, 0x01, 0x00, 0x01, 0x03,
+    ];
+
+    let unconnected_send =
+        UnconnectedSend::new(RoutePath::from_route("backplane 1, slot 3").unwrap());
+
+    let identity_request =
+        MessageRouterRequest::new(ServiceCode::GetAttributeAll, CipPath::new(0x1, 0x1));
+
+    let request_bytes = write_request_bytes(&unconnected_send.wrap(identity_request));
+
+    assert_eq_hex!(expected_byte_array, request_bytes);
+}
+
+#[test]
+fn test_serialize_unconnected_send_padded_message() {
+    // A 9 byte Set Attribute Single, which needs a pad byte before the route path
+    let set_request = MessageRouterRequest::new_data(
+        ServiceCode::SetAttributeSingle,
+        CipPath::new_full(0x1, 0x1, 0x7),
+        Some(Box::new(0x01u8)),
+    );
+
+    let unconnected_send =
+        UnconnectedSend::new(RoutePath::new_backplane_slot(0)).with_timeout(Duration::from_secs(2));
+
+    let expected_byte_array: Vec<CipByte> = vec![
+        0x52, 0x02, 0x20, 0x06, 0x24, 0x01, 0x03, 0xfa, 0x09, 0x00, 0x10, 0x03, 0x20, 0x01, 0x24,
+        0x01, 0x30, 0x07, 0x01, 0x00, 0x01, 0x00, 0x01, 0x00,
+    ];
+    assert_eq!(
+        expected_byte_array,
+        write_request_bytes(&unconnected_send.wrap(set_request))
+    );
+
+    // The request data reads back into the wrapped message and the route
+    let unconnected_send_request = UnconnectedSendRequest::read_le(&mut std::io::Cursor::new(
+        expected_byte_array[6..].to_vec(),
+    ))
+    .unwrap();
+
+    assert_eq!(250, unconnected_send_request.timeout_ticks);
+    assert_eq!(
+        RoutePath::new_backplane_slot(0),
+        unconnected_send_request.route_path
+    );
+    assert_eq!(
+        ServiceCode::SetAttributeSingle,
+        unconnected_send_request
+            .message_request
+            .service_container
+            .service()
+    );
+}
+
+#[test]
+fn test_parse_route_through_bridge() {
+    let route_path = RoutePath::from_route("1, 3, 2, 192.168.1.10, backplane, slot 0").unwrap();
+
+    assert_eq!(
+        RoutePath::new(vec![
+            PortSegment::new_slot(1, 3),
+            PortSegment::new_address(2, "192.168.1.10"),
+            PortSegment::new_slot(1, 0),
+        ]),
+        route_path
+    );
+
+    /*
+    Route Path: Port: Backplane, Address: 3, Port: A, Address: 192.168.1.10, Port: Backplane, Address: 0
+        Path Segment: 0x01 (Port Segment)
+            Link Address: 3
+        Path Segment: 0x12 (Port Segment)
+            ...1 .... = Extended Link Address: True
+            .... 0010 = Port: A (2)
+            Link Address Size: 12
+            Link Address: 192.168.1.10
+        Path Segment: 0x01 (Port Segment)
+            Link Address: 0
+
+    -------------------------------------
+    Hex Dump:
+
+    0000   01 03 12 0c 31 39 32 2e 31 36 38 2e 31 2e 31 30
+    0010   01 00
+
+    */
+    let expected_byte_array: Vec<CipByte> = vec![
+        0x01, 0x03, 0x12, 0x0c, 0x31, 0x39, 0x32, 0x2e, 0x31, 0x36, 0x38, 0x2e, 0x31, 0x2e, 0x31,
+        0x30, 0x01, 0x00,
+    ];
+
+    let mut route_bytes: Vec<u8> = Vec::new();
+    route_path
+        .write_le(&mut std::io::Cursor::new(&mut route_bytes))
+        .unwrap();
+
+    assert_eq_hex!(expected_byte_array, route_bytes);
+    assert_eq!(9, route_path.word_size());
+
+    // Odd sized addresses and ports above 14 are padded and extended
+    let extended_route = RoutePath::new(vec![PortSegment::new_address(18, "10.0.0.10")]);
+    let mut extended_bytes: Vec<u8> = Vec::new();
+    extended_route
+        .write_le(&mut std::io::Cursor::new(&mut extended_bytes))
+        .unwrap();
+
+    assert_eq!(
+        vec![
+            0x1f, 0x09, 0x12, 0x00, 0x31, 0x30, 0x2e, 0x30, 0x2e, 0x30, 0x2e, 0x31, 0x30, 0x00
+        ],
+        extended_bytes
+    );
+    assert_eq!(
+        extended_route,
+        RoutePath::read_le_args(&mut std::io::Cursor::new(extended_bytes), (7,)).unwrap()
+    );
+
+    assert!(RoutePath::from_route("1, 3, 2").is_err());
+    assert!(RoutePath::from_route("backplane, slot 300").is_err());
+}
+
+#[test]
+fn test_unwrap_unconnected_send_replies() {
+    // The target's reply is returned as-is, even when the target rejects the request
+    let target_error = UnconnectedSend::unwrap_response(
+        read_response(vec![0x81, 0x00, 0x14, 0x00]),
+        ServiceCode::GetAttributeAll,
+    )
+    .unwrap();
+
+    assert_eq!(
+        ResponseStatusCode::AttributeNotSupported,
+        target_error.response_data.status
+    );
+
+    /*
+    Common Industrial Protocol
+    Service: Unconnected Send (Response)
+        1... .... = Request/Response: Response (0x1)
+        .101 0010 = Service: Unconnected Send (0x52)
+    Status: Connection failure: Unconnected Send timed out waiting for a response
+        General Status: Connection failure (0x01)
+        Additional Status Size: 1 words
+        Additional Status: 0x0204
+    Remaining Path Size: 1
+
+    -------------------------------------
+    Hex Dump:
+
+    0000   d2 00 01 01 04 02 01 00
+
+    */
+    let routing_error = UnconnectedSend::unwrap_response(
+        read_response(vec![0xd2, 0x00, 0x01, 0x01, 0x04, 0x02, 0x01, 0x00]),
+        ServiceCode::GetAttributeAll,
+    )
+    .unwrap_err();
+
+    assert_eq!(
+        Some(&RoutingError {
+            status: ResponseStatusCode::ConnectionFailure,
+            additional_status: vec![0x0204],
+            remaining_path_size: Some(1),
+        }),
+        routing_error.custom_err::<RoutingError>()
+    );
+
+    // A fragmented tag read shares the Unconnected Send service code, so its status belongs to the target
+    let partial_read = UnconnectedSend::unwrap_response(
+        read_response(vec![
+            0xd2, 0x00, 0x06, 0x00, 0xc4, 0x00, 0x01, 0x00, 0x00, 0x00,
+        ]),
+        LogixServiceCode::ReadTagFragmented.into(),
+    )
+    .unwrap();
+
+    assert_eq!(
+        ResponseStatusCode::PartialTransfer,
+        partial_read.response_data.status
+    );
+}